@@ -6,9 +6,9 @@ use crate::app_config::AppType;
 use crate::cli::commands::config_common;
 use crate::cli::commands::config_webdav;
 use crate::cli::i18n::texts;
-use crate::cli::ui::{error, highlight, info, success, to_json};
+use crate::cli::ui::{error, highlight, info, success, to_json, warning};
 use crate::error::AppError;
-use crate::services::ConfigService;
+use crate::services::{ConfigSections, ConfigService, ProviderService};
 use crate::store::AppState;
 
 #[derive(Subcommand)]
@@ -21,12 +21,23 @@ pub enum ConfigCommand {
     Export {
         /// Output file path
         file: PathBuf,
+        /// Only export these sections, comma-separated (providers, mcp, prompts); merged on import
+        #[arg(long)]
+        sections: Option<String>,
     },
     /// Import configuration from file
     Import {
         /// Input file path
         file: PathBuf,
     },
+    /// Import configuration from an http(s) URL (e.g. a team's shared base config)
+    ImportUrl {
+        /// URL of a CC Switch SQL export
+        url: String,
+        /// Replace existing local providers and MCP servers
+        #[arg(long)]
+        overwrite: bool,
+    },
     /// Create a backup of current configuration
     Backup {
         /// Optional custom name for the backup
@@ -43,10 +54,17 @@ pub enum ConfigCommand {
         #[arg(long, conflicts_with = "backup")]
         file: Option<PathBuf>,
     },
+    /// Inspect an exported configuration file without importing it
+    Inspect {
+        /// Exported SQL file path
+        file: PathBuf,
+    },
     /// Validate configuration file
     Validate,
     /// Reset to default configuration
     Reset,
+    /// Print current settings as JSON with secrets replaced, for pasting into bug reports
+    Template,
 
     /// Manage common configuration snippet (per app)
     #[command(subcommand)]
@@ -54,23 +72,29 @@ pub enum ConfigCommand {
 
     /// Manage WebDAV sync settings and operations
     #[command(name = "webdav", subcommand)]
-    WebDav(config_webdav::WebDavCommand),
+    WebDav(Box<config_webdav::WebDavCommand>),
 }
 
 pub fn execute(cmd: ConfigCommand, app: Option<AppType>) -> Result<(), AppError> {
     match cmd {
         ConfigCommand::Show => show_config(),
         ConfigCommand::Path => show_path(),
-        ConfigCommand::Export { file } => export_config(&file),
+        ConfigCommand::Export { file, sections } => export_config(&file, sections.as_deref()),
         ConfigCommand::Import { file } => import_config(&file),
+        ConfigCommand::ImportUrl { url, overwrite } => import_config_from_url(&url, overwrite),
         ConfigCommand::Backup { name } => backup_config(name.as_deref()),
         ConfigCommand::Restore { backup, file } => {
             restore_config(backup.as_deref(), file.as_deref())
         }
+        ConfigCommand::Inspect { file } => inspect_config(&file),
         ConfigCommand::Validate => validate_config(),
         ConfigCommand::Reset => reset_config(),
+        ConfigCommand::Template => {
+            println!("{}", crate::settings::export_template());
+            Ok(())
+        }
         ConfigCommand::Common(cmd) => config_common::execute(cmd, app.unwrap_or(AppType::Claude)),
-        ConfigCommand::WebDav(cmd) => config_webdav::execute(cmd),
+        ConfigCommand::WebDav(cmd) => config_webdav::execute(*cmd),
     }
 }
 
@@ -130,11 +154,19 @@ fn show_path() -> Result<(), AppError> {
     Ok(())
 }
 
-fn export_config(file: &PathBuf) -> Result<(), AppError> {
+fn export_config(file: &PathBuf, sections: Option<&str>) -> Result<(), AppError> {
+    let sections = sections.map(ConfigSections::parse).transpose()?;
+
     println!(
         "{}",
         info(&format!("Exporting configuration to {}...", file.display()))
     );
+    if sections.is_none_or(|sections| sections.providers) {
+        println!(
+            "{}",
+            warning("The export includes provider API keys in plain text; share it only with trusted people.")
+        );
+    }
 
     // Check if target file already exists
     if file.exists() {
@@ -158,12 +190,26 @@ fn export_config(file: &PathBuf) -> Result<(), AppError> {
     }
 
     // Export configuration
-    ConfigService::export_config_to_path(file)?;
-
-    println!(
-        "{}",
-        success(&format!("✓ Configuration exported to {}", file.display()))
-    );
+    match sections {
+        Some(sections) => {
+            ConfigService::export_sections(sections, file)?;
+            println!(
+                "{}",
+                success(&format!(
+                    "✓ Sections {} exported to {}",
+                    sections.names().join(", "),
+                    file.display()
+                ))
+            );
+        }
+        None => {
+            ConfigService::export_config_to_path(file)?;
+            println!(
+                "{}",
+                success(&format!("✓ Configuration exported to {}", file.display()))
+            );
+        }
+    }
 
     Ok(())
 }
@@ -185,10 +231,18 @@ fn import_config(file: &PathBuf) -> Result<(), AppError> {
         )));
     }
 
+    let sections = ConfigService::inspect_bundle(file)?.sections;
+
     // Confirm import
     println!();
     println!("{}", highlight("Warning:"));
-    println!("This will replace your current database with the imported SQL backup.");
+    match sections {
+        Some(sections) => println!(
+            "This will merge {} from the file into your current configuration.",
+            sections.names().join(", ")
+        ),
+        None => println!("This will replace your current database with the imported SQL backup."),
+    }
     println!("A backup will be created automatically.");
     println!();
 
@@ -227,6 +281,44 @@ fn import_config(file: &PathBuf) -> Result<(), AppError> {
     Ok(())
 }
 
+fn import_config_from_url(url: &str, overwrite: bool) -> Result<(), AppError> {
+    let display_url = crate::services::webdav::redact_url(url);
+    println!(
+        "{}",
+        info(&format!("Importing configuration from {display_url}..."))
+    );
+
+    let state = get_state()?;
+    let (backup_id, report) = ConfigService::import_from_url(url, overwrite, &state)?;
+
+    // 导入后同步 live 配置
+    if let Err(e) = crate::services::provider::ProviderService::sync_current_to_live(&state) {
+        log::warn!("配置导入后同步 live 配置失败: {e}");
+    }
+
+    println!(
+        "{}",
+        success(&format!("✓ Configuration imported from {display_url}"))
+    );
+    println!(
+        "  Providers: {}, MCP servers: {}, Prompts: {}",
+        report.providers, report.mcp_servers, report.prompts
+    );
+    for warning_text in &report.warnings {
+        println!("{}", warning(&format!("  - {warning_text}")));
+    }
+    if !backup_id.is_empty() {
+        println!("{}", info(&format!("  Backup created: {}", backup_id)));
+    }
+    println!();
+    println!(
+        "{}",
+        info("Note: Restart your CLI clients to apply the changes.")
+    );
+
+    Ok(())
+}
+
 fn backup_config(custom_name: Option<&str>) -> Result<(), AppError> {
     let config_path = crate::config::get_app_config_path();
 
@@ -436,6 +528,43 @@ fn restore_config(backup_id: Option<&str>, file_path: Option<&Path>) -> Result<(
     Ok(())
 }
 
+fn inspect_config(file: &Path) -> Result<(), AppError> {
+    println!("{}", info(&format!("Inspecting {}...", file.display())));
+    println!();
+
+    let report = ConfigService::inspect_bundle(file)?;
+
+    println!("{} SQL export parsed", success("✓"));
+    println!(
+        "Schema version:    {} (supported: {})",
+        report.schema_version, report.supported_schema_version
+    );
+    if let Some(exported_at) = &report.exported_at {
+        println!("Exported at:       {}", exported_at);
+    }
+    println!("Providers:         {}", report.providers);
+    println!("MCP servers:       {}", report.mcp_servers);
+    println!("Prompts:           {}", report.prompts);
+    if let Some(sections) = report.sections {
+        println!(
+            "Sections:          {} (partial export, merged on import)",
+            sections.names().join(", ")
+        );
+    }
+
+    println!();
+    if report.warnings.is_empty() {
+        println!("{}", success("✓ File can be imported"));
+    } else {
+        println!("{}", highlight("Warnings:"));
+        for warning in &report.warnings {
+            println!("  - {}", warning);
+        }
+    }
+
+    Ok(())
+}
+
 fn validate_config() -> Result<(), AppError> {
     let config_dir = crate::config::get_app_config_dir();
     let db_path = config_dir.join("cc-switch.db");
@@ -470,12 +599,56 @@ fn validate_config() -> Result<(), AppError> {
     println!("MCP servers:       {}", mcp_count);
     println!("Skills installed:  {}", skills_count);
 
+    let duplicates = duplicate_endpoint_warnings(&get_state()?);
+    if !duplicates.is_empty() {
+        println!();
+        for line in &duplicates {
+            println!("{}", warning(line));
+        }
+    }
+
+    let settings_warnings = crate::settings::settings_health().warnings();
+    if !settings_warnings.is_empty() {
+        println!();
+        for line in &settings_warnings {
+            println!("{}", warning(line));
+        }
+    }
+
     println!();
     println!("{}", success("✓ Database validation passed"));
 
     Ok(())
 }
 
+/// 各应用中 base URL 相同的供应商，每组一行警告；读取失败的应用跳过
+pub(crate) fn duplicate_endpoint_warnings(state: &AppState) -> Vec<String> {
+    let mut lines = Vec::new();
+    for app_type in AppType::all() {
+        let groups =
+            ProviderService::find_duplicate_endpoints(state, app_type.clone()).unwrap_or_default();
+        for group in groups {
+            let members: Vec<String> = group
+                .members
+                .iter()
+                .map(|member| {
+                    if member.has_api_key {
+                        member.id.clone()
+                    } else {
+                        texts::config_validation_member_without_key(&member.id)
+                    }
+                })
+                .collect();
+            lines.push(texts::config_validation_duplicate_endpoint(
+                app_type.as_str(),
+                &group.base_url,
+                &members,
+            ));
+        }
+    }
+    lines
+}
+
 fn reset_config() -> Result<(), AppError> {
     println!("{}", highlight("Reset Configuration"));
     println!("{}", "=".repeat(50));
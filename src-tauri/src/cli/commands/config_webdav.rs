@@ -1,10 +1,15 @@
 use clap::Subcommand;
 
-use crate::cli::ui::{highlight, info, success, warning};
+use crate::cli::i18n::{current_language, format_bytes};
+use crate::cli::ui::{error, highlight, info, success, warning};
 use crate::error::AppError;
+use crate::services::webdav::detect_provider;
 use crate::{
-    get_webdav_sync_settings, set_webdav_sync_settings, webdav_jianguoyun_preset,
-    WebDavSyncService, WebDavSyncSettings,
+    get_active_webdav_target, get_webdav_sync_settings, get_webdav_target, list_webdav_targets,
+    set_active_webdav_target, set_webdav_sync_settings, upsert_webdav_target,
+    webdav_jianguoyun_preset, CompressionAlgo, ConflictPolicy, DiagnosticCheck, DiagnosticStatus,
+    SkillsStorage, SyncDecision, WebDavSyncService, WebDavSyncSettings, WebDavSyncStatus,
+    WebDavSyncSummary,
 };
 
 #[derive(Subcommand, Debug, Clone)]
@@ -14,6 +19,10 @@ pub enum WebDavCommand {
 
     /// Create or update WebDAV sync settings
     Set {
+        /// Save into this named target instead of the active one
+        #[arg(long)]
+        target: Option<String>,
+
         #[arg(long)]
         base_url: Option<String>,
 
@@ -40,11 +49,86 @@ pub enum WebDavCommand {
 
         #[arg(long, conflicts_with = "auto_sync")]
         no_auto_sync: bool,
+
+        /// Create missing remote directories with MKCOL (default)
+        #[arg(long, conflicts_with = "no_auto_create_dirs")]
+        auto_create_dirs: bool,
+
+        /// Never send MKCOL; require the remote directory to be pre-created
+        #[arg(long, conflicts_with = "auto_create_dirs")]
+        no_auto_create_dirs: bool,
+
+        /// After a download, re-export the database and warn if entity row counts differ
+        #[arg(long, conflicts_with = "no_verify_after_apply")]
+        verify_after_apply: bool,
+
+        #[arg(long, conflicts_with = "verify_after_apply")]
+        no_verify_after_apply: bool,
+
+        /// Sync the database as well as skills (default)
+        #[arg(long, conflicts_with = "no_sync_database")]
+        sync_database: bool,
+
+        /// Skills-only mode: keep db.sql out of uploads and never overwrite the local database
+        #[arg(long, conflicts_with = "sync_database")]
+        no_sync_database: bool,
+
+        /// How `sync` resolves changes made on both sides since the last sync
+        #[arg(long, value_enum)]
+        conflict_policy: Option<ConflictPolicyArg>,
+
+        /// Read username/password from a JSON or netrc-style file (pass "" to clear)
+        #[arg(long)]
+        credentials_file: Option<String>,
+
+        /// Trust an extra PEM CA certificate file, e.g. for an internal CA (pass "" to clear)
+        #[arg(long)]
+        ca_cert: Option<String>,
+
+        /// Note written to README.txt in the remote profile folder on upload (pass "" to clear)
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Prefix for remote artifact file names, e.g. "ccswitch-" to store db.sql as ccswitch-db.sql (pass "" to clear)
+        #[arg(long)]
+        artifact_prefix: Option<String>,
+
+        /// Per-artifact download limit in bytes, up to 4 GB (pass 0 to restore the 512 MB default)
+        #[arg(long)]
+        max_artifact_bytes: Option<u64>,
+
+        /// Pin this supported protocol version into uploaded manifests (pass 0 to choose it automatically)
+        #[arg(long)]
+        protocol_version: Option<u32>,
+
+        /// Store skills as a single skills.zip (default) or as individual files under skills/
+        #[arg(long, value_enum)]
+        skills_storage: Option<SkillsStorageArg>,
+
+        /// Store identical skill files once in skills.zip and restore the copies on download
+        #[arg(long, conflicts_with = "no_dedup")]
+        dedup: bool,
+
+        #[arg(long, conflicts_with = "dedup")]
+        no_dedup: bool,
+
+        /// Compression used for skills.zip entries (deflate by default)
+        #[arg(long, value_enum)]
+        compression: Option<CompressionAlgoArg>,
     },
 
     /// Clear stored WebDAV sync settings
     Clear,
 
+    /// List saved WebDAV targets (* marks the active one)
+    Targets,
+
+    /// Switch the active WebDAV target used by upload/download/sync
+    Use {
+        /// Target name
+        name: String,
+    },
+
     /// Apply Jianguoyun preset settings
     Jianguoyun {
         #[arg(long)]
@@ -70,19 +154,151 @@ pub enum WebDavCommand {
     CheckConnection,
 
     /// Upload the current local snapshot to WebDAV
-    Upload,
+    Upload {
+        /// Print the sync summary as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Overwrite the remote even if another device changed it since the last sync
+        #[arg(long)]
+        force: bool,
+
+        /// Short note describing this upload, stored in the remote manifest
+        #[arg(long, short = 'm')]
+        message: Option<String>,
+    },
 
     /// Download the current remote snapshot from WebDAV
-    Download,
+    Download {
+        /// Print the sync summary as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Fail instead of warning when the remote snapshot timestamp suggests a wrong device clock
+        #[arg(long)]
+        strict_clock: bool,
+    },
+
+    /// Upload or download depending on which side changed since the last sync
+    Sync {
+        /// Print the sync summary as JSON
+        #[arg(long)]
+        json: bool,
+    },
 
     /// Migrate legacy V1 remote data to V2 protocol
     MigrateV1ToV2,
+
+    /// Delete only the remote manifest so the next upload from any device republishes it
+    InvalidateRemoteManifest {
+        /// Skip the confirmation prompt
+        #[arg(long)]
+        yes: bool,
+    },
+
+    /// Recompute the remote manifest's snapshot ID from its artifacts and fix it if it is wrong
+    RepairRemoteManifest,
+
+    /// Change the WebDAV password, saving it only if a connection check with it succeeds
+    RotatePassword {
+        /// New password; prompted for when omitted
+        #[arg(long)]
+        password: Option<String>,
+    },
+
+    /// Delete the whole remote profile directory (manifest and all artifacts)
+    WipeRemote {
+        /// Profile name typed as confirmation; prompted for when omitted
+        #[arg(long)]
+        confirm: Option<String>,
+    },
+
+    /// Print the raw remote manifest.json, e.g. to attach to a bug report
+    ShowManifest,
+
+    /// Run every WebDAV check (settings, login, directory, write test, manifest) and print a checklist
+    Diagnose {
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Skip the remaining checks after the first failure
+        #[arg(long)]
+        stop_on_failure: bool,
+    },
+
+    /// Compare local and remote skills file by file without changing either side
+    DiffSkills {
+        /// Output the diff as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Show what an upload would send (artifact sizes and target URL) without uploading
+    Preflight {
+        /// Output the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// `--conflict-policy` 的取值，对应 [`ConflictPolicy`]
+#[derive(clap::ValueEnum, Debug, Clone, Copy)]
+pub enum ConflictPolicyArg {
+    Error,
+    PreferRemote,
+    PreferLocal,
+}
+
+impl From<ConflictPolicyArg> for ConflictPolicy {
+    fn from(arg: ConflictPolicyArg) -> Self {
+        match arg {
+            ConflictPolicyArg::Error => Self::Error,
+            ConflictPolicyArg::PreferRemote => Self::PreferRemote,
+            ConflictPolicyArg::PreferLocal => Self::PreferLocal,
+        }
+    }
+}
+
+/// `--skills-storage` 的取值，对应 [`SkillsStorage`]
+#[derive(clap::ValueEnum, Debug, Clone, Copy)]
+pub enum SkillsStorageArg {
+    Zip,
+    Tree,
+}
+
+impl From<SkillsStorageArg> for SkillsStorage {
+    fn from(arg: SkillsStorageArg) -> Self {
+        match arg {
+            SkillsStorageArg::Zip => Self::Zip,
+            SkillsStorageArg::Tree => Self::Tree,
+        }
+    }
+}
+
+/// `--compression` 的取值，对应 [`CompressionAlgo`]
+#[derive(clap::ValueEnum, Debug, Clone, Copy)]
+pub enum CompressionAlgoArg {
+    Deflate,
+    Zstd,
+    Stored,
+}
+
+impl From<CompressionAlgoArg> for CompressionAlgo {
+    fn from(arg: CompressionAlgoArg) -> Self {
+        match arg {
+            CompressionAlgoArg::Deflate => Self::Deflate,
+            CompressionAlgoArg::Zstd => Self::Zstd,
+            CompressionAlgoArg::Stored => Self::Stored,
+        }
+    }
 }
 
 pub fn execute(cmd: WebDavCommand) -> Result<(), AppError> {
     match cmd {
         WebDavCommand::Show => show(),
         WebDavCommand::Set {
+            target,
             base_url,
             remote_root,
             profile,
@@ -92,7 +308,25 @@ pub fn execute(cmd: WebDavCommand) -> Result<(), AppError> {
             disable,
             auto_sync,
             no_auto_sync,
+            auto_create_dirs,
+            no_auto_create_dirs,
+            verify_after_apply,
+            no_verify_after_apply,
+            sync_database,
+            no_sync_database,
+            conflict_policy,
+            credentials_file,
+            ca_cert,
+            description,
+            artifact_prefix,
+            max_artifact_bytes,
+            protocol_version,
+            skills_storage,
+            dedup,
+            no_dedup,
+            compression,
         } => set(
+            target,
             base_url,
             remote_root,
             profile,
@@ -102,8 +336,27 @@ pub fn execute(cmd: WebDavCommand) -> Result<(), AppError> {
             disable,
             auto_sync,
             no_auto_sync,
+            auto_create_dirs,
+            no_auto_create_dirs,
+            verify_after_apply,
+            no_verify_after_apply,
+            sync_database,
+            no_sync_database,
+            conflict_policy.map(Into::into),
+            credentials_file,
+            ca_cert,
+            description,
+            artifact_prefix,
+            max_artifact_bytes,
+            protocol_version,
+            skills_storage.map(Into::into),
+            dedup,
+            no_dedup,
+            compression.map(Into::into),
         ),
         WebDavCommand::Clear => clear(),
+        WebDavCommand::Targets => targets(),
+        WebDavCommand::Use { name } => use_target(&name),
         WebDavCommand::Jianguoyun {
             username,
             password,
@@ -120,9 +373,25 @@ pub fn execute(cmd: WebDavCommand) -> Result<(), AppError> {
             no_auto_sync,
         ),
         WebDavCommand::CheckConnection => check_connection(),
-        WebDavCommand::Upload => upload(),
-        WebDavCommand::Download => download(),
+        WebDavCommand::Upload {
+            json,
+            force,
+            message,
+        } => upload(json, force, message.as_deref()),
+        WebDavCommand::Download { json, strict_clock } => download(json, strict_clock),
+        WebDavCommand::Sync { json } => sync(json),
         WebDavCommand::MigrateV1ToV2 => migrate_v1_to_v2(),
+        WebDavCommand::InvalidateRemoteManifest { yes } => invalidate_remote_manifest(yes),
+        WebDavCommand::RepairRemoteManifest => repair_remote_manifest(),
+        WebDavCommand::RotatePassword { password } => rotate_password(password),
+        WebDavCommand::WipeRemote { confirm } => wipe_remote(confirm),
+        WebDavCommand::ShowManifest => show_manifest(),
+        WebDavCommand::Diagnose {
+            json,
+            stop_on_failure,
+        } => diagnose(json, stop_on_failure),
+        WebDavCommand::DiffSkills { json } => diff_skills(json),
+        WebDavCommand::Preflight { json } => preflight(json),
     }
 }
 
@@ -140,13 +409,54 @@ fn show() -> Result<(), AppError> {
 
     println!("{}", highlight(crate::t!("WebDAV Sync", "WebDAV 同步")));
     println!("{}", "═".repeat(60));
+    if let Some(target) = get_active_webdav_target() {
+        println!("Target:       {target}");
+    }
     println!("Enabled:      {}", yes_no(settings.enabled));
     println!("Base URL:     {}", settings.base_url);
     println!("Remote Root:  {}", settings.remote_root);
     println!("Profile:      {}", settings.profile);
     println!("Username:     {}", blank_as_na(&settings.username));
     println!("Password:     {}", masked_secret(&settings.password));
+    if let Some(credentials_file) = &settings.credentials_file {
+        println!("Credentials:  {credentials_file}");
+    }
+    if let Some(ca_cert_path) = &settings.ca_cert_path {
+        println!("CA Cert:      {ca_cert_path}");
+    }
+    if let Some(description) = &settings.profile_description {
+        println!("Description:  {description}");
+    }
+    if let Some(prefix) = &settings.artifact_prefix {
+        println!("Name Prefix:  {prefix}");
+    }
+    if let Some(version) = settings.protocol_version_override {
+        println!("Protocol:     v{version} (pinned)");
+    }
+    if let Ok(manifest_url) = WebDavSyncService::remote_manifest_url() {
+        println!("Manifest:     {manifest_url}");
+    }
     println!("Auto Sync:    {}", yes_no(settings.auto_sync));
+    println!("Create Dirs:  {}", yes_no(settings.auto_create_dirs));
+    println!("Verify Apply: {}", yes_no(settings.verify_after_apply));
+    println!("Sync DB:      {}", yes_no(settings.sync_database));
+    println!(
+        "Skills Store: {}",
+        skills_storage_name(settings.skills_storage)
+    );
+    println!("Skills Dedup: {}", yes_no(settings.dedup));
+    println!(
+        "Skills Zip:   {}",
+        compression_algo_name(settings.compression)
+    );
+    println!(
+        "Max Artifact: {}",
+        format_bytes(settings.artifact_size_limit(), current_language())
+    );
+    println!(
+        "On Conflict:  {}",
+        conflict_policy_name(settings.conflict_policy)
+    );
     println!(
         "Last Sync:    {}",
         settings
@@ -155,6 +465,9 @@ fn show() -> Result<(), AppError> {
             .map(|value| value.to_string())
             .unwrap_or_else(|| "N/A".to_string())
     );
+    if let Some(note) = &settings.status.last_snapshot_note {
+        println!("Last Note:    {note}");
+    }
     println!(
         "Last Error:   {}",
         settings
@@ -170,6 +483,7 @@ fn show() -> Result<(), AppError> {
 
 #[allow(clippy::too_many_arguments)]
 fn set(
+    target: Option<String>,
     base_url: Option<String>,
     remote_root: Option<String>,
     profile: Option<String>,
@@ -179,9 +493,31 @@ fn set(
     disable: bool,
     auto_sync: bool,
     no_auto_sync: bool,
+    auto_create_dirs: bool,
+    no_auto_create_dirs: bool,
+    verify_after_apply: bool,
+    no_verify_after_apply: bool,
+    sync_database: bool,
+    no_sync_database: bool,
+    conflict_policy: Option<ConflictPolicy>,
+    credentials_file: Option<String>,
+    ca_cert: Option<String>,
+    description: Option<String>,
+    artifact_prefix: Option<String>,
+    max_artifact_bytes: Option<u64>,
+    protocol_version: Option<u32>,
+    skills_storage: Option<SkillsStorage>,
+    dedup: bool,
+    no_dedup: bool,
+    compression: Option<CompressionAlgo>,
 ) -> Result<(), AppError> {
+    let current = match target.as_deref() {
+        Some(name) => get_webdav_target(name),
+        None => get_webdav_sync_settings(),
+    };
+    let base_url_changed = base_url.is_some();
     let mut settings = merged_settings(
-        get_webdav_sync_settings(),
+        current.clone(),
         base_url,
         remote_root,
         profile,
@@ -191,9 +527,34 @@ fn set(
         disable,
         auto_sync,
         no_auto_sync,
+        auto_create_dirs,
+        no_auto_create_dirs,
+        verify_after_apply,
+        no_verify_after_apply,
+        sync_database,
+        no_sync_database,
+        conflict_policy,
+        credentials_file,
+        ca_cert,
+        description,
+        artifact_prefix,
+        max_artifact_bytes,
+        protocol_version,
+        skills_storage,
+        dedup,
+        no_dedup,
+        compression,
     );
     settings.normalize();
-    set_webdav_sync_settings(Some(settings))?;
+    // 远端位置变化时，旧的同步状态不再对应当前远端，需要重置
+    if current.is_some_and(|current| !current.same_remote(&settings)) {
+        settings.status = WebDavSyncStatus::default();
+    }
+    let detected = base_url_changed.then(|| detect_provider(&settings.base_url));
+    match target.as_deref() {
+        Some(name) => upsert_webdav_target(name, settings)?,
+        None => set_webdav_sync_settings(Some(settings))?,
+    }
     println!(
         "{}",
         success(crate::t!(
@@ -201,6 +562,14 @@ fn set(
             "✓ WebDAV 设置已保存。"
         ))
     );
+    if let Some(kind) = detected.filter(|kind| kind.uses_app_password()) {
+        let name = kind.name();
+        let en = format!(
+            "Detected {name}: use an app password generated in its security settings, not your account password."
+        );
+        let zh = format!("检测到 {name}：请使用其安全设置中生成的应用密码，而不是账户登录密码。");
+        println!("{}", info(crate::t!(&en, &zh)));
+    }
     Ok(())
 }
 
@@ -216,6 +585,43 @@ fn clear() -> Result<(), AppError> {
     Ok(())
 }
 
+fn targets() -> Result<(), AppError> {
+    let names = list_webdav_targets();
+    if names.is_empty() {
+        println!(
+            "{}",
+            info(crate::t!(
+                "No WebDAV targets saved.",
+                "尚未保存任何 WebDAV 目标。"
+            ))
+        );
+        return Ok(());
+    }
+    let active = get_active_webdav_target();
+    for name in names {
+        let marker = if active.as_deref() == Some(name.as_str()) {
+            "*"
+        } else {
+            " "
+        };
+        println!("{marker} {name}");
+    }
+    Ok(())
+}
+
+fn use_target(name: &str) -> Result<(), AppError> {
+    set_active_webdav_target(name)?;
+    println!(
+        "{}",
+        success(&format!(
+            "{} {}",
+            crate::t!("✓ Active WebDAV target:", "✓ 当前 WebDAV 目标:"),
+            name.trim()
+        ))
+    );
+    Ok(())
+}
+
 fn jianguoyun(
     username: String,
     password: String,
@@ -262,19 +668,73 @@ fn check_connection() -> Result<(), AppError> {
     Ok(())
 }
 
-fn upload() -> Result<(), AppError> {
-    let summary = WebDavSyncService::upload()?;
+fn upload(json: bool, force: bool, message: Option<&str>) -> Result<(), AppError> {
+    let summary = if force {
+        WebDavSyncService::upload_force_with_message(message)?
+    } else {
+        WebDavSyncService::upload_with_message(message)?
+    };
+    if json {
+        println!("{}", summary.to_json()?);
+        return Ok(());
+    }
+    print_summary_warnings(&summary);
     println!("{}", success(&summary.message));
     Ok(())
 }
 
-fn download() -> Result<(), AppError> {
-    let summary = WebDavSyncService::download()?;
-    sync_live_config_after_webdav();
+fn download(json: bool, strict_clock: bool) -> Result<(), AppError> {
+    let summary = if strict_clock {
+        WebDavSyncService::download_strict_clock()?
+    } else {
+        WebDavSyncService::download()?
+    };
+    if summary.changed() {
+        sync_live_config_after_webdav();
+    }
+    if json {
+        println!("{}", summary.to_json()?);
+        return Ok(());
+    }
+    print_summary_warnings(&summary);
+    println!("{}", success(&summary.message));
+    Ok(())
+}
+
+fn sync(json: bool) -> Result<(), AppError> {
+    let summary = WebDavSyncService::sync()?;
+    if json {
+        if summary.decision == SyncDecision::Download {
+            sync_live_config_after_webdav();
+        }
+        println!("{}", summary.to_json()?);
+        return Ok(());
+    }
+    match summary.decision {
+        SyncDecision::Download => sync_live_config_after_webdav(),
+        SyncDecision::V1MigrationNeeded => {
+            println!(
+                "{}",
+                warning(crate::t!(
+                    "Legacy V1 data found on the remote. Run `config webdav migrate-v1-to-v2` first.",
+                    "远端存在旧版 V1 数据，请先运行 `config webdav migrate-v1-to-v2`。"
+                ))
+            );
+            return Ok(());
+        }
+        _ => {}
+    }
+    print_summary_warnings(&summary);
     println!("{}", success(&summary.message));
     Ok(())
 }
 
+fn print_summary_warnings(summary: &WebDavSyncSummary) {
+    for message in &summary.warnings {
+        println!("{}", warning(message));
+    }
+}
+
 fn migrate_v1_to_v2() -> Result<(), AppError> {
     let summary = WebDavSyncService::migrate_v1_to_v2()?;
     sync_live_config_after_webdav();
@@ -282,6 +742,237 @@ fn migrate_v1_to_v2() -> Result<(), AppError> {
     Ok(())
 }
 
+fn invalidate_remote_manifest(yes: bool) -> Result<(), AppError> {
+    if !yes {
+        let confirm = inquire::Confirm::new(crate::t!(
+            "Delete the remote manifest? Other devices cannot download until a device uploads again.",
+            "删除远端 manifest？在某台设备重新上传前，其他设备将无法下载。"
+        ))
+        .with_default(false)
+        .prompt()
+        .map_err(|e| AppError::Message(format!("Prompt failed: {e}")))?;
+        if !confirm {
+            println!("{}", info(crate::t!("Cancelled.", "已取消。")));
+            return Ok(());
+        }
+    }
+
+    if WebDavSyncService::invalidate_remote_manifest()? {
+        println!(
+            "{}",
+            success(crate::t!(
+                "✓ Remote manifest deleted. Run `upload` on the device whose data should win.",
+                "✓ 远端 manifest 已删除。请在数据应当保留的设备上执行 `upload`。"
+            ))
+        );
+    } else {
+        println!(
+            "{}",
+            info(crate::t!(
+                "No remote manifest found; nothing to delete.",
+                "远端没有 manifest，无需删除。"
+            ))
+        );
+    }
+    Ok(())
+}
+
+fn repair_remote_manifest() -> Result<(), AppError> {
+    let message = if WebDavSyncService::repair_remote_manifest()? {
+        success(crate::t!(
+            "✓ Remote manifest snapshot ID was inconsistent and has been repaired.",
+            "✓ 远端 manifest 的 snapshot ID 不一致，已修复。"
+        ))
+    } else {
+        info(crate::t!(
+            "Remote manifest is consistent; nothing to repair.",
+            "远端 manifest 一致，无需修复。"
+        ))
+    };
+    println!("{message}");
+    Ok(())
+}
+
+fn rotate_password(password: Option<String>) -> Result<(), AppError> {
+    let password = match password {
+        Some(password) => password,
+        None => inquire::Password::new(crate::t!("New WebDAV password:", "新的 WebDAV 密码："))
+            .prompt()
+            .map_err(|e| AppError::Message(format!("Prompt failed: {e}")))?,
+    };
+
+    WebDavSyncService::rotate_password(&password)?;
+    println!(
+        "{}",
+        success(crate::t!(
+            "✓ Connection check passed; new WebDAV password saved.",
+            "✓ 连接检查通过，新的 WebDAV 密码已保存。"
+        ))
+    );
+    Ok(())
+}
+
+fn wipe_remote(confirm: Option<String>) -> Result<(), AppError> {
+    let confirm = match confirm {
+        Some(confirm) => confirm,
+        None => {
+            let profile = get_webdav_sync_settings()
+                .map(|settings| settings.profile)
+                .unwrap_or_default();
+            let en = format!(
+                "This permanently deletes all remote data of profile '{profile}'. Type the profile name to confirm:"
+            );
+            let zh = format!(
+                "此操作会永久删除 profile「{profile}」的全部远端数据。请输入 profile 名称确认："
+            );
+            inquire::Text::new(crate::t!(&en, &zh))
+                .prompt()
+                .map_err(|e| AppError::Message(format!("Prompt failed: {e}")))?
+        }
+    };
+
+    let removed = WebDavSyncService::wipe_remote(confirm.trim())?;
+    let detail = format!(
+        " ({removed} {})",
+        crate::t!("files removed", "个文件已删除")
+    );
+    println!(
+        "{}",
+        success(&format!(
+            "{}{detail}",
+            crate::t!(
+                "✓ Remote profile data deleted and local sync status cleared.",
+                "✓ 远端 profile 数据已删除，本地同步状态已清空。"
+            )
+        ))
+    );
+    Ok(())
+}
+
+fn show_manifest() -> Result<(), AppError> {
+    println!("{}", WebDavSyncService::fetch_remote_manifest_raw()?);
+    Ok(())
+}
+
+fn diff_skills(json: bool) -> Result<(), AppError> {
+    let diff = WebDavSyncService::diff_skills()?;
+    if json {
+        let output = serde_json::to_string_pretty(&diff)
+            .map_err(|e| AppError::JsonSerialize { source: e })?;
+        println!("{output}");
+        return Ok(());
+    }
+
+    if diff.is_empty() {
+        println!(
+            "{}",
+            success(crate::t!(
+                "✓ Local and remote skills are identical.",
+                "✓ 本地与远端 Skills 完全一致。"
+            ))
+        );
+        return Ok(());
+    }
+
+    for (label, paths) in [
+        (crate::t!("Only local", "仅本地"), &diff.added),
+        (crate::t!("Only remote", "仅远端"), &diff.removed),
+        (crate::t!("Changed", "已修改"), &diff.changed),
+    ] {
+        if paths.is_empty() {
+            continue;
+        }
+        println!("{}", highlight(&format!("{label} ({}):", paths.len())));
+        for path in paths {
+            println!("  {path}");
+        }
+    }
+    Ok(())
+}
+
+fn diagnose(json: bool, stop_on_failure: bool) -> Result<(), AppError> {
+    let report = WebDavSyncService::diagnose(!stop_on_failure)?;
+    if json {
+        let output = serde_json::to_string_pretty(&report)
+            .map_err(|e| AppError::JsonSerialize { source: e })?;
+        println!("{output}");
+    } else {
+        for step in &report.steps {
+            let label = match step.check {
+                DiagnosticCheck::Settings => crate::t!("Settings", "配置"),
+                DiagnosticCheck::Credentials => crate::t!("Login", "登录"),
+                DiagnosticCheck::Provider => crate::t!("Provider", "服务商"),
+                DiagnosticCheck::RemoteDirectory => crate::t!("Remote directory", "远端目录"),
+                DiagnosticCheck::WriteTest => crate::t!("Write test", "写入测试"),
+                DiagnosticCheck::RemoteManifest => crate::t!("Remote manifest", "远端 manifest"),
+            };
+            let line = match step.status {
+                DiagnosticStatus::Pass => success(&format!("✓ {label} ({} ms)", step.elapsed_ms)),
+                DiagnosticStatus::Fail => error(&format!("✗ {label} ({} ms)", step.elapsed_ms)),
+                DiagnosticStatus::Skip => {
+                    info(&format!("- {label} ({})", crate::t!("skipped", "已跳过")))
+                }
+            };
+            match &step.detail {
+                Some(detail) => println!("{line}: {detail}"),
+                None => println!("{line}"),
+            }
+        }
+    }
+    if report.passed() {
+        Ok(())
+    } else {
+        Err(AppError::localized(
+            "webdav.diagnose.failed",
+            "WebDAV 诊断发现未通过的检查项",
+            "WebDAV diagnosis found failing checks",
+        ))
+    }
+}
+
+fn preflight(json: bool) -> Result<(), AppError> {
+    let report = WebDavSyncService::preflight()?;
+    if json {
+        let output = serde_json::to_string_pretty(&report)
+            .map_err(|e| AppError::JsonSerialize { source: e })?;
+        println!("{output}");
+        return Ok(());
+    }
+
+    let lang = current_language();
+    let total = format_bytes(report.total_bytes(), lang);
+    let target = format!("{}/", report.target_url.trim_end_matches('/'));
+    let en = format!(
+        "Will upload {} files ({total}) to {target}",
+        report.artifacts.len() + 1
+    );
+    let zh = format!(
+        "将上传 {} 个文件（{total}）到 {target}",
+        report.artifacts.len() + 1
+    );
+    println!("{}", highlight(crate::t!(&en, &zh)));
+    let name_width = report
+        .artifacts
+        .iter()
+        .map(|artifact| artifact.name.len())
+        .chain(["manifest.json".len()])
+        .max()
+        .unwrap_or_default();
+    for (name, size) in report
+        .artifacts
+        .iter()
+        .map(|artifact| (artifact.name.as_str(), artifact.size))
+        .chain([("manifest.json", report.manifest_size)])
+    {
+        println!("  {name:<name_width$}  {}", format_bytes(size, lang));
+    }
+    println!("Snapshot:     {}", report.snapshot_id);
+    for warning_text in &report.warnings {
+        println!("{}", warning(warning_text));
+    }
+    Ok(())
+}
+
 fn sync_live_config_after_webdav() {
     let Ok(state) = crate::AppState::try_new() else {
         return;
@@ -290,7 +981,8 @@ fn sync_live_config_after_webdav() {
     if let Err(err) = crate::services::ProviderService::sync_current_to_live(&state) {
         let en = format!("Live config sync after WebDAV operation failed: {err}");
         let zh = format!("WebDAV 操作后同步 live 配置失败: {err}");
-        println!("{}", warning(crate::t!(&en, &zh)));
+        // 走 stderr，避免污染 `--json` 输出
+        eprintln!("{}", warning(crate::t!(&en, &zh)));
     }
 }
 
@@ -306,6 +998,23 @@ fn merged_settings(
     disable: bool,
     auto_sync: bool,
     no_auto_sync: bool,
+    auto_create_dirs: bool,
+    no_auto_create_dirs: bool,
+    verify_after_apply: bool,
+    no_verify_after_apply: bool,
+    sync_database: bool,
+    no_sync_database: bool,
+    conflict_policy: Option<ConflictPolicy>,
+    credentials_file: Option<String>,
+    ca_cert: Option<String>,
+    description: Option<String>,
+    artifact_prefix: Option<String>,
+    max_artifact_bytes: Option<u64>,
+    protocol_version: Option<u32>,
+    skills_storage: Option<SkillsStorage>,
+    dedup: bool,
+    no_dedup: bool,
+    compression: Option<CompressionAlgo>,
 ) -> WebDavSyncSettings {
     let mut settings = current.unwrap_or_default();
 
@@ -336,10 +1045,84 @@ fn merged_settings(
     if no_auto_sync {
         settings.auto_sync = false;
     }
+    if auto_create_dirs {
+        settings.auto_create_dirs = true;
+    }
+    if no_auto_create_dirs {
+        settings.auto_create_dirs = false;
+    }
+    if verify_after_apply {
+        settings.verify_after_apply = true;
+    }
+    if no_verify_after_apply {
+        settings.verify_after_apply = false;
+    }
+    if sync_database {
+        settings.sync_database = true;
+    }
+    if no_sync_database {
+        settings.sync_database = false;
+    }
+    if let Some(conflict_policy) = conflict_policy {
+        settings.conflict_policy = conflict_policy;
+    }
+    if let Some(credentials_file) = credentials_file {
+        settings.credentials_file = Some(credentials_file);
+    }
+    if let Some(ca_cert) = ca_cert {
+        settings.ca_cert_path = Some(ca_cert);
+    }
+    if let Some(description) = description {
+        settings.profile_description = Some(description);
+    }
+    if let Some(artifact_prefix) = artifact_prefix {
+        settings.artifact_prefix = Some(artifact_prefix);
+    }
+    if let Some(max_artifact_bytes) = max_artifact_bytes {
+        settings.max_artifact_bytes = (max_artifact_bytes > 0).then_some(max_artifact_bytes);
+    }
+    if let Some(protocol_version) = protocol_version {
+        settings.protocol_version_override = (protocol_version > 0).then_some(protocol_version);
+    }
+    if let Some(skills_storage) = skills_storage {
+        settings.skills_storage = skills_storage;
+    }
+    if dedup {
+        settings.dedup = true;
+    }
+    if no_dedup {
+        settings.dedup = false;
+    }
+    if let Some(compression) = compression {
+        settings.compression = compression;
+    }
 
     settings
 }
 
+fn skills_storage_name(storage: SkillsStorage) -> &'static str {
+    match storage {
+        SkillsStorage::Zip => "zip",
+        SkillsStorage::Tree => "tree",
+    }
+}
+
+fn compression_algo_name(compression: CompressionAlgo) -> &'static str {
+    match compression {
+        CompressionAlgo::Deflate => "deflate",
+        CompressionAlgo::Zstd => "zstd",
+        CompressionAlgo::Stored => "stored",
+    }
+}
+
+fn conflict_policy_name(policy: ConflictPolicy) -> &'static str {
+    match policy {
+        ConflictPolicy::Error => "error",
+        ConflictPolicy::PreferRemote => "prefer-remote",
+        ConflictPolicy::PreferLocal => "prefer-local",
+    }
+}
+
 fn yes_no(value: bool) -> &'static str {
     if value {
         "yes"
@@ -367,7 +1150,9 @@ fn masked_secret(value: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::merged_settings;
-    use crate::{WebDavSyncSettings, WebDavSyncStatus};
+    use crate::{
+        CompressionAlgo, ConflictPolicy, SkillsStorage, WebDavSyncSettings, WebDavSyncStatus,
+    };
 
     #[test]
     fn merged_settings_updates_selected_fields_only() {
@@ -379,6 +1164,19 @@ mod tests {
             username: "demo".to_string(),
             password: "secret".to_string(),
             auto_sync: false,
+            conflict_policy: ConflictPolicy::default(),
+            credentials_file: None,
+            ca_cert_path: None,
+            auto_create_dirs: true,
+            verify_after_apply: false,
+            max_artifact_bytes: None,
+            sync_database: true,
+            skills_storage: SkillsStorage::default(),
+            dedup: false,
+            compression: CompressionAlgo::default(),
+            profile_description: None,
+            artifact_prefix: None,
+            protocol_version_override: None,
             status: WebDavSyncStatus {
                 last_error: Some("boom".to_string()),
                 ..WebDavSyncStatus::default()
@@ -396,6 +1194,23 @@ mod tests {
             false,
             true,
             false,
+            false,
+            true,
+            true,
+            false,
+            false,
+            true,
+            Some(ConflictPolicy::PreferRemote),
+            None,
+            None,
+            Some("Team laptops".to_string()),
+            Some("ccswitch-".to_string()),
+            Some(1024 * 1024 * 1024),
+            Some(2),
+            Some(SkillsStorage::Tree),
+            true,
+            false,
+            Some(CompressionAlgo::Zstd),
         );
 
         assert!(merged.enabled);
@@ -405,6 +1220,17 @@ mod tests {
         assert_eq!(merged.username, "demo");
         assert_eq!(merged.password, "secret");
         assert!(merged.auto_sync);
+        assert!(!merged.auto_create_dirs);
+        assert!(merged.verify_after_apply);
+        assert!(!merged.sync_database);
+        assert_eq!(merged.conflict_policy, ConflictPolicy::PreferRemote);
+        assert_eq!(merged.max_artifact_bytes, Some(1024 * 1024 * 1024));
+        assert_eq!(merged.protocol_version_override, Some(2));
+        assert_eq!(merged.skills_storage, SkillsStorage::Tree);
+        assert!(merged.dedup);
+        assert_eq!(merged.compression, CompressionAlgo::Zstd);
+        assert_eq!(merged.profile_description.as_deref(), Some("Team laptops"));
+        assert_eq!(merged.artifact_prefix.as_deref(), Some("ccswitch-"));
         assert_eq!(merged.status.last_error.as_deref(), Some("boom"));
     }
 }
@@ -2,7 +2,9 @@ use crate::app_config::AppType;
 use crate::cli::ui::{create_table, error, highlight, info, success};
 use crate::error::AppError;
 use crate::services::env_checker;
-use crate::services::local_env_check::{check_local_environment, ToolCheckStatus};
+use crate::services::local_env_check::{
+    check_local_environment, collect_env_report, ToolCheckStatus,
+};
 use clap::Subcommand;
 
 #[derive(Subcommand)]
@@ -12,16 +14,20 @@ pub enum EnvCommand {
     /// List all relevant environment variables
     List,
     /// Check whether Claude/Codex/Gemini/OpenCode CLIs are installed locally
-    Tools,
+    Tools {
+        /// Also report tool-related environment variables (secrets are redacted)
+        #[arg(long)]
+        vars: bool,
+    },
 }
 
 pub fn execute(cmd: EnvCommand, app: Option<AppType>) -> Result<(), AppError> {
-    let app_type = app.unwrap_or(AppType::Claude);
+    let app_type = app.clone().unwrap_or(AppType::Claude);
 
     match cmd {
         EnvCommand::Check => check_conflicts(app_type),
         EnvCommand::List => list_env_vars(app_type),
-        EnvCommand::Tools => check_local_tools(),
+        EnvCommand::Tools { vars } => check_local_tools(app.as_ref(), vars),
     }
 }
 
@@ -133,8 +139,8 @@ fn list_env_vars(app_type: AppType) -> Result<(), AppError> {
     Ok(())
 }
 
-fn check_local_tools() -> Result<(), AppError> {
-    let results = check_local_environment();
+fn check_local_tools(app: Option<&AppType>, vars: bool) -> Result<(), AppError> {
+    let results = check_local_environment(app);
 
     println!("\n{}", highlight("Local CLI Tools"));
     println!("{}", "═".repeat(60));
@@ -150,9 +156,38 @@ fn check_local_tools() -> Result<(), AppError> {
 
     println!("{}", table);
 
+    if vars {
+        print_env_report();
+    }
+
     Ok(())
 }
 
+fn print_env_report() {
+    let report = collect_env_report();
+
+    println!("\n{}", highlight("Tool Environment Variables"));
+    println!("{}", "═".repeat(60));
+
+    if report.vars.is_empty() {
+        println!("{}", info("No tool-related environment variables are set."));
+        return;
+    }
+
+    let mut vars = report.vars.into_iter().collect::<Vec<_>>();
+    vars.sort();
+    let mut table = create_table();
+    table.set_header(vec!["Variable", "Value"]);
+    for (name, value) in vars {
+        table.add_row(vec![name, value]);
+    }
+    println!("{}", table);
+    println!(
+        "{}",
+        info("These variables take precedence over the config files CC-Switch writes.")
+    );
+}
+
 fn tool_status_summary(status: &ToolCheckStatus) -> String {
     match status {
         ToolCheckStatus::Ok { version } => format!("ok ({version})"),
@@ -22,6 +22,17 @@ pub enum McpCommand {
         /// Server ID to delete
         id: String,
     },
+    /// Delete all MCP servers whose ID matches a glob pattern (e.g. "test-*")
+    DeleteMatching {
+        /// Glob pattern matched against server IDs (`*`, `?`, `[...]`)
+        pattern: String,
+        /// Also delete servers that are still enabled for some app
+        #[arg(long)]
+        force: bool,
+        /// Skip the confirmation prompt
+        #[arg(long)]
+        yes: bool,
+    },
     /// Enable an MCP server for specific app(s)
     Enable {
         /// Server ID to enable
@@ -51,6 +62,11 @@ pub fn execute(cmd: McpCommand, app: Option<AppType>) -> Result<(), AppError> {
         McpCommand::Add => add_server(app_type),
         McpCommand::Edit { id } => edit_server(app_type, &id),
         McpCommand::Delete { id } => delete_server(&id),
+        McpCommand::DeleteMatching {
+            pattern,
+            force,
+            yes,
+        } => delete_matching(&pattern, force, yes),
         McpCommand::Enable { id } => enable_server(app_type, &id),
         McpCommand::Disable { id } => disable_server(app_type, &id),
         McpCommand::Validate { command } => validate_command(&command),
@@ -77,9 +93,9 @@ fn list_servers(app_type: AppType) -> Result<(), AppError> {
     let mut table = create_table();
     table.set_header(vec!["ID", "Name", "Claude", "Codex", "Gemini", "Tags"]);
 
-    // 按 ID 排序
+    // 按显示名称排序（随界面语言）
     let mut server_list: Vec<_> = servers.into_iter().collect();
-    server_list.sort_by(|(a, _), (b, _)| a.cmp(b));
+    crate::cli::i18n::sort_entries(&mut server_list, |(_, server)| &server.name);
 
     for (id, server) in server_list {
         let claude_marker = if server.apps.claude { "✓" } else { " " };
@@ -182,6 +198,33 @@ fn delete_server(id: &str) -> Result<(), AppError> {
     Ok(())
 }
 
+fn delete_matching(pattern: &str, force: bool, yes: bool) -> Result<(), AppError> {
+    let state = get_state()?;
+
+    if !yes {
+        let confirm =
+            inquire::Confirm::new(&format!("Delete all MCP servers matching '{}'?", pattern))
+                .with_default(false)
+                .prompt()
+                .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))?;
+        if !confirm {
+            println!("{}", info("Cancelled."));
+            return Ok(());
+        }
+    }
+
+    let deleted = McpService::delete_matching(&state, pattern, force)?;
+    if deleted.is_empty() {
+        println!("{}", info(&format!("No MCP servers match '{}'", pattern)));
+        return Ok(());
+    }
+    for id in &deleted {
+        println!("{}", success(&format!("✓ Deleted MCP server '{}'", id)));
+    }
+
+    Ok(())
+}
+
 fn enable_server(app_type: AppType, id: &str) -> Result<(), AppError> {
     let state = get_state()?;
     let app_str = app_type.as_str().to_string();
@@ -72,9 +72,9 @@ fn list_prompts(app_type: AppType) -> Result<(), AppError> {
     let mut table = create_table();
     table.set_header(vec!["", "ID", "Name", "Description", "Updated"]);
 
-    // 按更新时间排序
+    // 按显示名称排序（随界面语言）
     let mut prompt_list: Vec<_> = prompts.into_iter().collect();
-    prompt_list.sort_by(|(_, a), (_, b)| b.updated_at.unwrap_or(0).cmp(&a.updated_at.unwrap_or(0)));
+    crate::cli::i18n::sort_entries(&mut prompt_list, |(_, prompt)| &prompt.name);
 
     for (id, prompt) in prompt_list {
         let enabled_marker = if prompt.enabled { "✓" } else { " " };
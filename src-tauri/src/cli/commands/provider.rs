@@ -3,14 +3,14 @@ use clap::Subcommand;
 use super::provider_inspect;
 use crate::app_config::AppType;
 use crate::cli::commands::provider_input::{
-    current_timestamp, display_provider_summary, generate_provider_id, prompt_basic_fields,
-    prompt_optional_fields, prompt_settings_config, prompt_settings_config_for_add, OptionalFields,
-    ProviderAddMode,
+    current_timestamp, display_provider_summary, prompt_basic_fields, prompt_optional_fields,
+    prompt_settings_config, prompt_settings_config_for_add, OptionalFields, ProviderAddMode,
 };
 use crate::cli::i18n::texts;
 use crate::cli::ui::{error, highlight, info, success, warning};
 use crate::error::AppError;
 use crate::provider::{Provider, ProviderMeta};
+use crate::services::provider::generate_provider_id;
 use crate::services::ProviderService;
 use crate::store::AppState;
 use inquire::{Confirm, Select, Text};
@@ -51,6 +51,9 @@ pub enum ProviderCommand {
     Speedtest {
         /// Provider ID to test
         id: String,
+        /// Test all configured endpoints and switch to the fastest healthy one
+        #[arg(long)]
+        apply: bool,
     },
     /// Run stream health check for a provider
     StreamCheck {
@@ -62,6 +65,10 @@ pub enum ProviderCommand {
         /// Provider ID to query
         id: String,
     },
+    /// Check whether stored API keys are still accepted
+    CheckKeys,
+    /// Import providers from the app's live config files
+    ImportLive,
 }
 
 pub fn execute(cmd: ProviderCommand, app: Option<AppType>) -> Result<(), AppError> {
@@ -75,13 +82,20 @@ pub fn execute(cmd: ProviderCommand, app: Option<AppType>) -> Result<(), AppErro
         ProviderCommand::Edit { id } => edit_provider(app_type, &id),
         ProviderCommand::Delete { id } => delete_provider(app_type, &id),
         ProviderCommand::Duplicate { id } => duplicate_provider(app_type, &id),
-        ProviderCommand::Speedtest { id } => provider_inspect::speedtest_provider(app_type, &id),
+        ProviderCommand::Speedtest { id, apply: false } => {
+            provider_inspect::speedtest_provider(app_type, &id)
+        }
+        ProviderCommand::Speedtest { id, apply: true } => {
+            provider_inspect::speedtest_apply_fastest(app_type, &id)
+        }
         ProviderCommand::StreamCheck { id } => {
             provider_inspect::stream_check_provider(app_type, &id)
         }
         ProviderCommand::FetchModels { id } => {
             provider_inspect::fetch_models_provider(app_type, &id)
         }
+        ProviderCommand::CheckKeys => provider_inspect::check_keys(app_type),
+        ProviderCommand::ImportLive => import_live_providers(app_type),
     }
 }
 
@@ -383,6 +397,35 @@ fn edit_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
     Ok(())
 }
 
+fn import_live_providers(app_type: AppType) -> Result<(), AppError> {
+    let state = get_state()?;
+    let imported = ProviderService::import_from_live(&state, app_type.clone())?;
+
+    if imported.is_empty() {
+        println!(
+            "{}",
+            info(&format!(
+                "No new providers found in the {} live config.",
+                app_type.as_str()
+            ))
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        success(&format!(
+            "✓ Imported {} provider(s) from the {} live config",
+            imported.len(),
+            app_type.as_str()
+        ))
+    );
+    for id in imported {
+        println!("  - {id}");
+    }
+    Ok(())
+}
+
 fn duplicate_provider(_app_type: AppType, id: &str) -> Result<(), AppError> {
     println!("{}", info(&format!("Duplicating provider '{}'...", id)));
     println!("{}", error("Provider duplication is not yet implemented."));
@@ -135,42 +135,6 @@ impl OptionalFields {
     }
 }
 
-/// 生成唯一的 Provider ID
-/// 基于名称转换为 kebab-case，如有冲突则追加数字后缀
-pub fn generate_provider_id(name: &str, existing_ids: &[String]) -> String {
-    // 转换为 kebab-case
-    let base_id = name
-        .to_lowercase()
-        .chars()
-        .map(|c| {
-            if c.is_alphanumeric() || c == '-' || c == '_' {
-                c
-            } else if c.is_whitespace() {
-                '-'
-            } else {
-                '-'
-            }
-        })
-        .collect::<String>()
-        .trim_matches('-')
-        .to_string();
-
-    // 检查唯一性
-    if !existing_ids.contains(&base_id) {
-        return base_id;
-    }
-
-    // 追加数字后缀
-    let mut counter = 1;
-    loop {
-        let candidate = format!("{}-{}", base_id, counter);
-        if !existing_ids.contains(&candidate) {
-            return candidate;
-        }
-        counter += 1;
-    }
-}
-
 /// 收集基本字段：name, website_url
 pub fn prompt_basic_fields(
     current: Option<&Provider>,
@@ -5,7 +5,10 @@ use crate::cli::i18n::texts;
 use crate::cli::ui::{create_table, error, highlight, info, success, warning};
 use crate::error::AppError;
 use crate::provider::Provider;
-use crate::services::{ProviderService, SpeedtestService, StreamCheckService};
+use crate::services::provider::mask_api_key;
+use crate::services::{
+    ConfigService, KeyStatus, ProviderService, SpeedtestService, StreamCheckService,
+};
 use crate::store::AppState;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -54,7 +57,7 @@ pub(crate) fn list_providers(app_type: AppType) -> Result<(), AppError> {
         (Some(idx_a), Some(idx_b)) => idx_a.cmp(&idx_b),
         (Some(_), None) => std::cmp::Ordering::Less,
         (None, Some(_)) => std::cmp::Ordering::Greater,
-        (None, None) => a.created_at.cmp(&b.created_at),
+        (None, None) => crate::cli::i18n::collate(&a.name, &b.name),
     });
 
     for (id, provider) in provider_list {
@@ -133,6 +136,24 @@ pub(crate) fn show_current(app_type: AppType) -> Result<(), AppError> {
         println!("  API URL:  {}", api_url);
     }
 
+    // 代理接管期间 live 配置本就指向本地代理，比较指纹只会误报漂移
+    let takeover_active = !app_type.is_additive_mode()
+        && state
+            .proxy_service
+            .is_app_takeover_active_blocking(&app_type)
+            .map_err(AppError::Message)?;
+    let live = (!takeover_active)
+        .then(|| ConfigService::live_provider_fingerprint(app_type.clone()))
+        .flatten();
+    if let Some(live) = live {
+        if ConfigService::provider_fingerprint(&app_type, provider).is_some_and(|p| p != live) {
+            println!(
+                "\n{}",
+                warning(&texts::live_config_drift_warning(app_type.as_str()))
+            );
+        }
+    }
+
     println!("\n{}", "─".repeat(60));
 
     Ok(())
@@ -184,14 +205,40 @@ pub(crate) fn speedtest_provider(app_type: AppType, id: &str) -> Result<(), AppE
 
         if let Some(err) = &result.error {
             println!("\n{}", error(&format!("Error: {}", err)));
-        } else if result.latency.is_some() {
+        } else if result.is_healthy() {
             println!("\n{}", success("✓ Speedtest completed successfully"));
+        } else if let Some(status) = result.status {
+            println!(
+                "\n{}",
+                warning(&format!(
+                    "✗ Endpoint responded with HTTP {status}; check the API URL"
+                ))
+            );
         }
     }
 
     Ok(())
 }
 
+pub(crate) fn speedtest_apply_fastest(app_type: AppType, id: &str) -> Result<(), AppError> {
+    let state = get_state()?;
+
+    println!(
+        "{}",
+        info(&format!("Testing all endpoints of provider '{}'...", id))
+    );
+
+    let runtime = tokio::runtime::Runtime::new()
+        .map_err(|e| AppError::Message(format!("Failed to create async runtime: {}", e)))?;
+    let chosen = runtime.block_on(ProviderService::auto_select_fastest(&state, app_type, id))?;
+
+    println!(
+        "{}",
+        success(&format!("✓ Switched to fastest endpoint: {}", chosen))
+    );
+    Ok(())
+}
+
 pub(crate) fn stream_check_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
     let state = get_state()?;
     let providers = ProviderService::list(&state, app_type.clone())?;
@@ -255,6 +302,7 @@ pub(crate) fn fetch_models_provider(app_type: AppType, id: &str) -> Result<(), A
             &target.base_url,
             Some(target.auth_value.as_str()),
             to_tui_strategy(target.strategy),
+            None,
         )
         .await
         .map_err(AppError::Message)
@@ -281,6 +329,60 @@ pub(crate) fn fetch_models_provider(app_type: AppType, id: &str) -> Result<(), A
     Ok(())
 }
 
+pub(crate) fn check_keys(app_type: AppType) -> Result<(), AppError> {
+    let state = get_state()?;
+    let providers = ProviderService::list(&state, app_type.clone())?;
+
+    println!("{}", info("Checking provider API keys..."));
+    println!();
+
+    let runtime = tokio::runtime::Runtime::new()
+        .map_err(|e| AppError::Message(format!("Failed to create async runtime: {}", e)))?;
+    let statuses =
+        runtime.block_on(async { ProviderService::check_all_keys(&state, app_type).await })?;
+
+    if statuses.is_empty() {
+        println!("{}", info("No providers with an API key to check."));
+        return Ok(());
+    }
+
+    let mut table = create_table();
+    table.set_header(vec!["ID", "Name", "Key"]);
+    for (id, status) in &statuses {
+        let name = providers
+            .get(id)
+            .map(|provider| provider.name.clone())
+            .unwrap_or_default();
+        let label = match status {
+            KeyStatus::Valid => success("valid"),
+            KeyStatus::Unauthorized => error("unauthorized"),
+            KeyStatus::RateLimited => warning("rate limited"),
+            KeyStatus::Unreachable => warning("unreachable"),
+            KeyStatus::Unknown => warning("unknown"),
+        };
+        table.add_row(vec![id.clone(), name, label]);
+    }
+    println!("{}", table);
+    println!();
+
+    let stale = statuses
+        .values()
+        .filter(|status| **status == KeyStatus::Unauthorized)
+        .count();
+    if stale == 0 {
+        println!("{}", success("✓ No rejected keys found"));
+    } else {
+        println!(
+            "{}",
+            warning(&format!(
+                "{stale} provider key(s) were rejected; update them with `provider edit <id>`."
+            ))
+        );
+    }
+
+    Ok(())
+}
+
 fn model_fetch_target(
     provider: &Provider,
     app_type: &AppType,
@@ -479,14 +581,6 @@ fn extract_claude_config(settings_config: &Value) -> ClaudeConfig {
     }
 }
 
-fn mask_api_key(key: &str) -> String {
-    if key.len() > 8 {
-        format!("{}...", &key[..8])
-    } else {
-        key.to_string()
-    }
-}
-
 fn simplify_model_name(name: &str) -> String {
     if let Some(pos) = name.rfind('-') {
         let suffix = &name[pos + 1..];
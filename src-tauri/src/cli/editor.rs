@@ -1,7 +1,168 @@
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
 use crate::cli::i18n::texts;
+use crate::settings::Viewer;
 use crate::AppError;
 
+/// 查看内容时写入的临时文件名，扩展名便于编辑器/分页器按纯文本处理
+const VIEW_FILE_NAME: &str = "content.txt";
+
 pub fn open_external_editor(initial_content: &str) -> Result<String, AppError> {
     edit::edit(initial_content)
         .map_err(|e| AppError::Message(format!("{}: {}", texts::editor_failed(), e)))
 }
+
+/// 按 `viewer` 用外部程序查看 `content`，阻塞到程序退出。
+///
+/// 返回 `false` 表示没有可用的外部程序（`Inline`、未设置 `$VISUAL`/`$EDITOR`、
+/// 程序不存在），调用方应回落为内嵌展示。
+pub fn view_in_external_program(viewer: Viewer, content: &str) -> Result<bool, AppError> {
+    let Some(program) = viewer_program(viewer, |name| std::env::var(name).ok()) else {
+        return Ok(false);
+    };
+    view_with(content, |path| run_viewer(&program, path))
+}
+
+/// 解析外部查看程序：分页器取 `$PAGER`（默认 `less`），编辑器依次取 `$VISUAL`、`$EDITOR`
+fn viewer_program(viewer: Viewer, env: impl Fn(&str) -> Option<String>) -> Option<String> {
+    let from_env = |name: &str| env(name).filter(|value| !value.trim().is_empty());
+    match viewer {
+        Viewer::Inline => None,
+        Viewer::Pager => Some(from_env("PAGER").unwrap_or_else(|| "less".to_string())),
+        Viewer::Editor => from_env("VISUAL").or_else(|| from_env("EDITOR")),
+    }
+}
+
+/// 把内容写入只读临时文件交给 `run`，结束后删除临时目录。
+/// `run` 返回 `NotFound` 时视为程序不可用并返回 `false`。
+fn view_with(content: &str, run: impl FnOnce(&Path) -> io::Result<()>) -> Result<bool, AppError> {
+    let dir = crate::config::create_temp_dir(
+        "创建查看内容的临时目录失败",
+        "Failed to create a temp dir for viewing content",
+    )?;
+    let path = dir.path().join(VIEW_FILE_NAME);
+    std::fs::write(&path, content).map_err(|e| AppError::io(&path, e))?;
+    set_readonly(&path, true)?;
+
+    let result = run(&path);
+    // Windows 上只读文件会让临时目录删除失败，先恢复可写
+    set_readonly(&path, false)?;
+    drop(dir);
+
+    match result {
+        Ok(()) => Ok(true),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(err) => Err(AppError::localized(
+            "viewer.launch_failed",
+            format!("启动外部查看程序失败: {err}"),
+            format!("Failed to launch the external viewer: {err}"),
+        )),
+    }
+}
+
+fn set_readonly(path: &Path, readonly: bool) -> Result<(), AppError> {
+    let mut permissions = std::fs::metadata(path)
+        .map_err(|e| AppError::io(path, e))?
+        .permissions();
+    #[allow(clippy::permissions_set_readonly_false)]
+    permissions.set_readonly(readonly);
+    std::fs::set_permissions(path, permissions).map_err(|e| AppError::io(path, e))
+}
+
+/// 运行查看程序；`program` 可以带参数（如 `less -R`、`code --wait`），退出码不视为错误
+fn run_viewer(program: &str, path: &Path) -> io::Result<()> {
+    let mut parts = program.split_whitespace();
+    let Some(bin) = parts.next() else {
+        return Err(io::ErrorKind::NotFound.into());
+    };
+    Command::new(bin).args(parts).arg(path).status().map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env_of<'a>(pairs: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| {
+            pairs
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        }
+    }
+
+    #[test]
+    fn viewer_program_prefers_visual_and_defaults_pager_to_less() {
+        assert_eq!(
+            viewer_program(Viewer::Inline, env_of(&[("PAGER", "more")])),
+            None
+        );
+        assert_eq!(
+            viewer_program(Viewer::Pager, env_of(&[])).as_deref(),
+            Some("less")
+        );
+        assert_eq!(
+            viewer_program(Viewer::Pager, env_of(&[("PAGER", "most")])).as_deref(),
+            Some("most")
+        );
+        assert_eq!(
+            viewer_program(
+                Viewer::Editor,
+                env_of(&[("VISUAL", "code --wait"), ("EDITOR", "vi")])
+            )
+            .as_deref(),
+            Some("code --wait")
+        );
+        assert_eq!(
+            viewer_program(Viewer::Editor, env_of(&[("VISUAL", " "), ("EDITOR", "vi")])).as_deref(),
+            Some("vi")
+        );
+        assert_eq!(viewer_program(Viewer::Editor, env_of(&[])), None);
+    }
+
+    #[test]
+    fn view_with_writes_readonly_temp_file_and_cleans_it_up() {
+        let mut seen = None;
+        let shown = view_with("line 1\nline 2", |path| {
+            assert_eq!(std::fs::read_to_string(path).unwrap(), "line 1\nline 2");
+            assert!(std::fs::metadata(path).unwrap().permissions().readonly());
+            seen = Some(path.to_path_buf());
+            Ok(())
+        })
+        .expect("view content");
+
+        assert!(shown);
+        let path = seen.expect("viewer should receive the temp file");
+        assert!(!path.exists());
+        assert!(!path.parent().unwrap().exists());
+    }
+
+    #[test]
+    fn view_with_falls_back_to_inline_when_program_is_missing() {
+        let mut seen = None;
+        let shown = view_with("content", |path| {
+            seen = Some(path.to_path_buf());
+            Err(io::ErrorKind::NotFound.into())
+        })
+        .expect("missing program is not an error");
+
+        assert!(!shown);
+        assert!(!seen.unwrap().exists());
+        assert!(!view_in_external_program(Viewer::Inline, "content").unwrap());
+    }
+
+    #[test]
+    fn view_with_reports_other_launch_failures() {
+        let err = view_with("content", |_| Err(io::ErrorKind::PermissionDenied.into()))
+            .expect_err("permission errors should surface");
+        assert!(matches!(
+            err,
+            AppError::Localized {
+                key: "viewer.launch_failed",
+                ..
+            }
+        ));
+    }
+}
@@ -2,6 +2,10 @@ use crate::settings::{get_settings, update_settings};
 use std::sync::OnceLock;
 use std::sync::RwLock;
 
+mod table;
+
+pub use table::{missing_translations, text};
+
 #[cfg(test)]
 use std::cell::RefCell;
 
@@ -27,12 +31,31 @@ impl Language {
         }
     }
 
+    /// 解析语言代码，兼容 `$LANG` 形式的 locale（如 `zh_CN.UTF-8`、`en_US`）：
+    /// 去掉 `.` 之后的编码与 `@` 之后的修饰，`_` 统一为 `-` 后按主语言匹配。
     pub fn from_code(code: &str) -> Self {
-        match code.to_lowercase().as_str() {
-            "zh" | "zh-cn" | "zh-tw" | "chinese" => Language::Chinese,
+        let normalized = code
+            .trim()
+            .split(['.', '@'])
+            .next()
+            .unwrap_or_default()
+            .replace('_', "-")
+            .to_lowercase();
+        let primary = normalized.split('-').next().unwrap_or_default();
+        match primary {
+            "zh" | "chinese" => Language::Chinese,
             _ => Language::English,
         }
     }
+
+    /// 从环境变量推断语言：`CC_SWITCH_LANG` 优先，其次按 POSIX 约定 `LC_ALL` > `LANG`
+    fn from_env() -> Option<Self> {
+        ["CC_SWITCH_LANG", "LC_ALL", "LANG"]
+            .into_iter()
+            .filter_map(|key| std::env::var(key).ok())
+            .find(|value| !value.trim().is_empty())
+            .map(|value| Language::from_code(&value))
+    }
 }
 
 impl std::fmt::Display for Language {
@@ -54,6 +77,7 @@ fn language_store() -> &'static RwLock<Language> {
                 .language
                 .as_deref()
                 .map(Language::from_code)
+                .or_else(Language::from_env)
                 .unwrap_or(Language::English)
         };
         RwLock::new(lang)
@@ -112,6 +136,56 @@ pub fn is_chinese() -> bool {
     current_language() == Language::Chinese
 }
 
+/// Format a byte count with an adaptive binary unit, e.g. `1.5 GB`.
+///
+/// Values are rounded to one decimal place (a trailing `.0` is dropped) and
+/// promoted to the next unit when rounding would reach 1024.
+pub fn format_bytes(n: u64, lang: Language) -> String {
+    match lang {
+        Language::English => crate::services::webdav_sync::size_en(n),
+        Language::Chinese => crate::services::webdav_sync::size_zh(n),
+    }
+}
+
+/// Collation key for a display name.
+///
+/// English compares case-insensitively; Chinese additionally replaces each
+/// Han character with its toneless pinyin so that `阿里` sorts before `智谱`.
+/// Each pinyin syllable is followed by `\u{1}` so `xi an` stays ahead of `xian`.
+pub fn collation_key(name: &str, lang: Language) -> String {
+    use pinyin::ToPinyin;
+
+    let mut key = String::with_capacity(name.len());
+    for ch in name.chars() {
+        match (lang, ch.to_pinyin()) {
+            (Language::Chinese, Some(pinyin)) => {
+                key.push_str(pinyin.plain());
+                key.push('\u{1}');
+            }
+            _ => key.extend(ch.to_lowercase()),
+        }
+    }
+    key
+}
+
+/// Compare two display names using the current language's collation, falling
+/// back to the raw string so the order is total.
+pub fn collate(a: &str, b: &str) -> std::cmp::Ordering {
+    let lang = current_language();
+    collation_key(a, lang)
+        .cmp(&collation_key(b, lang))
+        .then_with(|| a.cmp(b))
+}
+
+/// Sort list entries by display name using the current language's collation.
+pub fn sort_entries<T>(entries: &mut [T], name: impl Fn(&T) -> &str) {
+    let lang = current_language();
+    entries.sort_by_cached_key(|entry| {
+        let name = name(entry);
+        (collation_key(name, lang), name.to_string())
+    });
+}
+
 // ============================================================================
 // Localized Text Macros and Functions
 // ============================================================================
@@ -136,34 +210,22 @@ pub use t;
 // ============================================================================
 
 pub mod texts {
-    use super::is_chinese;
+    use super::{is_chinese, text};
 
     // ============================================
     // ENTITY TYPE CONSTANTS (实体类型常量)
     // ============================================
 
     pub fn entity_provider() -> &'static str {
-        if is_chinese() {
-            "供应商"
-        } else {
-            "provider"
-        }
+        text("entity_provider")
     }
 
     pub fn entity_server() -> &'static str {
-        if is_chinese() {
-            "服务器"
-        } else {
-            "server"
-        }
+        text("entity_server")
     }
 
     pub fn entity_prompt() -> &'static str {
-        if is_chinese() {
-            "提示词"
-        } else {
-            "prompt"
-        }
+        text("entity_prompt")
     }
 
     // ============================================
@@ -248,27 +310,15 @@ pub mod texts {
 
     // Welcome & Headers
     pub fn welcome_title() -> &'static str {
-        if is_chinese() {
-            "    🎯 CC-Switch 交互模式"
-        } else {
-            "    🎯 CC-Switch Interactive Mode"
-        }
+        text("welcome_title")
     }
 
     pub fn application() -> &'static str {
-        if is_chinese() {
-            "应用程序"
-        } else {
-            "Application"
-        }
+        text("application")
     }
 
     pub fn goodbye() -> &'static str {
-        if is_chinese() {
-            "👋 再见！"
-        } else {
-            "👋 Goodbye!"
-        }
+        text("goodbye")
     }
 
     // Main Menu
@@ -281,19 +331,11 @@ pub mod texts {
     }
 
     pub fn interactive_requires_tty() -> &'static str {
-        if is_chinese() {
-            "交互模式需要在 TTY 终端中运行（请不要通过管道/重定向调用）。"
-        } else {
-            "Interactive mode requires a TTY (do not run with pipes/redirection)."
-        }
+        text("interactive_requires_tty")
     }
 
     pub fn interactive_legacy_tui_removed() -> &'static str {
-        if is_chinese() {
-            "旧版 legacy TUI 已移除，请直接使用当前默认的交互 TUI。"
-        } else {
-            "The legacy TUI has been removed. Please use the default interactive TUI instead."
-        }
+        text("interactive_legacy_tui_removed")
     }
 
     // Ratatui TUI (new interactive UI)
@@ -302,19 +344,11 @@ pub mod texts {
     }
 
     pub fn tui_tabs_title() -> &'static str {
-        if is_chinese() {
-            "App"
-        } else {
-            "App"
-        }
+        text("tui_tabs_title")
     }
 
     pub fn tui_hint_app_switch() -> &'static str {
-        if is_chinese() {
-            "切换 App:"
-        } else {
-            "Switch App:"
-        }
+        text("tui_hint_app_switch")
     }
 
     pub fn tui_filter_icon() -> &'static str {
@@ -358,11 +392,7 @@ pub mod texts {
     }
 
     pub fn tui_toast_json_must_be_object() -> &'static str {
-        if is_chinese() {
-            "JSON 必须是对象（例如：{\"env\":{...}}）"
-        } else {
-            "JSON must be an object (e.g. {\"env\":{...}})"
-        }
+        text("tui_toast_json_must_be_object")
     }
 
     pub fn tui_error_invalid_config_structure(e: &str) -> String {
@@ -420,211 +450,107 @@ pub mod texts {
         }
     }
     pub fn tui_nav_title() -> &'static str {
-        if is_chinese() {
-            "菜单"
-        } else {
-            "Menu"
-        }
+        text("tui_nav_title")
     }
 
     pub fn tui_filter_title() -> &'static str {
-        if is_chinese() {
-            "过滤"
-        } else {
-            "Filter"
-        }
+        text("tui_filter_title")
     }
 
     pub fn tui_footer_global() -> &'static str {
-        if is_chinese() {
-            "[ ] 切换应用  ←→ 切换菜单/内容  ↑↓ 移动  Enter 详情  s 切换  / 过滤  Esc 返回  ? 帮助"
-        } else {
-            "[ ] switch app  ←→ focus menu/content  ↑↓ move  Enter details  s switch  / filter  Esc back  ? help"
-        }
+        text("tui_footer_global")
     }
 
     pub fn tui_footer_group_nav() -> &'static str {
-        if is_chinese() {
-            "导航"
-        } else {
-            "NAV"
-        }
+        text("tui_footer_group_nav")
     }
 
     pub fn tui_footer_group_actions() -> &'static str {
-        if is_chinese() {
-            "功能"
-        } else {
-            "ACT"
-        }
+        text("tui_footer_group_actions")
     }
 
     pub fn tui_footer_nav_keys() -> &'static str {
-        if is_chinese() {
-            "←→ 菜单/内容  ↑↓ 移动"
-        } else {
-            "←→ menu/content  ↑↓ move"
-        }
+        text("tui_footer_nav_keys")
     }
 
     pub fn tui_footer_action_keys() -> &'static str {
-        if is_chinese() {
-            "[ ] 切换应用  Enter 详情  s 切换  / 过滤  Esc 返回  ? 帮助"
-        } else {
-            "[ ] switch app  Enter details  s switch  / filter  Esc back  ? help"
-        }
+        text("tui_footer_action_keys")
     }
 
     pub fn tui_footer_action_keys_main() -> &'static str {
-        if is_chinese() {
-            "[ ] 切换应用  / 过滤  Esc 返回  ? 帮助"
-        } else {
-            "[ ] switch app  / filter  Esc back  ? help"
-        }
+        text("tui_footer_action_keys_main")
     }
 
     pub fn tui_footer_action_keys_providers() -> &'static str {
-        if is_chinese() {
-            "[ ] 切换应用  Enter 详情  s 切换  a 添加  e 编辑  d 删除  t 测速  c 健康检查  / 过滤  Esc 返回  ? 帮助"
-        } else {
-            "[ ] switch app  Enter details  s switch  a add  e edit  d delete  t speedtest  c stream check  / filter  Esc back  ? help"
-        }
+        text("tui_footer_action_keys_providers")
     }
 
     pub fn tui_footer_action_keys_provider_detail() -> &'static str {
-        if is_chinese() {
-            "[ ] 切换应用  s 切换  e 编辑  t 测速  c 健康检查  / 过滤  Esc 返回  ? 帮助"
-        } else {
-            "[ ] switch app  s switch  e edit  t speedtest  c stream check  / filter  Esc back  ? help"
-        }
+        text("tui_footer_action_keys_provider_detail")
     }
 
     pub fn tui_footer_action_keys_mcp() -> &'static str {
-        if is_chinese() {
-            "[ ] 切换应用  x 启用/禁用  m 应用  a 添加  e 编辑  i 导入  d 删除  / 过滤  Esc 返回  ? 帮助"
-        } else {
-            "[ ] switch app  x toggle  m apps  a add  e edit  i import  d delete  / filter  Esc back  ? help"
-        }
+        text("tui_footer_action_keys_mcp")
     }
 
     pub fn tui_footer_action_keys_prompts() -> &'static str {
-        if is_chinese() {
-            "[ ] 切换应用  Enter 查看  a 激活  x 取消激活  e 编辑  d 删除  / 过滤  Esc 返回  ? 帮助"
-        } else {
-            "[ ] switch app  Enter view  a activate  x deactivate  e edit  d delete  / filter  Esc back  ? help"
-        }
+        text("tui_footer_action_keys_prompts")
     }
 
     pub fn tui_footer_action_keys_config() -> &'static str {
-        if is_chinese() {
-            "[ ] 切换应用  Enter 打开  e 编辑片段  / 过滤  Esc 返回  ? 帮助"
-        } else {
-            "[ ] switch app  Enter open  e edit snippet  / filter  Esc back  ? help"
-        }
+        text("tui_footer_action_keys_config")
     }
 
     pub fn tui_footer_action_keys_common_snippet_view() -> &'static str {
-        if is_chinese() {
-            "a 应用  c 清空  e 编辑  ↑↓ 滚动  Esc 返回"
-        } else {
-            "a apply  c clear  e edit  ↑↓ scroll  Esc back"
-        }
+        text("tui_footer_action_keys_common_snippet_view")
     }
 
     pub fn tui_footer_action_keys_settings() -> &'static str {
-        if is_chinese() {
-            "[ ] 切换应用  Enter 应用  / 过滤  Esc 返回  ? 帮助"
-        } else {
-            "[ ] switch app  Enter apply  / filter  Esc back  ? help"
-        }
+        text("tui_footer_action_keys_settings")
     }
 
     pub fn tui_footer_action_keys_global() -> &'static str {
-        if is_chinese() {
-            "[ ] 切换应用  / 过滤  Esc 返回  ? 帮助"
-        } else {
-            "[ ] switch app  / filter  Esc back  ? help"
-        }
+        text("tui_footer_action_keys_global")
     }
 
     pub fn tui_footer_filter_mode() -> &'static str {
-        if is_chinese() {
-            "输入关键字过滤，Enter 应用，Esc 清空并退出"
-        } else {
-            "Type to filter, Enter apply, Esc clear & exit"
-        }
+        text("tui_footer_filter_mode")
     }
 
     pub fn tui_help_title() -> &'static str {
-        if is_chinese() {
-            "帮助"
-        } else {
-            "Help"
-        }
+        text("tui_help_title")
     }
 
     pub fn tui_help_text() -> &'static str {
-        if is_chinese() {
-            "[ ]  切换应用\n←→  切换菜单/内容焦点\n↑↓  移动\n/   过滤\nEsc  返回\n?   显示/关闭帮助\n\n页面快捷键（在页面内容区顶部显示）：\n- 供应商：Enter 详情，s 切换，a 添加，e 编辑，d 删除，t 测速，c 健康检查\n- 供应商详情：s 切换，e 编辑，t 测速，c 健康检查\n- MCP：x 启用/禁用(当前应用)，m 选择应用，a 添加，e 编辑，i 导入已有，d 删除\n- 提示词：Enter 查看，a 激活，x 取消激活(当前)，e 编辑，d 删除\n- 技能：Enter 详情，x 启用/禁用(当前应用)，m 选择应用，d 卸载，i 导入已有\n- 配置：Enter 打开/执行，e 编辑片段\n- 设置：Enter 应用"
-        } else {
-            "[ ]  switch app\n←→  focus menu/content\n↑↓  move\n/   filter\nEsc  back\n?   toggle help\n\nPage keys (shown at the top of each page):\n- Providers: Enter details, s switch, a add, e edit, d delete, t speedtest, c stream check\n- Provider Detail: s switch, e edit, t speedtest, c stream check\n- MCP: x toggle current, m select apps, a add, e edit, i import existing, d delete\n- Prompts: Enter view, a activate, x deactivate active, e edit, d delete\n- Skills: Enter details, x toggle current, m select apps, d uninstall, i import existing\n- Config: Enter open/run, e edit snippet\n- Settings: Enter apply"
-        }
+        text("tui_help_text")
     }
 
     pub fn tui_confirm_title() -> &'static str {
-        if is_chinese() {
-            "确认"
-        } else {
-            "Confirm"
-        }
+        text("tui_confirm_title")
     }
 
     pub fn tui_confirm_exit_title() -> &'static str {
-        if is_chinese() {
-            "退出"
-        } else {
-            "Exit"
-        }
+        text("tui_confirm_exit_title")
     }
 
     pub fn tui_confirm_exit_message() -> &'static str {
-        if is_chinese() {
-            "确定退出 cc-switch？"
-        } else {
-            "Exit cc-switch?"
-        }
+        text("tui_confirm_exit_message")
     }
 
     pub fn tui_confirm_yes_hint() -> &'static str {
-        if is_chinese() {
-            "y/Enter = 是"
-        } else {
-            "y/Enter = Yes"
-        }
+        text("tui_confirm_yes_hint")
     }
 
     pub fn tui_confirm_no_hint() -> &'static str {
-        if is_chinese() {
-            "n/Esc   = 否"
-        } else {
-            "n/Esc   = No"
-        }
+        text("tui_confirm_no_hint")
     }
 
     pub fn tui_input_title() -> &'static str {
-        if is_chinese() {
-            "输入"
-        } else {
-            "Input"
-        }
+        text("tui_input_title")
     }
 
     pub fn tui_editor_text_field_title() -> &'static str {
-        if is_chinese() {
-            "文本"
-        } else {
-            "Text"
-        }
+        text("tui_editor_text_field_title")
     }
 
     pub fn tui_editor_json_field_title() -> &'static str {
@@ -632,75 +558,39 @@ pub mod texts {
     }
 
     pub fn tui_editor_hint_view() -> &'static str {
-        if is_chinese() {
-            "Enter 编辑  ↑↓ 滚动  Ctrl+S 保存  Esc 返回"
-        } else {
-            "Enter edit  ↑↓ scroll  Ctrl+S save  Esc back"
-        }
+        text("tui_editor_hint_view")
     }
 
     pub fn tui_editor_hint_edit() -> &'static str {
-        if is_chinese() {
-            "编辑中：Esc 退出编辑  Ctrl+S 保存"
-        } else {
-            "Editing: Esc stop editing  Ctrl+S save"
-        }
+        text("tui_editor_hint_edit")
     }
 
     pub fn tui_editor_discard_title() -> &'static str {
-        if is_chinese() {
-            "放弃修改"
-        } else {
-            "Discard Changes"
-        }
+        text("tui_editor_discard_title")
     }
 
     pub fn tui_editor_discard_message() -> &'static str {
-        if is_chinese() {
-            "有未保存的修改，确定放弃？"
-        } else {
-            "You have unsaved changes. Discard them?"
-        }
+        text("tui_editor_discard_message")
     }
 
     pub fn tui_editor_save_before_close_title() -> &'static str {
-        if is_chinese() {
-            "当前未保存"
-        } else {
-            "Unsaved Changes"
-        }
+        text("tui_editor_save_before_close_title")
     }
 
     pub fn tui_editor_save_before_close_message() -> &'static str {
-        if is_chinese() {
-            "当前有未保存的修改。"
-        } else {
-            "You have unsaved changes."
-        }
+        text("tui_editor_save_before_close_message")
     }
 
     pub fn tui_speedtest_title() -> &'static str {
-        if is_chinese() {
-            "测速"
-        } else {
-            "Speedtest"
-        }
+        text("tui_speedtest_title")
     }
 
     pub fn tui_stream_check_title() -> &'static str {
-        if is_chinese() {
-            "健康检查"
-        } else {
-            "Stream Check"
-        }
+        text("tui_stream_check_title")
     }
 
     pub fn tui_main_hint() -> &'static str {
-        if is_chinese() {
-            "使用左侧菜单（↑↓ + Enter）。←→ 在菜单与内容间切换焦点。"
-        } else {
-            "Use the left menu (↑↓ + Enter). ←→ switches focus between menu and content."
-        }
+        text("tui_main_hint")
     }
 
     pub fn tui_header_proxy_status(enabled: bool) -> String {
@@ -712,99 +602,51 @@ pub mod texts {
     }
 
     pub fn tui_home_section_connection() -> &'static str {
-        if is_chinese() {
-            "连接信息"
-        } else {
-            "Connection Details"
-        }
+        text("tui_home_section_connection")
     }
 
     pub fn tui_home_section_proxy() -> &'static str {
-        if is_chinese() {
-            "代理仪表盘"
-        } else {
-            "Proxy Dashboard"
-        }
+        text("tui_home_section_proxy")
     }
 
     pub fn tui_home_section_context() -> &'static str {
-        if is_chinese() {
-            "Session Context"
-        } else {
-            "Session Context"
-        }
+        text("tui_home_section_context")
     }
 
     pub fn tui_home_section_local_env_check() -> &'static str {
-        if is_chinese() {
-            "本地环境检查"
-        } else {
-            "Local environment check"
-        }
+        text("tui_home_section_local_env_check")
     }
 
     pub fn tui_home_section_webdav() -> &'static str {
-        if is_chinese() {
-            "WebDAV 同步"
-        } else {
-            "WebDAV Sync"
-        }
+        text("tui_home_section_webdav")
     }
 
     pub fn tui_label_webdav_status() -> &'static str {
-        if is_chinese() {
-            "状态"
-        } else {
-            "Status"
-        }
+        text("tui_label_webdav_status")
     }
 
     pub fn tui_label_webdav_last_sync() -> &'static str {
-        if is_chinese() {
-            "最近同步"
-        } else {
-            "Last sync"
-        }
+        text("tui_label_webdav_last_sync")
     }
 
     pub fn tui_webdav_status_not_configured() -> &'static str {
-        if is_chinese() {
-            "未配置"
-        } else {
-            "Not configured"
-        }
+        text("tui_webdav_status_not_configured")
     }
 
     pub fn tui_webdav_status_configured() -> &'static str {
-        if is_chinese() {
-            "已配置"
-        } else {
-            "Configured"
-        }
+        text("tui_webdav_status_configured")
     }
 
     pub fn tui_webdav_status_never_synced() -> &'static str {
-        if is_chinese() {
-            "从未同步"
-        } else {
-            "Never synced"
-        }
+        text("tui_webdav_status_never_synced")
     }
 
     pub fn tui_webdav_status_ok() -> &'static str {
-        if is_chinese() {
-            "正常"
-        } else {
-            "OK"
-        }
+        text("tui_webdav_status_ok")
     }
 
     pub fn tui_webdav_status_error() -> &'static str {
-        if is_chinese() {
-            "失败"
-        } else {
-            "Error"
-        }
+        text("tui_webdav_status_error")
     }
 
     pub fn tui_webdav_status_error_with_detail(detail: &str) -> String {
@@ -816,59 +658,31 @@ pub mod texts {
     }
 
     pub fn tui_local_env_not_installed() -> &'static str {
-        if is_chinese() {
-            "未安装或不可执行"
-        } else {
-            "not installed or not executable"
-        }
+        text("tui_local_env_not_installed")
     }
 
     pub fn tui_home_status_online() -> &'static str {
-        if is_chinese() {
-            "在线"
-        } else {
-            "Online"
-        }
+        text("tui_home_status_online")
     }
 
     pub fn tui_home_status_offline() -> &'static str {
-        if is_chinese() {
-            "离线"
-        } else {
-            "Offline"
-        }
+        text("tui_home_status_offline")
     }
 
     pub fn tui_proxy_dashboard_status_running() -> &'static str {
-        if is_chinese() {
-            "已启用"
-        } else {
-            "ACTIVE"
-        }
+        text("tui_proxy_dashboard_status_running")
     }
 
     pub fn tui_proxy_dashboard_status_stopped() -> &'static str {
-        if is_chinese() {
-            "本地"
-        } else {
-            "LOCAL"
-        }
+        text("tui_proxy_dashboard_status_stopped")
     }
 
     pub fn tui_proxy_dashboard_status_local_only() -> &'static str {
-        if is_chinese() {
-            "仅本地"
-        } else {
-            "LOCAL ONLY"
-        }
+        text("tui_proxy_dashboard_status_local_only")
     }
 
     pub fn tui_proxy_dashboard_status_unsupported() -> &'static str {
-        if is_chinese() {
-            "不支持"
-        } else {
-            "UNSUPPORTED"
-        }
+        text("tui_proxy_dashboard_status_unsupported")
     }
 
     pub fn tui_proxy_dashboard_manual_routing_copy(app: &str) -> String {
@@ -880,11 +694,7 @@ pub mod texts {
     }
 
     pub fn tui_proxy_dashboard_failover_copy() -> &'static str {
-        if is_chinese() {
-            "仅做手动路由，不会自动切换供应商。"
-        } else {
-            "automatic failover stays off; provider changes stay manual."
-        }
+        text("tui_proxy_dashboard_failover_copy")
     }
 
     pub fn tui_proxy_dashboard_cta_start(app: &str) -> String {
@@ -904,27 +714,15 @@ pub mod texts {
     }
 
     pub fn tui_proxy_loading_title_start() -> &'static str {
-        if is_chinese() {
-            "启动代理中"
-        } else {
-            "Starting proxy"
-        }
+        text("tui_proxy_loading_title_start")
     }
 
     pub fn tui_proxy_loading_title_stop() -> &'static str {
-        if is_chinese() {
-            "停止代理中"
-        } else {
-            "Stopping proxy"
-        }
+        text("tui_proxy_loading_title_stop")
     }
 
     pub fn tui_proxy_dashboard_running_elsewhere() -> &'static str {
-        if is_chinese() {
-            "代理已在运行。请先停止当前路由，再从这里启动。"
-        } else {
-            "Proxy is already running. Stop the current route before starting it here."
-        }
+        text("tui_proxy_dashboard_running_elsewhere")
     }
 
     pub fn tui_proxy_dashboard_current_app_on(app: &str) -> String {
@@ -952,11 +750,7 @@ pub mod texts {
     }
 
     pub fn tui_proxy_dashboard_shared_runtime_ready() -> &'static str {
-        if is_chinese() {
-            "共享 runtime 就绪"
-        } else {
-            "Shared runtime ready"
-        }
+        text("tui_proxy_dashboard_shared_runtime_ready")
     }
 
     pub fn tui_proxy_dashboard_no_route_for_app(app: &str) -> String {
@@ -968,59 +762,31 @@ pub mod texts {
     }
 
     pub fn tui_proxy_dashboard_takeover_active() -> &'static str {
-        if is_chinese() {
-            "已接管"
-        } else {
-            "active"
-        }
+        text("tui_proxy_dashboard_takeover_active")
     }
 
     pub fn tui_proxy_dashboard_takeover_inactive() -> &'static str {
-        if is_chinese() {
-            "未接管"
-        } else {
-            "inactive"
-        }
+        text("tui_proxy_dashboard_takeover_inactive")
     }
 
     pub fn tui_proxy_dashboard_takeover_unsupported() -> &'static str {
-        if is_chinese() {
-            "不支持"
-        } else {
-            "not supported"
-        }
+        text("tui_proxy_dashboard_takeover_unsupported")
     }
 
     pub fn tui_proxy_dashboard_uptime_stopped() -> &'static str {
-        if is_chinese() {
-            "未运行"
-        } else {
-            "--"
-        }
+        text("tui_proxy_dashboard_uptime_stopped")
     }
 
     pub fn tui_proxy_dashboard_requests_idle() -> &'static str {
-        if is_chinese() {
-            "暂无流量"
-        } else {
-            "No traffic yet"
-        }
+        text("tui_proxy_dashboard_requests_idle")
     }
 
     pub fn tui_proxy_dashboard_tokens_idle() -> &'static str {
-        if is_chinese() {
-            "暂无 token 流量"
-        } else {
-            "No token traffic yet"
-        }
+        text("tui_proxy_dashboard_tokens_idle")
     }
 
     pub fn tui_proxy_dashboard_target_waiting() -> &'static str {
-        if is_chinese() {
-            "等待首个请求"
-        } else {
-            "Waiting for first request"
-        }
+        text("tui_proxy_dashboard_target_waiting")
     }
 
     pub fn tui_proxy_dashboard_request_summary(total: u64, success_rate: f32) -> String {
@@ -1040,107 +806,55 @@ pub mod texts {
     }
 
     pub fn tui_label_current_app_takeover() -> &'static str {
-        if is_chinese() {
-            "当前应用接管"
-        } else {
-            "Current app takeover"
-        }
+        text("tui_label_current_app_takeover")
     }
 
     pub fn tui_label_current_app_route() -> &'static str {
-        if is_chinese() {
-            "当前应用路由"
-        } else {
-            "Current app route"
-        }
+        text("tui_label_current_app_route")
     }
 
     pub fn tui_label_latest_proxy_route() -> &'static str {
-        if is_chinese() {
-            "最近代理路由"
-        } else {
-            "Latest proxy route"
-        }
+        text("tui_label_latest_proxy_route")
     }
 
     pub fn tui_label_shared_runtime() -> &'static str {
-        if is_chinese() {
-            "共享 runtime"
-        } else {
-            "Shared runtime"
-        }
+        text("tui_label_shared_runtime")
     }
 
     pub fn tui_label_listen() -> &'static str {
-        if is_chinese() {
-            "监听"
-        } else {
-            "Listen"
-        }
+        text("tui_label_listen")
     }
 
     pub fn tui_label_uptime() -> &'static str {
-        if is_chinese() {
-            "运行时长"
-        } else {
-            "Uptime"
-        }
+        text("tui_label_uptime")
     }
 
     pub fn tui_label_requests() -> &'static str {
-        if is_chinese() {
-            "请求"
-        } else {
-            "Requests"
-        }
+        text("tui_label_requests")
     }
 
     pub fn tui_label_traffic() -> &'static str {
-        if is_chinese() {
-            "流量"
-        } else {
-            "Traffic"
-        }
+        text("tui_label_traffic")
     }
 
     pub fn tui_label_proxy_requests() -> &'static str {
-        if is_chinese() {
-            "代理总请求"
-        } else {
-            "Proxy requests"
-        }
+        text("tui_label_proxy_requests")
     }
 
     pub fn tui_label_active_target() -> &'static str {
-        if is_chinese() {
-            "当前路由目标"
-        } else {
-            "Active target"
-        }
+        text("tui_label_active_target")
     }
 
     pub fn tui_label_last_error() -> &'static str {
-        if is_chinese() {
-            "最近错误"
-        } else {
-            "Last error"
-        }
+        text("tui_label_last_error")
     }
 
     pub fn tui_label_last_proxy_error() -> &'static str {
-        if is_chinese() {
-            "最近一次代理错误"
-        } else {
-            "Last proxy error"
-        }
+        text("tui_label_last_proxy_error")
     }
 
     pub fn tui_label_mcp_servers_active() -> &'static str {
-        if is_chinese() {
-            "已启用"
-        } else {
-            "Active"
-        }
+        text("tui_label_mcp_servers_active")
     }
 
     pub fn tui_na() -> &'static str {
@@ -1148,11 +862,7 @@ pub mod texts {
     }
 
     pub fn tui_loading() -> &'static str {
-        if is_chinese() {
-            "处理中…"
-        } else {
-            "Working…"
-        }
+        text("tui_loading")
     }
 
     pub fn tui_header_id() -> &'static str {
@@ -1164,59 +874,31 @@ pub mod texts {
     }
 
     pub fn tui_header_directory() -> &'static str {
-        if is_chinese() {
-            "目录"
-        } else {
-            "Directory"
-        }
+        text("tui_header_directory")
     }
 
     pub fn tui_header_repo() -> &'static str {
-        if is_chinese() {
-            "仓库"
-        } else {
-            "Repo"
-        }
+        text("tui_header_repo")
     }
 
     pub fn tui_header_branch() -> &'static str {
-        if is_chinese() {
-            "分支"
-        } else {
-            "Branch"
-        }
+        text("tui_header_branch")
     }
 
     pub fn tui_header_path() -> &'static str {
-        if is_chinese() {
-            "路径"
-        } else {
-            "Path"
-        }
+        text("tui_header_path")
     }
 
     pub fn tui_header_found_in() -> &'static str {
-        if is_chinese() {
-            "发现于"
-        } else {
-            "Found In"
-        }
+        text("tui_header_found_in")
     }
 
     pub fn tui_header_field() -> &'static str {
-        if is_chinese() {
-            "字段"
-        } else {
-            "Field"
-        }
+        text("tui_header_field")
     }
 
     pub fn tui_header_value() -> &'static str {
-        if is_chinese() {
-            "值"
-        } else {
-            "Value"
-        }
+        text("tui_header_value")
     }
 
     pub fn tui_header_claude_short() -> &'static str {
@@ -1244,59 +926,31 @@ pub mod texts {
     }
 
     pub fn tui_label_directory() -> &'static str {
-        if is_chinese() {
-            "目录"
-        } else {
-            "Directory"
-        }
+        text("tui_label_directory")
     }
 
     pub fn tui_label_enabled_for() -> &'static str {
-        if is_chinese() {
-            "已启用"
-        } else {
-            "Enabled"
-        }
+        text("tui_label_enabled_for")
     }
 
     pub fn tui_label_repo() -> &'static str {
-        if is_chinese() {
-            "仓库"
-        } else {
-            "Repo"
-        }
+        text("tui_label_repo")
     }
 
     pub fn tui_label_readme() -> &'static str {
-        if is_chinese() {
-            "README"
-        } else {
-            "README"
-        }
+        text("tui_label_readme")
     }
 
     pub fn tui_label_base_url() -> &'static str {
-        if is_chinese() {
-            "API 请求地址"
-        } else {
-            "Base URL"
-        }
+        text("tui_label_base_url")
     }
 
     pub fn tui_label_api_key() -> &'static str {
-        if is_chinese() {
-            "API Key"
-        } else {
-            "API Key"
-        }
+        text("tui_label_api_key")
     }
 
     pub fn tui_label_claude_api_format() -> &'static str {
-        if is_chinese() {
-            "API 格式"
-        } else {
-            "API Format"
-        }
+        text("tui_label_claude_api_format")
     }
 
     pub fn tui_claude_api_format_value(api_format: &str) -> &'static str {
@@ -1326,11 +980,7 @@ pub mod texts {
     }
 
     pub fn tui_claude_api_format_requires_proxy_title() -> &'static str {
-        if is_chinese() {
-            "需开启代理"
-        } else {
-            "Proxy Required"
-        }
+        text("tui_claude_api_format_requires_proxy_title")
     }
 
     pub fn tui_claude_api_format_requires_proxy_message(api_format: &str) -> String {
@@ -1343,107 +993,55 @@ pub mod texts {
     }
 
     pub fn tui_claude_api_format_popup_title() -> &'static str {
-        if is_chinese() {
-            "API 格式"
-        } else {
-            "API Format"
-        }
+        text("tui_claude_api_format_popup_title")
     }
 
     pub fn tui_label_claude_model_config() -> &'static str {
-        if is_chinese() {
-            "Claude 模型配置"
-        } else {
-            "Claude Model Config"
-        }
+        text("tui_label_claude_model_config")
     }
 
     pub fn tui_label_provider_package() -> &'static str {
-        if is_chinese() {
-            "Provider / npm 包"
-        } else {
-            "Provider / npm"
-        }
+        text("tui_label_provider_package")
     }
 
     pub fn tui_label_openclaw_api() -> &'static str {
-        if is_chinese() {
-            "API 协议"
-        } else {
-            "API Mode"
-        }
+        text("tui_label_openclaw_api")
     }
 
     pub fn tui_label_openclaw_user_agent() -> &'static str {
-        if is_chinese() {
-            "发送 User-Agent"
-        } else {
-            "Send User-Agent"
-        }
+        text("tui_label_openclaw_user_agent")
     }
 
     pub fn tui_label_openclaw_models() -> &'static str {
-        if is_chinese() {
-            "模型列表"
-        } else {
-            "Models"
-        }
+        text("tui_label_openclaw_models")
     }
 
     pub fn tui_label_openclaw_status() -> &'static str {
-        if is_chinese() {
-            "状态"
-        } else {
-            "Status"
-        }
+        text("tui_label_openclaw_status")
     }
 
     pub fn tui_label_openclaw_model() -> &'static str {
-        if is_chinese() {
-            "模型"
-        } else {
-            "Model"
-        }
+        text("tui_label_openclaw_model")
     }
 
     pub fn tui_openclaw_status_default() -> &'static str {
-        if is_chinese() {
-            "默认"
-        } else {
-            "default"
-        }
+        text("tui_openclaw_status_default")
     }
 
     pub fn tui_openclaw_status_in_config_and_saved() -> &'static str {
-        if is_chinese() {
-            "配置中 + 已保存"
-        } else {
-            "in config + saved"
-        }
+        text("tui_openclaw_status_in_config_and_saved")
     }
 
     pub fn tui_openclaw_status_live_only() -> &'static str {
-        if is_chinese() {
-            "仅当前配置"
-        } else {
-            "live only"
-        }
+        text("tui_openclaw_status_live_only")
     }
 
     pub fn tui_openclaw_status_saved_only() -> &'static str {
-        if is_chinese() {
-            "仅已保存"
-        } else {
-            "saved only"
-        }
+        text("tui_openclaw_status_saved_only")
     }
 
     pub fn tui_openclaw_status_untracked() -> &'static str {
-        if is_chinese() {
-            "未跟踪"
-        } else {
-            "untracked"
-        }
+        text("tui_openclaw_status_untracked")
     }
 
     pub fn tui_openclaw_models_summary(total: usize) -> String {
@@ -1467,131 +1065,67 @@ pub mod texts {
     }
 
     pub fn tui_openclaw_models_open_hint() -> &'static str {
-        if is_chinese() {
-            "按 Enter 编辑 OpenClaw 模型列表"
-        } else {
-            "Press Enter to edit OpenClaw models"
-        }
+        text("tui_openclaw_models_open_hint")
     }
 
     pub fn tui_openclaw_models_editor_title() -> &'static str {
-        if is_chinese() {
-            "OpenClaw 模型列表"
-        } else {
-            "OpenClaw Models"
-        }
+        text("tui_openclaw_models_editor_title")
     }
 
     pub fn tui_toast_json_must_be_array() -> &'static str {
-        if is_chinese() {
-            "JSON 必须是数组"
-        } else {
-            "JSON must be an array"
-        }
+        text("tui_toast_json_must_be_array")
     }
 
     pub fn tui_label_opencode_model_id() -> &'static str {
-        if is_chinese() {
-            "主模型 ID"
-        } else {
-            "Main Model ID"
-        }
+        text("tui_label_opencode_model_id")
     }
 
     pub fn tui_label_opencode_model_name() -> &'static str {
-        if is_chinese() {
-            "主模型名称"
-        } else {
-            "Main Model Name"
-        }
+        text("tui_label_opencode_model_name")
     }
 
     pub fn tui_label_context_limit() -> &'static str {
-        if is_chinese() {
-            "上下文限制"
-        } else {
-            "Context Limit"
-        }
+        text("tui_label_context_limit")
     }
 
     pub fn tui_label_output_limit() -> &'static str {
-        if is_chinese() {
-            "输出限制"
-        } else {
-            "Output Limit"
-        }
+        text("tui_label_output_limit")
     }
 
     pub fn tui_label_command() -> &'static str {
-        if is_chinese() {
-            "命令"
-        } else {
-            "Command"
-        }
+        text("tui_label_command")
     }
 
     pub fn tui_label_args() -> &'static str {
-        if is_chinese() {
-            "参数"
-        } else {
-            "Args"
-        }
+        text("tui_label_args")
     }
 
     pub fn tui_label_app_claude() -> &'static str {
-        if is_chinese() {
-            "应用: Claude"
-        } else {
-            "App: Claude"
-        }
+        text("tui_label_app_claude")
     }
 
     pub fn tui_label_app_codex() -> &'static str {
-        if is_chinese() {
-            "应用: Codex"
-        } else {
-            "App: Codex"
-        }
+        text("tui_label_app_codex")
     }
 
     pub fn tui_label_app_gemini() -> &'static str {
-        if is_chinese() {
-            "应用: Gemini"
-        } else {
-            "App: Gemini"
-        }
+        text("tui_label_app_gemini")
     }
 
     pub fn tui_form_templates_title() -> &'static str {
-        if is_chinese() {
-            "模板"
-        } else {
-            "Templates"
-        }
+        text("tui_form_templates_title")
     }
 
     pub fn tui_form_common_config_button() -> &'static str {
-        if is_chinese() {
-            "通用配置"
-        } else {
-            "Common Config"
-        }
+        text("tui_form_common_config_button")
     }
 
     pub fn tui_form_attach_common_config() -> &'static str {
-        if is_chinese() {
-            "添加通用配置"
-        } else {
-            "Attach Common Config"
-        }
+        text("tui_form_attach_common_config")
     }
 
     pub fn tui_form_fields_title() -> &'static str {
-        if is_chinese() {
-            "字段"
-        } else {
-            "Fields"
-        }
+        text("tui_form_fields_title")
     }
 
     pub fn tui_form_json_title() -> &'static str {
@@ -1599,83 +1133,43 @@ pub mod texts {
     }
 
     pub fn tui_codex_auth_json_title() -> &'static str {
-        if is_chinese() {
-            "auth.json (JSON) *"
-        } else {
-            "auth.json (JSON) *"
-        }
+        text("tui_codex_auth_json_title")
     }
 
     pub fn tui_codex_config_toml_title() -> &'static str {
-        if is_chinese() {
-            "config.toml (TOML)"
-        } else {
-            "config.toml (TOML)"
-        }
+        text("tui_codex_config_toml_title")
     }
 
     pub fn tui_form_input_title() -> &'static str {
-        if is_chinese() {
-            "输入"
-        } else {
-            "Input"
-        }
+        text("tui_form_input_title")
     }
 
     pub fn tui_form_editing_title() -> &'static str {
-        if is_chinese() {
-            "编辑中"
-        } else {
-            "Editing"
-        }
+        text("tui_form_editing_title")
     }
 
     pub fn tui_claude_model_config_popup_title() -> &'static str {
-        if is_chinese() {
-            "Claude 模型配置"
-        } else {
-            "Claude Model Configuration"
-        }
+        text("tui_claude_model_config_popup_title")
     }
 
     pub fn tui_claude_model_main_label() -> &'static str {
-        if is_chinese() {
-            "主模型"
-        } else {
-            "Main Model"
-        }
+        text("tui_claude_model_main_label")
     }
 
     pub fn tui_claude_reasoning_model_label() -> &'static str {
-        if is_chinese() {
-            "推理模型 (Thinking)"
-        } else {
-            "Reasoning Model (Thinking)"
-        }
+        text("tui_claude_reasoning_model_label")
     }
 
     pub fn tui_claude_default_haiku_model_label() -> &'static str {
-        if is_chinese() {
-            "默认 Haiku 模型"
-        } else {
-            "Default Haiku Model"
-        }
+        text("tui_claude_default_haiku_model_label")
     }
 
     pub fn tui_claude_default_sonnet_model_label() -> &'static str {
-        if is_chinese() {
-            "默认 Sonnet 模型"
-        } else {
-            "Default Sonnet Model"
-        }
+        text("tui_claude_default_sonnet_model_label")
     }
 
     pub fn tui_claude_default_opus_model_label() -> &'static str {
-        if is_chinese() {
-            "默认 Opus 模型"
-        } else {
-            "Default Opus Model"
-        }
+        text("tui_claude_default_opus_model_label")
     }
 
     pub fn tui_claude_model_config_summary(configured_count: usize) -> String {
@@ -1687,27 +1181,15 @@ pub mod texts {
     }
 
     pub fn tui_claude_model_config_open_hint() -> &'static str {
-        if is_chinese() {
-            "按 Enter 配置 Claude 模型"
-        } else {
-            "Press Enter to configure Claude models"
-        }
+        text("tui_claude_model_config_open_hint")
     }
 
     pub fn tui_hint_press() -> &'static str {
-        if is_chinese() {
-            "按 "
-        } else {
-            "Press "
-        }
+        text("tui_hint_press")
     }
 
     pub fn tui_hint_auto_fetch_models_from_api() -> &'static str {
-        if is_chinese() {
-            " 从 API 自动获取模型。"
-        } else {
-            " to auto-fetch models from API."
-        }
+        text("tui_hint_auto_fetch_models_from_api")
     }
 
     pub fn tui_model_fetch_popup_title(fetching: bool) -> String {
@@ -1727,35 +1209,19 @@ pub mod texts {
     }
 
     pub fn tui_model_fetch_search_placeholder() -> &'static str {
-        if is_chinese() {
-            "输入过滤 或 直接回车使用输入值..."
-        } else {
-            "Type to filter, or press Enter to use input..."
-        }
+        text("tui_model_fetch_search_placeholder")
     }
 
     pub fn tui_model_fetch_search_title() -> &'static str {
-        if is_chinese() {
-            "模型搜索"
-        } else {
-            "Model Search"
-        }
+        text("tui_model_fetch_search_title")
     }
 
     pub fn tui_model_fetch_no_models() -> &'static str {
-        if is_chinese() {
-            "没有获取到模型 (可直接输入并在此回车)"
-        } else {
-            "No models found (type custom and press Enter)"
-        }
+        text("tui_model_fetch_no_models")
     }
 
     pub fn tui_model_fetch_no_matches() -> &'static str {
-        if is_chinese() {
-            "没有匹配结果 (可直接输入并在此回车)"
-        } else {
-            "No matching models (press Enter to use input)"
-        }
+        text("tui_model_fetch_no_matches")
     }
 
     pub fn tui_model_fetch_error_hint(err: &str) -> String {
@@ -1767,51 +1233,27 @@ pub mod texts {
     }
 
     pub fn tui_provider_not_found() -> &'static str {
-        if is_chinese() {
-            "未找到该供应商。"
-        } else {
-            "Provider not found."
-        }
+        text("tui_provider_not_found")
     }
 
     pub fn tui_provider_title() -> &'static str {
-        if is_chinese() {
-            "供应商"
-        } else {
-            "Provider"
-        }
+        text("tui_provider_title")
     }
 
     pub fn tui_provider_detail_title() -> &'static str {
-        if is_chinese() {
-            "供应商详情"
-        } else {
-            "Provider Detail"
-        }
+        text("tui_provider_detail_title")
     }
 
     pub fn tui_provider_add_title() -> &'static str {
-        if is_chinese() {
-            "新增供应商"
-        } else {
-            "Add Provider"
-        }
+        text("tui_provider_add_title")
     }
 
     pub fn tui_codex_official_no_api_key_tip() -> &'static str {
-        if is_chinese() {
-            "官方无需填写 API Key，直接保存即可。"
-        } else {
-            "Official provider doesn't require an API key. Just save."
-        }
+        text("tui_codex_official_no_api_key_tip")
     }
 
     pub fn tui_toast_codex_official_auth_json_disabled() -> &'static str {
-        if is_chinese() {
-            "官方模式下不支持编辑 auth.json（切换时会移除）。"
-        } else {
-            "auth.json editing is disabled for the official provider (it will be removed on switch)."
-        }
+        text("tui_toast_codex_official_auth_json_disabled")
     }
 
     pub fn tui_provider_edit_title(name: &str) -> String {
@@ -1823,475 +1265,239 @@ pub mod texts {
     }
 
     pub fn tui_provider_detail_keys() -> &'static str {
-        if is_chinese() {
-            "按键：s=切换  e=编辑  t=测速  c=健康检查"
-        } else {
-            "Keys: s=switch  e=edit  t=speedtest  c=stream check"
-        }
+        text("tui_provider_detail_keys")
     }
 
     pub fn tui_key_switch() -> &'static str {
-        if is_chinese() {
-            "切换"
-        } else {
-            "switch"
-        }
+        text("tui_key_switch")
     }
 
     pub fn tui_key_add_remove() -> &'static str {
-        if is_chinese() {
-            "添加/移除"
-        } else {
-            "add/remove"
-        }
+        text("tui_key_add_remove")
     }
 
     pub fn tui_key_set_default() -> &'static str {
-        if is_chinese() {
-            "设为默认"
-        } else {
-            "set default"
-        }
+        text("tui_key_set_default")
     }
 
     pub fn tui_key_edit() -> &'static str {
-        if is_chinese() {
-            "编辑"
-        } else {
-            "edit"
-        }
+        text("tui_key_edit")
     }
 
     pub fn tui_key_speedtest() -> &'static str {
-        if is_chinese() {
-            "测速"
-        } else {
-            "speedtest"
-        }
+        text("tui_key_speedtest")
     }
 
     pub fn tui_key_stream_check() -> &'static str {
-        if is_chinese() {
-            "健康检查"
-        } else {
-            "stream check"
-        }
+        text("tui_key_stream_check")
     }
 
     pub fn tui_stream_check_status_operational() -> &'static str {
-        if is_chinese() {
-            "正常"
-        } else {
-            "operational"
-        }
+        text("tui_stream_check_status_operational")
     }
 
     pub fn tui_stream_check_status_degraded() -> &'static str {
-        if is_chinese() {
-            "降级"
-        } else {
-            "degraded"
-        }
+        text("tui_stream_check_status_degraded")
     }
 
     pub fn tui_stream_check_status_failed() -> &'static str {
-        if is_chinese() {
-            "失败"
-        } else {
-            "failed"
-        }
+        text("tui_stream_check_status_failed")
     }
 
     pub fn tui_key_details() -> &'static str {
-        if is_chinese() {
-            "详情"
-        } else {
-            "details"
-        }
+        text("tui_key_details")
     }
 
     pub fn tui_key_view() -> &'static str {
-        if is_chinese() {
-            "查看"
-        } else {
-            "view"
-        }
+        text("tui_key_view")
     }
 
     pub fn tui_key_add() -> &'static str {
-        if is_chinese() {
-            "新增"
-        } else {
-            "add"
-        }
+        text("tui_key_add")
     }
 
     pub fn tui_key_delete() -> &'static str {
-        if is_chinese() {
-            "删除"
-        } else {
-            "delete"
-        }
+        text("tui_key_delete")
     }
 
     pub fn tui_key_import() -> &'static str {
-        if is_chinese() {
-            "导入"
-        } else {
-            "import"
-        }
+        text("tui_key_import")
     }
 
     pub fn tui_key_install() -> &'static str {
-        if is_chinese() {
-            "安装"
-        } else {
-            "install"
-        }
+        text("tui_key_install")
     }
 
     pub fn tui_key_uninstall() -> &'static str {
-        if is_chinese() {
-            "卸载"
-        } else {
-            "uninstall"
-        }
+        text("tui_key_uninstall")
     }
 
     pub fn tui_key_discover() -> &'static str {
-        if is_chinese() {
-            "发现"
-        } else {
-            "discover"
-        }
+        text("tui_key_discover")
     }
 
     pub fn tui_key_unmanaged() -> &'static str {
-        if is_chinese() {
-            "已有"
-        } else {
-            "existing"
-        }
+        text("tui_key_unmanaged")
     }
 
     pub fn tui_key_repos() -> &'static str {
-        if is_chinese() {
-            "仓库"
-        } else {
-            "repos"
-        }
+        text("tui_key_repos")
     }
 
     pub fn tui_key_sync() -> &'static str {
-        if is_chinese() {
-            "同步"
-        } else {
-            "sync"
-        }
+        text("tui_key_sync")
     }
 
     pub fn tui_key_sync_method() -> &'static str {
-        if is_chinese() {
-            "同步方式"
-        } else {
-            "sync method"
-        }
+        text("tui_key_sync_method")
     }
 
     pub fn tui_key_search() -> &'static str {
-        if is_chinese() {
-            "搜索"
-        } else {
-            "search"
-        }
+        text("tui_key_search")
     }
 
     pub fn tui_key_refresh() -> &'static str {
-        if is_chinese() {
-            "刷新"
-        } else {
-            "refresh"
-        }
+        text("tui_key_refresh")
     }
 
     pub fn tui_key_start_proxy() -> &'static str {
-        if is_chinese() {
-            "启动代理"
-        } else {
-            "start proxy"
-        }
+        text("tui_key_start_proxy")
     }
 
     pub fn tui_key_stop_proxy() -> &'static str {
-        if is_chinese() {
-            "停止代理"
-        } else {
-            "stop proxy"
-        }
+        text("tui_key_stop_proxy")
     }
 
     pub fn tui_key_proxy_on() -> &'static str {
-        if is_chinese() {
-            "代理开"
-        } else {
-            "proxy on"
-        }
+        text("tui_key_proxy_on")
     }
 
     pub fn tui_key_proxy_off() -> &'static str {
-        if is_chinese() {
-            "代理关"
-        } else {
-            "proxy off"
-        }
+        text("tui_key_proxy_off")
     }
 
     pub fn tui_key_focus() -> &'static str {
-        if is_chinese() {
-            "切换窗口"
-        } else {
-            "next pane"
-        }
+        text("tui_key_focus")
     }
 
     pub fn tui_key_toggle() -> &'static str {
-        if is_chinese() {
-            "启用/禁用"
-        } else {
-            "toggle"
-        }
+        text("tui_key_toggle")
     }
 
     pub fn tui_key_apps() -> &'static str {
-        if is_chinese() {
-            "应用"
-        } else {
-            "apps"
-        }
+        text("tui_key_apps")
     }
 
     pub fn tui_key_activate() -> &'static str {
-        if is_chinese() {
-            "激活"
-        } else {
-            "activate"
-        }
+        text("tui_key_activate")
     }
 
     pub fn tui_key_deactivate() -> &'static str {
-        if is_chinese() {
-            "取消激活"
-        } else {
-            "deactivate"
-        }
+        text("tui_key_deactivate")
     }
 
     pub fn tui_key_open() -> &'static str {
-        if is_chinese() {
-            "打开"
-        } else {
-            "open"
-        }
+        text("tui_key_open")
     }
 
     pub fn tui_key_open_directory() -> &'static str {
-        if is_chinese() {
-            "打开目录"
-        } else {
-            "open dir"
-        }
+        text("tui_key_open_directory")
     }
 
     pub fn tui_key_create() -> &'static str {
-        if is_chinese() {
-            "新建"
-        } else {
-            "create"
-        }
+        text("tui_key_create")
     }
 
     pub fn tui_key_apply() -> &'static str {
-        if is_chinese() {
-            "应用"
-        } else {
-            "apply"
-        }
+        text("tui_key_apply")
     }
 
     pub fn tui_key_edit_snippet() -> &'static str {
-        if is_chinese() {
-            "编辑片段"
-        } else {
-            "edit snippet"
-        }
+        text("tui_key_edit_snippet")
     }
 
     pub fn tui_key_close() -> &'static str {
-        if is_chinese() {
-            "关闭"
-        } else {
-            "close"
-        }
+        text("tui_key_close")
     }
 
     pub fn tui_key_exit() -> &'static str {
-        if is_chinese() {
-            "退出"
-        } else {
-            "exit"
-        }
+        text("tui_key_exit")
     }
 
     pub fn tui_key_cancel() -> &'static str {
-        if is_chinese() {
-            "取消"
-        } else {
-            "cancel"
-        }
+        text("tui_key_cancel")
     }
 
     pub fn tui_key_submit() -> &'static str {
-        if is_chinese() {
-            "提交"
-        } else {
-            "submit"
-        }
+        text("tui_key_submit")
     }
 
     pub fn tui_key_yes() -> &'static str {
-        if is_chinese() {
-            "确认"
-        } else {
-            "confirm"
-        }
+        text("tui_key_yes")
     }
 
     pub fn tui_key_no() -> &'static str {
-        if is_chinese() {
-            "返回"
-        } else {
-            "back"
-        }
+        text("tui_key_no")
     }
 
     pub fn tui_key_scroll() -> &'static str {
-        if is_chinese() {
-            "滚动"
-        } else {
-            "scroll"
-        }
+        text("tui_key_scroll")
     }
 
     pub fn tui_key_restore() -> &'static str {
-        if is_chinese() {
-            "恢复"
-        } else {
-            "restore"
-        }
+        text("tui_key_restore")
     }
 
     pub fn tui_key_takeover() -> &'static str {
-        if is_chinese() {
-            "接管"
-        } else {
-            "take over"
-        }
+        text("tui_key_takeover")
     }
 
     pub fn tui_key_save() -> &'static str {
-        if is_chinese() {
-            "保存"
-        } else {
-            "save"
-        }
+        text("tui_key_save")
     }
 
     pub fn tui_key_external_editor() -> &'static str {
-        if is_chinese() {
-            "外部编辑器"
-        } else {
-            "external editor"
-        }
+        text("tui_key_external_editor")
     }
 
     pub fn tui_key_save_and_exit() -> &'static str {
-        if is_chinese() {
-            "保存并退出"
-        } else {
-            "save & exit"
-        }
+        text("tui_key_save_and_exit")
     }
 
     pub fn tui_key_exit_without_save() -> &'static str {
-        if is_chinese() {
-            "不保存退出"
-        } else {
-            "exit w/o save"
-        }
+        text("tui_key_exit_without_save")
     }
 
     pub fn tui_key_edit_mode() -> &'static str {
-        if is_chinese() {
-            "编辑"
-        } else {
-            "edit"
-        }
+        text("tui_key_edit_mode")
     }
 
     pub fn tui_key_clear() -> &'static str {
-        if is_chinese() {
-            "清除"
-        } else {
-            "clear"
-        }
+        text("tui_key_clear")
     }
 
     pub fn tui_key_move() -> &'static str {
-        if is_chinese() {
-            "移动"
-        } else {
-            "move"
-        }
+        text("tui_key_move")
     }
 
     pub fn tui_key_exit_edit() -> &'static str {
-        if is_chinese() {
-            "退出编辑"
-        } else {
-            "exit edit"
-        }
+        text("tui_key_exit_edit")
     }
 
     pub fn tui_key_select() -> &'static str {
-        if is_chinese() {
-            "选择"
-        } else {
-            "select"
-        }
+        text("tui_key_select")
     }
 
     pub fn tui_key_fetch_model() -> &'static str {
-        if is_chinese() {
-            "获取模型"
-        } else {
-            "fetch model"
-        }
+        text("tui_key_fetch_model")
     }
 
     pub fn tui_key_deactivate_active() -> &'static str {
-        if is_chinese() {
-            "取消激活(当前)"
-        } else {
-            "deactivate active"
-        }
+        text("tui_key_deactivate_active")
     }
 
     pub fn tui_provider_list_keys() -> &'static str {
-        if is_chinese() {
-            "按键：a=新增  e=编辑  Enter=详情  s=切换  /=搜索"
-        } else {
-            "Keys: a=add  e=edit  Enter=details  s=switch  /=filter"
-        }
+        text("tui_provider_list_keys")
     }
 
     pub fn tui_home_ascii_logo() -> &'static str {
@@ -2305,11 +1511,7 @@ pub mod texts {
     }
 
     pub fn tui_common_snippet_keys() -> &'static str {
-        if is_chinese() {
-            "按键：e=编辑  c=清除  a=应用  Esc=返回"
-        } else {
-            "Keys: e=edit  c=clear  a=apply  Esc=back"
-        }
+        text("tui_common_snippet_keys")
     }
 
     pub fn tui_view_config_app(app: &str) -> String {
@@ -2361,171 +1563,87 @@ pub mod texts {
     }
 
     pub fn tui_settings_header_language() -> &'static str {
-        if is_chinese() {
-            "语言"
-        } else {
-            "Language"
-        }
+        text("tui_settings_header_language")
     }
 
     pub fn tui_settings_header_setting() -> &'static str {
-        if is_chinese() {
-            "设置项"
-        } else {
-            "Setting"
-        }
+        text("tui_settings_header_setting")
     }
 
     pub fn tui_settings_header_value() -> &'static str {
-        if is_chinese() {
-            "值"
-        } else {
-            "Value"
-        }
+        text("tui_settings_header_value")
     }
 
     pub fn tui_settings_title() -> &'static str {
-        if is_chinese() {
-            "设置"
-        } else {
-            "Settings"
-        }
+        text("tui_settings_title")
     }
 
     pub fn tui_settings_visible_apps_label() -> &'static str {
-        if is_chinese() {
-            "可见应用"
-        } else {
-            "Visible Apps"
-        }
+        text("tui_settings_visible_apps_label")
     }
 
     pub fn tui_settings_visible_apps_title() -> &'static str {
-        if is_chinese() {
-            "选择可见应用"
-        } else {
-            "Choose Visible Apps"
-        }
+        text("tui_settings_visible_apps_title")
     }
 
     pub fn tui_settings_proxy_title() -> &'static str {
-        if is_chinese() {
-            "本地代理"
-        } else {
-            "Local Proxy"
-        }
+        text("tui_settings_proxy_title")
     }
 
     pub fn tui_settings_proxy_listen_address_label() -> &'static str {
-        if is_chinese() {
-            "监听地址"
-        } else {
-            "Listen Address"
-        }
+        text("tui_settings_proxy_listen_address_label")
     }
 
     pub fn tui_settings_proxy_listen_port_label() -> &'static str {
-        if is_chinese() {
-            "监听端口"
-        } else {
-            "Listen Port"
-        }
+        text("tui_settings_proxy_listen_port_label")
     }
 
     pub fn tui_settings_proxy_listen_address_prompt() -> &'static str {
-        if is_chinese() {
-            "输入监听地址（如 127.0.0.1 / localhost / 0.0.0.0）"
-        } else {
-            "Enter listen address (for example 127.0.0.1 / localhost / 0.0.0.0)"
-        }
+        text("tui_settings_proxy_listen_address_prompt")
     }
 
     pub fn tui_settings_proxy_listen_port_prompt() -> &'static str {
-        if is_chinese() {
-            "输入监听端口（1024-65535）"
-        } else {
-            "Enter listen port (1024-65535)"
-        }
+        text("tui_settings_proxy_listen_port_prompt")
     }
 
     pub fn tui_settings_proxy_restart_hint() -> &'static str {
-        if is_chinese() {
-            "修改监听地址或端口后，需先停止并重新开启本地代理才能生效"
-        } else {
-            "Changes to listen address or port require stopping and restarting the local proxy"
-        }
+        text("tui_settings_proxy_restart_hint")
     }
 
     pub fn tui_settings_proxy_stop_before_edit_hint() -> &'static str {
-        if is_chinese() {
-            "请先停止本地代理，再修改监听地址或端口"
-        } else {
-            "Stop the local proxy before editing listen address or port"
-        }
+        text("tui_settings_proxy_stop_before_edit_hint")
     }
 
     pub fn tui_toast_proxy_listen_address_invalid() -> &'static str {
-        if is_chinese() {
-            "地址无效，请输入有效的 IPv4 地址、localhost 或 0.0.0.0"
-        } else {
-            "Invalid address. Enter a valid IPv4 address, localhost, or 0.0.0.0"
-        }
+        text("tui_toast_proxy_listen_address_invalid")
     }
 
     pub fn tui_toast_proxy_listen_port_invalid() -> &'static str {
-        if is_chinese() {
-            "端口无效，请输入 1024-65535 之间的数字"
-        } else {
-            "Invalid port. Enter a number between 1024 and 65535"
-        }
+        text("tui_toast_proxy_listen_port_invalid")
     }
 
     pub fn tui_toast_proxy_settings_saved() -> &'static str {
-        if is_chinese() {
-            "本地代理配置已保存。"
-        } else {
-            "Local proxy settings saved."
-        }
+        text("tui_toast_proxy_settings_saved")
     }
 
     pub fn tui_toast_proxy_settings_restart_required() -> &'static str {
-        if is_chinese() {
-            "本地代理正在运行；新监听地址/端口会在重启代理后生效。"
-        } else {
-            "The local proxy is running; the new listen address/port will apply after restart."
-        }
+        text("tui_toast_proxy_settings_restart_required")
     }
 
     pub fn tui_toast_proxy_settings_stop_before_edit() -> &'static str {
-        if is_chinese() {
-            "本地代理正在运行。请先停止代理，再修改监听地址或端口。"
-        } else {
-            "The local proxy is running. Stop it before editing listen address or port."
-        }
+        text("tui_toast_proxy_settings_stop_before_edit")
     }
 
     pub fn tui_toast_visible_apps_zero_selection_warning() -> &'static str {
-        if is_chinese() {
-            "至少保留一个可见应用。"
-        } else {
-            "Keep at least one app visible."
-        }
+        text("tui_toast_visible_apps_zero_selection_warning")
     }
 
     pub fn tui_toast_visible_apps_saved() -> &'static str {
-        if is_chinese() {
-            "可见应用已保存。"
-        } else {
-            "Visible apps saved."
-        }
+        text("tui_toast_visible_apps_saved")
     }
 
     pub fn tui_config_title() -> &'static str {
-        if is_chinese() {
-            "配置"
-        } else {
-            "Configuration"
-        }
+        text("tui_config_title")
     }
 
     // ---------------------------------------------------------------------
@@ -2533,27 +1651,15 @@ pub mod texts {
     // ---------------------------------------------------------------------
 
     pub fn tui_skills_install_title() -> &'static str {
-        if is_chinese() {
-            "安装 Skill"
-        } else {
-            "Install Skill"
-        }
+        text("tui_skills_install_title")
     }
 
     pub fn tui_skills_install_prompt() -> &'static str {
-        if is_chinese() {
-            "输入技能目录，或完整标识（owner/name:directory）："
-        } else {
-            "Enter a skill directory, or a full key (owner/name:directory):"
-        }
+        text("tui_skills_install_prompt")
     }
 
     pub fn tui_skills_uninstall_title() -> &'static str {
-        if is_chinese() {
-            "卸载 Skill"
-        } else {
-            "Uninstall Skill"
-        }
+        text("tui_skills_uninstall_title")
     }
 
     pub fn tui_confirm_uninstall_skill_message(name: &str, directory: &str) -> String {
@@ -2565,83 +1671,43 @@ pub mod texts {
     }
 
     pub fn tui_skills_discover_title() -> &'static str {
-        if is_chinese() {
-            "发现 Skills"
-        } else {
-            "Discover Skills"
-        }
+        text("tui_skills_discover_title")
     }
 
     pub fn tui_skills_discover_prompt() -> &'static str {
-        if is_chinese() {
-            "输入关键词（留空显示全部）："
-        } else {
-            "Enter a keyword (leave empty to show all):"
-        }
+        text("tui_skills_discover_prompt")
     }
 
     pub fn tui_skills_discover_query_empty() -> &'static str {
-        if is_chinese() {
-            "全部"
-        } else {
-            "all"
-        }
+        text("tui_skills_discover_query_empty")
     }
 
     pub fn tui_skills_discover_hint() -> &'static str {
-        if is_chinese() {
-            "按 f 搜索仓库里的技能，按 r 管理技能仓库。"
-        } else {
-            "Press f to search skills from enabled repositories, or r to manage repositories."
-        }
+        text("tui_skills_discover_hint")
     }
 
     pub fn tui_skills_repos_title() -> &'static str {
-        if is_chinese() {
-            "Skill 仓库"
-        } else {
-            "Skill Repositories"
-        }
+        text("tui_skills_repos_title")
     }
 
     pub fn tui_skills_repos_hint() -> &'static str {
-        if is_chinese() {
-            "技能发现会从这里已启用的仓库加载列表。"
-        } else {
-            "Skill discovery loads results from the repositories enabled here."
-        }
+        text("tui_skills_repos_hint")
     }
 
     pub fn tui_skills_repos_empty() -> &'static str {
-        if is_chinese() {
-            "未配置任何 Skill 仓库。按 a 添加。"
-        } else {
-            "No skill repositories configured. Press a to add."
-        }
+        text("tui_skills_repos_empty")
     }
 
     pub fn tui_skills_repos_add_title() -> &'static str {
-        if is_chinese() {
-            "添加仓库"
-        } else {
-            "Add Repository"
-        }
+        text("tui_skills_repos_add_title")
     }
 
     pub fn tui_skills_repos_add_prompt() -> &'static str {
-        if is_chinese() {
-            "输入 GitHub 仓库（owner/name，可选 @branch）或完整 URL："
-        } else {
-            "Enter a GitHub repository (owner/name, optional @branch) or a full URL:"
-        }
+        text("tui_skills_repos_add_prompt")
     }
 
     pub fn tui_skills_repos_remove_title() -> &'static str {
-        if is_chinese() {
-            "移除仓库"
-        } else {
-            "Remove Repository"
-        }
+        text("tui_skills_repos_remove_title")
     }
 
     pub fn tui_confirm_remove_repo_message(owner: &str, name: &str) -> String {
@@ -2658,11 +1724,7 @@ pub mod texts {
     }
 
     pub fn tui_skills_import_title() -> &'static str {
-        if is_chinese() {
-            "导入已有技能"
-        } else {
-            "Import Existing Skills"
-        }
+        text("tui_skills_import_title")
     }
 
     pub fn tui_skills_unmanaged_hint() -> &'static str {
@@ -2670,51 +1732,27 @@ pub mod texts {
     }
 
     pub fn tui_skills_import_description() -> &'static str {
-        if is_chinese() {
-            "选择要导入到 CC Switch 统一管理的技能。"
-        } else {
-            "Select skills to import into CC Switch unified management."
-        }
+        text("tui_skills_import_description")
     }
 
     pub fn tui_skills_unmanaged_empty() -> &'static str {
-        if is_chinese() {
-            "未发现可导入的技能。"
-        } else {
-            "No skills to import found."
-        }
+        text("tui_skills_unmanaged_empty")
     }
 
     pub fn tui_skills_detail_title() -> &'static str {
-        if is_chinese() {
-            "Skill 详情"
-        } else {
-            "Skill Detail"
-        }
+        text("tui_skills_detail_title")
     }
 
     pub fn tui_skill_not_found() -> &'static str {
-        if is_chinese() {
-            "未找到该 Skill。"
-        } else {
-            "Skill not found."
-        }
+        text("tui_skill_not_found")
     }
 
-    pub fn tui_skills_sync_method_label() -> &'static str {
-        if is_chinese() {
-            "同步方式"
-        } else {
-            "Sync"
-        }
+    pub fn tui_skills_sync_method_label() -> &'static str {
+        text("tui_skills_sync_method_label")
     }
 
     pub fn tui_skills_sync_method_title() -> &'static str {
-        if is_chinese() {
-            "选择同步方式"
-        } else {
-            "Select Sync Method"
-        }
+        text("tui_skills_sync_method_title")
     }
 
     pub fn tui_skills_sync_method_name(method: crate::services::skill::SyncMethod) -> &'static str {
@@ -2786,203 +1824,103 @@ pub mod texts {
     }
 
     pub fn tui_mcp_action_import_existing() -> &'static str {
-        if is_chinese() {
-            "导入已有"
-        } else {
-            "Import Existing"
-        }
+        text("tui_mcp_action_import_existing")
     }
 
     pub fn tui_skills_action_import_existing() -> &'static str {
-        if is_chinese() {
-            "导入已有"
-        } else {
-            "Import Existing"
-        }
+        text("tui_skills_action_import_existing")
     }
 
     pub fn tui_skills_empty_title() -> &'static str {
-        if is_chinese() {
-            "暂无已安装的技能"
-        } else {
-            "No installed skills"
-        }
+        text("tui_skills_empty_title")
     }
 
     pub fn tui_skills_empty_subtitle() -> &'static str {
-        if is_chinese() {
-            "从仓库发现并安装技能，或导入已有技能。"
-        } else {
-            "Discover and install skills from repositories, or import existing skills."
-        }
+        text("tui_skills_empty_subtitle")
     }
 
     pub fn tui_skills_empty_hint() -> &'static str {
-        if is_chinese() {
-            "暂无已安装技能。按 f 发现新技能，或按 i 导入已有技能。"
-        } else {
-            "No installed skills. Press f to discover skills, or i to import existing skills."
-        }
+        text("tui_skills_empty_hint")
     }
 
     pub fn tui_config_item_export() -> &'static str {
-        if is_chinese() {
-            "导出配置"
-        } else {
-            "Export Config"
-        }
+        text("tui_config_item_export")
     }
 
     pub fn tui_config_item_import() -> &'static str {
-        if is_chinese() {
-            "导入配置"
-        } else {
-            "Import Config"
-        }
+        text("tui_config_item_import")
     }
 
     pub fn tui_config_item_backup() -> &'static str {
-        if is_chinese() {
-            "备份配置"
-        } else {
-            "Backup Config"
-        }
+        text("tui_config_item_backup")
     }
 
     pub fn tui_config_item_restore() -> &'static str {
-        if is_chinese() {
-            "恢复配置"
-        } else {
-            "Restore Config"
-        }
+        text("tui_config_item_restore")
     }
 
     pub fn tui_config_item_validate() -> &'static str {
-        if is_chinese() {
-            "验证配置"
-        } else {
-            "Validate Config"
-        }
+        text("tui_config_item_validate")
     }
 
     pub fn tui_config_item_common_snippet() -> &'static str {
-        if is_chinese() {
-            "通用配置片段"
-        } else {
-            "Common Config Snippet"
-        }
+        text("tui_config_item_common_snippet")
     }
 
     pub fn tui_config_item_proxy() -> &'static str {
-        if is_chinese() {
-            "本地代理"
-        } else {
-            "Local Proxy"
-        }
+        text("tui_config_item_proxy")
     }
 
     pub fn tui_config_item_openclaw_env() -> &'static str {
-        if is_chinese() {
-            "环境变量"
-        } else {
-            "Env Variables"
-        }
+        text("tui_config_item_openclaw_env")
     }
 
     pub fn tui_config_item_openclaw_workspace() -> &'static str {
-        if is_chinese() {
-            "Workspace 文件管理"
-        } else {
-            "Workspace Files"
-        }
+        text("tui_config_item_openclaw_workspace")
     }
 
     pub fn tui_config_item_openclaw_tools() -> &'static str {
-        if is_chinese() {
-            "工具权限"
-        } else {
-            "Tool Permissions"
-        }
+        text("tui_config_item_openclaw_tools")
     }
 
     pub fn tui_config_item_openclaw_agents() -> &'static str {
-        if is_chinese() {
-            "Agents 配置"
-        } else {
-            "Agents Config"
-        }
+        text("tui_config_item_openclaw_agents")
     }
 
     pub fn tui_config_item_webdav_sync() -> &'static str {
-        if is_chinese() {
-            "WebDAV 同步"
-        } else {
-            "WebDAV Sync"
-        }
+        text("tui_config_item_webdav_sync")
     }
 
     pub fn tui_config_item_webdav_settings() -> &'static str {
-        if is_chinese() {
-            "WebDAV 同步设置（JSON）"
-        } else {
-            "WebDAV Sync Settings (JSON)"
-        }
+        text("tui_config_item_webdav_settings")
     }
 
     pub fn tui_config_item_webdav_check_connection() -> &'static str {
-        if is_chinese() {
-            "WebDAV 检查连接"
-        } else {
-            "WebDAV Check Connection"
-        }
+        text("tui_config_item_webdav_check_connection")
     }
 
     pub fn tui_config_item_webdav_upload() -> &'static str {
-        if is_chinese() {
-            "WebDAV 上传到远端"
-        } else {
-            "WebDAV Upload to Remote"
-        }
+        text("tui_config_item_webdav_upload")
     }
 
     pub fn tui_config_item_webdav_download() -> &'static str {
-        if is_chinese() {
-            "WebDAV 下载到本地"
-        } else {
-            "WebDAV Download to Local"
-        }
+        text("tui_config_item_webdav_download")
     }
 
     pub fn tui_config_item_webdav_reset() -> &'static str {
-        if is_chinese() {
-            "重置 WebDAV 配置"
-        } else {
-            "Reset WebDAV Settings"
-        }
+        text("tui_config_item_webdav_reset")
     }
 
     pub fn tui_config_item_webdav_jianguoyun_quick_setup() -> &'static str {
-        if is_chinese() {
-            "坚果云一键配置"
-        } else {
-            "Jianguoyun Quick Setup"
-        }
+        text("tui_config_item_webdav_jianguoyun_quick_setup")
     }
 
     pub fn tui_webdav_settings_editor_title() -> &'static str {
-        if is_chinese() {
-            "编辑 WebDAV 同步设置（JSON）"
-        } else {
-            "Edit WebDAV Sync Settings (JSON)"
-        }
+        text("tui_webdav_settings_editor_title")
     }
 
     pub fn tui_config_webdav_title() -> &'static str {
-        if is_chinese() {
-            "WebDAV 同步"
-        } else {
-            "WebDAV Sync"
-        }
+        text("tui_config_webdav_title")
     }
 
     pub fn tui_openclaw_config_env_title() -> &'static str {
@@ -2994,27 +1932,15 @@ pub mod texts {
     }
 
     pub fn tui_openclaw_workspace_files_block_title() -> &'static str {
-        if is_chinese() {
-            "Workspace 文件"
-        } else {
-            "Workspace Files"
-        }
+        text("tui_openclaw_workspace_files_block_title")
     }
 
     pub fn tui_openclaw_workspace_directory_label() -> &'static str {
-        if is_chinese() {
-            "工作区目录"
-        } else {
-            "Workspace directory"
-        }
+        text("tui_openclaw_workspace_directory_label")
     }
 
     pub fn tui_openclaw_workspace_daily_memory_label() -> &'static str {
-        if is_chinese() {
-            "Daily Memory"
-        } else {
-            "Daily Memory"
-        }
+        text("tui_openclaw_workspace_daily_memory_label")
     }
 
     pub fn tui_openclaw_workspace_daily_memory_count(count: usize) -> String {
@@ -3028,19 +1954,11 @@ pub mod texts {
     }
 
     pub fn tui_openclaw_workspace_status_exists() -> &'static str {
-        if is_chinese() {
-            "已存在"
-        } else {
-            "Exists"
-        }
+        text("tui_openclaw_workspace_status_exists")
     }
 
     pub fn tui_openclaw_workspace_status_missing() -> &'static str {
-        if is_chinese() {
-            "缺失"
-        } else {
-            "Missing"
-        }
+        text("tui_openclaw_workspace_status_missing")
     }
 
     pub fn tui_openclaw_config_tools_title() -> &'static str {
@@ -3048,83 +1966,43 @@ pub mod texts {
     }
 
     pub fn tui_openclaw_tools_description() -> &'static str {
-        if is_chinese() {
-            "管理 openclaw.json 中的工具权限配置（允许/拒绝列表）"
-        } else {
-            "Manage tool permissions in openclaw.json (allow/deny lists)"
-        }
+        text("tui_openclaw_tools_description")
     }
 
     pub fn tui_openclaw_tools_profile_block_title() -> &'static str {
-        if is_chinese() {
-            "权限档位"
-        } else {
-            "Permission Profile"
-        }
+        text("tui_openclaw_tools_profile_block_title")
     }
 
     pub fn tui_openclaw_tools_rules_block_title() -> &'static str {
-        if is_chinese() {
-            "规则列表"
-        } else {
-            "Rule Lists"
-        }
+        text("tui_openclaw_tools_rules_block_title")
     }
 
     pub fn tui_openclaw_tools_profile_label() -> &'static str {
-        if is_chinese() {
-            "配置档位"
-        } else {
-            "Profile"
-        }
+        text("tui_openclaw_tools_profile_label")
     }
 
     pub fn tui_openclaw_tools_profile_unset() -> &'static str {
-        if is_chinese() {
-            "未设置"
-        } else {
-            "Not set"
-        }
+        text("tui_openclaw_tools_profile_unset")
     }
 
     pub fn tui_openclaw_tools_profile_minimal() -> &'static str {
-        if is_chinese() {
-            "最小权限"
-        } else {
-            "Minimal"
-        }
+        text("tui_openclaw_tools_profile_minimal")
     }
 
     pub fn tui_openclaw_tools_profile_coding() -> &'static str {
-        if is_chinese() {
-            "编码"
-        } else {
-            "Coding"
-        }
+        text("tui_openclaw_tools_profile_coding")
     }
 
     pub fn tui_openclaw_tools_profile_messaging() -> &'static str {
-        if is_chinese() {
-            "对话"
-        } else {
-            "Messaging"
-        }
+        text("tui_openclaw_tools_profile_messaging")
     }
 
     pub fn tui_openclaw_tools_profile_full() -> &'static str {
-        if is_chinese() {
-            "完全访问"
-        } else {
-            "Full"
-        }
+        text("tui_openclaw_tools_profile_full")
     }
 
     pub fn tui_openclaw_tools_unsupported_profile_title() -> &'static str {
-        if is_chinese() {
-            "检测到不受支持的工具配置"
-        } else {
-            "Unsupported tools profile detected"
-        }
+        text("tui_openclaw_tools_unsupported_profile_title")
     }
 
     pub fn tui_openclaw_tools_unsupported_profile_description(value: &str) -> String {
@@ -3140,75 +2018,39 @@ pub mod texts {
     }
 
     pub fn tui_openclaw_tools_unsupported_profile_label() -> &'static str {
-        if is_chinese() {
-            "不受支持"
-        } else {
-            "unsupported"
-        }
+        text("tui_openclaw_tools_unsupported_profile_label")
     }
 
     pub fn tui_openclaw_tools_allow_list_label() -> &'static str {
-        if is_chinese() {
-            "允许列表"
-        } else {
-            "Allow List"
-        }
+        text("tui_openclaw_tools_allow_list_label")
     }
 
     pub fn tui_openclaw_tools_deny_list_label() -> &'static str {
-        if is_chinese() {
-            "拒绝列表"
-        } else {
-            "Deny List"
-        }
+        text("tui_openclaw_tools_deny_list_label")
     }
 
     pub fn tui_openclaw_tools_pattern_placeholder() -> &'static str {
-        if is_chinese() {
-            "工具名称或模式"
-        } else {
-            "Tool name or pattern"
-        }
+        text("tui_openclaw_tools_pattern_placeholder")
     }
 
     pub fn tui_openclaw_tools_add_allow_rule() -> &'static str {
-        if is_chinese() {
-            "+ 添加允许规则"
-        } else {
-            "+ Add allow rule"
-        }
+        text("tui_openclaw_tools_add_allow_rule")
     }
 
     pub fn tui_openclaw_tools_add_deny_rule() -> &'static str {
-        if is_chinese() {
-            "+ 添加拒绝规则"
-        } else {
-            "+ Add deny rule"
-        }
+        text("tui_openclaw_tools_add_deny_rule")
     }
 
     pub fn tui_openclaw_tools_extra_fields_label() -> &'static str {
-        if is_chinese() {
-            "保留的其他字段"
-        } else {
-            "Preserved extra fields"
-        }
+        text("tui_openclaw_tools_extra_fields_label")
     }
 
     pub fn tui_openclaw_tools_save_label() -> &'static str {
-        if is_chinese() {
-            "保存"
-        } else {
-            "Save"
-        }
+        text("tui_openclaw_tools_save_label")
     }
 
     pub fn tui_openclaw_tools_load_failed_message() -> &'static str {
-        if is_chinese() {
-            "当前 tools 配置无法加载；请先修复上方解析警告，再编辑工具权限。"
-        } else {
-            "The current tools section could not be loaded. Fix the parse warning above before editing tool permissions."
-        }
+        text("tui_openclaw_tools_load_failed_message")
     }
 
     pub fn tui_toast_openclaw_tools_save_result(success: bool) -> &'static str {
@@ -3234,75 +2076,39 @@ pub mod texts {
     }
 
     pub fn tui_toast_openclaw_tools_save_blocked_parse_error() -> &'static str {
-        if is_chinese() {
-            "请先修复 OpenClaw 工具配置解析警告，再保存工具权限"
-        } else {
-            "Fix OpenClaw tools parse warnings before saving tool permissions"
-        }
+        text("tui_toast_openclaw_tools_save_blocked_parse_error")
     }
 
     pub fn tui_toast_openclaw_tools_rule_empty() -> &'static str {
-        if is_chinese() {
-            "工具规则不能为空"
-        } else {
-            "Tool rule cannot be empty"
-        }
+        text("tui_toast_openclaw_tools_rule_empty")
     }
 
     pub fn tui_openclaw_agents_description() -> &'static str {
-        if is_chinese() {
-            "管理 openclaw.json 中的 agents.defaults 配置（默认模型、运行参数等）"
-        } else {
-            "Manage agents.defaults in openclaw.json (default model, runtime parameters, etc.)"
-        }
+        text("tui_openclaw_agents_description")
     }
 
     pub fn tui_openclaw_agents_model_section() -> &'static str {
-        if is_chinese() {
-            "模型配置"
-        } else {
-            "Model Configuration"
-        }
+        text("tui_openclaw_agents_model_section")
     }
 
     pub fn tui_openclaw_agents_primary_model() -> &'static str {
-        if is_chinese() {
-            "默认模型"
-        } else {
-            "Default Model"
-        }
+        text("tui_openclaw_agents_primary_model")
     }
 
     pub fn tui_openclaw_agents_not_set() -> &'static str {
-        if is_chinese() {
-            "未设置"
-        } else {
-            "Not set"
-        }
+        text("tui_openclaw_agents_not_set")
     }
 
     pub fn tui_openclaw_agents_fallback_models() -> &'static str {
-        if is_chinese() {
-            "回退模型"
-        } else {
-            "Fallback Models"
-        }
+        text("tui_openclaw_agents_fallback_models")
     }
 
     pub fn tui_openclaw_agents_add_fallback() -> &'static str {
-        if is_chinese() {
-            "添加回退模型"
-        } else {
-            "Add fallback model"
-        }
+        text("tui_openclaw_agents_add_fallback")
     }
 
     pub fn tui_openclaw_agents_add_fallback_disabled() -> &'static str {
-        if is_chinese() {
-            "没有可添加的回退模型了"
-        } else {
-            "No fallback models available to add"
-        }
+        text("tui_openclaw_agents_add_fallback_disabled")
     }
 
     pub fn tui_openclaw_agents_not_in_list(value: &str) -> String {
@@ -3314,43 +2120,23 @@ pub mod texts {
     }
 
     pub fn tui_openclaw_agents_runtime_section() -> &'static str {
-        if is_chinese() {
-            "运行参数"
-        } else {
-            "Runtime Parameters"
-        }
+        text("tui_openclaw_agents_runtime_section")
     }
 
     pub fn tui_openclaw_agents_workspace() -> &'static str {
-        if is_chinese() {
-            "工作区路径"
-        } else {
-            "Workspace Path"
-        }
+        text("tui_openclaw_agents_workspace")
     }
 
     pub fn tui_openclaw_agents_timeout() -> &'static str {
-        if is_chinese() {
-            "超时时间（秒）"
-        } else {
-            "Timeout (seconds)"
-        }
+        text("tui_openclaw_agents_timeout")
     }
 
     pub fn tui_openclaw_agents_context_tokens() -> &'static str {
-        if is_chinese() {
-            "上下文 Token 数"
-        } else {
-            "Context Tokens"
-        }
+        text("tui_openclaw_agents_context_tokens")
     }
 
     pub fn tui_openclaw_agents_max_concurrent() -> &'static str {
-        if is_chinese() {
-            "最大并发数"
-        } else {
-            "Max Concurrent"
-        }
+        text("tui_openclaw_agents_max_concurrent")
     }
 
     pub fn tui_openclaw_agents_preserved_non_standard_value(value: &str) -> String {
@@ -3362,59 +2148,31 @@ pub mod texts {
     }
 
     pub fn tui_openclaw_agents_preserved_runtime_notice() -> &'static str {
-        if is_chinese() {
-            "非标准运行参数会在你替换它们之前保持原样保存。"
-        } else {
-            "Non-standard runtime values are preserved until you replace them."
-        }
+        text("tui_openclaw_agents_preserved_runtime_notice")
     }
 
     pub fn tui_openclaw_agents_preserved_fields_label() -> &'static str {
-        if is_chinese() {
-            "保留字段"
-        } else {
-            "Preserved Fields"
-        }
+        text("tui_openclaw_agents_preserved_fields_label")
     }
 
     pub fn tui_openclaw_agents_legacy_timeout_title() -> &'static str {
-        if is_chinese() {
-            "检测到旧版超时字段"
-        } else {
-            "Legacy timeout detected"
-        }
+        text("tui_openclaw_agents_legacy_timeout_title")
     }
 
     pub fn tui_openclaw_agents_legacy_timeout_description() -> &'static str {
-        if is_chinese() {
-            "当前配置仍在使用 agents.defaults.timeout。保存本页面时会迁移为 timeoutSeconds。"
-        } else {
-            "This config still uses agents.defaults.timeout. Saving here will migrate it to timeoutSeconds."
-        }
+        text("tui_openclaw_agents_legacy_timeout_description")
     }
 
     pub fn tui_openclaw_agents_legacy_timeout_invalid_description() -> &'static str {
-        if is_chinese() {
-            "当前配置仍在使用 agents.defaults.timeout，但该值无法自动迁移。请先改为数字，或清空该字段后再保存。"
-        } else {
-            "This config still uses agents.defaults.timeout, but the current value cannot be migrated automatically. Change it to a number or clear the field before saving."
-        }
+        text("tui_openclaw_agents_legacy_timeout_invalid_description")
     }
 
     pub fn tui_openclaw_agents_load_failed_message() -> &'static str {
-        if is_chinese() {
-            "当前 agents.defaults 配置无法加载；请先修复上方解析警告，再编辑 Agents 配置。"
-        } else {
-            "The current agents.defaults section could not be loaded. Fix the parse warning above before editing agents defaults."
-        }
+        text("tui_openclaw_agents_load_failed_message")
     }
 
     pub fn tui_openclaw_agents_save_label() -> &'static str {
-        if is_chinese() {
-            "保存"
-        } else {
-            "Save"
-        }
+        text("tui_openclaw_agents_save_label")
     }
 
     pub fn tui_toast_openclaw_agents_save_result(success: bool) -> &'static str {
@@ -3440,19 +2198,11 @@ pub mod texts {
     }
 
     pub fn tui_toast_openclaw_agents_save_blocked_parse_error() -> &'static str {
-        if is_chinese() {
-            "请先修复 OpenClaw agents.defaults 解析警告，再保存 Agents 配置"
-        } else {
-            "Fix OpenClaw agents parse warnings before saving agents defaults"
-        }
+        text("tui_toast_openclaw_agents_save_blocked_parse_error")
     }
 
     pub fn tui_toast_openclaw_agents_save_blocked_legacy_timeout() -> &'static str {
-        if is_chinese() {
-            "请先处理 agents.defaults.timeout，再保存 Agents 配置"
-        } else {
-            "Resolve agents.defaults.timeout before saving agents config"
-        }
+        text("tui_toast_openclaw_agents_save_blocked_legacy_timeout")
     }
 
     pub fn tui_openclaw_config_agents_title() -> &'static str {
@@ -3460,91 +2210,47 @@ pub mod texts {
     }
 
     pub fn tui_openclaw_config_env_editor_title() -> &'static str {
-        if is_chinese() {
-            "编辑环境变量 (JSON)"
-        } else {
-            "Edit Env Variables (JSON)"
-        }
+        text("tui_openclaw_config_env_editor_title")
     }
 
     pub fn tui_openclaw_config_env_description() -> &'static str {
-        if is_chinese() {
-            "管理 openclaw.json 中的环境变量映射；保存时会写回 env.vars。"
-        } else {
-            "Manage the environment variable map in openclaw.json; saving writes back to env.vars."
-        }
+        text("tui_openclaw_config_env_description")
     }
 
     pub fn tui_openclaw_config_tools_editor_title() -> &'static str {
-        if is_chinese() {
-            "编辑工具权限 (JSON)"
-        } else {
-            "Edit Tool Permissions (JSON)"
-        }
+        text("tui_openclaw_config_tools_editor_title")
     }
 
     pub fn tui_openclaw_config_agents_editor_title() -> &'static str {
-        if is_chinese() {
-            "编辑 Agents 配置 (JSON)"
-        } else {
-            "Edit Agents Config (JSON)"
-        }
+        text("tui_openclaw_config_agents_editor_title")
     }
 
     pub fn tui_openclaw_config_warning_title() -> &'static str {
-        if is_chinese() {
-            "OpenClaw 配置告警"
-        } else {
-            "OpenClaw Health Warnings"
-        }
+        text("tui_openclaw_config_warning_title")
     }
 
     pub fn tui_openclaw_config_file_label() -> &'static str {
-        if is_chinese() {
-            "配置文件"
-        } else {
-            "Config file"
-        }
+        text("tui_openclaw_config_file_label")
     }
 
     pub fn tui_openclaw_config_section_label() -> &'static str {
-        if is_chinese() {
-            "当前配置"
-        } else {
-            "Section"
-        }
+        text("tui_openclaw_config_section_label")
     }
 
     pub fn tui_openclaw_config_warning_state_label() -> &'static str {
-        if is_chinese() {
-            "告警状态"
-        } else {
-            "Warnings"
-        }
+        text("tui_openclaw_config_warning_state_label")
     }
 
     pub fn tui_openclaw_config_warning_present() -> &'static str {
-        if is_chinese() {
-            "发现告警"
-        } else {
-            "Warnings detected"
-        }
+        text("tui_openclaw_config_warning_present")
     }
 
     pub fn tui_openclaw_config_warning_clean() -> &'static str {
-        if is_chinese() {
-            "正常"
-        } else {
-            "Healthy"
-        }
+        text("tui_openclaw_config_warning_clean")
     }
 
     pub fn tui_openclaw_config_path_not_available() -> &'static str {
-        if is_chinese() {
-            "不可用"
-        } else {
-            "n/a"
-        }
+        text("tui_openclaw_config_path_not_available")
     }
 
     pub fn tui_toast_openclaw_config_saved(section: &str) -> String {
@@ -3604,43 +2310,23 @@ pub mod texts {
     }
 
     pub fn tui_openclaw_daily_memory_title() -> &'static str {
-        if is_chinese() {
-            "Daily Memory"
-        } else {
-            "Daily Memory"
-        }
+        text("tui_openclaw_daily_memory_title")
     }
 
     pub fn tui_openclaw_daily_memory_directory_label() -> &'static str {
-        if is_chinese() {
-            "Memory 目录"
-        } else {
-            "Memory directory"
-        }
+        text("tui_openclaw_daily_memory_directory_label")
     }
 
     pub fn tui_openclaw_daily_memory_create_title() -> &'static str {
-        if is_chinese() {
-            "新建 Daily Memory"
-        } else {
-            "Create Daily Memory"
-        }
+        text("tui_openclaw_daily_memory_create_title")
     }
 
     pub fn tui_openclaw_daily_memory_create_prompt() -> &'static str {
-        if is_chinese() {
-            "输入文件名（YYYY-MM-DD.md）："
-        } else {
-            "Enter a filename (YYYY-MM-DD.md):"
-        }
+        text("tui_openclaw_daily_memory_create_prompt")
     }
 
     pub fn tui_openclaw_daily_memory_invalid_filename() -> &'static str {
-        if is_chinese() {
-            "文件名无效，请使用 YYYY-MM-DD.md。"
-        } else {
-            "Invalid filename. Use YYYY-MM-DD.md."
-        }
+        text("tui_openclaw_daily_memory_invalid_filename")
     }
 
     pub fn tui_openclaw_daily_memory_editor_title(filename: &str) -> String {
@@ -3708,11 +2394,7 @@ pub mod texts {
     }
 
     pub fn tui_openclaw_daily_memory_delete_title() -> &'static str {
-        if is_chinese() {
-            "删除 Daily Memory"
-        } else {
-            "Delete Daily Memory"
-        }
+        text("tui_openclaw_daily_memory_delete_title")
     }
 
     pub fn tui_openclaw_daily_memory_delete_message(filename: &str) -> String {
@@ -3732,139 +2414,71 @@ pub mod texts {
     }
 
     pub fn tui_openclaw_daily_memory_empty() -> &'static str {
-        if is_chinese() {
-            "还没有 Daily Memory 文件。按 a 新建。"
-        } else {
-            "No daily memory files yet. Press a to create one."
-        }
+        text("tui_openclaw_daily_memory_empty")
     }
 
     pub fn tui_openclaw_daily_memory_search_empty() -> &'static str {
-        if is_chinese() {
-            "没有匹配的 Daily Memory 文件。"
-        } else {
-            "No matching daily memory files."
-        }
+        text("tui_openclaw_daily_memory_search_empty")
     }
 
     pub fn tui_webdav_jianguoyun_setup_title() -> &'static str {
-        if is_chinese() {
-            "坚果云一键配置"
-        } else {
-            "Jianguoyun Quick Setup"
-        }
+        text("tui_webdav_jianguoyun_setup_title")
     }
 
     pub fn tui_webdav_jianguoyun_username_prompt() -> &'static str {
-        if is_chinese() {
-            "请输入坚果云账号（通常是邮箱）："
-        } else {
-            "Enter your Jianguoyun account (usually email):"
-        }
+        text("tui_webdav_jianguoyun_username_prompt")
     }
 
     pub fn tui_webdav_jianguoyun_app_password_prompt() -> &'static str {
-        if is_chinese() {
-            "请输入坚果云第三方应用密码："
-        } else {
-            "Enter your Jianguoyun app password:"
-        }
+        text("tui_webdav_jianguoyun_app_password_prompt")
     }
 
     pub fn tui_webdav_loading_title_check_connection() -> &'static str {
-        if is_chinese() {
-            "WebDAV 检查连接"
-        } else {
-            "WebDAV Check Connection"
-        }
+        text("tui_webdav_loading_title_check_connection")
     }
 
     pub fn tui_webdav_loading_title_upload() -> &'static str {
-        if is_chinese() {
-            "WebDAV 上传"
-        } else {
-            "WebDAV Upload"
-        }
+        text("tui_webdav_loading_title_upload")
     }
 
     pub fn tui_webdav_loading_title_download() -> &'static str {
-        if is_chinese() {
-            "WebDAV 下载"
-        } else {
-            "WebDAV Download"
-        }
+        text("tui_webdav_loading_title_download")
     }
 
     pub fn tui_webdav_loading_title_quick_setup() -> &'static str {
-        if is_chinese() {
-            "坚果云一键配置"
-        } else {
-            "Jianguoyun Quick Setup"
-        }
+        text("tui_webdav_loading_title_quick_setup")
     }
 
     pub fn tui_webdav_loading_message() -> &'static str {
-        if is_chinese() {
-            "正在处理 WebDAV 请求，请稍候…"
-        } else {
-            "Processing WebDAV request, please wait..."
-        }
+        text("tui_webdav_loading_message")
     }
 
     pub fn tui_config_item_reset() -> &'static str {
-        if is_chinese() {
-            "重置配置"
-        } else {
-            "Reset Config"
-        }
+        text("tui_config_item_reset")
     }
 
     pub fn tui_config_item_show_full() -> &'static str {
-        if is_chinese() {
-            "查看完整配置"
-        } else {
-            "Show Full Config"
-        }
+        text("tui_config_item_show_full")
     }
 
     pub fn tui_config_item_show_path() -> &'static str {
-        if is_chinese() {
-            "显示配置路径"
-        } else {
-            "Show Config Path"
-        }
+        text("tui_config_item_show_path")
     }
 
     pub fn tui_hint_esc_close() -> &'static str {
-        if is_chinese() {
-            "Esc = 关闭"
-        } else {
-            "Esc = Close"
-        }
+        text("tui_hint_esc_close")
     }
 
     pub fn tui_hint_enter_submit_esc_cancel() -> &'static str {
-        if is_chinese() {
-            "Enter = 提交, Esc = 取消"
-        } else {
-            "Enter = Submit, Esc = Cancel"
-        }
+        text("tui_hint_enter_submit_esc_cancel")
     }
 
     pub fn tui_hint_enter_restore_esc_cancel() -> &'static str {
-        if is_chinese() {
-            "Enter = 恢复, Esc = 取消"
-        } else {
-            "Enter = restore, Esc = cancel"
-        }
+        text("tui_hint_enter_restore_esc_cancel")
     }
 
     pub fn tui_backup_picker_title() -> &'static str {
-        if is_chinese() {
-            "选择备份（Enter 恢复）"
-        } else {
-            "Select Backup (Enter to restore)"
-        }
+        text("tui_backup_picker_title")
     }
 
     pub fn tui_speedtest_running(url: &str) -> String {
@@ -3900,51 +2514,27 @@ pub mod texts {
     }
 
     pub fn tui_toast_provider_already_in_use() -> &'static str {
-        if is_chinese() {
-            "已在使用该供应商。"
-        } else {
-            "Already using this provider."
-        }
+        text("tui_toast_provider_already_in_use")
     }
 
     pub fn tui_toast_provider_cannot_delete_current() -> &'static str {
-        if is_chinese() {
-            "不能删除当前供应商。"
-        } else {
-            "Cannot delete current provider."
-        }
+        text("tui_toast_provider_cannot_delete_current")
     }
 
     pub fn tui_toast_provider_cannot_remove_default_model() -> &'static str {
-        if is_chinese() {
-            "被当前默认模型引用的供应商不能直接从配置中移除。"
-        } else {
-            "A provider referenced by the current default model cannot be removed from config directly."
-        }
+        text("tui_toast_provider_cannot_remove_default_model")
     }
 
     pub fn tui_toast_provider_default_requires_live_config() -> &'static str {
-        if is_chinese() {
-            "请先将该供应商添加到配置中，再设为默认。"
-        } else {
-            "Add this provider to config before setting it as default."
-        }
+        text("tui_toast_provider_default_requires_live_config")
     }
 
     pub fn tui_toast_provider_default_model_missing() -> &'static str {
-        if is_chinese() {
-            "该供应商缺少可设为默认的主模型。"
-        } else {
-            "This provider has no primary model to set as default."
-        }
+        text("tui_toast_provider_default_model_missing")
     }
 
     pub fn tui_toast_provider_removed_from_config() -> &'static str {
-        if is_chinese() {
-            "已从当前 OpenClaw 配置中移除该供应商。"
-        } else {
-            "Provider removed from the current OpenClaw config."
-        }
+        text("tui_toast_provider_removed_from_config")
     }
 
     pub fn tui_toast_provider_set_as_default(model: &str) -> String {
@@ -3956,11 +2546,7 @@ pub mod texts {
     }
 
     pub fn tui_confirm_delete_provider_title() -> &'static str {
-        if is_chinese() {
-            "删除供应商"
-        } else {
-            "Delete Provider"
-        }
+        text("tui_confirm_delete_provider_title")
     }
 
     pub fn tui_confirm_delete_provider_message(name: &str, id: &str) -> String {
@@ -3972,11 +2558,7 @@ pub mod texts {
     }
 
     pub fn tui_mcp_add_title() -> &'static str {
-        if is_chinese() {
-            "新增 MCP 服务器"
-        } else {
-            "Add MCP Server"
-        }
+        text("tui_mcp_add_title")
     }
 
     pub fn tui_mcp_edit_title(name: &str) -> String {
@@ -4004,19 +2586,11 @@ pub mod texts {
     }
 
     pub fn tui_toast_provider_no_api_url() -> &'static str {
-        if is_chinese() {
-            "该供应商未配置 API URL。"
-        } else {
-            "No API URL configured for this provider."
-        }
+        text("tui_toast_provider_no_api_url")
     }
 
     pub fn tui_confirm_delete_mcp_title() -> &'static str {
-        if is_chinese() {
-            "删除 MCP 服务器"
-        } else {
-            "Delete MCP Server"
-        }
+        text("tui_confirm_delete_mcp_title")
     }
 
     pub fn tui_confirm_delete_mcp_message(name: &str, id: &str) -> String {
@@ -4036,27 +2610,15 @@ pub mod texts {
     }
 
     pub fn tui_toast_prompt_no_active_to_deactivate() -> &'static str {
-        if is_chinese() {
-            "没有可停用的活动提示词。"
-        } else {
-            "No active prompt to deactivate."
-        }
+        text("tui_toast_prompt_no_active_to_deactivate")
     }
 
     pub fn tui_toast_prompt_cannot_delete_active() -> &'static str {
-        if is_chinese() {
-            "不能删除正在启用的提示词。"
-        } else {
-            "Cannot delete the active prompt."
-        }
+        text("tui_toast_prompt_cannot_delete_active")
     }
 
     pub fn tui_confirm_delete_prompt_title() -> &'static str {
-        if is_chinese() {
-            "删除提示词"
-        } else {
-            "Delete Prompt"
-        }
+        text("tui_confirm_delete_prompt_title")
     }
 
     pub fn tui_confirm_delete_prompt_message(name: &str, id: &str) -> String {
@@ -4068,19 +2630,11 @@ pub mod texts {
     }
 
     pub fn tui_toast_prompt_edit_not_implemented() -> &'static str {
-        if is_chinese() {
-            "提示词编辑尚未实现。"
-        } else {
-            "Prompt editing not implemented yet."
-        }
+        text("tui_toast_prompt_edit_not_implemented")
     }
 
     pub fn tui_toast_prompt_edit_finished() -> &'static str {
-        if is_chinese() {
-            "提示词编辑完成"
-        } else {
-            "Prompt edit finished"
-        }
+        text("tui_toast_prompt_edit_finished")
     }
 
     pub fn tui_toast_prompt_not_found(id: &str) -> String {
@@ -4092,11 +2646,7 @@ pub mod texts {
     }
 
     pub fn tui_config_paths_title() -> &'static str {
-        if is_chinese() {
-            "配置路径"
-        } else {
-            "Configuration Paths"
-        }
+        text("tui_config_paths_title")
     }
 
     pub fn tui_config_paths_config_file(path: &str) -> String {
@@ -4124,59 +2674,31 @@ pub mod texts {
     }
 
     pub fn tui_config_export_title() -> &'static str {
-        if is_chinese() {
-            "导出配置"
-        } else {
-            "Export Configuration"
-        }
+        text("tui_config_export_title")
     }
 
     pub fn tui_config_export_prompt() -> &'static str {
-        if is_chinese() {
-            "导出路径："
-        } else {
-            "Export path:"
-        }
+        text("tui_config_export_prompt")
     }
 
     pub fn tui_config_import_title() -> &'static str {
-        if is_chinese() {
-            "导入配置"
-        } else {
-            "Import Configuration"
-        }
+        text("tui_config_import_title")
     }
 
     pub fn tui_config_import_prompt() -> &'static str {
-        if is_chinese() {
-            "从路径导入："
-        } else {
-            "Import from path:"
-        }
+        text("tui_config_import_prompt")
     }
 
     pub fn tui_config_backup_title() -> &'static str {
-        if is_chinese() {
-            "备份配置"
-        } else {
-            "Backup Configuration"
-        }
+        text("tui_config_backup_title")
     }
 
     pub fn tui_config_backup_prompt() -> &'static str {
-        if is_chinese() {
-            "可选名称（留空使用默认值）："
-        } else {
-            "Optional name (empty for default):"
-        }
+        text("tui_config_backup_prompt")
     }
 
     pub fn tui_toast_no_backups_found() -> &'static str {
-        if is_chinese() {
-            "未找到备份。"
-        } else {
-            "No backups found."
-        }
+        text("tui_toast_no_backups_found")
     }
 
     pub fn tui_error_failed_to_read(e: &str) -> String {
@@ -4196,35 +2718,19 @@ pub mod texts {
     }
 
     pub fn tui_config_reset_title() -> &'static str {
-        if is_chinese() {
-            "重置配置"
-        } else {
-            "Reset Configuration"
-        }
+        text("tui_config_reset_title")
     }
 
     pub fn tui_config_reset_message() -> &'static str {
-        if is_chinese() {
-            "重置为默认配置？（这将覆盖当前配置）"
-        } else {
-            "Reset to default configuration? (This will overwrite your current config)"
-        }
+        text("tui_config_reset_message")
     }
 
     pub fn tui_toast_export_path_empty() -> &'static str {
-        if is_chinese() {
-            "导出路径为空。"
-        } else {
-            "Export path is empty."
-        }
+        text("tui_toast_export_path_empty")
     }
 
     pub fn tui_toast_import_path_empty() -> &'static str {
-        if is_chinese() {
-            "导入路径为空。"
-        } else {
-            "Import path is empty."
-        }
+        text("tui_toast_import_path_empty")
     }
 
     pub fn tui_confirm_import_message(path: &str) -> String {
@@ -4236,19 +2742,11 @@ pub mod texts {
     }
 
     pub fn tui_toast_command_empty() -> &'static str {
-        if is_chinese() {
-            "命令为空。"
-        } else {
-            "Command is empty."
-        }
+        text("tui_toast_command_empty")
     }
 
     pub fn tui_confirm_restore_backup_title() -> &'static str {
-        if is_chinese() {
-            "恢复备份"
-        } else {
-            "Restore Backup"
-        }
+        text("tui_confirm_restore_backup_title")
     }
 
     pub fn tui_confirm_restore_backup_message(name: &str) -> String {
@@ -4344,11 +2842,7 @@ pub mod texts {
     }
 
     pub fn tui_toast_speedtest_finished() -> &'static str {
-        if is_chinese() {
-            "测速完成。"
-        } else {
-            "Speedtest finished."
-        }
+        text("tui_toast_speedtest_finished")
     }
 
     pub fn tui_toast_speedtest_failed(err: &str) -> String {
@@ -4368,11 +2862,7 @@ pub mod texts {
     }
 
     pub fn tui_toast_speedtest_disabled() -> &'static str {
-        if is_chinese() {
-            "本次会话测速不可用。"
-        } else {
-            "Speedtest is disabled for this session."
-        }
+        text("tui_toast_speedtest_disabled")
     }
 
     pub fn tui_toast_local_env_check_unavailable(err: &str) -> String {
@@ -4384,11 +2874,7 @@ pub mod texts {
     }
 
     pub fn tui_toast_local_env_check_disabled() -> &'static str {
-        if is_chinese() {
-            "本次会话本地环境检查不可用。"
-        } else {
-            "Local environment check is disabled for this session."
-        }
+        text("tui_toast_local_env_check_disabled")
     }
 
     pub fn tui_toast_local_env_check_request_failed(err: &str) -> String {
@@ -4408,11 +2894,7 @@ pub mod texts {
     }
 
     pub fn tui_toast_stream_check_finished() -> &'static str {
-        if is_chinese() {
-            "健康检查完成。"
-        } else {
-            "Stream check finished."
-        }
+        text("tui_toast_stream_check_finished")
     }
 
     pub fn tui_toast_stream_check_failed(err: &str) -> String {
@@ -4432,11 +2914,7 @@ pub mod texts {
     }
 
     pub fn tui_toast_stream_check_disabled() -> &'static str {
-        if is_chinese() {
-            "本次会话健康检查不可用。"
-        } else {
-            "Stream check is disabled for this session."
-        }
+        text("tui_toast_stream_check_disabled")
     }
 
     pub fn tui_toast_stream_check_request_failed(err: &str) -> String {
@@ -4472,27 +2950,15 @@ pub mod texts {
     }
 
     pub fn tui_toast_model_fetch_worker_disabled() -> &'static str {
-        if is_chinese() {
-            "本次会话模型获取后台任务不可用。"
-        } else {
-            "Model fetch worker is disabled for this session."
-        }
+        text("tui_toast_model_fetch_worker_disabled")
     }
 
     pub fn tui_toast_webdav_worker_disabled() -> &'static str {
-        if is_chinese() {
-            "本次会话 WebDAV 后台任务不可用。"
-        } else {
-            "WebDAV worker is disabled for this session."
-        }
+        text("tui_toast_webdav_worker_disabled")
     }
 
     pub fn tui_error_skills_worker_unavailable() -> &'static str {
-        if is_chinese() {
-            "Skills 后台任务不可用。"
-        } else {
-            "Skills worker unavailable."
-        }
+        text("tui_error_skills_worker_unavailable")
     }
 
     pub fn tui_toast_skills_discover_finished(count: usize) -> String {
@@ -4528,19 +2994,11 @@ pub mod texts {
     }
 
     pub fn tui_toast_skill_already_installed() -> &'static str {
-        if is_chinese() {
-            "该 Skill 已安装。"
-        } else {
-            "Skill already installed."
-        }
+        text("tui_toast_skill_already_installed")
     }
 
     pub fn tui_toast_skill_spec_empty() -> &'static str {
-        if is_chinese() {
-            "Skill 不能为空。"
-        } else {
-            "Skill spec is empty."
-        }
+        text("tui_toast_skill_spec_empty")
     }
 
     pub fn tui_toast_skill_toggled(directory: &str, enabled: bool) -> String {
@@ -4563,19 +3021,11 @@ pub mod texts {
     }
 
     pub fn tui_toast_skill_apps_updated() -> &'static str {
-        if is_chinese() {
-            "Skill 应用已更新。"
-        } else {
-            "Skill apps updated."
-        }
+        text("tui_toast_skill_apps_updated")
     }
 
     pub fn tui_toast_skills_synced() -> &'static str {
-        if is_chinese() {
-            "Skills 同步完成。"
-        } else {
-            "Skills synced."
-        }
+        text("tui_toast_skills_synced")
     }
 
     pub fn tui_toast_skills_sync_method_set(method: &str) -> String {
@@ -4587,43 +3037,23 @@ pub mod texts {
     }
 
     pub fn tui_toast_repo_spec_empty() -> &'static str {
-        if is_chinese() {
-            "仓库不能为空。"
-        } else {
-            "Repository is empty."
-        }
+        text("tui_toast_repo_spec_empty")
     }
 
     pub fn tui_error_repo_spec_empty() -> &'static str {
-        if is_chinese() {
-            "仓库不能为空。"
-        } else {
-            "Repository cannot be empty."
-        }
+        text("tui_error_repo_spec_empty")
     }
 
     pub fn tui_error_repo_spec_invalid() -> &'static str {
-        if is_chinese() {
-            "仓库格式无效。请使用 owner/name 或 https://github.com/owner/name"
-        } else {
-            "Invalid repo format. Use owner/name or https://github.com/owner/name"
-        }
+        text("tui_error_repo_spec_invalid")
     }
 
     pub fn tui_toast_repo_added() -> &'static str {
-        if is_chinese() {
-            "仓库已添加。"
-        } else {
-            "Repository added."
-        }
+        text("tui_toast_repo_added")
     }
 
     pub fn tui_toast_repo_removed() -> &'static str {
-        if is_chinese() {
-            "仓库已移除。"
-        } else {
-            "Repository removed."
-        }
+        text("tui_toast_repo_removed")
     }
 
     pub fn tui_toast_repo_toggled(enabled: bool) -> String {
@@ -4691,11 +3121,7 @@ pub mod texts {
     }
 
     pub fn tui_toast_no_unmanaged_selected() -> &'static str {
-        if is_chinese() {
-            "请至少选择一个要导入的技能。"
-        } else {
-            "Select at least one skill to import."
-        }
+        text("tui_toast_no_unmanaged_selected")
     }
 
     pub fn tui_toast_unmanaged_imported(count: usize) -> String {
@@ -4707,91 +3133,47 @@ pub mod texts {
     }
 
     pub fn tui_toast_provider_deleted() -> &'static str {
-        if is_chinese() {
-            "供应商已删除。"
-        } else {
-            "Provider deleted."
-        }
+        text("tui_toast_provider_deleted")
     }
 
     pub fn tui_toast_provider_add_finished() -> &'static str {
-        if is_chinese() {
-            "供应商新增流程已完成。"
-        } else {
-            "Provider add flow finished."
-        }
+        text("tui_toast_provider_add_finished")
     }
 
     pub fn tui_toast_provider_add_missing_fields() -> &'static str {
-        if is_chinese() {
-            "请填写 name，id 会自动生成。"
-        } else {
-            "Please fill in name. id will be generated automatically."
-        }
+        text("tui_toast_provider_add_missing_fields")
     }
 
     pub fn tui_toast_provider_missing_name() -> &'static str {
-        if is_chinese() {
-            "请填写 name，id 会自动生成。"
-        } else {
-            "Please fill in name. id will be generated automatically."
-        }
+        text("tui_toast_provider_missing_name")
     }
 
     pub fn tui_toast_provider_add_failed() -> &'static str {
-        if is_chinese() {
-            "新增供应商失败。"
-        } else {
-            "Failed to add provider."
-        }
+        text("tui_toast_provider_add_failed")
     }
 
     pub fn tui_toast_provider_edit_finished() -> &'static str {
-        if is_chinese() {
-            "供应商编辑流程已完成。"
-        } else {
-            "Provider edit flow finished."
-        }
+        text("tui_toast_provider_edit_finished")
     }
 
     pub fn tui_toast_mcp_updated() -> &'static str {
-        if is_chinese() {
-            "MCP 已更新。"
-        } else {
-            "MCP updated."
-        }
+        text("tui_toast_mcp_updated")
     }
 
     pub fn tui_toast_mcp_upserted() -> &'static str {
-        if is_chinese() {
-            "MCP 服务器已保存。"
-        } else {
-            "MCP server saved."
-        }
+        text("tui_toast_mcp_upserted")
     }
 
     pub fn tui_toast_mcp_missing_fields() -> &'static str {
-        if is_chinese() {
-            "请在 JSON 中填写 id 和 name。"
-        } else {
-            "Please fill in id and name in JSON."
-        }
+        text("tui_toast_mcp_missing_fields")
     }
 
     pub fn tui_toast_mcp_server_deleted() -> &'static str {
-        if is_chinese() {
-            "MCP 服务器已删除。"
-        } else {
-            "MCP server deleted."
-        }
+        text("tui_toast_mcp_server_deleted")
     }
 
     pub fn tui_toast_mcp_server_not_found() -> &'static str {
-        if is_chinese() {
-            "未找到 MCP 服务器。"
-        } else {
-            "MCP server not found."
-        }
+        text("tui_toast_mcp_server_not_found")
     }
 
     pub fn tui_toast_mcp_imported(count: usize) -> String {
@@ -4831,27 +3213,15 @@ pub mod texts {
     }
 
     pub fn tui_toast_prompt_activated() -> &'static str {
-        if is_chinese() {
-            "提示词已启用。"
-        } else {
-            "Prompt activated."
-        }
+        text("tui_toast_prompt_activated")
     }
 
     pub fn tui_toast_prompt_deactivated() -> &'static str {
-        if is_chinese() {
-            "提示词已停用。"
-        } else {
-            "Prompt deactivated."
-        }
+        text("tui_toast_prompt_deactivated")
     }
 
     pub fn tui_toast_prompt_deleted() -> &'static str {
-        if is_chinese() {
-            "提示词已删除。"
-        } else {
-            "Prompt deleted."
-        }
+        text("tui_toast_prompt_deleted")
     }
 
     pub fn tui_toast_exported_to(path: &str) -> String {
@@ -4871,11 +3241,7 @@ pub mod texts {
     }
 
     pub fn tui_toast_imported_config() -> &'static str {
-        if is_chinese() {
-            "配置已导入。"
-        } else {
-            "Imported config."
-        }
+        text("tui_toast_imported_config")
     }
 
     pub fn tui_toast_imported_with_backup(backup_id: &str) -> String {
@@ -4886,12 +3252,8 @@ pub mod texts {
         }
     }
 
-    pub fn tui_toast_no_config_file_to_backup() -> &'static str {
-        if is_chinese() {
-            "没有可备份的配置文件。"
-        } else {
-            "No config file to backup."
-        }
+    pub fn tui_toast_no_config_file_to_backup() -> &'static str {
+        text("tui_toast_no_config_file_to_backup")
     }
 
     pub fn tui_toast_backup_created(id: &str) -> String {
@@ -4903,11 +3265,7 @@ pub mod texts {
     }
 
     pub fn tui_toast_restored_from_backup() -> &'static str {
-        if is_chinese() {
-            "已从备份恢复。"
-        } else {
-            "Restored from backup."
-        }
+        text("tui_toast_restored_from_backup")
     }
 
     pub fn tui_toast_restored_with_pre_backup(pre_backup: &str) -> String {
@@ -4919,19 +3277,11 @@ pub mod texts {
     }
 
     pub fn tui_toast_webdav_settings_saved() -> &'static str {
-        if is_chinese() {
-            "WebDAV 同步设置已保存。"
-        } else {
-            "WebDAV sync settings saved."
-        }
+        text("tui_toast_webdav_settings_saved")
     }
 
     pub fn tui_toast_proxy_takeover_requires_running() -> &'static str {
-        if is_chinese() {
-            "前台代理未运行，请先启动 `cc-switch proxy serve`。"
-        } else {
-            "Foreground proxy is not running. Start `cc-switch proxy serve` first."
-        }
+        text("tui_toast_proxy_takeover_requires_running")
     }
 
     pub fn tui_toast_proxy_takeover_updated(app: &str, enabled: bool) -> String {
@@ -4979,99 +3329,51 @@ pub mod texts {
     }
 
     pub fn tui_error_proxy_worker_unavailable() -> &'static str {
-        if is_chinese() {
-            "代理任务不可用。"
-        } else {
-            "Proxy worker unavailable."
-        }
+        text("tui_error_proxy_worker_unavailable")
     }
 
     pub fn tui_toast_webdav_settings_cleared() -> &'static str {
-        if is_chinese() {
-            "WebDAV 同步设置已清空。"
-        } else {
-            "WebDAV sync settings cleared."
-        }
+        text("tui_toast_webdav_settings_cleared")
     }
 
     pub fn tui_toast_webdav_connection_ok() -> &'static str {
-        if is_chinese() {
-            "WebDAV 连接检查通过。"
-        } else {
-            "WebDAV connection check passed."
-        }
+        text("tui_toast_webdav_connection_ok")
     }
 
     pub fn tui_toast_webdav_upload_ok() -> &'static str {
-        if is_chinese() {
-            "WebDAV 上传完成。"
-        } else {
-            "WebDAV upload completed."
-        }
+        text("tui_toast_webdav_upload_ok")
     }
 
     pub fn tui_toast_webdav_download_ok() -> &'static str {
-        if is_chinese() {
-            "WebDAV 下载完成。"
-        } else {
-            "WebDAV download completed."
-        }
+        text("tui_toast_webdav_download_ok")
     }
 
     pub fn tui_webdav_v1_migration_title() -> &'static str {
-        if is_chinese() {
-            "发现旧版同步数据"
-        } else {
-            "Legacy sync data detected"
-        }
+        text("tui_webdav_v1_migration_title")
     }
 
     pub fn tui_webdav_v1_migration_message() -> &'static str {
-        if is_chinese() {
-            "远端存在 V1 格式的同步数据，是否迁移到 V2？\n迁移将下载旧数据、应用到本地、重新上传为新格式，并清理旧数据。"
-        } else {
-            "V1 sync data found on remote. Migrate to V2?\nThis will download old data, apply locally, re-upload as V2, and clean up V1 data."
-        }
+        text("tui_webdav_v1_migration_message")
     }
 
     pub fn tui_webdav_loading_title_v1_migration() -> &'static str {
-        if is_chinese() {
-            "V1 → V2 迁移"
-        } else {
-            "V1 → V2 Migration"
-        }
+        text("tui_webdav_loading_title_v1_migration")
     }
 
     pub fn tui_toast_webdav_v1_migration_ok() -> &'static str {
-        if is_chinese() {
-            "V1 → V2 迁移完成，旧数据已清理。"
-        } else {
-            "V1 → V2 migration completed, old data cleaned up."
-        }
+        text("tui_toast_webdav_v1_migration_ok")
     }
 
     pub fn tui_toast_webdav_jianguoyun_configured() -> &'static str {
-        if is_chinese() {
-            "坚果云一键配置完成，连接检查通过。"
-        } else {
-            "Jianguoyun quick setup completed and connection verified."
-        }
+        text("tui_toast_webdav_jianguoyun_configured")
     }
 
     pub fn tui_toast_webdav_username_empty() -> &'static str {
-        if is_chinese() {
-            "请输入 WebDAV 用户名。"
-        } else {
-            "Please enter a WebDAV username."
-        }
+        text("tui_toast_webdav_username_empty")
     }
 
     pub fn tui_toast_webdav_password_empty() -> &'static str {
-        if is_chinese() {
-            "请输入 WebDAV 第三方应用密码。"
-        } else {
-            "Please enter a WebDAV app password."
-        }
+        text("tui_toast_webdav_password_empty")
     }
 
     pub fn tui_toast_webdav_request_failed(err: &str) -> String {
@@ -5099,42 +3401,47 @@ pub mod texts {
     }
 
     pub fn tui_toast_config_file_does_not_exist() -> &'static str {
-        if is_chinese() {
-            "配置文件不存在。"
-        } else {
-            "Config file does not exist."
-        }
+        text("tui_toast_config_file_does_not_exist")
     }
 
     pub fn tui_config_validation_title() -> &'static str {
-        if is_chinese() {
-            "配置校验"
-        } else {
-            "Config Validation"
-        }
+        text("tui_config_validation_title")
     }
 
     pub fn tui_config_validation_failed_title() -> &'static str {
+        text("tui_config_validation_failed_title")
+    }
+
+    pub fn tui_config_validation_ok() -> &'static str {
+        text("tui_config_validation_ok")
+    }
+
+    pub fn tui_config_validation_provider_count(app: &str, count: usize) -> String {
         if is_chinese() {
-            "配置校验失败"
+            format!("{app} 供应商:  {count}")
         } else {
-            "Config Validation Failed"
+            format!("{app} providers:  {count}")
         }
     }
 
-    pub fn tui_config_validation_ok() -> &'static str {
+    pub fn config_validation_duplicate_endpoint(
+        app: &str,
+        base_url: &str,
+        members: &[String],
+    ) -> String {
+        let members = members.join(", ");
         if is_chinese() {
-            "✓ 配置是有效的 JSON"
+            format!("⚠ {app} 中多个供应商指向同一端点 {base_url}: {members}")
         } else {
-            "✓ Configuration is valid JSON"
+            format!("⚠ {app} providers share the endpoint {base_url}: {members}")
         }
     }
 
-    pub fn tui_config_validation_provider_count(app: &str, count: usize) -> String {
+    pub fn config_validation_member_without_key(id: &str) -> String {
         if is_chinese() {
-            format!("{app} 供应商:  {count}")
+            format!("{id}（未配置 API Key）")
         } else {
-            format!("{app} providers:  {count}")
+            format!("{id} (no API key)")
         }
     }
 
@@ -5147,19 +3454,11 @@ pub mod texts {
     }
 
     pub fn tui_toast_validation_passed() -> &'static str {
-        if is_chinese() {
-            "校验通过。"
-        } else {
-            "Validation passed."
-        }
+        text("tui_toast_validation_passed")
     }
 
     pub fn tui_toast_config_reset_to_defaults() -> &'static str {
-        if is_chinese() {
-            "配置已重置为默认值。"
-        } else {
-            "Config reset to defaults."
-        }
+        text("tui_toast_config_reset_to_defaults")
     }
 
     pub fn tui_toast_config_reset_with_backup(backup_id: &str) -> String {
@@ -5331,83 +3630,43 @@ pub mod texts {
     // ============================================
 
     pub fn skills_management() -> &'static str {
-        if is_chinese() {
-            "技能管理"
-        } else {
-            "Skills Management"
-        }
+        text("skills_management")
     }
 
     pub fn no_skills_installed() -> &'static str {
-        if is_chinese() {
-            "未安装任何 Skills。"
-        } else {
-            "No skills installed."
-        }
+        text("no_skills_installed")
     }
 
     pub fn skills_discover() -> &'static str {
-        if is_chinese() {
-            "🔎 发现/搜索 Skills"
-        } else {
-            "🔎 Discover/Search Skills"
-        }
+        text("skills_discover")
     }
 
     pub fn skills_install() -> &'static str {
-        if is_chinese() {
-            "⬇️  安装 Skill"
-        } else {
-            "⬇️  Install Skill"
-        }
+        text("skills_install")
     }
 
     pub fn skills_uninstall() -> &'static str {
-        if is_chinese() {
-            "🗑️  卸载 Skill"
-        } else {
-            "🗑️  Uninstall Skill"
-        }
+        text("skills_uninstall")
     }
 
     pub fn skills_toggle_for_app() -> &'static str {
-        if is_chinese() {
-            "✅ 启用/禁用（当前应用）"
-        } else {
-            "✅ Enable/Disable (Current App)"
-        }
+        text("skills_toggle_for_app")
     }
 
     pub fn skills_show_info() -> &'static str {
-        if is_chinese() {
-            "ℹ️  查看 Skill 信息"
-        } else {
-            "ℹ️  Skill Info"
-        }
+        text("skills_show_info")
     }
 
     pub fn skills_sync_now() -> &'static str {
-        if is_chinese() {
-            "🔄 同步 Skills 到本地"
-        } else {
-            "🔄 Sync Skills to Live"
-        }
+        text("skills_sync_now")
     }
 
     pub fn skills_sync_method() -> &'static str {
-        if is_chinese() {
-            "🔗 同步方式（auto/symlink/copy）"
-        } else {
-            "🔗 Sync Method (auto/symlink/copy)"
-        }
+        text("skills_sync_method")
     }
 
     pub fn skills_select_sync_method() -> &'static str {
-        if is_chinese() {
-            "选择同步方式："
-        } else {
-            "Select sync method:"
-        }
+        text("skills_select_sync_method")
     }
 
     pub fn skills_current_sync_method(method: &str) -> String {
@@ -5427,51 +3686,27 @@ pub mod texts {
     }
 
     pub fn skills_scan_unmanaged() -> &'static str {
-        if is_chinese() {
-            "🕵️  查找已有技能"
-        } else {
-            "🕵️  Find Existing Skills"
-        }
+        text("skills_scan_unmanaged")
     }
 
     pub fn skills_import_from_apps() -> &'static str {
-        if is_chinese() {
-            "📥 导入已有技能"
-        } else {
-            "📥 Import Existing Skills"
-        }
+        text("skills_import_from_apps")
     }
 
     pub fn skills_manage_repos() -> &'static str {
-        if is_chinese() {
-            "📦 管理技能仓库"
-        } else {
-            "📦 Manage Skill Repos"
-        }
+        text("skills_manage_repos")
     }
 
     pub fn skills_enter_query() -> &'static str {
-        if is_chinese() {
-            "输入搜索关键词（可选）："
-        } else {
-            "Enter search query (optional):"
-        }
+        text("skills_enter_query")
     }
 
     pub fn skills_enter_install_spec() -> &'static str {
-        if is_chinese() {
-            "输入技能目录，或完整标识（owner/name:directory）："
-        } else {
-            "Enter a skill directory, or a full key (owner/name:directory):"
-        }
+        text("skills_enter_install_spec")
     }
 
     pub fn skills_select_skill() -> &'static str {
-        if is_chinese() {
-            "选择一个 Skill："
-        } else {
-            "Select a skill:"
-        }
+        text("skills_select_skill")
     }
 
     pub fn skills_confirm_install(name: &str, app: &str) -> String {
@@ -5505,59 +3740,31 @@ pub mod texts {
     }
 
     pub fn skills_no_unmanaged_found() -> &'static str {
-        if is_chinese() {
-            "未发现可导入的技能。所有技能已在 CC Switch 中统一管理。"
-        } else {
-            "No skills to import found. All skills are already managed by CC Switch."
-        }
+        text("skills_no_unmanaged_found")
     }
 
     pub fn skills_select_unmanaged_to_import() -> &'static str {
-        if is_chinese() {
-            "选择要导入的技能："
-        } else {
-            "Select skills to import:"
-        }
+        text("skills_select_unmanaged_to_import")
     }
 
     pub fn skills_repos_management() -> &'static str {
-        if is_chinese() {
-            "技能仓库管理"
-        } else {
-            "Skill Repos"
-        }
+        text("skills_repos_management")
     }
 
     pub fn skills_repo_list() -> &'static str {
-        if is_chinese() {
-            "📋 查看仓库列表"
-        } else {
-            "📋 List Repos"
-        }
+        text("skills_repo_list")
     }
 
     pub fn skills_repo_add() -> &'static str {
-        if is_chinese() {
-            "➕ 添加仓库"
-        } else {
-            "➕ Add Repo"
-        }
+        text("skills_repo_add")
     }
 
     pub fn skills_repo_remove() -> &'static str {
-        if is_chinese() {
-            "➖ 移除仓库"
-        } else {
-            "➖ Remove Repo"
-        }
+        text("skills_repo_remove")
     }
 
     pub fn skills_repo_enter_spec() -> &'static str {
-        if is_chinese() {
-            "输入 GitHub 仓库（owner/name，可选 @branch）或完整 URL："
-        } else {
-            "Enter a GitHub repository (owner/name, optional @branch) or a full URL:"
-        }
+        text("skills_repo_enter_spec")
     }
 
     // ============================================
@@ -5565,139 +3772,71 @@ pub mod texts {
     // ============================================
 
     pub fn provider_management() -> &'static str {
-        if is_chinese() {
-            "🔌 供应商管理"
-        } else {
-            "🔌 Provider Management"
-        }
+        text("provider_management")
     }
 
     pub fn no_providers() -> &'static str {
-        if is_chinese() {
-            "未找到供应商。"
-        } else {
-            "No providers found."
-        }
+        text("no_providers")
     }
 
     pub fn view_current_provider() -> &'static str {
-        if is_chinese() {
-            "📋 查看当前供应商详情"
-        } else {
-            "📋 View Current Provider Details"
-        }
+        text("view_current_provider")
     }
 
     pub fn switch_provider() -> &'static str {
-        if is_chinese() {
-            "🔄 切换供应商"
-        } else {
-            "🔄 Switch Provider"
-        }
+        text("switch_provider")
     }
 
     pub fn add_provider() -> &'static str {
-        if is_chinese() {
-            "➕ 新增供应商"
-        } else {
-            "➕ Add Provider"
-        }
+        text("add_provider")
     }
 
     pub fn add_official_provider() -> &'static str {
-        if is_chinese() {
-            "添加官方供应商"
-        } else {
-            "Add Official Provider"
-        }
+        text("add_official_provider")
     }
 
     pub fn add_third_party_provider() -> &'static str {
-        if is_chinese() {
-            "添加第三方供应商"
-        } else {
-            "Add Third-Party Provider"
-        }
+        text("add_third_party_provider")
     }
 
     pub fn select_provider_add_mode() -> &'static str {
-        if is_chinese() {
-            "请选择供应商类型："
-        } else {
-            "Select provider type:"
-        }
+        text("select_provider_add_mode")
     }
 
     pub fn delete_provider() -> &'static str {
-        if is_chinese() {
-            "🗑️  删除供应商"
-        } else {
-            "🗑️  Delete Provider"
-        }
+        text("delete_provider")
     }
 
     pub fn back_to_main() -> &'static str {
-        if is_chinese() {
-            "⬅️  返回主菜单"
-        } else {
-            "⬅️  Back to Main Menu"
-        }
+        text("back_to_main")
     }
 
     pub fn choose_action() -> &'static str {
-        if is_chinese() {
-            "选择操作："
-        } else {
-            "Choose an action:"
-        }
+        text("choose_action")
     }
 
     pub fn esc_to_go_back_help() -> &'static str {
-        if is_chinese() {
-            "Esc 返回上一步"
-        } else {
-            "Esc to go back"
-        }
+        text("esc_to_go_back_help")
     }
 
     pub fn select_filter_help() -> &'static str {
-        if is_chinese() {
-            "Esc 返回；输入可过滤"
-        } else {
-            "Esc to go back; type to filter"
-        }
+        text("select_filter_help")
     }
 
     pub fn current_provider_details() -> &'static str {
-        if is_chinese() {
-            "当前供应商详情"
-        } else {
-            "Current Provider Details"
-        }
+        text("current_provider_details")
     }
 
     pub fn only_one_provider() -> &'static str {
-        if is_chinese() {
-            "只有一个供应商，无法切换。"
-        } else {
-            "Only one provider available. Cannot switch."
-        }
+        text("only_one_provider")
     }
 
     pub fn no_other_providers() -> &'static str {
-        if is_chinese() {
-            "没有其他供应商可切换。"
-        } else {
-            "No other providers to switch to."
-        }
+        text("no_other_providers")
     }
 
     pub fn select_provider_to_switch() -> &'static str {
-        if is_chinese() {
-            "选择要切换到的供应商："
-        } else {
-            "Select provider to switch to:"
-        }
+        text("select_provider_to_switch")
     }
 
     pub fn switched_to_provider(id: &str) -> String {
@@ -5709,11 +3848,7 @@ pub mod texts {
     }
 
     pub fn restart_note() -> &'static str {
-        if is_chinese() {
-            "注意：请重启 CLI 客户端以应用更改。"
-        } else {
-            "Note: Restart your CLI client to apply the changes."
-        }
+        text("restart_note")
     }
 
     pub fn live_sync_skipped_uninitialized_warning(app: &str) -> String {
@@ -5725,19 +3860,11 @@ pub mod texts {
     }
 
     pub fn no_deletable_providers() -> &'static str {
-        if is_chinese() {
-            "没有可删除的供应商（无法删除当前供应商）。"
-        } else {
-            "No providers available for deletion (cannot delete current provider)."
-        }
+        text("no_deletable_providers")
     }
 
     pub fn select_provider_to_delete() -> &'static str {
-        if is_chinese() {
-            "选择要删除的供应商："
-        } else {
-            "Select provider to delete:"
-        }
+        text("select_provider_to_delete")
     }
 
     pub fn confirm_delete(id: &str) -> String {
@@ -5749,51 +3876,27 @@ pub mod texts {
     }
 
     pub fn cancelled() -> &'static str {
-        if is_chinese() {
-            "已取消。"
-        } else {
-            "Cancelled."
-        }
+        text("cancelled")
     }
 
     pub fn selection_cancelled() -> &'static str {
-        if is_chinese() {
-            "已取消选择"
-        } else {
-            "Selection cancelled"
-        }
+        text("selection_cancelled")
     }
 
     pub fn invalid_selection() -> &'static str {
-        if is_chinese() {
-            "选择无效"
-        } else {
-            "Invalid selection"
-        }
+        text("invalid_selection")
     }
 
     pub fn available_backups() -> &'static str {
-        if is_chinese() {
-            "可用备份"
-        } else {
-            "Available Backups"
-        }
+        text("available_backups")
     }
 
     pub fn no_backups_found() -> &'static str {
-        if is_chinese() {
-            "未找到备份。"
-        } else {
-            "No backups found."
-        }
+        text("no_backups_found")
     }
 
     pub fn create_backup_first_hint() -> &'static str {
-        if is_chinese() {
-            "请先创建备份：cc-switch config backup"
-        } else {
-            "Create a backup first: cc-switch config backup"
-        }
+        text("create_backup_first_hint")
     }
 
     pub fn found_backups(count: usize) -> String {
@@ -5805,43 +3908,23 @@ pub mod texts {
     }
 
     pub fn select_backup_to_restore() -> &'static str {
-        if is_chinese() {
-            "选择要恢复的备份："
-        } else {
-            "Select backup to restore:"
-        }
+        text("select_backup_to_restore")
     }
 
     pub fn warning_title() -> &'static str {
-        if is_chinese() {
-            "警告："
-        } else {
-            "Warning:"
-        }
+        text("warning_title")
     }
 
     pub fn config_restore_warning_replace() -> &'static str {
-        if is_chinese() {
-            "这将用所选备份替换你当前的配置。"
-        } else {
-            "This will replace your current configuration with the selected backup."
-        }
+        text("config_restore_warning_replace")
     }
 
     pub fn config_restore_warning_pre_backup() -> &'static str {
-        if is_chinese() {
-            "系统会先创建一次当前状态的备份。"
-        } else {
-            "A backup of the current state will be created first."
-        }
+        text("config_restore_warning_pre_backup")
     }
 
     pub fn config_restore_confirm_prompt() -> &'static str {
-        if is_chinese() {
-            "确认继续恢复？"
-        } else {
-            "Continue with restore?"
-        }
+        text("config_restore_confirm_prompt")
     }
 
     pub fn deleted_provider(id: &str) -> String {
@@ -5854,27 +3937,15 @@ pub mod texts {
 
     // Provider Input - Basic Fields
     pub fn provider_name_label() -> &'static str {
-        if is_chinese() {
-            "供应商名称："
-        } else {
-            "Provider Name:"
-        }
+        text("provider_name_label")
     }
 
     pub fn provider_name_help() -> &'static str {
-        if is_chinese() {
-            "必填，用于显示的友好名称"
-        } else {
-            "Required, friendly display name"
-        }
+        text("provider_name_help")
     }
 
     pub fn provider_name_help_edit() -> &'static str {
-        if is_chinese() {
-            "必填，直接回车保持原值"
-        } else {
-            "Required, press Enter to keep"
-        }
+        text("provider_name_help_edit")
     }
 
     pub fn provider_name_placeholder() -> &'static str {
@@ -5882,35 +3953,19 @@ pub mod texts {
     }
 
     pub fn provider_name_empty_error() -> &'static str {
-        if is_chinese() {
-            "供应商名称不能为空"
-        } else {
-            "Provider name cannot be empty"
-        }
+        text("provider_name_empty_error")
     }
 
     pub fn website_url_label() -> &'static str {
-        if is_chinese() {
-            "官网 URL（可选）："
-        } else {
-            "Website URL (opt.):"
-        }
+        text("website_url_label")
     }
 
     pub fn website_url_help() -> &'static str {
-        if is_chinese() {
-            "供应商的网站地址，直接回车跳过"
-        } else {
-            "Provider's website, press Enter to skip"
-        }
+        text("website_url_help")
     }
-
-    pub fn website_url_help_edit() -> &'static str {
-        if is_chinese() {
-            "留空则不修改，直接回车跳过"
-        } else {
-            "Leave blank to keep, Enter to skip"
-        }
+
+    pub fn website_url_help_edit() -> &'static str {
+        text("website_url_help_edit")
     }
 
     pub fn website_url_placeholder() -> &'static str {
@@ -5922,6 +3977,16 @@ pub mod texts {
         "Use 'cc-switch provider add' to create a new provider."
     }
 
+    pub fn live_config_drift_warning(app: &str) -> String {
+        if is_chinese() {
+            format!("{app} 的 live 配置与当前选中的供应商不一致，是否手动修改过？")
+        } else {
+            format!(
+                "{app}'s live config doesn't match the selected provider — did you edit it manually?"
+            )
+        }
+    }
+
     pub fn app_config_not_found(app: &str) -> String {
         if is_chinese() {
             format!("应用 {} 配置不存在", app)
@@ -5947,43 +4012,23 @@ pub mod texts {
     }
 
     pub fn configure_optional_fields_prompt() -> &'static str {
-        if is_chinese() {
-            "配置可选字段（备注、排序索引）？"
-        } else {
-            "Configure optional fields (notes, sort index)?"
-        }
+        text("configure_optional_fields_prompt")
     }
 
     pub fn current_config_header() -> &'static str {
-        if is_chinese() {
-            "当前配置："
-        } else {
-            "Current Configuration:"
-        }
+        text("current_config_header")
     }
 
     pub fn modify_provider_config_prompt() -> &'static str {
-        if is_chinese() {
-            "修改供应商配置（API Key, Base URL 等）？"
-        } else {
-            "Modify provider configuration (API Key, Base URL, etc.)?"
-        }
+        text("modify_provider_config_prompt")
     }
 
     pub fn modify_optional_fields_prompt() -> &'static str {
-        if is_chinese() {
-            "修改可选字段（备注、排序索引）？"
-        } else {
-            "Modify optional fields (notes, sort index)?"
-        }
+        text("modify_optional_fields_prompt")
     }
 
     pub fn current_provider_synced_warning() -> &'static str {
-        if is_chinese() {
-            "⚠ 此供应商当前已激活，修改已同步到 live 配置"
-        } else {
-            "⚠ This provider is currently active, changes synced to live config"
-        }
+        text("current_provider_synced_warning")
     }
 
     pub fn input_failed_error(err: &str) -> String {
@@ -6000,270 +4045,138 @@ pub mod texts {
 
     // Provider Input - Basic Fields
     pub fn provider_name_prompt() -> &'static str {
-        if is_chinese() {
-            "供应商名称："
-        } else {
-            "Provider Name:"
-        }
+        text("provider_name_prompt")
     }
 
     // Provider Input - Claude Configuration
     pub fn config_claude_header() -> &'static str {
-        if is_chinese() {
-            "配置 Claude 供应商："
-        } else {
-            "Configure Claude Provider:"
-        }
+        text("config_claude_header")
     }
 
     pub fn api_key_label() -> &'static str {
-        if is_chinese() {
-            "API Key："
-        } else {
-            "API Key:"
-        }
+        text("api_key_label")
     }
 
     pub fn api_key_help() -> &'static str {
-        if is_chinese() {
-            "留空使用默认值"
-        } else {
-            "Leave empty to use default"
-        }
+        text("api_key_help")
     }
 
     pub fn base_url_label() -> &'static str {
-        if is_chinese() {
-            "Base URL："
-        } else {
-            "Base URL:"
-        }
+        text("base_url_label")
     }
 
     pub fn base_url_empty_error() -> &'static str {
-        if is_chinese() {
-            "API 请求地址不能为空"
-        } else {
-            "API URL cannot be empty"
-        }
+        text("base_url_empty_error")
     }
 
     pub fn base_url_placeholder() -> &'static str {
-        if is_chinese() {
-            "如 https://api.anthropic.com"
-        } else {
-            "e.g., https://api.anthropic.com"
-        }
+        text("base_url_placeholder")
     }
 
     pub fn configure_model_names_prompt() -> &'static str {
-        if is_chinese() {
-            "配置模型名称？"
-        } else {
-            "Configure model names?"
-        }
+        text("configure_model_names_prompt")
     }
 
     pub fn model_default_label() -> &'static str {
-        if is_chinese() {
-            "默认模型："
-        } else {
-            "Default Model:"
-        }
+        text("model_default_label")
     }
 
     pub fn model_default_help() -> &'static str {
-        if is_chinese() {
-            "留空使用 Claude Code 默认模型"
-        } else {
-            "Leave empty to use Claude Code default"
-        }
+        text("model_default_help")
     }
 
     pub fn model_haiku_label() -> &'static str {
-        if is_chinese() {
-            "Haiku 模型："
-        } else {
-            "Haiku Model:"
-        }
+        text("model_haiku_label")
     }
 
     pub fn model_haiku_placeholder() -> &'static str {
-        if is_chinese() {
-            "如 claude-3-5-haiku-20241022"
-        } else {
-            "e.g., claude-3-5-haiku-20241022"
-        }
+        text("model_haiku_placeholder")
     }
 
     pub fn model_sonnet_label() -> &'static str {
-        if is_chinese() {
-            "Sonnet 模型："
-        } else {
-            "Sonnet Model:"
-        }
+        text("model_sonnet_label")
     }
 
     pub fn model_sonnet_placeholder() -> &'static str {
-        if is_chinese() {
-            "如 claude-3-5-sonnet-20241022"
-        } else {
-            "e.g., claude-3-5-sonnet-20241022"
-        }
+        text("model_sonnet_placeholder")
     }
 
     pub fn model_opus_label() -> &'static str {
-        if is_chinese() {
-            "Opus 模型："
-        } else {
-            "Opus Model:"
-        }
+        text("model_opus_label")
     }
 
     pub fn model_opus_placeholder() -> &'static str {
-        if is_chinese() {
-            "如 claude-3-opus-20240229"
-        } else {
-            "e.g., claude-3-opus-20240229"
-        }
+        text("model_opus_placeholder")
     }
 
     // Provider Input - Codex Configuration
     pub fn config_codex_header() -> &'static str {
-        if is_chinese() {
-            "配置 Codex 供应商："
-        } else {
-            "Configure Codex Provider:"
-        }
+        text("config_codex_header")
     }
 
     pub fn openai_api_key_label() -> &'static str {
-        if is_chinese() {
-            "OpenAI API Key："
-        } else {
-            "OpenAI API Key:"
-        }
+        text("openai_api_key_label")
     }
 
     pub fn anthropic_api_key_label() -> &'static str {
-        if is_chinese() {
-            "Anthropic API Key："
-        } else {
-            "Anthropic API Key:"
-        }
+        text("anthropic_api_key_label")
     }
 
     pub fn config_toml_label() -> &'static str {
-        if is_chinese() {
-            "配置内容 (TOML)："
-        } else {
-            "Config Content (TOML):"
-        }
+        text("config_toml_label")
     }
 
     pub fn config_toml_help() -> &'static str {
-        if is_chinese() {
-            "按 Esc 后 Enter 提交"
-        } else {
-            "Press Esc then Enter to submit"
-        }
+        text("config_toml_help")
     }
 
     pub fn config_toml_placeholder() -> &'static str {
-        if is_chinese() {
-            "留空使用默认配置"
-        } else {
-            "Leave empty to use default config"
-        }
+        text("config_toml_placeholder")
     }
 
     // Codex 0.64+ Configuration
     pub fn codex_auth_mode_info() -> &'static str {
-        if is_chinese() {
-            "⚠ 请选择 Codex 的鉴权方式（决定 API Key 从哪里读取）"
-        } else {
-            "⚠ Choose how Codex authenticates (where the API key is read from)"
-        }
+        text("codex_auth_mode_info")
     }
 
     pub fn codex_auth_mode_label() -> &'static str {
-        if is_chinese() {
-            "认证方式："
-        } else {
-            "Auth Mode:"
-        }
+        text("codex_auth_mode_label")
     }
 
     pub fn codex_auth_mode_help() -> &'static str {
-        if is_chinese() {
-            "OpenAI 认证：使用 auth.json/凭据存储；环境变量：使用 env_key 指定的变量（未设置会报错）"
-        } else {
-            "OpenAI auth uses auth.json/credential store; env var mode uses env_key (missing env var will error)"
-        }
+        text("codex_auth_mode_help")
     }
 
     pub fn codex_auth_mode_openai() -> &'static str {
-        if is_chinese() {
-            "OpenAI 认证（推荐，无需环境变量）"
-        } else {
-            "OpenAI auth (recommended, no env var)"
-        }
+        text("codex_auth_mode_openai")
     }
 
     pub fn codex_auth_mode_env_var() -> &'static str {
-        if is_chinese() {
-            "环境变量（env_key，需要手动 export）"
-        } else {
-            "Environment variable (env_key, requires export)"
-        }
+        text("codex_auth_mode_env_var")
     }
 
     pub fn codex_official_provider_tip() -> &'static str {
-        if is_chinese() {
-            "提示：官方供应商将使用 Codex 官方登录保存的凭证（codex login 可能会打开浏览器），无需填写 API Key"
-        } else {
-            "Tip: Official provider uses Codex login credentials (`codex login` may open a browser); no API key required"
-        }
+        text("codex_official_provider_tip")
     }
 
     pub fn codex_env_key_info() -> &'static str {
-        if is_chinese() {
-            "⚠ 环境变量模式：Codex 将从指定的环境变量读取 API Key"
-        } else {
-            "⚠ Env var mode: Codex will read the API key from the specified environment variable"
-        }
+        text("codex_env_key_info")
     }
 
     pub fn codex_env_key_label() -> &'static str {
-        if is_chinese() {
-            "环境变量名称："
-        } else {
-            "Environment Variable Name:"
-        }
+        text("codex_env_key_label")
     }
 
     pub fn codex_env_key_help() -> &'static str {
-        if is_chinese() {
-            "Codex 将从此环境变量读取 API 密钥（默认: OPENAI_API_KEY）"
-        } else {
-            "Codex will read API key from this env var (default: OPENAI_API_KEY)"
-        }
+        text("codex_env_key_help")
     }
 
     pub fn codex_wire_api_label() -> &'static str {
-        if is_chinese() {
-            "API 格式："
-        } else {
-            "API Format:"
-        }
+        text("codex_wire_api_label")
     }
 
     pub fn codex_wire_api_help() -> &'static str {
-        if is_chinese() {
-            "chat = Chat Completions API (大多数第三方), responses = OpenAI Responses API"
-        } else {
-            "chat = Chat Completions API (most providers), responses = OpenAI Responses API"
-        }
+        text("codex_wire_api_help")
     }
 
     pub fn codex_env_reminder(env_key: &str) -> String {
@@ -6281,11 +4194,7 @@ pub mod texts {
     }
 
     pub fn codex_openai_auth_info() -> &'static str {
-        if is_chinese() {
-            "✓ OpenAI 认证模式：Codex 将使用 auth.json/系统凭据存储，无需设置 OPENAI_API_KEY 环境变量"
-        } else {
-            "✓ OpenAI auth mode: Codex will use auth.json/credential store; no OPENAI_API_KEY env var required"
-        }
+        text("codex_openai_auth_info")
     }
 
     pub fn codex_dual_write_info(env_key: &str, _api_key: &str) -> String {
@@ -6309,349 +4218,177 @@ pub mod texts {
     }
 
     pub fn use_current_config_prompt() -> &'static str {
-        if is_chinese() {
-            "使用当前配置？"
-        } else {
-            "Use current configuration?"
-        }
+        text("use_current_config_prompt")
     }
 
     pub fn use_current_config_help() -> &'static str {
-        if is_chinese() {
-            "选择 No 将进入自定义输入模式"
-        } else {
-            "Select No to enter custom input mode"
-        }
+        text("use_current_config_help")
     }
 
     pub fn input_toml_config() -> &'static str {
-        if is_chinese() {
-            "输入 TOML 配置（多行，输入空行结束）："
-        } else {
-            "Enter TOML config (multiple lines, empty line to finish):"
-        }
+        text("input_toml_config")
     }
 
     pub fn direct_enter_to_finish() -> &'static str {
-        if is_chinese() {
-            "直接回车结束输入"
-        } else {
-            "Press Enter to finish"
-        }
+        text("direct_enter_to_finish")
     }
 
     pub fn current_config_label() -> &'static str {
-        if is_chinese() {
-            "当前配置："
-        } else {
-            "Current Config:"
-        }
+        text("current_config_label")
     }
 
     pub fn config_toml_header() -> &'static str {
-        if is_chinese() {
-            "Config.toml 配置："
-        } else {
-            "Config.toml Configuration:"
-        }
+        text("config_toml_header")
     }
 
     // Provider Input - Gemini Configuration
     pub fn config_gemini_header() -> &'static str {
-        if is_chinese() {
-            "配置 Gemini 供应商："
-        } else {
-            "Configure Gemini Provider:"
-        }
+        text("config_gemini_header")
     }
 
     pub fn auth_type_label() -> &'static str {
-        if is_chinese() {
-            "认证类型："
-        } else {
-            "Auth Type:"
-        }
+        text("auth_type_label")
     }
 
     pub fn auth_type_api_key() -> &'static str {
-        if is_chinese() {
-            "API Key"
-        } else {
-            "API Key"
-        }
+        text("auth_type_api_key")
     }
 
     pub fn auth_type_service_account() -> &'static str {
-        if is_chinese() {
-            "Service Account (ADC)"
-        } else {
-            "Service Account (ADC)"
-        }
+        text("auth_type_service_account")
     }
 
     pub fn gemini_api_key_label() -> &'static str {
-        if is_chinese() {
-            "Gemini API Key："
-        } else {
-            "Gemini API Key:"
-        }
+        text("gemini_api_key_label")
     }
 
     pub fn gemini_base_url_label() -> &'static str {
-        if is_chinese() {
-            "Base URL："
-        } else {
-            "Base URL:"
-        }
+        text("gemini_base_url_label")
     }
 
     pub fn gemini_base_url_help() -> &'static str {
-        if is_chinese() {
-            "留空使用官方 API"
-        } else {
-            "Leave empty to use official API"
-        }
+        text("gemini_base_url_help")
     }
 
     pub fn gemini_base_url_placeholder() -> &'static str {
-        if is_chinese() {
-            "如 https://generativelanguage.googleapis.com"
-        } else {
-            "e.g., https://generativelanguage.googleapis.com"
-        }
+        text("gemini_base_url_placeholder")
     }
 
     pub fn adc_project_id_label() -> &'static str {
-        if is_chinese() {
-            "GCP Project ID："
-        } else {
-            "GCP Project ID:"
-        }
+        text("adc_project_id_label")
     }
 
     pub fn adc_location_label() -> &'static str {
-        if is_chinese() {
-            "GCP Location："
-        } else {
-            "GCP Location:"
-        }
+        text("adc_location_label")
     }
 
     pub fn adc_location_placeholder() -> &'static str {
-        if is_chinese() {
-            "如 us-central1"
-        } else {
-            "e.g., us-central1"
-        }
+        text("adc_location_placeholder")
     }
 
     pub fn google_oauth_official() -> &'static str {
-        if is_chinese() {
-            "Google OAuth（官方）"
-        } else {
-            "Google OAuth (Official)"
-        }
+        text("google_oauth_official")
     }
 
     pub fn packycode_api_key() -> &'static str {
-        if is_chinese() {
-            "PackyCode API Key"
-        } else {
-            "PackyCode API Key"
-        }
+        text("packycode_api_key")
     }
 
     pub fn generic_api_key() -> &'static str {
-        if is_chinese() {
-            "通用 API Key"
-        } else {
-            "Generic API Key"
-        }
+        text("generic_api_key")
     }
 
     pub fn select_auth_method_help() -> &'static str {
-        if is_chinese() {
-            "选择 Gemini 的认证方式"
-        } else {
-            "Select authentication method for Gemini"
-        }
+        text("select_auth_method_help")
     }
 
     pub fn use_google_oauth_warning() -> &'static str {
-        if is_chinese() {
-            "使用 Google OAuth，将清空 API Key 配置"
-        } else {
-            "Using Google OAuth, API Key config will be cleared"
-        }
+        text("use_google_oauth_warning")
     }
 
     pub fn packycode_api_key_help() -> &'static str {
-        if is_chinese() {
-            "从 PackyCode 获取的 API Key"
-        } else {
-            "API Key obtained from PackyCode"
-        }
+        text("packycode_api_key_help")
     }
 
     pub fn packycode_endpoint_help() -> &'static str {
-        if is_chinese() {
-            "PackyCode API 端点"
-        } else {
-            "PackyCode API endpoint"
-        }
+        text("packycode_endpoint_help")
     }
 
     pub fn generic_api_key_help() -> &'static str {
-        if is_chinese() {
-            "通用的 Gemini API Key"
-        } else {
-            "Generic Gemini API Key"
-        }
+        text("generic_api_key_help")
     }
 
     // Provider Input - Optional Fields
     pub fn notes_label() -> &'static str {
-        if is_chinese() {
-            "备注："
-        } else {
-            "Notes:"
-        }
+        text("notes_label")
     }
 
     pub fn notes_placeholder() -> &'static str {
-        if is_chinese() {
-            "可选的备注信息"
-        } else {
-            "Optional notes"
-        }
+        text("notes_placeholder")
     }
 
     pub fn sort_index_label() -> &'static str {
-        if is_chinese() {
-            "排序索引："
-        } else {
-            "Sort Index:"
-        }
+        text("sort_index_label")
     }
 
     pub fn sort_index_help() -> &'static str {
-        if is_chinese() {
-            "数字越小越靠前，留空使用创建时间排序"
-        } else {
-            "Lower numbers appear first, leave empty to sort by creation time"
-        }
+        text("sort_index_help")
     }
 
     pub fn sort_index_placeholder() -> &'static str {
-        if is_chinese() {
-            "如 1, 2, 3..."
-        } else {
-            "e.g., 1, 2, 3..."
-        }
+        text("sort_index_placeholder")
     }
 
     pub fn invalid_sort_index() -> &'static str {
-        if is_chinese() {
-            "排序索引必须是有效的数字"
-        } else {
-            "Sort index must be a valid number"
-        }
+        text("invalid_sort_index")
     }
 
     pub fn optional_fields_config() -> &'static str {
-        if is_chinese() {
-            "可选字段配置："
-        } else {
-            "Optional Fields Configuration:"
-        }
+        text("optional_fields_config")
     }
 
     pub fn notes_example_placeholder() -> &'static str {
-        if is_chinese() {
-            "自定义供应商，用于测试"
-        } else {
-            "Custom provider for testing"
-        }
+        text("notes_example_placeholder")
     }
 
     pub fn notes_help_edit() -> &'static str {
-        if is_chinese() {
-            "关于此供应商的额外说明，直接回车保持原值"
-        } else {
-            "Additional notes about this provider, press Enter to keep current value"
-        }
+        text("notes_help_edit")
     }
 
     pub fn notes_help_new() -> &'static str {
-        if is_chinese() {
-            "关于此供应商的额外说明，直接回车跳过"
-        } else {
-            "Additional notes about this provider, press Enter to skip"
-        }
+        text("notes_help_new")
     }
 
     pub fn sort_index_help_edit() -> &'static str {
-        if is_chinese() {
-            "数字，用于控制显示顺序，直接回车保持原值"
-        } else {
-            "Number for display order, press Enter to keep current value"
-        }
+        text("sort_index_help_edit")
     }
 
     pub fn sort_index_help_new() -> &'static str {
-        if is_chinese() {
-            "数字，用于控制显示顺序，直接回车跳过"
-        } else {
-            "Number for display order, press Enter to skip"
-        }
+        text("sort_index_help_new")
     }
 
     pub fn invalid_sort_index_number() -> &'static str {
-        if is_chinese() {
-            "排序索引必须是数字"
-        } else {
-            "Sort index must be a number"
-        }
+        text("invalid_sort_index_number")
     }
 
     pub fn provider_config_summary() -> &'static str {
-        if is_chinese() {
-            "=== 供应商配置摘要 ==="
-        } else {
-            "=== Provider Configuration Summary ==="
-        }
+        text("provider_config_summary")
     }
 
     pub fn id_label() -> &'static str {
-        if is_chinese() {
-            "ID"
-        } else {
-            "ID"
-        }
+        text("id_label")
     }
 
     pub fn website_label() -> &'static str {
-        if is_chinese() {
-            "官网"
-        } else {
-            "Website"
-        }
+        text("website_label")
     }
 
     pub fn core_config_label() -> &'static str {
-        if is_chinese() {
-            "核心配置："
-        } else {
-            "Core Configuration:"
-        }
+        text("core_config_label")
     }
 
-    pub fn model_label() -> &'static str {
-        if is_chinese() {
-            "模型"
-        } else {
-            "Model"
-        }
+    pub fn model_label() -> &'static str {
+        text("model_label")
     }
 
     pub fn config_toml_lines(count: usize) -> String {
@@ -6663,51 +4400,27 @@ pub mod texts {
     }
 
     pub fn optional_fields_label() -> &'static str {
-        if is_chinese() {
-            "可选字段："
-        } else {
-            "Optional Fields:"
-        }
+        text("optional_fields_label")
     }
 
     pub fn notes_label_colon() -> &'static str {
-        if is_chinese() {
-            "备注"
-        } else {
-            "Notes"
-        }
+        text("notes_label_colon")
     }
 
     pub fn sort_index_label_colon() -> &'static str {
-        if is_chinese() {
-            "排序索引"
-        } else {
-            "Sort Index"
-        }
+        text("sort_index_label_colon")
     }
 
     pub fn id_label_colon() -> &'static str {
-        if is_chinese() {
-            "ID"
-        } else {
-            "ID"
-        }
+        text("id_label_colon")
     }
 
     pub fn url_label_colon() -> &'static str {
-        if is_chinese() {
-            "网址"
-        } else {
-            "URL"
-        }
+        text("url_label_colon")
     }
 
     pub fn api_url_label_colon() -> &'static str {
-        if is_chinese() {
-            "API 地址"
-        } else {
-            "API URL"
-        }
+        text("api_url_label_colon")
     }
 
     pub fn summary_divider() -> &'static str {
@@ -6716,349 +4429,177 @@ pub mod texts {
 
     // Provider Input - Summary Display
     pub fn basic_info_header() -> &'static str {
-        if is_chinese() {
-            "基本信息"
-        } else {
-            "Basic Info"
-        }
+        text("basic_info_header")
     }
 
     pub fn name_display_label() -> &'static str {
-        if is_chinese() {
-            "名称"
-        } else {
-            "Name"
-        }
+        text("name_display_label")
     }
 
     pub fn app_display_label() -> &'static str {
-        if is_chinese() {
-            "应用"
-        } else {
-            "App"
-        }
+        text("app_display_label")
     }
 
     pub fn notes_display_label() -> &'static str {
-        if is_chinese() {
-            "备注"
-        } else {
-            "Notes"
-        }
+        text("notes_display_label")
     }
 
     pub fn sort_index_display_label() -> &'static str {
-        if is_chinese() {
-            "排序"
-        } else {
-            "Sort Index"
-        }
+        text("sort_index_display_label")
     }
 
     pub fn config_info_header() -> &'static str {
-        if is_chinese() {
-            "配置信息"
-        } else {
-            "Configuration"
-        }
+        text("config_info_header")
     }
 
     pub fn api_key_display_label() -> &'static str {
-        if is_chinese() {
-            "API Key"
-        } else {
-            "API Key"
-        }
+        text("api_key_display_label")
     }
 
     pub fn base_url_display_label() -> &'static str {
-        if is_chinese() {
-            "Base URL"
-        } else {
-            "Base URL"
-        }
+        text("base_url_display_label")
     }
 
     pub fn model_config_header() -> &'static str {
-        if is_chinese() {
-            "模型配置"
-        } else {
-            "Model Configuration"
-        }
+        text("model_config_header")
     }
 
     pub fn default_model_display() -> &'static str {
-        if is_chinese() {
-            "默认"
-        } else {
-            "Default"
-        }
+        text("default_model_display")
     }
 
     pub fn haiku_model_display() -> &'static str {
-        if is_chinese() {
-            "Haiku"
-        } else {
-            "Haiku"
-        }
+        text("haiku_model_display")
     }
 
     pub fn sonnet_model_display() -> &'static str {
-        if is_chinese() {
-            "Sonnet"
-        } else {
-            "Sonnet"
-        }
+        text("sonnet_model_display")
     }
 
     pub fn opus_model_display() -> &'static str {
-        if is_chinese() {
-            "Opus"
-        } else {
-            "Opus"
-        }
+        text("opus_model_display")
     }
 
     pub fn auth_type_display_label() -> &'static str {
-        if is_chinese() {
-            "认证"
-        } else {
-            "Auth Type"
-        }
+        text("auth_type_display_label")
     }
 
     pub fn project_id_display_label() -> &'static str {
-        if is_chinese() {
-            "项目 ID"
-        } else {
-            "Project ID"
-        }
+        text("project_id_display_label")
     }
 
     pub fn location_display_label() -> &'static str {
-        if is_chinese() {
-            "位置"
-        } else {
-            "Location"
-        }
+        text("location_display_label")
     }
 
     // Interactive Provider - Menu Options
     pub fn edit_provider_menu() -> &'static str {
-        if is_chinese() {
-            "➕ 编辑供应商"
-        } else {
-            "➕ Edit Provider"
-        }
+        text("edit_provider_menu")
     }
 
     pub fn no_editable_providers() -> &'static str {
-        if is_chinese() {
-            "没有可编辑的供应商"
-        } else {
-            "No providers available for editing"
-        }
+        text("no_editable_providers")
     }
 
     pub fn select_provider_to_edit() -> &'static str {
-        if is_chinese() {
-            "选择要编辑的供应商："
-        } else {
-            "Select provider to edit:"
-        }
+        text("select_provider_to_edit")
     }
 
     pub fn choose_edit_mode() -> &'static str {
-        if is_chinese() {
-            "选择编辑模式："
-        } else {
-            "Choose edit mode:"
-        }
+        text("choose_edit_mode")
     }
 
     pub fn select_config_file_to_edit() -> &'static str {
-        if is_chinese() {
-            "选择要编辑的配置文件："
-        } else {
-            "Select config file to edit:"
-        }
+        text("select_config_file_to_edit")
     }
 
     pub fn provider_missing_auth_field() -> &'static str {
-        if is_chinese() {
-            "settings_config 中缺少 'auth' 字段"
-        } else {
-            "Missing 'auth' field in settings_config"
-        }
+        text("provider_missing_auth_field")
     }
 
     pub fn provider_missing_or_invalid_config_field() -> &'static str {
-        if is_chinese() {
-            "settings_config 中缺少或无效的 'config' 字段"
-        } else {
-            "Missing or invalid 'config' field in settings_config"
-        }
+        text("provider_missing_or_invalid_config_field")
     }
 
     pub fn edit_mode_interactive() -> &'static str {
-        if is_chinese() {
-            "📝 交互式编辑 (分步提示)"
-        } else {
-            "📝 Interactive editing (step-by-step prompts)"
-        }
+        text("edit_mode_interactive")
     }
 
     pub fn edit_mode_json_editor() -> &'static str {
-        if is_chinese() {
-            "✏️  JSON 编辑 (使用外部编辑器)"
-        } else {
-            "✏️  JSON editing (use external editor)"
-        }
+        text("edit_mode_json_editor")
     }
 
     pub fn cancel() -> &'static str {
-        if is_chinese() {
-            "❌ 取消"
-        } else {
-            "❌ Cancel"
-        }
+        text("cancel")
     }
 
     pub fn opening_external_editor() -> &'static str {
-        if is_chinese() {
-            "正在打开外部编辑器..."
-        } else {
-            "Opening external editor..."
-        }
+        text("opening_external_editor")
     }
 
     pub fn invalid_json_syntax() -> &'static str {
-        if is_chinese() {
-            "无效的 JSON 语法"
-        } else {
-            "Invalid JSON syntax"
-        }
+        text("invalid_json_syntax")
     }
 
     pub fn invalid_provider_structure() -> &'static str {
-        if is_chinese() {
-            "无效的供应商结构"
-        } else {
-            "Invalid provider structure"
-        }
+        text("invalid_provider_structure")
     }
 
     pub fn provider_id_cannot_be_changed() -> &'static str {
-        if is_chinese() {
-            "供应商 ID 不能被修改"
-        } else {
-            "Provider ID cannot be changed"
-        }
+        text("provider_id_cannot_be_changed")
     }
 
     pub fn retry_editing() -> &'static str {
-        if is_chinese() {
-            "是否重新编辑？"
-        } else {
-            "Retry editing?"
-        }
+        text("retry_editing")
     }
 
     pub fn no_changes_detected() -> &'static str {
-        if is_chinese() {
-            "未检测到任何更改"
-        } else {
-            "No changes detected"
-        }
+        text("no_changes_detected")
     }
 
     pub fn provider_summary() -> &'static str {
-        if is_chinese() {
-            "供应商信息摘要"
-        } else {
-            "Provider Summary"
-        }
+        text("provider_summary")
     }
 
     pub fn confirm_save_changes() -> &'static str {
-        if is_chinese() {
-            "确认保存更改？"
-        } else {
-            "Save changes?"
-        }
+        text("confirm_save_changes")
     }
 
     pub fn editor_failed() -> &'static str {
-        if is_chinese() {
-            "编辑器失败"
-        } else {
-            "Editor failed"
-        }
+        text("editor_failed")
     }
 
     pub fn invalid_selection_format() -> &'static str {
-        if is_chinese() {
-            "无效的选择格式"
-        } else {
-            "Invalid selection format"
-        }
+        text("invalid_selection_format")
     }
 
     // Provider Display Labels (for show_current and view_provider_detail)
     pub fn basic_info_section_header() -> &'static str {
-        if is_chinese() {
-            "基本信息 / Basic Info"
-        } else {
-            "Basic Info"
-        }
+        text("basic_info_section_header")
     }
 
     pub fn name_label_with_colon() -> &'static str {
-        if is_chinese() {
-            "名称"
-        } else {
-            "Name"
-        }
+        text("name_label_with_colon")
     }
 
     pub fn app_label_with_colon() -> &'static str {
-        if is_chinese() {
-            "应用"
-        } else {
-            "App"
-        }
+        text("app_label_with_colon")
     }
 
     pub fn api_config_section_header() -> &'static str {
-        if is_chinese() {
-            "API 配置 / API Configuration"
-        } else {
-            "API Configuration"
-        }
+        text("api_config_section_header")
     }
 
     pub fn model_config_section_header() -> &'static str {
-        if is_chinese() {
-            "模型配置 / Model Configuration"
-        } else {
-            "Model Configuration"
-        }
+        text("model_config_section_header")
     }
 
     pub fn main_model_label_with_colon() -> &'static str {
-        if is_chinese() {
-            "主模型"
-        } else {
-            "Main Model"
-        }
+        text("main_model_label_with_colon")
     }
 
     pub fn updated_config_header() -> &'static str {
-        if is_chinese() {
-            "修改后配置："
-        } else {
-            "Updated Configuration:"
-        }
+        text("updated_config_header")
     }
 
     // Provider Add/Edit Messages
@@ -7071,11 +4612,7 @@ pub mod texts {
     }
 
     pub fn edit_fields_instruction() -> &'static str {
-        if is_chinese() {
-            "逐个编辑字段（直接回车保留当前值）：\n"
-        } else {
-            "Edit fields one by one (press Enter to keep current value):\n"
-        }
+        text("edit_fields_instruction")
     }
 
     // ============================================
@@ -7083,35 +4620,19 @@ pub mod texts {
     // ============================================
 
     pub fn mcp_management() -> &'static str {
-        if is_chinese() {
-            "🛠️  MCP 服务器管理"
-        } else {
-            "🛠️  MCP Server Management"
-        }
+        text("mcp_management")
     }
 
     pub fn no_mcp_servers() -> &'static str {
-        if is_chinese() {
-            "未找到 MCP 服务器。"
-        } else {
-            "No MCP servers found."
-        }
+        text("no_mcp_servers")
     }
 
     pub fn sync_all_servers() -> &'static str {
-        if is_chinese() {
-            "🔄 同步所有服务器"
-        } else {
-            "🔄 Sync All Servers"
-        }
+        text("sync_all_servers")
     }
 
     pub fn synced_successfully() -> &'static str {
-        if is_chinese() {
-            "✓ 所有 MCP 服务器同步成功"
-        } else {
-            "✓ All MCP servers synced successfully"
-        }
+        text("synced_successfully")
     }
 
     // ============================================
@@ -7119,43 +4640,23 @@ pub mod texts {
     // ============================================
 
     pub fn prompts_management() -> &'static str {
-        if is_chinese() {
-            "💬 提示词管理"
-        } else {
-            "💬 Prompt Management"
-        }
+        text("prompts_management")
     }
 
     pub fn no_prompts() -> &'static str {
-        if is_chinese() {
-            "未找到提示词预设。"
-        } else {
-            "No prompt presets found."
-        }
+        text("no_prompts")
     }
 
     pub fn switch_active_prompt() -> &'static str {
-        if is_chinese() {
-            "🔄 切换活动提示词"
-        } else {
-            "🔄 Switch Active Prompt"
-        }
+        text("switch_active_prompt")
     }
 
     pub fn no_prompts_available() -> &'static str {
-        if is_chinese() {
-            "没有可用的提示词。"
-        } else {
-            "No prompts available."
-        }
+        text("no_prompts_available")
     }
 
     pub fn select_prompt_to_activate() -> &'static str {
-        if is_chinese() {
-            "选择要激活的提示词："
-        } else {
-            "Select prompt to activate:"
-        }
+        text("select_prompt_to_activate")
     }
 
     pub fn activated_prompt(id: &str) -> String {
@@ -7175,44 +4676,24 @@ pub mod texts {
     }
 
     pub fn prompt_cleared_note() -> &'static str {
-        if is_chinese() {
-            "实时文件已清空"
-        } else {
-            "Live prompt file has been cleared"
-        }
+        text("prompt_cleared_note")
     }
 
     pub fn prompt_synced_note() -> &'static str {
-        if is_chinese() {
-            "注意：提示词已同步到实时配置文件。"
-        } else {
-            "Note: The prompt has been synced to the live configuration file."
-        }
+        text("prompt_synced_note")
     }
 
     // Configuration View
     pub fn current_configuration() -> &'static str {
-        if is_chinese() {
-            "👁️  当前配置"
-        } else {
-            "👁️  Current Configuration"
-        }
+        text("current_configuration")
     }
 
     pub fn provider_label() -> &'static str {
-        if is_chinese() {
-            "供应商："
-        } else {
-            "Provider:"
-        }
+        text("provider_label")
     }
 
     pub fn mcp_servers_label() -> &'static str {
-        if is_chinese() {
-            "MCP 服务器："
-        } else {
-            "MCP Servers:"
-        }
+        text("mcp_servers_label")
     }
 
     pub fn tui_label_mcp_short() -> &'static str {
@@ -7220,116 +4701,60 @@ pub mod texts {
     }
 
     pub fn tui_label_skills() -> &'static str {
-        if is_chinese() {
-            "技能:"
-        } else {
-            "Skills:"
-        }
+        text("tui_label_skills")
     }
 
     pub fn prompts_label() -> &'static str {
-        if is_chinese() {
-            "提示词："
-        } else {
-            "Prompts:"
-        }
+        text("prompts_label")
     }
 
     pub fn total() -> &'static str {
-        if is_chinese() {
-            "总计"
-        } else {
-            "Total"
-        }
+        text("total")
     }
 
     pub fn enabled() -> &'static str {
-        if is_chinese() {
-            "启用"
-        } else {
-            "Enabled"
-        }
+        text("enabled")
     }
 
     pub fn disabled() -> &'static str {
-        if is_chinese() {
-            "禁用"
-        } else {
-            "Disabled"
-        }
+        text("disabled")
     }
 
     pub fn active() -> &'static str {
-        if is_chinese() {
-            "活动"
-        } else {
-            "Active"
-        }
+        text("active")
     }
 
     pub fn none() -> &'static str {
-        if is_chinese() {
-            "无"
-        } else {
-            "None"
-        }
+        text("none")
     }
 
     // Settings
     pub fn settings_title() -> &'static str {
-        if is_chinese() {
-            "⚙️  设置"
-        } else {
-            "⚙️  Settings"
-        }
+        text("settings_title")
     }
 
     pub fn change_language() -> &'static str {
-        if is_chinese() {
-            "🌐 切换语言"
-        } else {
-            "🌐 Change Language"
-        }
+        text("change_language")
     }
 
     pub fn current_language_label() -> &'static str {
-        if is_chinese() {
-            "当前语言"
-        } else {
-            "Current Language"
-        }
+        text("current_language_label")
     }
 
     pub fn select_language() -> &'static str {
-        if is_chinese() {
-            "选择语言："
-        } else {
-            "Select language:"
-        }
+        text("select_language")
     }
 
     pub fn language_changed() -> &'static str {
-        if is_chinese() {
-            "✓ 语言已更改"
-        } else {
-            "✓ Language changed"
-        }
+        text("language_changed")
     }
 
     pub fn skip_claude_onboarding() -> &'static str {
-        if is_chinese() {
-            "🚫 跳过 Claude Code 初次安装确认"
-        } else {
-            "🚫 Skip Claude Code onboarding confirmation"
-        }
+        text("skip_claude_onboarding")
     }
 
     pub fn skip_claude_onboarding_label() -> &'static str {
-        if is_chinese() {
-            "跳过 Claude Code 初次安装确认"
-        } else {
-            "Skip Claude Code onboarding confirmation"
-        }
+        text("skip_claude_onboarding_label")
     }
 
     pub fn skip_claude_onboarding_confirm(enable: bool, path: &str) -> String {
@@ -7373,19 +4798,11 @@ pub mod texts {
     }
 
     pub fn enable_claude_plugin_integration() -> &'static str {
-        if is_chinese() {
-            "🔌 接管 Claude Code for VSCode 插件"
-        } else {
-            "🔌 Apply to Claude Code for VSCode"
-        }
+        text("enable_claude_plugin_integration")
     }
 
     pub fn enable_claude_plugin_integration_label() -> &'static str {
-        if is_chinese() {
-            "接管 Claude Code for VSCode 插件"
-        } else {
-            "Apply to Claude Code for VSCode"
-        }
+        text("enable_claude_plugin_integration_label")
     }
 
     pub fn enable_claude_plugin_integration_confirm(enable: bool, path: &str) -> String {
@@ -7436,11 +4853,7 @@ pub mod texts {
 
     // App Selection
     pub fn select_application() -> &'static str {
-        if is_chinese() {
-            "选择应用程序："
-        } else {
-            "Select application:"
-        }
+        text("select_application")
     }
 
     pub fn switched_to_app(app: &str) -> String {
@@ -7453,117 +4866,61 @@ pub mod texts {
 
     // Common
     pub fn press_enter() -> &'static str {
-        if is_chinese() {
-            "按 Enter 继续..."
-        } else {
-            "Press Enter to continue..."
-        }
+        text("press_enter")
     }
 
     pub fn error_prefix() -> &'static str {
-        if is_chinese() {
-            "错误"
-        } else {
-            "Error"
-        }
+        text("error_prefix")
     }
 
     // Table Headers
     pub fn header_name() -> &'static str {
-        if is_chinese() {
-            "名称"
-        } else {
-            "Name"
-        }
+        text("header_name")
     }
 
     pub fn header_category() -> &'static str {
-        if is_chinese() {
-            "类别"
-        } else {
-            "Category"
-        }
+        text("header_category")
     }
 
     pub fn header_description() -> &'static str {
-        if is_chinese() {
-            "描述"
-        } else {
-            "Description"
-        }
+        text("header_description")
     }
 
     // Config Management
     pub fn config_management() -> &'static str {
-        if is_chinese() {
-            "⚙️  配置文件管理"
-        } else {
-            "⚙️  Configuration Management"
-        }
+        text("config_management")
     }
 
     pub fn config_export() -> &'static str {
-        if is_chinese() {
-            "📤 导出配置"
-        } else {
-            "📤 Export Config"
-        }
+        text("config_export")
     }
 
     pub fn config_import() -> &'static str {
-        if is_chinese() {
-            "📥 导入配置"
-        } else {
-            "📥 Import Config"
-        }
+        text("config_import")
     }
 
     pub fn config_backup() -> &'static str {
-        if is_chinese() {
-            "💾 备份配置"
-        } else {
-            "💾 Backup Config"
-        }
+        text("config_backup")
     }
 
     pub fn config_restore() -> &'static str {
-        if is_chinese() {
-            "♻️  恢复配置"
-        } else {
-            "♻️  Restore Config"
-        }
+        text("config_restore")
     }
 
     pub fn config_validate() -> &'static str {
-        if is_chinese() {
-            "✓ 验证配置"
-        } else {
-            "✓ Validate Config"
-        }
+        text("config_validate")
     }
 
     pub fn config_common_snippet() -> &'static str {
-        if is_chinese() {
-            "🧩 通用配置片段"
-        } else {
-            "🧩 Common Config Snippet"
-        }
+        text("config_common_snippet")
     }
 
     pub fn config_common_snippet_title() -> &'static str {
-        if is_chinese() {
-            "通用配置片段"
-        } else {
-            "Common Config Snippet"
-        }
+        text("config_common_snippet_title")
     }
 
     pub fn config_common_snippet_none_set() -> &'static str {
-        if is_chinese() {
-            "未设置通用配置片段。"
-        } else {
-            "No common config snippet is set."
-        }
+        text("config_common_snippet_none_set")
     }
 
     pub fn config_common_snippet_set_for_app(app: &str) -> String {
@@ -7575,75 +4932,39 @@ pub mod texts {
     }
 
     pub fn config_common_snippet_require_json_or_file() -> &'static str {
-        if is_chinese() {
-            "请提供 --json 或 --file"
-        } else {
-            "Please provide --json or --file"
-        }
+        text("config_common_snippet_require_json_or_file")
     }
 
     pub fn config_reset() -> &'static str {
-        if is_chinese() {
-            "🔄 重置配置"
-        } else {
-            "🔄 Reset Config"
-        }
+        text("config_reset")
     }
 
     pub fn config_show_full() -> &'static str {
-        if is_chinese() {
-            "👁️  查看完整配置"
-        } else {
-            "👁️  Show Full Config"
-        }
+        text("config_show_full")
     }
 
     pub fn config_show_path() -> &'static str {
-        if is_chinese() {
-            "📍 显示配置路径"
-        } else {
-            "📍 Show Config Path"
-        }
+        text("config_show_path")
     }
 
     pub fn enter_export_path() -> &'static str {
-        if is_chinese() {
-            "输入导出文件路径："
-        } else {
-            "Enter export file path:"
-        }
+        text("enter_export_path")
     }
 
     pub fn enter_import_path() -> &'static str {
-        if is_chinese() {
-            "输入导入文件路径："
-        } else {
-            "Enter import file path:"
-        }
+        text("enter_import_path")
     }
 
     pub fn enter_restore_path() -> &'static str {
-        if is_chinese() {
-            "输入备份文件路径："
-        } else {
-            "Enter backup file path:"
-        }
+        text("enter_restore_path")
     }
 
     pub fn confirm_import() -> &'static str {
-        if is_chinese() {
-            "确定要导入配置吗？这将覆盖当前配置。"
-        } else {
-            "Are you sure you want to import? This will overwrite current configuration."
-        }
+        text("confirm_import")
     }
 
     pub fn confirm_reset() -> &'static str {
-        if is_chinese() {
-            "确定要重置配置吗？这将删除所有自定义设置。"
-        } else {
-            "Are you sure you want to reset? This will delete all custom settings."
-        }
+        text("confirm_reset")
     }
 
     pub fn common_config_snippet_editor_prompt(app: &str) -> String {
@@ -7688,83 +5009,43 @@ pub mod texts {
     }
 
     pub fn common_config_snippet_not_object() -> &'static str {
-        if is_chinese() {
-            "通用配置必须是 JSON 对象（例如：{\"env\":{...}}）"
-        } else {
-            "Common config must be a JSON object (e.g. {\"env\":{...}})"
-        }
+        text("common_config_snippet_not_object")
     }
 
     pub fn common_config_snippet_saved() -> &'static str {
-        if is_chinese() {
-            "✓ 已保存通用配置片段"
-        } else {
-            "✓ Common config snippet saved"
-        }
+        text("common_config_snippet_saved")
     }
 
     pub fn common_config_snippet_cleared() -> &'static str {
-        if is_chinese() {
-            "✓ 已清除通用配置片段"
-        } else {
-            "✓ Common config snippet cleared"
-        }
+        text("common_config_snippet_cleared")
     }
 
     pub fn common_config_snippet_apply_now() -> &'static str {
-        if is_chinese() {
-            "现在应用到当前供应商（写入 live 配置）？"
-        } else {
-            "Apply to current provider now (write live config)?"
-        }
+        text("common_config_snippet_apply_now")
     }
 
     pub fn common_config_snippet_no_current_provider() -> &'static str {
-        if is_chinese() {
-            "当前未选择供应商，已保存通用配置片段。"
-        } else {
-            "No current provider selected; common config snippet saved."
-        }
+        text("common_config_snippet_no_current_provider")
     }
 
     pub fn common_config_snippet_no_current_provider_after_clear() -> &'static str {
-        if is_chinese() {
-            "当前未选择供应商，已清除通用配置片段。"
-        } else {
-            "No current provider selected; common config snippet cleared."
-        }
+        text("common_config_snippet_no_current_provider_after_clear")
     }
 
     pub fn common_config_snippet_applied() -> &'static str {
-        if is_chinese() {
-            "✓ 已应用到 live 配置（请重启对应客户端）"
-        } else {
-            "✓ Applied to live config (restart the client)"
-        }
+        text("common_config_snippet_applied")
     }
 
     pub fn common_config_snippet_apply_hint() -> &'static str {
-        if is_chinese() {
-            "提示：切换一次供应商即可重新写入 live 配置。"
-        } else {
-            "Tip: switch provider once to re-write the live config."
-        }
+        text("common_config_snippet_apply_hint")
     }
 
     pub fn common_config_snippet_apply_not_needed() -> &'static str {
-        if is_chinese() {
-            "当前配置已是最新，无需重新应用。"
-        } else {
-            "The current live config is already up to date; nothing to apply."
-        }
+        text("common_config_snippet_apply_not_needed")
     }
 
     pub fn confirm_restore() -> &'static str {
-        if is_chinese() {
-            "确定要从备份恢复配置吗？"
-        } else {
-            "Are you sure you want to restore from backup?"
-        }
+        text("confirm_restore")
     }
 
     pub fn exported_to(path: &str) -> String {
@@ -7800,19 +5081,11 @@ pub mod texts {
     }
 
     pub fn config_valid() -> &'static str {
-        if is_chinese() {
-            "✓ 配置文件有效"
-        } else {
-            "✓ Configuration is valid"
-        }
+        text("config_valid")
     }
 
     pub fn config_reset_done() -> &'static str {
-        if is_chinese() {
-            "✓ 配置已重置为默认值"
-        } else {
-            "✓ Configuration reset to defaults"
-        }
+        text("config_reset_done")
     }
 
     pub fn file_overwrite_confirm(path: &str) -> String {
@@ -7825,91 +5098,47 @@ pub mod texts {
 
     // MCP Management Additional
     pub fn mcp_delete_server() -> &'static str {
-        if is_chinese() {
-            "🗑️  删除服务器"
-        } else {
-            "🗑️  Delete Server"
-        }
+        text("mcp_delete_server")
     }
 
     pub fn mcp_enable_server() -> &'static str {
-        if is_chinese() {
-            "✅ 启用服务器"
-        } else {
-            "✅ Enable Server"
-        }
+        text("mcp_enable_server")
     }
 
     pub fn mcp_disable_server() -> &'static str {
-        if is_chinese() {
-            "❌ 禁用服务器"
-        } else {
-            "❌ Disable Server"
-        }
+        text("mcp_disable_server")
     }
 
     pub fn mcp_import_servers() -> &'static str {
-        if is_chinese() {
-            "📥 导入已有 MCP 服务器"
-        } else {
-            "📥 Import Existing MCP Servers"
-        }
+        text("mcp_import_servers")
     }
 
     pub fn mcp_validate_command() -> &'static str {
-        if is_chinese() {
-            "✓ 验证命令"
-        } else {
-            "✓ Validate Command"
-        }
+        text("mcp_validate_command")
     }
 
     pub fn select_server_to_delete() -> &'static str {
-        if is_chinese() {
-            "选择要删除的服务器："
-        } else {
-            "Select server to delete:"
-        }
+        text("select_server_to_delete")
     }
 
     pub fn select_server_to_enable() -> &'static str {
-        if is_chinese() {
-            "选择要启用的服务器："
-        } else {
-            "Select server to enable:"
-        }
+        text("select_server_to_enable")
     }
 
     pub fn select_server_to_disable() -> &'static str {
-        if is_chinese() {
-            "选择要禁用的服务器："
-        } else {
-            "Select server to disable:"
-        }
+        text("select_server_to_disable")
     }
 
     pub fn select_apps_to_enable() -> &'static str {
-        if is_chinese() {
-            "选择要启用的应用："
-        } else {
-            "Select apps to enable for:"
-        }
+        text("select_apps_to_enable")
     }
 
     pub fn select_apps_to_disable() -> &'static str {
-        if is_chinese() {
-            "选择要禁用的应用："
-        } else {
-            "Select apps to disable for:"
-        }
+        text("select_apps_to_disable")
     }
 
     pub fn enter_command_to_validate() -> &'static str {
-        if is_chinese() {
-            "输入要验证的命令："
-        } else {
-            "Enter command to validate:"
-        }
+        text("enter_command_to_validate")
     }
 
     pub fn server_deleted(id: &str) -> String {
@@ -7962,43 +5191,23 @@ pub mod texts {
 
     // Prompts Management Additional
     pub fn prompts_show_content() -> &'static str {
-        if is_chinese() {
-            "👁️  查看完整内容"
-        } else {
-            "👁️  View Full Content"
-        }
+        text("prompts_show_content")
     }
 
     pub fn prompts_delete() -> &'static str {
-        if is_chinese() {
-            "🗑️  删除提示词"
-        } else {
-            "🗑️  Delete Prompt"
-        }
+        text("prompts_delete")
     }
 
     pub fn prompts_view_current() -> &'static str {
-        if is_chinese() {
-            "📋 查看当前提示词"
-        } else {
-            "📋 View Current Prompt"
-        }
+        text("prompts_view_current")
     }
 
     pub fn select_prompt_to_view() -> &'static str {
-        if is_chinese() {
-            "选择要查看的提示词："
-        } else {
-            "Select prompt to view:"
-        }
+        text("select_prompt_to_view")
     }
 
     pub fn select_prompt_to_delete() -> &'static str {
-        if is_chinese() {
-            "选择要删除的提示词："
-        } else {
-            "Select prompt to delete:"
-        }
+        text("select_prompt_to_delete")
     }
 
     pub fn prompt_deleted(id: &str) -> String {
@@ -8010,52 +5219,28 @@ pub mod texts {
     }
 
     pub fn no_active_prompt() -> &'static str {
-        if is_chinese() {
-            "当前没有激活的提示词。"
-        } else {
-            "No active prompt."
-        }
+        text("no_active_prompt")
     }
 
     pub fn cannot_delete_active() -> &'static str {
-        if is_chinese() {
-            "无法删除当前激活的提示词。"
-        } else {
-            "Cannot delete the active prompt."
-        }
+        text("cannot_delete_active")
     }
 
     pub fn no_servers_to_delete() -> &'static str {
-        if is_chinese() {
-            "没有可删除的服务器。"
-        } else {
-            "No servers to delete."
-        }
+        text("no_servers_to_delete")
     }
 
     pub fn no_prompts_to_delete() -> &'static str {
-        if is_chinese() {
-            "没有可删除的提示词。"
-        } else {
-            "No prompts to delete."
-        }
+        text("no_prompts_to_delete")
     }
 
     // Provider Speedtest
     pub fn speedtest_endpoint() -> &'static str {
-        if is_chinese() {
-            "🚀 测试端点速度"
-        } else {
-            "🚀 Speedtest endpoint"
-        }
+        text("speedtest_endpoint")
     }
 
     pub fn back() -> &'static str {
-        if is_chinese() {
-            "← 返回"
-        } else {
-            "← Back"
-        }
+        text("back")
     }
 
     // ============================================
@@ -8063,43 +5248,23 @@ pub mod texts {
     // ============================================
 
     pub fn tui_settings_check_for_updates() -> &'static str {
-        if is_chinese() {
-            "检查更新"
-        } else {
-            "Check for Updates"
-        }
+        text("tui_settings_check_for_updates")
     }
 
     pub fn tui_update_checking_title() -> &'static str {
-        if is_chinese() {
-            "检查更新中"
-        } else {
-            "Checking for Updates"
-        }
+        text("tui_update_checking_title")
     }
 
     pub fn tui_update_available_title() -> &'static str {
-        if is_chinese() {
-            "发现新版本"
-        } else {
-            "Update Available"
-        }
+        text("tui_update_available_title")
     }
 
     pub fn tui_update_downloading_title() -> &'static str {
-        if is_chinese() {
-            "正在更新"
-        } else {
-            "Updating"
-        }
+        text("tui_update_downloading_title")
     }
 
     pub fn tui_update_result_title() -> &'static str {
-        if is_chinese() {
-            "更新结果"
-        } else {
-            "Update Result"
-        }
+        text("tui_update_result_title")
     }
 
     pub fn tui_update_version_info(current: &str, new: &str) -> String {
@@ -8111,19 +5276,11 @@ pub mod texts {
     }
 
     pub fn tui_update_btn_update() -> &'static str {
-        if is_chinese() {
-            "更新"
-        } else {
-            "Update"
-        }
+        text("tui_update_btn_update")
     }
 
     pub fn tui_update_btn_cancel() -> &'static str {
-        if is_chinese() {
-            "取消"
-        } else {
-            "Cancel"
-        }
+        text("tui_update_btn_cancel")
     }
 
     pub fn tui_update_downloading_kb(kb: u64) -> String {
@@ -8151,19 +5308,11 @@ pub mod texts {
     }
 
     pub fn tui_update_err_worker_unavailable() -> &'static str {
-        if is_chinese() {
-            "更新服务不可用"
-        } else {
-            "Update worker unavailable"
-        }
+        text("tui_update_err_worker_unavailable")
     }
 
     pub fn tui_update_err_check_first() -> &'static str {
-        if is_chinese() {
-            "请先检查更新"
-        } else {
-            "Please check for updates first"
-        }
+        text("tui_update_err_check_first")
     }
 
     pub fn tui_toast_already_latest(v: &str) -> String {
@@ -8191,11 +5340,7 @@ pub mod texts {
     }
 
     pub fn tui_key_hide() -> &'static str {
-        if is_chinese() {
-            "隐藏"
-        } else {
-            "hide"
-        }
+        text("tui_key_hide")
     }
 
     pub fn tui_toast_update_bg_success(tag: &str) -> String {
@@ -8215,43 +5360,23 @@ pub mod texts {
     }
 
     pub fn tui_provider_imported_live_config_name() -> &'static str {
-        if is_chinese() {
-            "导入的 Live 配置"
-        } else {
-            "Imported Live Config"
-        }
+        text("tui_provider_imported_live_config_name")
     }
 
     pub fn tui_codex_imported_live_config_name() -> &'static str {
-        if is_chinese() {
-            "导入的 Codex Live 配置"
-        } else {
-            "Imported Codex Live Config"
-        }
+        text("tui_codex_imported_live_config_name")
     }
 
     pub fn tui_toast_provider_live_config_imported() -> &'static str {
-        if is_chinese() {
-            "已将当前 live 配置导入为供应商"
-        } else {
-            "Imported the current live config as a provider"
-        }
+        text("tui_toast_provider_live_config_imported")
     }
 
     pub fn tui_toast_codex_live_config_imported() -> &'static str {
-        if is_chinese() {
-            "已将当前 Codex live 配置导入为供应商"
-        } else {
-            "Imported the current Codex live config as a provider"
-        }
+        text("tui_toast_codex_live_config_imported")
     }
 
     pub fn tui_provider_switch_first_use_title() -> &'static str {
-        if is_chinese() {
-            "检测到现有 live 配置"
-        } else {
-            "Existing live config detected"
-        }
+        text("tui_provider_switch_first_use_title")
     }
 
     pub fn tui_provider_switch_first_use_message(path: &str) -> String {
@@ -8263,11 +5388,7 @@ pub mod texts {
     }
 
     pub fn tui_codex_provider_switch_first_use_title() -> &'static str {
-        if is_chinese() {
-            "检测到现有 Codex live 配置"
-        } else {
-            "Existing Codex live config detected"
-        }
+        text("tui_codex_provider_switch_first_use_title")
     }
 
     pub fn tui_codex_provider_switch_first_use_message(paths: &str) -> String {
@@ -8279,35 +5400,19 @@ pub mod texts {
     }
 
     pub fn tui_provider_switch_first_use_import_button() -> &'static str {
-        if is_chinese() {
-            "导入当前配置"
-        } else {
-            "Import Current Config"
-        }
+        text("tui_provider_switch_first_use_import_button")
     }
 
     pub fn tui_provider_switch_first_use_continue_button() -> &'static str {
-        if is_chinese() {
-            "继续切换"
-        } else {
-            "Continue Switching"
-        }
+        text("tui_provider_switch_first_use_continue_button")
     }
 
     pub fn tui_provider_switch_first_use_cancel_button() -> &'static str {
-        if is_chinese() {
-            "取消"
-        } else {
-            "Cancel"
-        }
+        text("tui_provider_switch_first_use_cancel_button")
     }
 
     pub fn tui_provider_switch_shared_config_tip_title() -> &'static str {
-        if is_chinese() {
-            "已切换供应商"
-        } else {
-            "Provider Switched"
-        }
+        text("tui_provider_switch_shared_config_tip_title")
     }
 
     pub fn tui_provider_switch_shared_config_tip_message() -> String {
@@ -8333,10 +5438,97 @@ pub mod texts {
 
 #[cfg(test)]
 mod tests {
-    use super::{texts, use_test_language, Language};
+    use super::{
+        format_bytes, missing_translations, sort_entries, table, texts, use_test_language, Language,
+    };
+    use std::collections::HashSet;
     use std::sync::mpsc;
     use std::thread;
 
+    #[test]
+    fn no_text_key_is_missing_a_translation() {
+        assert_eq!(missing_translations(Language::Chinese), Vec::<&str>::new());
+        assert_eq!(missing_translations(Language::English), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn text_keys_are_unique() {
+        let mut seen = HashSet::new();
+        for (key, ..) in table::TEXTS {
+            assert!(seen.insert(*key), "duplicate text key: {key}");
+        }
+    }
+
+    #[test]
+    fn text_wrappers_follow_current_language() {
+        {
+            let _lang = use_test_language(Language::Chinese);
+            assert_eq!(texts::tui_update_checking_title(), "检查更新中");
+        }
+        let _lang = use_test_language(Language::English);
+        assert_eq!(texts::tui_update_checking_title(), "Checking for Updates");
+    }
+
+    #[test]
+    fn from_code_accepts_environment_locales() {
+        assert_eq!(Language::from_code("zh_CN.UTF-8"), Language::Chinese);
+        assert_eq!(Language::from_code("zh_TW"), Language::Chinese);
+        assert_eq!(Language::from_code("ZH-cn"), Language::Chinese);
+        assert_eq!(Language::from_code("en_US"), Language::English);
+        assert_eq!(Language::from_code("en_US.UTF-8"), Language::English);
+    }
+
+    #[test]
+    fn from_code_defaults_unknown_locales_to_english() {
+        assert_eq!(Language::from_code("fr_FR.UTF-8"), Language::English);
+        assert_eq!(Language::from_code("C"), Language::English);
+        assert_eq!(Language::from_code(""), Language::English);
+    }
+
+    #[test]
+    fn format_bytes_switches_units_at_boundaries() {
+        assert_eq!(format_bytes(0, Language::English), "0 B");
+        assert_eq!(format_bytes(1023, Language::English), "1023 B");
+        assert_eq!(format_bytes(1023, Language::Chinese), "1023 字节");
+        assert_eq!(format_bytes(1024, Language::English), "1 KB");
+        assert_eq!(format_bytes(1536 * 1024, Language::English), "1.5 MB");
+        assert_eq!(format_bytes(1536 * 1024, Language::Chinese), "1.5 MB");
+        assert_eq!(format_bytes(512 * 1024 * 1024, Language::English), "512 MB");
+        assert_eq!(
+            format_bytes(3 * 1024 * 1024 * 1024 / 2, Language::English),
+            "1.5 GB"
+        );
+    }
+
+    #[test]
+    fn format_bytes_rounds_to_one_decimal_and_promotes_units() {
+        assert_eq!(format_bytes(1075, Language::English), "1 KB");
+        assert_eq!(format_bytes(1127, Language::English), "1.1 KB");
+        // 1023.99 KB 四舍五入后应进位为 1 MB，而不是 "1024 KB"
+        assert_eq!(format_bytes(1024 * 1024 - 1, Language::English), "1 MB");
+        assert_eq!(format_bytes(u64::MAX, Language::English), "16777216 TB");
+    }
+
+    #[test]
+    fn sort_entries_is_case_insensitive_in_english() {
+        let _lang = use_test_language(Language::English);
+        let mut names = vec!["banana", "Apple", "cherry", "apple pie", "Banana"];
+        sort_entries(&mut names, |name| name);
+        assert_eq!(names, ["Apple", "apple pie", "Banana", "banana", "cherry"]);
+    }
+
+    #[test]
+    fn sort_entries_orders_chinese_by_pinyin_not_codepoint() {
+        let _lang = use_test_language(Language::Chinese);
+        let mut names = vec!["智谱", "百度", "阿里云", "Kimi", "腾讯"];
+        let mut by_codepoint = names.clone();
+        by_codepoint.sort();
+
+        sort_entries(&mut names, |name| name);
+        assert_eq!(names, ["阿里云", "百度", "Kimi", "腾讯", "智谱"]);
+        assert_ne!(names, by_codepoint);
+    }
+
     #[test]
     fn website_url_label_keeps_optional_with_abbrev() {
         let label = texts::website_url_label();
@@ -0,0 +1,800 @@
+//! 静态文案表。
+//!
+//! 每项为 `(key, en, zh)`，key 与 `texts` 中同名的无参函数一致；这些函数只是
+//! 对 [`text`] 的薄封装。新增语言时在表中补一列，并用 [`missing_translations`]
+//! 检查遗漏。带参数的文案仍保留为 `texts` 中的函数。
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use super::{current_language, Language};
+
+type TextEntry = (&'static str, &'static str, &'static str);
+
+pub(super) const TEXTS: &[TextEntry] = &[
+    ("entity_provider", "provider", "供应商"),
+    ("entity_server", "server", "服务器"),
+    ("entity_prompt", "prompt", "提示词"),
+    ("welcome_title", "    🎯 CC-Switch Interactive Mode", "    🎯 CC-Switch 交互模式"),
+    ("application", "Application", "应用程序"),
+    ("goodbye", "👋 Goodbye!", "👋 再见！"),
+    ("interactive_requires_tty", "Interactive mode requires a TTY (do not run with pipes/redirection).", "交互模式需要在 TTY 终端中运行（请不要通过管道/重定向调用）。"),
+    ("interactive_legacy_tui_removed", "The legacy TUI has been removed. Please use the default interactive TUI instead.", "旧版 legacy TUI 已移除，请直接使用当前默认的交互 TUI。"),
+    ("tui_tabs_title", "App", "App"),
+    ("tui_hint_app_switch", "Switch App:", "切换 App:"),
+    ("tui_toast_json_must_be_object", "JSON must be an object (e.g. {\"env\":{...}})", "JSON 必须是对象（例如：{\"env\":{...}}）"),
+    ("tui_nav_title", "Menu", "菜单"),
+    ("tui_filter_title", "Filter", "过滤"),
+    ("tui_footer_global", "[ ] switch app  ←→ focus menu/content  ↑↓ move  Enter details  s switch  / filter  Esc back  ? help", "[ ] 切换应用  ←→ 切换菜单/内容  ↑↓ 移动  Enter 详情  s 切换  / 过滤  Esc 返回  ? 帮助"),
+    ("tui_footer_group_nav", "NAV", "导航"),
+    ("tui_footer_group_actions", "ACT", "功能"),
+    ("tui_footer_nav_keys", "←→ menu/content  ↑↓ move", "←→ 菜单/内容  ↑↓ 移动"),
+    ("tui_footer_action_keys", "[ ] switch app  Enter details  s switch  / filter  Esc back  ? help", "[ ] 切换应用  Enter 详情  s 切换  / 过滤  Esc 返回  ? 帮助"),
+    ("tui_footer_action_keys_main", "[ ] switch app  / filter  Esc back  ? help", "[ ] 切换应用  / 过滤  Esc 返回  ? 帮助"),
+    ("tui_footer_action_keys_providers", "[ ] switch app  Enter details  s switch  a add  e edit  d delete  t speedtest  c stream check  / filter  Esc back  ? help", "[ ] 切换应用  Enter 详情  s 切换  a 添加  e 编辑  d 删除  t 测速  c 健康检查  / 过滤  Esc 返回  ? 帮助"),
+    ("tui_footer_action_keys_provider_detail", "[ ] switch app  s switch  e edit  t speedtest  c stream check  / filter  Esc back  ? help", "[ ] 切换应用  s 切换  e 编辑  t 测速  c 健康检查  / 过滤  Esc 返回  ? 帮助"),
+    ("tui_footer_action_keys_mcp", "[ ] switch app  x toggle  m apps  a add  e edit  i import  d delete  / filter  Esc back  ? help", "[ ] 切换应用  x 启用/禁用  m 应用  a 添加  e 编辑  i 导入  d 删除  / 过滤  Esc 返回  ? 帮助"),
+    ("tui_footer_action_keys_prompts", "[ ] switch app  Enter view  a activate  x deactivate  e edit  d delete  / filter  Esc back  ? help", "[ ] 切换应用  Enter 查看  a 激活  x 取消激活  e 编辑  d 删除  / 过滤  Esc 返回  ? 帮助"),
+    ("tui_footer_action_keys_config", "[ ] switch app  Enter open  e edit snippet  / filter  Esc back  ? help", "[ ] 切换应用  Enter 打开  e 编辑片段  / 过滤  Esc 返回  ? 帮助"),
+    ("tui_footer_action_keys_common_snippet_view", "a apply  c clear  e edit  ↑↓ scroll  Esc back", "a 应用  c 清空  e 编辑  ↑↓ 滚动  Esc 返回"),
+    ("tui_footer_action_keys_settings", "[ ] switch app  Enter apply  / filter  Esc back  ? help", "[ ] 切换应用  Enter 应用  / 过滤  Esc 返回  ? 帮助"),
+    ("tui_footer_action_keys_global", "[ ] switch app  / filter  Esc back  ? help", "[ ] 切换应用  / 过滤  Esc 返回  ? 帮助"),
+    ("tui_footer_filter_mode", "Type to filter, Enter apply, Esc clear & exit", "输入关键字过滤，Enter 应用，Esc 清空并退出"),
+    ("tui_help_title", "Help", "帮助"),
+    ("tui_help_text", "[ ]  switch app\n←→  focus menu/content\n↑↓  move\n/   filter\nEsc  back\n?   toggle help\n\nPage keys (shown at the top of each page):\n- Providers: Enter details, s switch, a add, e edit, d delete, t speedtest, c stream check\n- Provider Detail: s switch, e edit, t speedtest, c stream check\n- MCP: x toggle current, m select apps, a add, e edit, i import existing, d delete\n- Prompts: Enter view, a activate, x deactivate active, e edit, d delete\n- Skills: Enter details, x toggle current, m select apps, d uninstall, i import existing\n- Config: Enter open/run, e edit snippet\n- Settings: Enter apply", "[ ]  切换应用\n←→  切换菜单/内容焦点\n↑↓  移动\n/   过滤\nEsc  返回\n?   显示/关闭帮助\n\n页面快捷键（在页面内容区顶部显示）：\n- 供应商：Enter 详情，s 切换，a 添加，e 编辑，d 删除，t 测速，c 健康检查\n- 供应商详情：s 切换，e 编辑，t 测速，c 健康检查\n- MCP：x 启用/禁用(当前应用)，m 选择应用，a 添加，e 编辑，i 导入已有，d 删除\n- 提示词：Enter 查看，a 激活，x 取消激活(当前)，e 编辑，d 删除\n- 技能：Enter 详情，x 启用/禁用(当前应用)，m 选择应用，d 卸载，i 导入已有\n- 配置：Enter 打开/执行，e 编辑片段\n- 设置：Enter 应用"),
+    ("tui_confirm_title", "Confirm", "确认"),
+    ("tui_confirm_exit_title", "Exit", "退出"),
+    ("tui_confirm_exit_message", "Exit cc-switch?", "确定退出 cc-switch？"),
+    ("tui_confirm_yes_hint", "y/Enter = Yes", "y/Enter = 是"),
+    ("tui_confirm_no_hint", "n/Esc   = No", "n/Esc   = 否"),
+    ("tui_input_title", "Input", "输入"),
+    ("tui_editor_text_field_title", "Text", "文本"),
+    ("tui_editor_hint_view", "Enter edit  ↑↓ scroll  Ctrl+S save  Esc back", "Enter 编辑  ↑↓ 滚动  Ctrl+S 保存  Esc 返回"),
+    ("tui_editor_hint_edit", "Editing: Esc stop editing  Ctrl+S save", "编辑中：Esc 退出编辑  Ctrl+S 保存"),
+    ("tui_editor_discard_title", "Discard Changes", "放弃修改"),
+    ("tui_editor_discard_message", "You have unsaved changes. Discard them?", "有未保存的修改，确定放弃？"),
+    ("tui_editor_save_before_close_title", "Unsaved Changes", "当前未保存"),
+    ("tui_editor_save_before_close_message", "You have unsaved changes.", "当前有未保存的修改。"),
+    ("tui_speedtest_title", "Speedtest", "测速"),
+    ("tui_stream_check_title", "Stream Check", "健康检查"),
+    ("tui_main_hint", "Use the left menu (↑↓ + Enter). ←→ switches focus between menu and content.", "使用左侧菜单（↑↓ + Enter）。←→ 在菜单与内容间切换焦点。"),
+    ("tui_home_section_connection", "Connection Details", "连接信息"),
+    ("tui_home_section_proxy", "Proxy Dashboard", "代理仪表盘"),
+    ("tui_home_section_context", "Session Context", "Session Context"),
+    ("tui_home_section_local_env_check", "Local environment check", "本地环境检查"),
+    ("tui_home_section_webdav", "WebDAV Sync", "WebDAV 同步"),
+    ("tui_label_webdav_status", "Status", "状态"),
+    ("tui_label_webdav_last_sync", "Last sync", "最近同步"),
+    ("tui_webdav_status_not_configured", "Not configured", "未配置"),
+    ("tui_webdav_status_configured", "Configured", "已配置"),
+    ("tui_webdav_status_never_synced", "Never synced", "从未同步"),
+    ("tui_webdav_status_ok", "OK", "正常"),
+    ("tui_webdav_status_error", "Error", "失败"),
+    ("tui_local_env_not_installed", "not installed or not executable", "未安装或不可执行"),
+    ("tui_home_status_online", "Online", "在线"),
+    ("tui_home_status_offline", "Offline", "离线"),
+    ("tui_proxy_dashboard_status_running", "ACTIVE", "已启用"),
+    ("tui_proxy_dashboard_status_stopped", "LOCAL", "本地"),
+    ("tui_proxy_dashboard_status_local_only", "LOCAL ONLY", "仅本地"),
+    ("tui_proxy_dashboard_status_unsupported", "UNSUPPORTED", "不支持"),
+    ("tui_proxy_dashboard_failover_copy", "automatic failover stays off; provider changes stay manual.", "仅做手动路由，不会自动切换供应商。"),
+    ("tui_proxy_loading_title_start", "Starting proxy", "启动代理中"),
+    ("tui_proxy_loading_title_stop", "Stopping proxy", "停止代理中"),
+    ("tui_proxy_dashboard_running_elsewhere", "Proxy is already running. Stop the current route before starting it here.", "代理已在运行。请先停止当前路由，再从这里启动。"),
+    ("tui_proxy_dashboard_shared_runtime_ready", "Shared runtime ready", "共享 runtime 就绪"),
+    ("tui_proxy_dashboard_takeover_active", "active", "已接管"),
+    ("tui_proxy_dashboard_takeover_inactive", "inactive", "未接管"),
+    ("tui_proxy_dashboard_takeover_unsupported", "not supported", "不支持"),
+    ("tui_proxy_dashboard_uptime_stopped", "--", "未运行"),
+    ("tui_proxy_dashboard_requests_idle", "No traffic yet", "暂无流量"),
+    ("tui_proxy_dashboard_tokens_idle", "No token traffic yet", "暂无 token 流量"),
+    ("tui_proxy_dashboard_target_waiting", "Waiting for first request", "等待首个请求"),
+    ("tui_label_current_app_takeover", "Current app takeover", "当前应用接管"),
+    ("tui_label_current_app_route", "Current app route", "当前应用路由"),
+    ("tui_label_latest_proxy_route", "Latest proxy route", "最近代理路由"),
+    ("tui_label_shared_runtime", "Shared runtime", "共享 runtime"),
+    ("tui_label_listen", "Listen", "监听"),
+    ("tui_label_uptime", "Uptime", "运行时长"),
+    ("tui_label_requests", "Requests", "请求"),
+    ("tui_label_traffic", "Traffic", "流量"),
+    ("tui_label_proxy_requests", "Proxy requests", "代理总请求"),
+    ("tui_label_active_target", "Active target", "当前路由目标"),
+    ("tui_label_last_error", "Last error", "最近错误"),
+    ("tui_label_last_proxy_error", "Last proxy error", "最近一次代理错误"),
+    ("tui_label_mcp_servers_active", "Active", "已启用"),
+    ("tui_loading", "Working…", "处理中…"),
+    ("tui_header_directory", "Directory", "目录"),
+    ("tui_header_repo", "Repo", "仓库"),
+    ("tui_header_branch", "Branch", "分支"),
+    ("tui_header_path", "Path", "路径"),
+    ("tui_header_found_in", "Found In", "发现于"),
+    ("tui_header_field", "Field", "字段"),
+    ("tui_header_value", "Value", "值"),
+    ("tui_label_directory", "Directory", "目录"),
+    ("tui_label_enabled_for", "Enabled", "已启用"),
+    ("tui_label_repo", "Repo", "仓库"),
+    ("tui_label_readme", "README", "README"),
+    ("tui_label_base_url", "Base URL", "API 请求地址"),
+    ("tui_label_api_key", "API Key", "API Key"),
+    ("tui_label_claude_api_format", "API Format", "API 格式"),
+    ("tui_claude_api_format_requires_proxy_title", "Proxy Required", "需开启代理"),
+    ("tui_claude_api_format_popup_title", "API Format", "API 格式"),
+    ("tui_label_claude_model_config", "Claude Model Config", "Claude 模型配置"),
+    ("tui_label_provider_package", "Provider / npm", "Provider / npm 包"),
+    ("tui_label_openclaw_api", "API Mode", "API 协议"),
+    ("tui_label_openclaw_user_agent", "Send User-Agent", "发送 User-Agent"),
+    ("tui_label_openclaw_models", "Models", "模型列表"),
+    ("tui_label_openclaw_status", "Status", "状态"),
+    ("tui_label_openclaw_model", "Model", "模型"),
+    ("tui_openclaw_status_default", "default", "默认"),
+    ("tui_openclaw_status_in_config_and_saved", "in config + saved", "配置中 + 已保存"),
+    ("tui_openclaw_status_live_only", "live only", "仅当前配置"),
+    ("tui_openclaw_status_saved_only", "saved only", "仅已保存"),
+    ("tui_openclaw_status_untracked", "untracked", "未跟踪"),
+    ("tui_openclaw_models_open_hint", "Press Enter to edit OpenClaw models", "按 Enter 编辑 OpenClaw 模型列表"),
+    ("tui_openclaw_models_editor_title", "OpenClaw Models", "OpenClaw 模型列表"),
+    ("tui_toast_json_must_be_array", "JSON must be an array", "JSON 必须是数组"),
+    ("tui_label_opencode_model_id", "Main Model ID", "主模型 ID"),
+    ("tui_label_opencode_model_name", "Main Model Name", "主模型名称"),
+    ("tui_label_context_limit", "Context Limit", "上下文限制"),
+    ("tui_label_output_limit", "Output Limit", "输出限制"),
+    ("tui_label_command", "Command", "命令"),
+    ("tui_label_args", "Args", "参数"),
+    ("tui_label_app_claude", "App: Claude", "应用: Claude"),
+    ("tui_label_app_codex", "App: Codex", "应用: Codex"),
+    ("tui_label_app_gemini", "App: Gemini", "应用: Gemini"),
+    ("tui_form_templates_title", "Templates", "模板"),
+    ("tui_form_common_config_button", "Common Config", "通用配置"),
+    ("tui_form_attach_common_config", "Attach Common Config", "添加通用配置"),
+    ("tui_form_fields_title", "Fields", "字段"),
+    ("tui_codex_auth_json_title", "auth.json (JSON) *", "auth.json (JSON) *"),
+    ("tui_codex_config_toml_title", "config.toml (TOML)", "config.toml (TOML)"),
+    ("tui_form_input_title", "Input", "输入"),
+    ("tui_form_editing_title", "Editing", "编辑中"),
+    ("tui_claude_model_config_popup_title", "Claude Model Configuration", "Claude 模型配置"),
+    ("tui_claude_model_main_label", "Main Model", "主模型"),
+    ("tui_claude_reasoning_model_label", "Reasoning Model (Thinking)", "推理模型 (Thinking)"),
+    ("tui_claude_default_haiku_model_label", "Default Haiku Model", "默认 Haiku 模型"),
+    ("tui_claude_default_sonnet_model_label", "Default Sonnet Model", "默认 Sonnet 模型"),
+    ("tui_claude_default_opus_model_label", "Default Opus Model", "默认 Opus 模型"),
+    ("tui_claude_model_config_open_hint", "Press Enter to configure Claude models", "按 Enter 配置 Claude 模型"),
+    ("tui_hint_press", "Press ", "按 "),
+    ("tui_hint_auto_fetch_models_from_api", " to auto-fetch models from API.", " 从 API 自动获取模型。"),
+    ("tui_model_fetch_search_placeholder", "Type to filter, or press Enter to use input...", "输入过滤 或 直接回车使用输入值..."),
+    ("tui_model_fetch_search_title", "Model Search", "模型搜索"),
+    ("tui_model_fetch_no_models", "No models found (type custom and press Enter)", "没有获取到模型 (可直接输入并在此回车)"),
+    ("tui_model_fetch_no_matches", "No matching models (press Enter to use input)", "没有匹配结果 (可直接输入并在此回车)"),
+    ("tui_provider_not_found", "Provider not found.", "未找到该供应商。"),
+    ("tui_provider_title", "Provider", "供应商"),
+    ("tui_provider_detail_title", "Provider Detail", "供应商详情"),
+    ("tui_provider_add_title", "Add Provider", "新增供应商"),
+    ("tui_codex_official_no_api_key_tip", "Official provider doesn't require an API key. Just save.", "官方无需填写 API Key，直接保存即可。"),
+    ("tui_toast_codex_official_auth_json_disabled", "auth.json editing is disabled for the official provider (it will be removed on switch).", "官方模式下不支持编辑 auth.json（切换时会移除）。"),
+    ("tui_provider_detail_keys", "Keys: s=switch  e=edit  t=speedtest  c=stream check", "按键：s=切换  e=编辑  t=测速  c=健康检查"),
+    ("tui_key_switch", "switch", "切换"),
+    ("tui_key_add_remove", "add/remove", "添加/移除"),
+    ("tui_key_set_default", "set default", "设为默认"),
+    ("tui_key_edit", "edit", "编辑"),
+    ("tui_key_speedtest", "speedtest", "测速"),
+    ("tui_key_stream_check", "stream check", "健康检查"),
+    ("tui_stream_check_status_operational", "operational", "正常"),
+    ("tui_stream_check_status_degraded", "degraded", "降级"),
+    ("tui_stream_check_status_failed", "failed", "失败"),
+    ("tui_key_details", "details", "详情"),
+    ("tui_key_view", "view", "查看"),
+    ("tui_key_add", "add", "新增"),
+    ("tui_key_delete", "delete", "删除"),
+    ("tui_key_import", "import", "导入"),
+    ("tui_key_install", "install", "安装"),
+    ("tui_key_uninstall", "uninstall", "卸载"),
+    ("tui_key_discover", "discover", "发现"),
+    ("tui_key_unmanaged", "existing", "已有"),
+    ("tui_key_repos", "repos", "仓库"),
+    ("tui_key_sync", "sync", "同步"),
+    ("tui_key_sync_method", "sync method", "同步方式"),
+    ("tui_key_search", "search", "搜索"),
+    ("tui_key_refresh", "refresh", "刷新"),
+    ("tui_key_start_proxy", "start proxy", "启动代理"),
+    ("tui_key_stop_proxy", "stop proxy", "停止代理"),
+    ("tui_key_proxy_on", "proxy on", "代理开"),
+    ("tui_key_proxy_off", "proxy off", "代理关"),
+    ("tui_key_focus", "next pane", "切换窗口"),
+    ("tui_key_toggle", "toggle", "启用/禁用"),
+    ("tui_key_apps", "apps", "应用"),
+    ("tui_key_activate", "activate", "激活"),
+    ("tui_key_deactivate", "deactivate", "取消激活"),
+    ("tui_key_open", "open", "打开"),
+    ("tui_key_open_directory", "open dir", "打开目录"),
+    ("tui_key_create", "create", "新建"),
+    ("tui_key_apply", "apply", "应用"),
+    ("tui_key_edit_snippet", "edit snippet", "编辑片段"),
+    ("tui_key_close", "close", "关闭"),
+    ("tui_key_exit", "exit", "退出"),
+    ("tui_key_cancel", "cancel", "取消"),
+    ("tui_key_submit", "submit", "提交"),
+    ("tui_key_yes", "confirm", "确认"),
+    ("tui_key_no", "back", "返回"),
+    ("tui_key_scroll", "scroll", "滚动"),
+    ("tui_key_restore", "restore", "恢复"),
+    ("tui_key_takeover", "take over", "接管"),
+    ("tui_key_save", "save", "保存"),
+    ("tui_key_external_editor", "external editor", "外部编辑器"),
+    ("tui_key_save_and_exit", "save & exit", "保存并退出"),
+    ("tui_key_exit_without_save", "exit w/o save", "不保存退出"),
+    ("tui_key_edit_mode", "edit", "编辑"),
+    ("tui_key_clear", "clear", "清除"),
+    ("tui_key_move", "move", "移动"),
+    ("tui_key_exit_edit", "exit edit", "退出编辑"),
+    ("tui_key_select", "select", "选择"),
+    ("tui_key_fetch_model", "fetch model", "获取模型"),
+    ("tui_key_deactivate_active", "deactivate active", "取消激活(当前)"),
+    ("tui_provider_list_keys", "Keys: a=add  e=edit  Enter=details  s=switch  /=filter", "按键：a=新增  e=编辑  Enter=详情  s=切换  /=搜索"),
+    ("tui_common_snippet_keys", "Keys: e=edit  c=clear  a=apply  Esc=back", "按键：e=编辑  c=清除  a=应用  Esc=返回"),
+    ("tui_settings_header_language", "Language", "语言"),
+    ("tui_settings_header_setting", "Setting", "设置项"),
+    ("tui_settings_header_value", "Value", "值"),
+    ("tui_settings_title", "Settings", "设置"),
+    ("tui_settings_visible_apps_label", "Visible Apps", "可见应用"),
+    ("tui_settings_visible_apps_title", "Choose Visible Apps", "选择可见应用"),
+    ("tui_settings_proxy_title", "Local Proxy", "本地代理"),
+    ("tui_settings_proxy_listen_address_label", "Listen Address", "监听地址"),
+    ("tui_settings_proxy_listen_port_label", "Listen Port", "监听端口"),
+    ("tui_settings_proxy_listen_address_prompt", "Enter listen address (for example 127.0.0.1 / localhost / 0.0.0.0)", "输入监听地址（如 127.0.0.1 / localhost / 0.0.0.0）"),
+    ("tui_settings_proxy_listen_port_prompt", "Enter listen port (1024-65535)", "输入监听端口（1024-65535）"),
+    ("tui_settings_proxy_restart_hint", "Changes to listen address or port require stopping and restarting the local proxy", "修改监听地址或端口后，需先停止并重新开启本地代理才能生效"),
+    ("tui_settings_proxy_stop_before_edit_hint", "Stop the local proxy before editing listen address or port", "请先停止本地代理，再修改监听地址或端口"),
+    ("tui_toast_proxy_listen_address_invalid", "Invalid address. Enter a valid IPv4 address, localhost, or 0.0.0.0", "地址无效，请输入有效的 IPv4 地址、localhost 或 0.0.0.0"),
+    ("tui_toast_proxy_listen_port_invalid", "Invalid port. Enter a number between 1024 and 65535", "端口无效，请输入 1024-65535 之间的数字"),
+    ("tui_toast_proxy_settings_saved", "Local proxy settings saved.", "本地代理配置已保存。"),
+    ("tui_toast_proxy_settings_restart_required", "The local proxy is running; the new listen address/port will apply after restart.", "本地代理正在运行；新监听地址/端口会在重启代理后生效。"),
+    ("tui_toast_proxy_settings_stop_before_edit", "The local proxy is running. Stop it before editing listen address or port.", "本地代理正在运行。请先停止代理，再修改监听地址或端口。"),
+    ("tui_toast_visible_apps_zero_selection_warning", "Keep at least one app visible.", "至少保留一个可见应用。"),
+    ("tui_toast_visible_apps_saved", "Visible apps saved.", "可见应用已保存。"),
+    ("tui_config_title", "Configuration", "配置"),
+    ("tui_skills_install_title", "Install Skill", "安装 Skill"),
+    ("tui_skills_install_prompt", "Enter a skill directory, or a full key (owner/name:directory):", "输入技能目录，或完整标识（owner/name:directory）："),
+    ("tui_skills_uninstall_title", "Uninstall Skill", "卸载 Skill"),
+    ("tui_skills_discover_title", "Discover Skills", "发现 Skills"),
+    ("tui_skills_discover_prompt", "Enter a keyword (leave empty to show all):", "输入关键词（留空显示全部）："),
+    ("tui_skills_discover_query_empty", "all", "全部"),
+    ("tui_skills_discover_hint", "Press f to search skills from enabled repositories, or r to manage repositories.", "按 f 搜索仓库里的技能，按 r 管理技能仓库。"),
+    ("tui_skills_repos_title", "Skill Repositories", "Skill 仓库"),
+    ("tui_skills_repos_hint", "Skill discovery loads results from the repositories enabled here.", "技能发现会从这里已启用的仓库加载列表。"),
+    ("tui_skills_repos_empty", "No skill repositories configured. Press a to add.", "未配置任何 Skill 仓库。按 a 添加。"),
+    ("tui_skills_repos_add_title", "Add Repository", "添加仓库"),
+    ("tui_skills_repos_add_prompt", "Enter a GitHub repository (owner/name, optional @branch) or a full URL:", "输入 GitHub 仓库（owner/name，可选 @branch）或完整 URL："),
+    ("tui_skills_repos_remove_title", "Remove Repository", "移除仓库"),
+    ("tui_skills_import_title", "Import Existing Skills", "导入已有技能"),
+    ("tui_skills_import_description", "Select skills to import into CC Switch unified management.", "选择要导入到 CC Switch 统一管理的技能。"),
+    ("tui_skills_unmanaged_empty", "No skills to import found.", "未发现可导入的技能。"),
+    ("tui_skills_detail_title", "Skill Detail", "Skill 详情"),
+    ("tui_skill_not_found", "Skill not found.", "未找到该 Skill。"),
+    ("tui_skills_sync_method_label", "Sync", "同步方式"),
+    ("tui_skills_sync_method_title", "Select Sync Method", "选择同步方式"),
+    ("tui_mcp_action_import_existing", "Import Existing", "导入已有"),
+    ("tui_skills_action_import_existing", "Import Existing", "导入已有"),
+    ("tui_skills_empty_title", "No installed skills", "暂无已安装的技能"),
+    ("tui_skills_empty_subtitle", "Discover and install skills from repositories, or import existing skills.", "从仓库发现并安装技能，或导入已有技能。"),
+    ("tui_skills_empty_hint", "No installed skills. Press f to discover skills, or i to import existing skills.", "暂无已安装技能。按 f 发现新技能，或按 i 导入已有技能。"),
+    ("tui_config_item_export", "Export Config", "导出配置"),
+    ("tui_config_item_import", "Import Config", "导入配置"),
+    ("tui_config_item_backup", "Backup Config", "备份配置"),
+    ("tui_config_item_restore", "Restore Config", "恢复配置"),
+    ("tui_config_item_validate", "Validate Config", "验证配置"),
+    ("tui_config_item_common_snippet", "Common Config Snippet", "通用配置片段"),
+    ("tui_config_item_proxy", "Local Proxy", "本地代理"),
+    ("tui_config_item_openclaw_env", "Env Variables", "环境变量"),
+    ("tui_config_item_openclaw_workspace", "Workspace Files", "Workspace 文件管理"),
+    ("tui_config_item_openclaw_tools", "Tool Permissions", "工具权限"),
+    ("tui_config_item_openclaw_agents", "Agents Config", "Agents 配置"),
+    ("tui_config_item_webdav_sync", "WebDAV Sync", "WebDAV 同步"),
+    ("tui_config_item_webdav_settings", "WebDAV Sync Settings (JSON)", "WebDAV 同步设置（JSON）"),
+    ("tui_config_item_webdav_check_connection", "WebDAV Check Connection", "WebDAV 检查连接"),
+    ("tui_config_item_webdav_upload", "WebDAV Upload to Remote", "WebDAV 上传到远端"),
+    ("tui_config_item_webdav_download", "WebDAV Download to Local", "WebDAV 下载到本地"),
+    ("tui_config_item_webdav_reset", "Reset WebDAV Settings", "重置 WebDAV 配置"),
+    ("tui_config_item_webdav_jianguoyun_quick_setup", "Jianguoyun Quick Setup", "坚果云一键配置"),
+    ("tui_webdav_settings_editor_title", "Edit WebDAV Sync Settings (JSON)", "编辑 WebDAV 同步设置（JSON）"),
+    ("tui_config_webdav_title", "WebDAV Sync", "WebDAV 同步"),
+    ("tui_openclaw_workspace_files_block_title", "Workspace Files", "Workspace 文件"),
+    ("tui_openclaw_workspace_directory_label", "Workspace directory", "工作区目录"),
+    ("tui_openclaw_workspace_daily_memory_label", "Daily Memory", "Daily Memory"),
+    ("tui_openclaw_workspace_status_exists", "Exists", "已存在"),
+    ("tui_openclaw_workspace_status_missing", "Missing", "缺失"),
+    ("tui_openclaw_tools_description", "Manage tool permissions in openclaw.json (allow/deny lists)", "管理 openclaw.json 中的工具权限配置（允许/拒绝列表）"),
+    ("tui_openclaw_tools_profile_block_title", "Permission Profile", "权限档位"),
+    ("tui_openclaw_tools_rules_block_title", "Rule Lists", "规则列表"),
+    ("tui_openclaw_tools_profile_label", "Profile", "配置档位"),
+    ("tui_openclaw_tools_profile_unset", "Not set", "未设置"),
+    ("tui_openclaw_tools_profile_minimal", "Minimal", "最小权限"),
+    ("tui_openclaw_tools_profile_coding", "Coding", "编码"),
+    ("tui_openclaw_tools_profile_messaging", "Messaging", "对话"),
+    ("tui_openclaw_tools_profile_full", "Full", "完全访问"),
+    ("tui_openclaw_tools_unsupported_profile_title", "Unsupported tools profile detected", "检测到不受支持的工具配置"),
+    ("tui_openclaw_tools_unsupported_profile_label", "unsupported", "不受支持"),
+    ("tui_openclaw_tools_allow_list_label", "Allow List", "允许列表"),
+    ("tui_openclaw_tools_deny_list_label", "Deny List", "拒绝列表"),
+    ("tui_openclaw_tools_pattern_placeholder", "Tool name or pattern", "工具名称或模式"),
+    ("tui_openclaw_tools_add_allow_rule", "+ Add allow rule", "+ 添加允许规则"),
+    ("tui_openclaw_tools_add_deny_rule", "+ Add deny rule", "+ 添加拒绝规则"),
+    ("tui_openclaw_tools_extra_fields_label", "Preserved extra fields", "保留的其他字段"),
+    ("tui_openclaw_tools_save_label", "Save", "保存"),
+    ("tui_openclaw_tools_load_failed_message", "The current tools section could not be loaded. Fix the parse warning above before editing tool permissions.", "当前 tools 配置无法加载；请先修复上方解析警告，再编辑工具权限。"),
+    ("tui_toast_openclaw_tools_save_blocked_parse_error", "Fix OpenClaw tools parse warnings before saving tool permissions", "请先修复 OpenClaw 工具配置解析警告，再保存工具权限"),
+    ("tui_toast_openclaw_tools_rule_empty", "Tool rule cannot be empty", "工具规则不能为空"),
+    ("tui_openclaw_agents_description", "Manage agents.defaults in openclaw.json (default model, runtime parameters, etc.)", "管理 openclaw.json 中的 agents.defaults 配置（默认模型、运行参数等）"),
+    ("tui_openclaw_agents_model_section", "Model Configuration", "模型配置"),
+    ("tui_openclaw_agents_primary_model", "Default Model", "默认模型"),
+    ("tui_openclaw_agents_not_set", "Not set", "未设置"),
+    ("tui_openclaw_agents_fallback_models", "Fallback Models", "回退模型"),
+    ("tui_openclaw_agents_add_fallback", "Add fallback model", "添加回退模型"),
+    ("tui_openclaw_agents_add_fallback_disabled", "No fallback models available to add", "没有可添加的回退模型了"),
+    ("tui_openclaw_agents_runtime_section", "Runtime Parameters", "运行参数"),
+    ("tui_openclaw_agents_workspace", "Workspace Path", "工作区路径"),
+    ("tui_openclaw_agents_timeout", "Timeout (seconds)", "超时时间（秒）"),
+    ("tui_openclaw_agents_context_tokens", "Context Tokens", "上下文 Token 数"),
+    ("tui_openclaw_agents_max_concurrent", "Max Concurrent", "最大并发数"),
+    ("tui_openclaw_agents_preserved_runtime_notice", "Non-standard runtime values are preserved until you replace them.", "非标准运行参数会在你替换它们之前保持原样保存。"),
+    ("tui_openclaw_agents_preserved_fields_label", "Preserved Fields", "保留字段"),
+    ("tui_openclaw_agents_legacy_timeout_title", "Legacy timeout detected", "检测到旧版超时字段"),
+    ("tui_openclaw_agents_legacy_timeout_description", "This config still uses agents.defaults.timeout. Saving here will migrate it to timeoutSeconds.", "当前配置仍在使用 agents.defaults.timeout。保存本页面时会迁移为 timeoutSeconds。"),
+    ("tui_openclaw_agents_legacy_timeout_invalid_description", "This config still uses agents.defaults.timeout, but the current value cannot be migrated automatically. Change it to a number or clear the field before saving.", "当前配置仍在使用 agents.defaults.timeout，但该值无法自动迁移。请先改为数字，或清空该字段后再保存。"),
+    ("tui_openclaw_agents_load_failed_message", "The current agents.defaults section could not be loaded. Fix the parse warning above before editing agents defaults.", "当前 agents.defaults 配置无法加载；请先修复上方解析警告，再编辑 Agents 配置。"),
+    ("tui_openclaw_agents_save_label", "Save", "保存"),
+    ("tui_toast_openclaw_agents_save_blocked_parse_error", "Fix OpenClaw agents parse warnings before saving agents defaults", "请先修复 OpenClaw agents.defaults 解析警告，再保存 Agents 配置"),
+    ("tui_toast_openclaw_agents_save_blocked_legacy_timeout", "Resolve agents.defaults.timeout before saving agents config", "请先处理 agents.defaults.timeout，再保存 Agents 配置"),
+    ("tui_openclaw_config_env_editor_title", "Edit Env Variables (JSON)", "编辑环境变量 (JSON)"),
+    ("tui_openclaw_config_env_description", "Manage the environment variable map in openclaw.json; saving writes back to env.vars.", "管理 openclaw.json 中的环境变量映射；保存时会写回 env.vars。"),
+    ("tui_openclaw_config_tools_editor_title", "Edit Tool Permissions (JSON)", "编辑工具权限 (JSON)"),
+    ("tui_openclaw_config_agents_editor_title", "Edit Agents Config (JSON)", "编辑 Agents 配置 (JSON)"),
+    ("tui_openclaw_config_warning_title", "OpenClaw Health Warnings", "OpenClaw 配置告警"),
+    ("tui_openclaw_config_file_label", "Config file", "配置文件"),
+    ("tui_openclaw_config_section_label", "Section", "当前配置"),
+    ("tui_openclaw_config_warning_state_label", "Warnings", "告警状态"),
+    ("tui_openclaw_config_warning_present", "Warnings detected", "发现告警"),
+    ("tui_openclaw_config_warning_clean", "Healthy", "正常"),
+    ("tui_openclaw_config_path_not_available", "n/a", "不可用"),
+    ("tui_openclaw_daily_memory_title", "Daily Memory", "Daily Memory"),
+    ("tui_openclaw_daily_memory_directory_label", "Memory directory", "Memory 目录"),
+    ("tui_openclaw_daily_memory_create_title", "Create Daily Memory", "新建 Daily Memory"),
+    ("tui_openclaw_daily_memory_create_prompt", "Enter a filename (YYYY-MM-DD.md):", "输入文件名（YYYY-MM-DD.md）："),
+    ("tui_openclaw_daily_memory_invalid_filename", "Invalid filename. Use YYYY-MM-DD.md.", "文件名无效，请使用 YYYY-MM-DD.md。"),
+    ("tui_openclaw_daily_memory_delete_title", "Delete Daily Memory", "删除 Daily Memory"),
+    ("tui_openclaw_daily_memory_empty", "No daily memory files yet. Press a to create one.", "还没有 Daily Memory 文件。按 a 新建。"),
+    ("tui_openclaw_daily_memory_search_empty", "No matching daily memory files.", "没有匹配的 Daily Memory 文件。"),
+    ("tui_webdav_jianguoyun_setup_title", "Jianguoyun Quick Setup", "坚果云一键配置"),
+    ("tui_webdav_jianguoyun_username_prompt", "Enter your Jianguoyun account (usually email):", "请输入坚果云账号（通常是邮箱）："),
+    ("tui_webdav_jianguoyun_app_password_prompt", "Enter your Jianguoyun app password:", "请输入坚果云第三方应用密码："),
+    ("tui_webdav_loading_title_check_connection", "WebDAV Check Connection", "WebDAV 检查连接"),
+    ("tui_webdav_loading_title_upload", "WebDAV Upload", "WebDAV 上传"),
+    ("tui_webdav_loading_title_download", "WebDAV Download", "WebDAV 下载"),
+    ("tui_webdav_loading_title_quick_setup", "Jianguoyun Quick Setup", "坚果云一键配置"),
+    ("tui_webdav_loading_message", "Processing WebDAV request, please wait...", "正在处理 WebDAV 请求，请稍候…"),
+    ("tui_config_item_reset", "Reset Config", "重置配置"),
+    ("tui_config_item_show_full", "Show Full Config", "查看完整配置"),
+    ("tui_config_item_show_path", "Show Config Path", "显示配置路径"),
+    ("tui_hint_esc_close", "Esc = Close", "Esc = 关闭"),
+    ("tui_hint_enter_submit_esc_cancel", "Enter = Submit, Esc = Cancel", "Enter = 提交, Esc = 取消"),
+    ("tui_hint_enter_restore_esc_cancel", "Enter = restore, Esc = cancel", "Enter = 恢复, Esc = 取消"),
+    ("tui_backup_picker_title", "Select Backup (Enter to restore)", "选择备份（Enter 恢复）"),
+    ("tui_toast_provider_already_in_use", "Already using this provider.", "已在使用该供应商。"),
+    ("tui_toast_provider_cannot_delete_current", "Cannot delete current provider.", "不能删除当前供应商。"),
+    ("tui_toast_provider_cannot_remove_default_model", "A provider referenced by the current default model cannot be removed from config directly.", "被当前默认模型引用的供应商不能直接从配置中移除。"),
+    ("tui_toast_provider_default_requires_live_config", "Add this provider to config before setting it as default.", "请先将该供应商添加到配置中，再设为默认。"),
+    ("tui_toast_provider_default_model_missing", "This provider has no primary model to set as default.", "该供应商缺少可设为默认的主模型。"),
+    ("tui_toast_provider_removed_from_config", "Provider removed from the current OpenClaw config.", "已从当前 OpenClaw 配置中移除该供应商。"),
+    ("tui_confirm_delete_provider_title", "Delete Provider", "删除供应商"),
+    ("tui_mcp_add_title", "Add MCP Server", "新增 MCP 服务器"),
+    ("tui_toast_provider_no_api_url", "No API URL configured for this provider.", "该供应商未配置 API URL。"),
+    ("tui_confirm_delete_mcp_title", "Delete MCP Server", "删除 MCP 服务器"),
+    ("tui_toast_prompt_no_active_to_deactivate", "No active prompt to deactivate.", "没有可停用的活动提示词。"),
+    ("tui_toast_prompt_cannot_delete_active", "Cannot delete the active prompt.", "不能删除正在启用的提示词。"),
+    ("tui_confirm_delete_prompt_title", "Delete Prompt", "删除提示词"),
+    ("tui_toast_prompt_edit_not_implemented", "Prompt editing not implemented yet.", "提示词编辑尚未实现。"),
+    ("tui_toast_prompt_edit_finished", "Prompt edit finished", "提示词编辑完成"),
+    ("tui_config_paths_title", "Configuration Paths", "配置路径"),
+    ("tui_config_export_title", "Export Configuration", "导出配置"),
+    ("tui_config_export_prompt", "Export path:", "导出路径："),
+    ("tui_config_import_title", "Import Configuration", "导入配置"),
+    ("tui_config_import_prompt", "Import from path:", "从路径导入："),
+    ("tui_config_backup_title", "Backup Configuration", "备份配置"),
+    ("tui_config_backup_prompt", "Optional name (empty for default):", "可选名称（留空使用默认值）："),
+    ("tui_toast_no_backups_found", "No backups found.", "未找到备份。"),
+    ("tui_config_reset_title", "Reset Configuration", "重置配置"),
+    ("tui_config_reset_message", "Reset to default configuration? (This will overwrite your current config)", "重置为默认配置？（这将覆盖当前配置）"),
+    ("tui_toast_export_path_empty", "Export path is empty.", "导出路径为空。"),
+    ("tui_toast_import_path_empty", "Import path is empty.", "导入路径为空。"),
+    ("tui_toast_command_empty", "Command is empty.", "命令为空。"),
+    ("tui_confirm_restore_backup_title", "Restore Backup", "恢复备份"),
+    ("tui_toast_speedtest_finished", "Speedtest finished.", "测速完成。"),
+    ("tui_toast_speedtest_disabled", "Speedtest is disabled for this session.", "本次会话测速不可用。"),
+    ("tui_toast_local_env_check_disabled", "Local environment check is disabled for this session.", "本次会话本地环境检查不可用。"),
+    ("tui_toast_stream_check_finished", "Stream check finished.", "健康检查完成。"),
+    ("tui_toast_stream_check_disabled", "Stream check is disabled for this session.", "本次会话健康检查不可用。"),
+    ("tui_toast_model_fetch_worker_disabled", "Model fetch worker is disabled for this session.", "本次会话模型获取后台任务不可用。"),
+    ("tui_toast_webdav_worker_disabled", "WebDAV worker is disabled for this session.", "本次会话 WebDAV 后台任务不可用。"),
+    ("tui_error_skills_worker_unavailable", "Skills worker unavailable.", "Skills 后台任务不可用。"),
+    ("tui_toast_skill_already_installed", "Skill already installed.", "该 Skill 已安装。"),
+    ("tui_toast_skill_spec_empty", "Skill spec is empty.", "Skill 不能为空。"),
+    ("tui_toast_skill_apps_updated", "Skill apps updated.", "Skill 应用已更新。"),
+    ("tui_toast_skills_synced", "Skills synced.", "Skills 同步完成。"),
+    ("tui_toast_repo_spec_empty", "Repository is empty.", "仓库不能为空。"),
+    ("tui_error_repo_spec_empty", "Repository cannot be empty.", "仓库不能为空。"),
+    ("tui_error_repo_spec_invalid", "Invalid repo format. Use owner/name or https://github.com/owner/name", "仓库格式无效。请使用 owner/name 或 https://github.com/owner/name"),
+    ("tui_toast_repo_added", "Repository added.", "仓库已添加。"),
+    ("tui_toast_repo_removed", "Repository removed.", "仓库已移除。"),
+    ("tui_toast_no_unmanaged_selected", "Select at least one skill to import.", "请至少选择一个要导入的技能。"),
+    ("tui_toast_provider_deleted", "Provider deleted.", "供应商已删除。"),
+    ("tui_toast_provider_add_finished", "Provider add flow finished.", "供应商新增流程已完成。"),
+    ("tui_toast_provider_add_missing_fields", "Please fill in name. id will be generated automatically.", "请填写 name，id 会自动生成。"),
+    ("tui_toast_provider_missing_name", "Please fill in name. id will be generated automatically.", "请填写 name，id 会自动生成。"),
+    ("tui_toast_provider_add_failed", "Failed to add provider.", "新增供应商失败。"),
+    ("tui_toast_provider_edit_finished", "Provider edit flow finished.", "供应商编辑流程已完成。"),
+    ("tui_toast_mcp_updated", "MCP updated.", "MCP 已更新。"),
+    ("tui_toast_mcp_upserted", "MCP server saved.", "MCP 服务器已保存。"),
+    ("tui_toast_mcp_missing_fields", "Please fill in id and name in JSON.", "请在 JSON 中填写 id 和 name。"),
+    ("tui_toast_mcp_server_deleted", "MCP server deleted.", "MCP 服务器已删除。"),
+    ("tui_toast_mcp_server_not_found", "MCP server not found.", "未找到 MCP 服务器。"),
+    ("tui_toast_prompt_activated", "Prompt activated.", "提示词已启用。"),
+    ("tui_toast_prompt_deactivated", "Prompt deactivated.", "提示词已停用。"),
+    ("tui_toast_prompt_deleted", "Prompt deleted.", "提示词已删除。"),
+    ("tui_toast_imported_config", "Imported config.", "配置已导入。"),
+    ("tui_toast_no_config_file_to_backup", "No config file to backup.", "没有可备份的配置文件。"),
+    ("tui_toast_restored_from_backup", "Restored from backup.", "已从备份恢复。"),
+    ("tui_toast_webdav_settings_saved", "WebDAV sync settings saved.", "WebDAV 同步设置已保存。"),
+    ("tui_toast_proxy_takeover_requires_running", "Foreground proxy is not running. Start `cc-switch proxy serve` first.", "前台代理未运行，请先启动 `cc-switch proxy serve`。"),
+    ("tui_error_proxy_worker_unavailable", "Proxy worker unavailable.", "代理任务不可用。"),
+    ("tui_toast_webdav_settings_cleared", "WebDAV sync settings cleared.", "WebDAV 同步设置已清空。"),
+    ("tui_toast_webdav_connection_ok", "WebDAV connection check passed.", "WebDAV 连接检查通过。"),
+    ("tui_toast_webdav_upload_ok", "WebDAV upload completed.", "WebDAV 上传完成。"),
+    ("tui_toast_webdav_download_ok", "WebDAV download completed.", "WebDAV 下载完成。"),
+    ("tui_webdav_v1_migration_title", "Legacy sync data detected", "发现旧版同步数据"),
+    ("tui_webdav_v1_migration_message", "V1 sync data found on remote. Migrate to V2?\nThis will download old data, apply locally, re-upload as V2, and clean up V1 data.", "远端存在 V1 格式的同步数据，是否迁移到 V2？\n迁移将下载旧数据、应用到本地、重新上传为新格式，并清理旧数据。"),
+    ("tui_webdav_loading_title_v1_migration", "V1 → V2 Migration", "V1 → V2 迁移"),
+    ("tui_toast_webdav_v1_migration_ok", "V1 → V2 migration completed, old data cleaned up.", "V1 → V2 迁移完成，旧数据已清理。"),
+    ("tui_toast_webdav_jianguoyun_configured", "Jianguoyun quick setup completed and connection verified.", "坚果云一键配置完成，连接检查通过。"),
+    ("tui_toast_webdav_username_empty", "Please enter a WebDAV username.", "请输入 WebDAV 用户名。"),
+    ("tui_toast_webdav_password_empty", "Please enter a WebDAV app password.", "请输入 WebDAV 第三方应用密码。"),
+    ("tui_toast_config_file_does_not_exist", "Config file does not exist.", "配置文件不存在。"),
+    ("tui_config_validation_title", "Config Validation", "配置校验"),
+    ("tui_config_validation_failed_title", "Config Validation Failed", "配置校验失败"),
+    ("tui_config_validation_ok", "✓ Configuration is valid JSON", "✓ 配置是有效的 JSON"),
+    ("tui_toast_validation_passed", "Validation passed.", "校验通过。"),
+    ("tui_toast_config_reset_to_defaults", "Config reset to defaults.", "配置已重置为默认值。"),
+    ("skills_management", "Skills Management", "技能管理"),
+    ("no_skills_installed", "No skills installed.", "未安装任何 Skills。"),
+    ("skills_discover", "🔎 Discover/Search Skills", "🔎 发现/搜索 Skills"),
+    ("skills_install", "⬇️  Install Skill", "⬇️  安装 Skill"),
+    ("skills_uninstall", "🗑️  Uninstall Skill", "🗑️  卸载 Skill"),
+    ("skills_toggle_for_app", "✅ Enable/Disable (Current App)", "✅ 启用/禁用（当前应用）"),
+    ("skills_show_info", "ℹ️  Skill Info", "ℹ️  查看 Skill 信息"),
+    ("skills_sync_now", "🔄 Sync Skills to Live", "🔄 同步 Skills 到本地"),
+    ("skills_sync_method", "🔗 Sync Method (auto/symlink/copy)", "🔗 同步方式（auto/symlink/copy）"),
+    ("skills_select_sync_method", "Select sync method:", "选择同步方式："),
+    ("skills_scan_unmanaged", "🕵️  Find Existing Skills", "🕵️  查找已有技能"),
+    ("skills_import_from_apps", "📥 Import Existing Skills", "📥 导入已有技能"),
+    ("skills_manage_repos", "📦 Manage Skill Repos", "📦 管理技能仓库"),
+    ("skills_enter_query", "Enter search query (optional):", "输入搜索关键词（可选）："),
+    ("skills_enter_install_spec", "Enter a skill directory, or a full key (owner/name:directory):", "输入技能目录，或完整标识（owner/name:directory）："),
+    ("skills_select_skill", "Select a skill:", "选择一个 Skill："),
+    ("skills_no_unmanaged_found", "No skills to import found. All skills are already managed by CC Switch.", "未发现可导入的技能。所有技能已在 CC Switch 中统一管理。"),
+    ("skills_select_unmanaged_to_import", "Select skills to import:", "选择要导入的技能："),
+    ("skills_repos_management", "Skill Repos", "技能仓库管理"),
+    ("skills_repo_list", "📋 List Repos", "📋 查看仓库列表"),
+    ("skills_repo_add", "➕ Add Repo", "➕ 添加仓库"),
+    ("skills_repo_remove", "➖ Remove Repo", "➖ 移除仓库"),
+    ("skills_repo_enter_spec", "Enter a GitHub repository (owner/name, optional @branch) or a full URL:", "输入 GitHub 仓库（owner/name，可选 @branch）或完整 URL："),
+    ("provider_management", "🔌 Provider Management", "🔌 供应商管理"),
+    ("no_providers", "No providers found.", "未找到供应商。"),
+    ("view_current_provider", "📋 View Current Provider Details", "📋 查看当前供应商详情"),
+    ("switch_provider", "🔄 Switch Provider", "🔄 切换供应商"),
+    ("add_provider", "➕ Add Provider", "➕ 新增供应商"),
+    ("add_official_provider", "Add Official Provider", "添加官方供应商"),
+    ("add_third_party_provider", "Add Third-Party Provider", "添加第三方供应商"),
+    ("select_provider_add_mode", "Select provider type:", "请选择供应商类型："),
+    ("delete_provider", "🗑️  Delete Provider", "🗑️  删除供应商"),
+    ("back_to_main", "⬅️  Back to Main Menu", "⬅️  返回主菜单"),
+    ("choose_action", "Choose an action:", "选择操作："),
+    ("esc_to_go_back_help", "Esc to go back", "Esc 返回上一步"),
+    ("select_filter_help", "Esc to go back; type to filter", "Esc 返回；输入可过滤"),
+    ("current_provider_details", "Current Provider Details", "当前供应商详情"),
+    ("only_one_provider", "Only one provider available. Cannot switch.", "只有一个供应商，无法切换。"),
+    ("no_other_providers", "No other providers to switch to.", "没有其他供应商可切换。"),
+    ("select_provider_to_switch", "Select provider to switch to:", "选择要切换到的供应商："),
+    ("restart_note", "Note: Restart your CLI client to apply the changes.", "注意：请重启 CLI 客户端以应用更改。"),
+    ("no_deletable_providers", "No providers available for deletion (cannot delete current provider).", "没有可删除的供应商（无法删除当前供应商）。"),
+    ("select_provider_to_delete", "Select provider to delete:", "选择要删除的供应商："),
+    ("cancelled", "Cancelled.", "已取消。"),
+    ("selection_cancelled", "Selection cancelled", "已取消选择"),
+    ("invalid_selection", "Invalid selection", "选择无效"),
+    ("available_backups", "Available Backups", "可用备份"),
+    ("no_backups_found", "No backups found.", "未找到备份。"),
+    ("create_backup_first_hint", "Create a backup first: cc-switch config backup", "请先创建备份：cc-switch config backup"),
+    ("select_backup_to_restore", "Select backup to restore:", "选择要恢复的备份："),
+    ("warning_title", "Warning:", "警告："),
+    ("config_restore_warning_replace", "This will replace your current configuration with the selected backup.", "这将用所选备份替换你当前的配置。"),
+    ("config_restore_warning_pre_backup", "A backup of the current state will be created first.", "系统会先创建一次当前状态的备份。"),
+    ("config_restore_confirm_prompt", "Continue with restore?", "确认继续恢复？"),
+    ("provider_name_label", "Provider Name:", "供应商名称："),
+    ("provider_name_help", "Required, friendly display name", "必填，用于显示的友好名称"),
+    ("provider_name_help_edit", "Required, press Enter to keep", "必填，直接回车保持原值"),
+    ("provider_name_empty_error", "Provider name cannot be empty", "供应商名称不能为空"),
+    ("website_url_label", "Website URL (opt.):", "官网 URL（可选）："),
+    ("website_url_help", "Provider's website, press Enter to skip", "供应商的网站地址，直接回车跳过"),
+    ("website_url_help_edit", "Leave blank to keep, Enter to skip", "留空则不修改，直接回车跳过"),
+    ("configure_optional_fields_prompt", "Configure optional fields (notes, sort index)?", "配置可选字段（备注、排序索引）？"),
+    ("current_config_header", "Current Configuration:", "当前配置："),
+    ("modify_provider_config_prompt", "Modify provider configuration (API Key, Base URL, etc.)?", "修改供应商配置（API Key, Base URL 等）？"),
+    ("modify_optional_fields_prompt", "Modify optional fields (notes, sort index)?", "修改可选字段（备注、排序索引）？"),
+    ("current_provider_synced_warning", "⚠ This provider is currently active, changes synced to live config", "⚠ 此供应商当前已激活，修改已同步到 live 配置"),
+    ("provider_name_prompt", "Provider Name:", "供应商名称："),
+    ("config_claude_header", "Configure Claude Provider:", "配置 Claude 供应商："),
+    ("api_key_label", "API Key:", "API Key："),
+    ("api_key_help", "Leave empty to use default", "留空使用默认值"),
+    ("base_url_label", "Base URL:", "Base URL："),
+    ("base_url_empty_error", "API URL cannot be empty", "API 请求地址不能为空"),
+    ("base_url_placeholder", "e.g., https://api.anthropic.com", "如 https://api.anthropic.com"),
+    ("configure_model_names_prompt", "Configure model names?", "配置模型名称？"),
+    ("model_default_label", "Default Model:", "默认模型："),
+    ("model_default_help", "Leave empty to use Claude Code default", "留空使用 Claude Code 默认模型"),
+    ("model_haiku_label", "Haiku Model:", "Haiku 模型："),
+    ("model_haiku_placeholder", "e.g., claude-3-5-haiku-20241022", "如 claude-3-5-haiku-20241022"),
+    ("model_sonnet_label", "Sonnet Model:", "Sonnet 模型："),
+    ("model_sonnet_placeholder", "e.g., claude-3-5-sonnet-20241022", "如 claude-3-5-sonnet-20241022"),
+    ("model_opus_label", "Opus Model:", "Opus 模型："),
+    ("model_opus_placeholder", "e.g., claude-3-opus-20240229", "如 claude-3-opus-20240229"),
+    ("config_codex_header", "Configure Codex Provider:", "配置 Codex 供应商："),
+    ("openai_api_key_label", "OpenAI API Key:", "OpenAI API Key："),
+    ("anthropic_api_key_label", "Anthropic API Key:", "Anthropic API Key："),
+    ("config_toml_label", "Config Content (TOML):", "配置内容 (TOML)："),
+    ("config_toml_help", "Press Esc then Enter to submit", "按 Esc 后 Enter 提交"),
+    ("config_toml_placeholder", "Leave empty to use default config", "留空使用默认配置"),
+    ("codex_auth_mode_info", "⚠ Choose how Codex authenticates (where the API key is read from)", "⚠ 请选择 Codex 的鉴权方式（决定 API Key 从哪里读取）"),
+    ("codex_auth_mode_label", "Auth Mode:", "认证方式："),
+    ("codex_auth_mode_help", "OpenAI auth uses auth.json/credential store; env var mode uses env_key (missing env var will error)", "OpenAI 认证：使用 auth.json/凭据存储；环境变量：使用 env_key 指定的变量（未设置会报错）"),
+    ("codex_auth_mode_openai", "OpenAI auth (recommended, no env var)", "OpenAI 认证（推荐，无需环境变量）"),
+    ("codex_auth_mode_env_var", "Environment variable (env_key, requires export)", "环境变量（env_key，需要手动 export）"),
+    ("codex_official_provider_tip", "Tip: Official provider uses Codex login credentials (`codex login` may open a browser); no API key required", "提示：官方供应商将使用 Codex 官方登录保存的凭证（codex login 可能会打开浏览器），无需填写 API Key"),
+    ("codex_env_key_info", "⚠ Env var mode: Codex will read the API key from the specified environment variable", "⚠ 环境变量模式：Codex 将从指定的环境变量读取 API Key"),
+    ("codex_env_key_label", "Environment Variable Name:", "环境变量名称："),
+    ("codex_env_key_help", "Codex will read API key from this env var (default: OPENAI_API_KEY)", "Codex 将从此环境变量读取 API 密钥（默认: OPENAI_API_KEY）"),
+    ("codex_wire_api_label", "API Format:", "API 格式："),
+    ("codex_wire_api_help", "chat = Chat Completions API (most providers), responses = OpenAI Responses API", "chat = Chat Completions API (大多数第三方), responses = OpenAI Responses API"),
+    ("codex_openai_auth_info", "✓ OpenAI auth mode: Codex will use auth.json/credential store; no OPENAI_API_KEY env var required", "✓ OpenAI 认证模式：Codex 将使用 auth.json/系统凭据存储，无需设置 OPENAI_API_KEY 环境变量"),
+    ("use_current_config_prompt", "Use current configuration?", "使用当前配置？"),
+    ("use_current_config_help", "Select No to enter custom input mode", "选择 No 将进入自定义输入模式"),
+    ("input_toml_config", "Enter TOML config (multiple lines, empty line to finish):", "输入 TOML 配置（多行，输入空行结束）："),
+    ("direct_enter_to_finish", "Press Enter to finish", "直接回车结束输入"),
+    ("current_config_label", "Current Config:", "当前配置："),
+    ("config_toml_header", "Config.toml Configuration:", "Config.toml 配置："),
+    ("config_gemini_header", "Configure Gemini Provider:", "配置 Gemini 供应商："),
+    ("auth_type_label", "Auth Type:", "认证类型："),
+    ("auth_type_api_key", "API Key", "API Key"),
+    ("auth_type_service_account", "Service Account (ADC)", "Service Account (ADC)"),
+    ("gemini_api_key_label", "Gemini API Key:", "Gemini API Key："),
+    ("gemini_base_url_label", "Base URL:", "Base URL："),
+    ("gemini_base_url_help", "Leave empty to use official API", "留空使用官方 API"),
+    ("gemini_base_url_placeholder", "e.g., https://generativelanguage.googleapis.com", "如 https://generativelanguage.googleapis.com"),
+    ("adc_project_id_label", "GCP Project ID:", "GCP Project ID："),
+    ("adc_location_label", "GCP Location:", "GCP Location："),
+    ("adc_location_placeholder", "e.g., us-central1", "如 us-central1"),
+    ("google_oauth_official", "Google OAuth (Official)", "Google OAuth（官方）"),
+    ("packycode_api_key", "PackyCode API Key", "PackyCode API Key"),
+    ("generic_api_key", "Generic API Key", "通用 API Key"),
+    ("select_auth_method_help", "Select authentication method for Gemini", "选择 Gemini 的认证方式"),
+    ("use_google_oauth_warning", "Using Google OAuth, API Key config will be cleared", "使用 Google OAuth，将清空 API Key 配置"),
+    ("packycode_api_key_help", "API Key obtained from PackyCode", "从 PackyCode 获取的 API Key"),
+    ("packycode_endpoint_help", "PackyCode API endpoint", "PackyCode API 端点"),
+    ("generic_api_key_help", "Generic Gemini API Key", "通用的 Gemini API Key"),
+    ("notes_label", "Notes:", "备注："),
+    ("notes_placeholder", "Optional notes", "可选的备注信息"),
+    ("sort_index_label", "Sort Index:", "排序索引："),
+    ("sort_index_help", "Lower numbers appear first, leave empty to sort by creation time", "数字越小越靠前，留空使用创建时间排序"),
+    ("sort_index_placeholder", "e.g., 1, 2, 3...", "如 1, 2, 3..."),
+    ("invalid_sort_index", "Sort index must be a valid number", "排序索引必须是有效的数字"),
+    ("optional_fields_config", "Optional Fields Configuration:", "可选字段配置："),
+    ("notes_example_placeholder", "Custom provider for testing", "自定义供应商，用于测试"),
+    ("notes_help_edit", "Additional notes about this provider, press Enter to keep current value", "关于此供应商的额外说明，直接回车保持原值"),
+    ("notes_help_new", "Additional notes about this provider, press Enter to skip", "关于此供应商的额外说明，直接回车跳过"),
+    ("sort_index_help_edit", "Number for display order, press Enter to keep current value", "数字，用于控制显示顺序，直接回车保持原值"),
+    ("sort_index_help_new", "Number for display order, press Enter to skip", "数字，用于控制显示顺序，直接回车跳过"),
+    ("invalid_sort_index_number", "Sort index must be a number", "排序索引必须是数字"),
+    ("provider_config_summary", "=== Provider Configuration Summary ===", "=== 供应商配置摘要 ==="),
+    ("id_label", "ID", "ID"),
+    ("website_label", "Website", "官网"),
+    ("core_config_label", "Core Configuration:", "核心配置："),
+    ("model_label", "Model", "模型"),
+    ("optional_fields_label", "Optional Fields:", "可选字段："),
+    ("notes_label_colon", "Notes", "备注"),
+    ("sort_index_label_colon", "Sort Index", "排序索引"),
+    ("id_label_colon", "ID", "ID"),
+    ("url_label_colon", "URL", "网址"),
+    ("api_url_label_colon", "API URL", "API 地址"),
+    ("basic_info_header", "Basic Info", "基本信息"),
+    ("name_display_label", "Name", "名称"),
+    ("app_display_label", "App", "应用"),
+    ("notes_display_label", "Notes", "备注"),
+    ("sort_index_display_label", "Sort Index", "排序"),
+    ("config_info_header", "Configuration", "配置信息"),
+    ("api_key_display_label", "API Key", "API Key"),
+    ("base_url_display_label", "Base URL", "Base URL"),
+    ("model_config_header", "Model Configuration", "模型配置"),
+    ("default_model_display", "Default", "默认"),
+    ("haiku_model_display", "Haiku", "Haiku"),
+    ("sonnet_model_display", "Sonnet", "Sonnet"),
+    ("opus_model_display", "Opus", "Opus"),
+    ("auth_type_display_label", "Auth Type", "认证"),
+    ("project_id_display_label", "Project ID", "项目 ID"),
+    ("location_display_label", "Location", "位置"),
+    ("edit_provider_menu", "➕ Edit Provider", "➕ 编辑供应商"),
+    ("no_editable_providers", "No providers available for editing", "没有可编辑的供应商"),
+    ("select_provider_to_edit", "Select provider to edit:", "选择要编辑的供应商："),
+    ("choose_edit_mode", "Choose edit mode:", "选择编辑模式："),
+    ("select_config_file_to_edit", "Select config file to edit:", "选择要编辑的配置文件："),
+    ("provider_missing_auth_field", "Missing 'auth' field in settings_config", "settings_config 中缺少 'auth' 字段"),
+    ("provider_missing_or_invalid_config_field", "Missing or invalid 'config' field in settings_config", "settings_config 中缺少或无效的 'config' 字段"),
+    ("edit_mode_interactive", "📝 Interactive editing (step-by-step prompts)", "📝 交互式编辑 (分步提示)"),
+    ("edit_mode_json_editor", "✏️  JSON editing (use external editor)", "✏️  JSON 编辑 (使用外部编辑器)"),
+    ("cancel", "❌ Cancel", "❌ 取消"),
+    ("opening_external_editor", "Opening external editor...", "正在打开外部编辑器..."),
+    ("invalid_json_syntax", "Invalid JSON syntax", "无效的 JSON 语法"),
+    ("invalid_provider_structure", "Invalid provider structure", "无效的供应商结构"),
+    ("provider_id_cannot_be_changed", "Provider ID cannot be changed", "供应商 ID 不能被修改"),
+    ("retry_editing", "Retry editing?", "是否重新编辑？"),
+    ("no_changes_detected", "No changes detected", "未检测到任何更改"),
+    ("provider_summary", "Provider Summary", "供应商信息摘要"),
+    ("confirm_save_changes", "Save changes?", "确认保存更改？"),
+    ("editor_failed", "Editor failed", "编辑器失败"),
+    ("invalid_selection_format", "Invalid selection format", "无效的选择格式"),
+    ("basic_info_section_header", "Basic Info", "基本信息 / Basic Info"),
+    ("name_label_with_colon", "Name", "名称"),
+    ("app_label_with_colon", "App", "应用"),
+    ("api_config_section_header", "API Configuration", "API 配置 / API Configuration"),
+    ("model_config_section_header", "Model Configuration", "模型配置 / Model Configuration"),
+    ("main_model_label_with_colon", "Main Model", "主模型"),
+    ("updated_config_header", "Updated Configuration:", "修改后配置："),
+    ("edit_fields_instruction", "Edit fields one by one (press Enter to keep current value):\n", "逐个编辑字段（直接回车保留当前值）：\n"),
+    ("mcp_management", "🛠️  MCP Server Management", "🛠️  MCP 服务器管理"),
+    ("no_mcp_servers", "No MCP servers found.", "未找到 MCP 服务器。"),
+    ("sync_all_servers", "🔄 Sync All Servers", "🔄 同步所有服务器"),
+    ("synced_successfully", "✓ All MCP servers synced successfully", "✓ 所有 MCP 服务器同步成功"),
+    ("prompts_management", "💬 Prompt Management", "💬 提示词管理"),
+    ("no_prompts", "No prompt presets found.", "未找到提示词预设。"),
+    ("switch_active_prompt", "🔄 Switch Active Prompt", "🔄 切换活动提示词"),
+    ("no_prompts_available", "No prompts available.", "没有可用的提示词。"),
+    ("select_prompt_to_activate", "Select prompt to activate:", "选择要激活的提示词："),
+    ("prompt_cleared_note", "Live prompt file has been cleared", "实时文件已清空"),
+    ("prompt_synced_note", "Note: The prompt has been synced to the live configuration file.", "注意：提示词已同步到实时配置文件。"),
+    ("current_configuration", "👁️  Current Configuration", "👁️  当前配置"),
+    ("provider_label", "Provider:", "供应商："),
+    ("mcp_servers_label", "MCP Servers:", "MCP 服务器："),
+    ("tui_label_skills", "Skills:", "技能:"),
+    ("prompts_label", "Prompts:", "提示词："),
+    ("total", "Total", "总计"),
+    ("enabled", "Enabled", "启用"),
+    ("disabled", "Disabled", "禁用"),
+    ("active", "Active", "活动"),
+    ("none", "None", "无"),
+    ("settings_title", "⚙️  Settings", "⚙️  设置"),
+    ("change_language", "🌐 Change Language", "🌐 切换语言"),
+    ("current_language_label", "Current Language", "当前语言"),
+    ("select_language", "Select language:", "选择语言："),
+    ("language_changed", "✓ Language changed", "✓ 语言已更改"),
+    ("skip_claude_onboarding", "🚫 Skip Claude Code onboarding confirmation", "🚫 跳过 Claude Code 初次安装确认"),
+    ("skip_claude_onboarding_label", "Skip Claude Code onboarding confirmation", "跳过 Claude Code 初次安装确认"),
+    ("enable_claude_plugin_integration", "🔌 Apply to Claude Code for VSCode", "🔌 接管 Claude Code for VSCode 插件"),
+    ("enable_claude_plugin_integration_label", "Apply to Claude Code for VSCode", "接管 Claude Code for VSCode 插件"),
+    ("select_application", "Select application:", "选择应用程序："),
+    ("press_enter", "Press Enter to continue...", "按 Enter 继续..."),
+    ("error_prefix", "Error", "错误"),
+    ("header_name", "Name", "名称"),
+    ("header_category", "Category", "类别"),
+    ("header_description", "Description", "描述"),
+    ("config_management", "⚙️  Configuration Management", "⚙️  配置文件管理"),
+    ("config_export", "📤 Export Config", "📤 导出配置"),
+    ("config_import", "📥 Import Config", "📥 导入配置"),
+    ("config_backup", "💾 Backup Config", "💾 备份配置"),
+    ("config_restore", "♻️  Restore Config", "♻️  恢复配置"),
+    ("config_validate", "✓ Validate Config", "✓ 验证配置"),
+    ("config_common_snippet", "🧩 Common Config Snippet", "🧩 通用配置片段"),
+    ("config_common_snippet_title", "Common Config Snippet", "通用配置片段"),
+    ("config_common_snippet_none_set", "No common config snippet is set.", "未设置通用配置片段。"),
+    ("config_common_snippet_require_json_or_file", "Please provide --json or --file", "请提供 --json 或 --file"),
+    ("config_reset", "🔄 Reset Config", "🔄 重置配置"),
+    ("config_show_full", "👁️  Show Full Config", "👁️  查看完整配置"),
+    ("config_show_path", "📍 Show Config Path", "📍 显示配置路径"),
+    ("enter_export_path", "Enter export file path:", "输入导出文件路径："),
+    ("enter_import_path", "Enter import file path:", "输入导入文件路径："),
+    ("enter_restore_path", "Enter backup file path:", "输入备份文件路径："),
+    ("confirm_import", "Are you sure you want to import? This will overwrite current configuration.", "确定要导入配置吗？这将覆盖当前配置。"),
+    ("confirm_reset", "Are you sure you want to reset? This will delete all custom settings.", "确定要重置配置吗？这将删除所有自定义设置。"),
+    ("common_config_snippet_not_object", "Common config must be a JSON object (e.g. {\"env\":{...}})", "通用配置必须是 JSON 对象（例如：{\"env\":{...}}）"),
+    ("common_config_snippet_saved", "✓ Common config snippet saved", "✓ 已保存通用配置片段"),
+    ("common_config_snippet_cleared", "✓ Common config snippet cleared", "✓ 已清除通用配置片段"),
+    ("common_config_snippet_apply_now", "Apply to current provider now (write live config)?", "现在应用到当前供应商（写入 live 配置）？"),
+    ("common_config_snippet_no_current_provider", "No current provider selected; common config snippet saved.", "当前未选择供应商，已保存通用配置片段。"),
+    ("common_config_snippet_no_current_provider_after_clear", "No current provider selected; common config snippet cleared.", "当前未选择供应商，已清除通用配置片段。"),
+    ("common_config_snippet_applied", "✓ Applied to live config (restart the client)", "✓ 已应用到 live 配置（请重启对应客户端）"),
+    ("common_config_snippet_apply_hint", "Tip: switch provider once to re-write the live config.", "提示：切换一次供应商即可重新写入 live 配置。"),
+    ("common_config_snippet_apply_not_needed", "The current live config is already up to date; nothing to apply.", "当前配置已是最新，无需重新应用。"),
+    ("confirm_restore", "Are you sure you want to restore from backup?", "确定要从备份恢复配置吗？"),
+    ("config_valid", "✓ Configuration is valid", "✓ 配置文件有效"),
+    ("config_reset_done", "✓ Configuration reset to defaults", "✓ 配置已重置为默认值"),
+    ("mcp_delete_server", "🗑️  Delete Server", "🗑️  删除服务器"),
+    ("mcp_enable_server", "✅ Enable Server", "✅ 启用服务器"),
+    ("mcp_disable_server", "❌ Disable Server", "❌ 禁用服务器"),
+    ("mcp_import_servers", "📥 Import Existing MCP Servers", "📥 导入已有 MCP 服务器"),
+    ("mcp_validate_command", "✓ Validate Command", "✓ 验证命令"),
+    ("select_server_to_delete", "Select server to delete:", "选择要删除的服务器："),
+    ("select_server_to_enable", "Select server to enable:", "选择要启用的服务器："),
+    ("select_server_to_disable", "Select server to disable:", "选择要禁用的服务器："),
+    ("select_apps_to_enable", "Select apps to enable for:", "选择要启用的应用："),
+    ("select_apps_to_disable", "Select apps to disable for:", "选择要禁用的应用："),
+    ("enter_command_to_validate", "Enter command to validate:", "输入要验证的命令："),
+    ("prompts_show_content", "👁️  View Full Content", "👁️  查看完整内容"),
+    ("prompts_delete", "🗑️  Delete Prompt", "🗑️  删除提示词"),
+    ("prompts_view_current", "📋 View Current Prompt", "📋 查看当前提示词"),
+    ("select_prompt_to_view", "Select prompt to view:", "选择要查看的提示词："),
+    ("select_prompt_to_delete", "Select prompt to delete:", "选择要删除的提示词："),
+    ("no_active_prompt", "No active prompt.", "当前没有激活的提示词。"),
+    ("cannot_delete_active", "Cannot delete the active prompt.", "无法删除当前激活的提示词。"),
+    ("no_servers_to_delete", "No servers to delete.", "没有可删除的服务器。"),
+    ("no_prompts_to_delete", "No prompts to delete.", "没有可删除的提示词。"),
+    ("speedtest_endpoint", "🚀 Speedtest endpoint", "🚀 测试端点速度"),
+    ("back", "← Back", "← 返回"),
+    ("tui_settings_check_for_updates", "Check for Updates", "检查更新"),
+    ("tui_update_checking_title", "Checking for Updates", "检查更新中"),
+    ("tui_update_available_title", "Update Available", "发现新版本"),
+    ("tui_update_downloading_title", "Updating", "正在更新"),
+    ("tui_update_result_title", "Update Result", "更新结果"),
+    ("tui_update_btn_update", "Update", "更新"),
+    ("tui_update_btn_cancel", "Cancel", "取消"),
+    ("tui_update_err_worker_unavailable", "Update worker unavailable", "更新服务不可用"),
+    ("tui_update_err_check_first", "Please check for updates first", "请先检查更新"),
+    ("tui_key_hide", "hide", "隐藏"),
+    ("tui_provider_imported_live_config_name", "Imported Live Config", "导入的 Live 配置"),
+    ("tui_codex_imported_live_config_name", "Imported Codex Live Config", "导入的 Codex Live 配置"),
+    ("tui_toast_provider_live_config_imported", "Imported the current live config as a provider", "已将当前 live 配置导入为供应商"),
+    ("tui_toast_codex_live_config_imported", "Imported the current Codex live config as a provider", "已将当前 Codex live 配置导入为供应商"),
+    ("tui_provider_switch_first_use_title", "Existing live config detected", "检测到现有 live 配置"),
+    ("tui_codex_provider_switch_first_use_title", "Existing Codex live config detected", "检测到现有 Codex live 配置"),
+    ("tui_provider_switch_first_use_import_button", "Import Current Config", "导入当前配置"),
+    ("tui_provider_switch_first_use_continue_button", "Continue Switching", "继续切换"),
+    ("tui_provider_switch_first_use_cancel_button", "Cancel", "取消"),
+    ("tui_provider_switch_shared_config_tip_title", "Provider Switched", "已切换供应商"),
+];
+
+fn index() -> &'static HashMap<&'static str, &'static TextEntry> {
+    static INDEX: OnceLock<HashMap<&'static str, &'static TextEntry>> = OnceLock::new();
+    INDEX.get_or_init(|| TEXTS.iter().map(|entry| (entry.0, entry)).collect())
+}
+
+fn translation(entry: &'static TextEntry, lang: Language) -> &'static str {
+    match lang {
+        Language::English => entry.1,
+        Language::Chinese => entry.2,
+    }
+}
+
+/// 按当前语言查表；缺少译文时回退到英文，未知 key 原样返回。
+pub fn text(key: &'static str) -> &'static str {
+    let Some(entry) = index().get(key).copied() else {
+        debug_assert!(false, "unknown text key: {key}");
+        return key;
+    };
+    let translated = translation(entry, current_language());
+    if translated.trim().is_empty() {
+        entry.1
+    } else {
+        translated
+    }
+}
+
+/// 列出指定语言下缺少译文（为空）的 key，按表中顺序返回。
+pub fn missing_translations(lang: Language) -> Vec<&'static str> {
+    TEXTS
+        .iter()
+        .filter(|entry| translation(entry, lang).trim().is_empty())
+        .map(|entry| entry.0)
+        .collect()
+}
@@ -204,9 +204,8 @@ mod tests {
         let cli = Cli::parse_from(["cc-switch", "config", "webdav", "show"]);
 
         match cli.command {
-            Some(Commands::Config(super::commands::config::ConfigCommand::WebDav(
-                super::commands::config_webdav::WebDavCommand::Show,
-            ))) => {}
+            Some(Commands::Config(super::commands::config::ConfigCommand::WebDav(cmd)))
+                if matches!(*cmd, super::commands::config_webdav::WebDavCommand::Show) => {}
             _ => panic!("expected config webdav show command"),
         }
     }
@@ -227,16 +226,19 @@ mod tests {
             "--enable",
         ]);
 
-        match cli.command {
-            Some(Commands::Config(super::commands::config::ConfigCommand::WebDav(
-                super::commands::config_webdav::WebDavCommand::Set {
-                    base_url,
-                    username,
-                    password,
-                    enable,
-                    ..
-                },
-            ))) => {
+        let Some(Commands::Config(super::commands::config::ConfigCommand::WebDav(cmd))) =
+            cli.command
+        else {
+            panic!("expected config webdav set command");
+        };
+        match *cmd {
+            super::commands::config_webdav::WebDavCommand::Set {
+                base_url,
+                username,
+                password,
+                enable,
+                ..
+            } => {
                 assert_eq!(base_url.as_deref(), Some("https://dav.example.com/root"));
                 assert_eq!(username.as_deref(), Some("demo"));
                 assert_eq!(password.as_deref(), Some("secret"));
@@ -246,14 +248,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parses_config_webdav_enum_flags_into_settings_values() {
+        let cli = Cli::parse_from([
+            "cc-switch",
+            "config",
+            "webdav",
+            "set",
+            "--conflict-policy",
+            "prefer-remote",
+            "--skills-storage",
+            "tree",
+            "--compression",
+            "zstd",
+        ]);
+
+        let Some(Commands::Config(super::commands::config::ConfigCommand::WebDav(cmd))) =
+            cli.command
+        else {
+            panic!("expected config webdav set command");
+        };
+        match *cmd {
+            super::commands::config_webdav::WebDavCommand::Set {
+                conflict_policy: Some(policy),
+                skills_storage: Some(storage),
+                compression: Some(compression),
+                ..
+            } => {
+                assert_eq!(
+                    crate::ConflictPolicy::from(policy),
+                    crate::ConflictPolicy::PreferRemote
+                );
+                assert_eq!(
+                    crate::SkillsStorage::from(storage),
+                    crate::SkillsStorage::Tree
+                );
+                assert_eq!(
+                    crate::CompressionAlgo::from(compression),
+                    crate::CompressionAlgo::Zstd
+                );
+            }
+            _ => panic!("expected config webdav set command with enum flags"),
+        }
+    }
+
     #[test]
     fn parses_config_webdav_check_connection_subcommand() {
         let cli = Cli::parse_from(["cc-switch", "config", "webdav", "check-connection"]);
 
         match cli.command {
-            Some(Commands::Config(super::commands::config::ConfigCommand::WebDav(
-                super::commands::config_webdav::WebDavCommand::CheckConnection,
-            ))) => {}
+            Some(Commands::Config(super::commands::config::ConfigCommand::WebDav(cmd)))
+                if matches!(
+                    *cmd,
+                    super::commands::config_webdav::WebDavCommand::CheckConnection
+                ) => {}
             _ => panic!("expected config webdav check-connection command"),
         }
     }
@@ -321,7 +369,7 @@ mod tests {
         let cli = Cli::parse_from(["cc-switch", "env", "tools"]);
 
         match cli.command {
-            Some(Commands::Env(super::commands::env::EnvCommand::Tools)) => {}
+            Some(Commands::Env(super::commands::env::EnvCommand::Tools { .. })) => {}
             _ => panic!("expected env tools command"),
         }
     }
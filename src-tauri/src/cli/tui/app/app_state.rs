@@ -8,6 +8,11 @@ pub enum Action {
     Quit,
     SetAppType(AppType),
     LocalEnvRefresh,
+    /// 按设置的 viewer 用外部程序查看完整内容
+    ViewContent {
+        title: String,
+        content: String,
+    },
 
     SkillsToggle {
         directory: String,
@@ -434,6 +439,8 @@ pub struct App {
 
     pub local_env_results: Vec<crate::services::local_env_check::ToolCheckResult>,
     pub local_env_loading: bool,
+    /// 正在进行的模型拉取的取消标记，选择框关闭后置位
+    pub model_fetch_cancel: Option<Arc<AtomicBool>>,
 
     pub provider_idx: usize,
     pub mcp_idx: usize,
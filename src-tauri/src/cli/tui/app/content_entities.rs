@@ -291,8 +291,15 @@ impl App {
                 let Some(row) = visible.get(self.prompt_idx) else {
                     return Action::None;
                 };
+                let title = texts::tui_prompt_title(&row.prompt.name);
+                if crate::settings::get_viewer() != crate::settings::Viewer::Inline {
+                    return Action::ViewContent {
+                        title,
+                        content: row.prompt.content.clone(),
+                    };
+                }
                 self.overlay = Overlay::TextView(TextViewState {
-                    title: texts::tui_prompt_title(&row.prompt.name),
+                    title,
                     lines: row.prompt.content.lines().map(|s| s.to_string()).collect(),
                     scroll: 0,
                     action: None,
@@ -613,7 +613,7 @@ impl App {
             let existing_ids = collect_existing_provider_ids(data);
             provider
                 .id
-                .set(crate::cli::commands::provider_input::generate_provider_id(
+                .set(crate::services::provider::generate_provider_id(
                     provider.name.value.trim(),
                     &existing_ids,
                 ));
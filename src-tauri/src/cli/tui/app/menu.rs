@@ -37,6 +37,7 @@ impl App {
             proxy_visual_transition: None,
             local_env_results: Vec::new(),
             local_env_loading: true,
+            model_fetch_cancel: None,
             provider_idx: 0,
             mcp_idx: 0,
             prompt_idx: 0,
@@ -183,6 +184,21 @@ impl App {
                 self.proxy_visual_transition = None;
             }
         }
+
+        self.cancel_abandoned_model_fetch();
+    }
+
+    /// 模型选择框已关闭或不再等待结果时，通知后台停止尚未完成的拉取
+    pub(crate) fn cancel_abandoned_model_fetch(&mut self) {
+        if matches!(
+            self.overlay,
+            Overlay::ModelFetchPicker { fetching: true, .. }
+        ) {
+            return;
+        }
+        if let Some(cancel) = self.model_fetch_cancel.take() {
+            cancel.store(true, Ordering::Relaxed);
+        }
     }
 
     pub(crate) fn observe_proxy_visual_state(&mut self, data: &UiData) {
@@ -602,6 +602,32 @@ mod tests {
         assert!(app.proxy_visual_transition.is_none());
     }
 
+    #[test]
+    fn tick_cancels_model_fetch_once_picker_is_closed() {
+        let mut app = App::new(Some(AppType::Claude));
+        let cancel = Arc::new(AtomicBool::new(false));
+        app.model_fetch_cancel = Some(cancel.clone());
+        app.overlay = Overlay::ModelFetchPicker {
+            request_id: 1,
+            field: ProviderAddField::ClaudeModelConfig,
+            claude_idx: None,
+            input: String::new(),
+            query: String::new(),
+            fetching: true,
+            models: Vec::new(),
+            error: None,
+            selected_idx: 0,
+        };
+
+        app.on_tick();
+        assert!(!cancel.load(Ordering::Relaxed));
+
+        app.overlay = Overlay::None;
+        app.on_tick();
+        assert!(cancel.load(Ordering::Relaxed));
+        assert!(app.model_fetch_cancel.is_none());
+    }
+
     #[test]
     fn proxy_transition_stays_active_long_enough_for_flash_return_phase() {
         let mut app = App::new(Some(AppType::Claude));
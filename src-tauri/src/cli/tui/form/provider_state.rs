@@ -561,7 +561,6 @@ pub(crate) fn resolve_provider_id_for_submit(
         return Some(id.to_string());
     }
 
-    let generated_id =
-        crate::cli::commands::provider_input::generate_provider_id(name.trim(), existing_ids);
+    let generated_id = crate::services::provider::generate_provider_id(name.trim(), existing_ids);
     (!generated_id.trim().is_empty()).then_some(generated_id)
 }
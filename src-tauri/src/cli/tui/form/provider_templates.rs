@@ -250,7 +250,7 @@ impl ProviderAddFormState {
         }
 
         if !self.id_is_manual && !self.name.is_blank() {
-            let id = crate::cli::commands::provider_input::generate_provider_id(
+            let id = crate::services::provider::generate_provider_id(
                 self.name.value.trim(),
                 existing_ids,
             );
@@ -33,9 +33,8 @@ use runtime_skills::{
 pub(crate) use runtime_systems::build_stream_check_result_lines;
 #[cfg(test)]
 use runtime_systems::{
-    apply_webdav_jianguoyun_quick_setup, build_model_fetch_candidate_urls, drain_latest_webdav_req,
-    model_fetch_strategy_for_field, parse_model_ids_from_response, update_webdav_last_error_with,
-    ProxyReq, UpdateMsg, WebDavReq, WebDavReqKind,
+    apply_webdav_jianguoyun_quick_setup, drain_latest_webdav_req, model_fetch_strategy_for_field,
+    update_webdav_last_error_with, ProxyReq, UpdateMsg, WebDavReq, WebDavReqKind,
 };
 pub(crate) use runtime_systems::{fetch_provider_models_for_tui, ModelFetchStrategy};
 use runtime_systems::{
@@ -179,7 +178,9 @@ pub fn run(app_override: Option<AppType>) -> Result<(), AppError> {
 
     let local_env = match start_local_env_system() {
         Ok(system) => {
-            if let Err(err) = system.req_tx.send(LocalEnvReq::Refresh) {
+            if let Err(err) = system.req_tx.send(LocalEnvReq::Refresh {
+                app_type: app.app_type.clone(),
+            }) {
                 app.local_env_loading = false;
                 app.push_toast(
                     texts::tui_toast_local_env_check_request_failed(&err.to_string()),
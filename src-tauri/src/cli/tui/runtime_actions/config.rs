@@ -11,6 +11,7 @@ use super::super::runtime_systems::{WebDavReq, WebDavReqKind};
 use super::helpers::{
     export_target, open_proxy_help as open_proxy_help_overlay, refresh_common_snippet_overlay,
     refresh_openclaw_daily_memory_search_results, refresh_openclaw_workspace_data,
+    show_full_content,
 };
 use super::RuntimeActionContext;
 
@@ -36,13 +37,8 @@ pub(super) fn show_full(ctx: &mut RuntimeActionContext<'_>) -> Result<(), AppErr
         .trim_start_matches("👁️")
         .trim()
         .to_string();
-    ctx.app.overlay = Overlay::TextView(TextViewState {
-        title,
-        lines: content.lines().map(|s| s.to_string()).collect(),
-        scroll: 0,
-        action: None,
-    });
-    Ok(())
+    drop(config);
+    show_full_content(ctx, title, content)
 }
 
 pub(super) fn import(ctx: &mut RuntimeActionContext<'_>, path: String) -> Result<(), AppError> {
@@ -126,7 +122,7 @@ pub(super) fn validate(ctx: &mut RuntimeActionContext<'_>) -> Result<(), AppErro
     let gemini_count = db.get_all_providers("gemini")?.len();
     let mcp_count = db.get_all_mcp_servers()?.len();
 
-    let lines = vec![
+    let mut lines = vec![
         texts::tui_config_validation_ok().to_string(),
         String::new(),
         texts::tui_config_validation_provider_count(AppType::Claude.as_str(), claude_count),
@@ -134,6 +130,11 @@ pub(super) fn validate(ctx: &mut RuntimeActionContext<'_>) -> Result<(), AppErro
         texts::tui_config_validation_provider_count(AppType::Gemini.as_str(), gemini_count),
         texts::tui_config_validation_mcp_servers(mcp_count),
     ];
+    let duplicates = crate::cli::commands::config::duplicate_endpoint_warnings(&load_state()?);
+    if !duplicates.is_empty() {
+        lines.push(String::new());
+        lines.extend(duplicates);
+    }
     ctx.app.overlay = Overlay::TextView(TextViewState {
         title: texts::tui_config_validation_title().to_string(),
         lines,
@@ -6,10 +6,12 @@ use crate::cli::i18n::texts;
 use crate::commands::workspace;
 use crate::error::AppError;
 use crate::services::McpService;
+use crate::settings::Viewer;
 
 use super::super::app::{App, LoadingKind, Overlay, TextViewState, ToastKind};
 use super::super::data::{load_proxy_config, load_state, UiData};
 use super::super::runtime_systems::{ProxyReq, RequestTracker};
+use super::RuntimeActionContext;
 
 pub(crate) fn import_mcp_for_current_app_with<FImport, FLoad>(
     app: &mut App,
@@ -194,6 +196,25 @@ pub(super) fn text_view(title: String, content: String) -> Overlay {
     })
 }
 
+/// 按设置的 viewer 展示完整内容：外部程序可用时暂时交出终端，否则回落为 TextView
+pub(super) fn show_full_content(
+    ctx: &mut RuntimeActionContext<'_>,
+    title: String,
+    content: String,
+) -> Result<(), AppError> {
+    let viewer = crate::settings::get_viewer();
+    if viewer != Viewer::Inline {
+        let shown = ctx.terminal.with_terminal_restored(|| {
+            crate::cli::editor::view_in_external_program(viewer, &content)
+        })?;
+        if shown {
+            return Ok(());
+        }
+    }
+    ctx.app.overlay = text_view(title, content);
+    Ok(())
+}
+
 pub(super) fn open_proxy_help(app: &mut App, data: &UiData) -> Result<(), AppError> {
     open_proxy_help_overlay_with(app, data, load_proxy_config)
 }
@@ -160,7 +160,9 @@ pub(crate) fn handle_action(
             };
 
             ctx.app.local_env_loading = true;
-            if let Err(err) = tx.send(LocalEnvReq::Refresh) {
+            if let Err(err) = tx.send(LocalEnvReq::Refresh {
+                app_type: ctx.app.app_type.clone(),
+            }) {
                 ctx.app.local_env_loading = false;
                 ctx.app.push_toast(
                     texts::tui_toast_local_env_check_request_failed(&err.to_string()),
@@ -169,6 +171,9 @@ pub(crate) fn handle_action(
             }
             Ok(())
         }
+        Action::ViewContent { title, content } => {
+            helpers::show_full_content(&mut ctx, title, content)
+        }
         Action::SwitchRoute(route) => {
             ctx.app.route = route;
             Ok(())
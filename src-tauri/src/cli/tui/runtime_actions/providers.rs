@@ -1,4 +1,6 @@
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 use crate::cli::i18n::texts;
 use crate::cli::tui::form::ClaudeApiFormat;
@@ -490,6 +492,10 @@ pub(super) fn model_fetch(
         return Ok(());
     };
     let request_id = next_model_fetch_request_id();
+    let cancel = Arc::new(AtomicBool::new(false));
+    if let Some(previous) = ctx.app.model_fetch_cancel.replace(cancel.clone()) {
+        previous.store(true, Ordering::Relaxed);
+    }
 
     ctx.app.overlay = Overlay::ModelFetchPicker {
         request_id,
@@ -509,6 +515,7 @@ pub(super) fn model_fetch(
         api_key,
         field,
         claude_idx,
+        cancel,
     }) {
         if let Overlay::ModelFetchPicker {
             fetching, error, ..
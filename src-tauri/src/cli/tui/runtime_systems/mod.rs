@@ -8,14 +8,11 @@ pub(crate) use handlers::{
     handle_local_env_msg, handle_model_fetch_msg, handle_proxy_msg, handle_skills_msg,
     handle_speedtest_msg, handle_stream_check_msg, handle_update_msg, handle_webdav_msg,
 };
-#[cfg(test)]
-pub(crate) use types::{
-    build_model_fetch_candidate_urls, model_fetch_strategy_for_field,
-    parse_model_ids_from_response, UpdateMsg,
-};
 pub(crate) use types::{
     build_stream_check_result_lines, fetch_provider_models_for_tui, ModelFetchStrategy,
 };
+#[cfg(test)]
+pub(crate) use types::{model_fetch_strategy_for_field, UpdateMsg};
 pub(crate) use types::{
     next_model_fetch_request_id, LocalEnvReq, ModelFetchReq, ProxyReq, RequestTracker, SkillsReq,
     StreamCheckReq, UpdateReq, WebDavReq, WebDavReqKind,
@@ -1,14 +1,12 @@
-use std::collections::HashSet;
-use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::mpsc;
-use std::time::Duration;
-
-use serde_json::Value;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{mpsc, Arc};
 
 use crate::app_config::AppType;
 use crate::cli::i18n::texts;
 use crate::provider::Provider;
-use crate::services::{EndpointLatency, HealthStatus, StreamCheckResult, SyncDecision};
+use crate::services::{
+    EndpointLatency, HealthStatus, ModelFetchAuth, ProviderService, StreamCheckResult, SyncDecision,
+};
 
 use super::super::form::ProviderAddField;
 
@@ -40,7 +38,8 @@ pub(crate) enum StreamCheckMsg {
 }
 
 pub(crate) enum LocalEnvReq {
-    Refresh,
+    /// 重新检测本地工具；当前应用对应的工具不受 `disabled_env_tools` 影响
+    Refresh { app_type: AppType },
 }
 
 pub(crate) enum LocalEnvMsg {
@@ -196,6 +195,8 @@ pub(crate) enum ModelFetchReq {
         api_key: Option<String>,
         field: ProviderAddField,
         claude_idx: Option<usize>,
+        /// 模型选择框关闭或发起新的拉取时置位
+        cancel: Arc<AtomicBool>,
     },
 }
 
@@ -229,140 +230,21 @@ pub(crate) fn model_fetch_strategy_for_field(field: ProviderAddField) -> ModelFe
     }
 }
 
-pub(crate) fn build_model_fetch_candidate_urls(
-    base_url: &str,
-    strategy: ModelFetchStrategy,
-) -> Vec<String> {
-    let base = base_url.trim().trim_end_matches('/');
-    if base.is_empty() {
-        return Vec::new();
-    }
-    if base.ends_with("/models") {
-        return vec![base.to_string()];
-    }
-
-    let append_models = format!("{base}/models");
-    let append_v1_models = if base.ends_with("/v1") || base.ends_with("/v1beta") {
-        None
-    } else {
-        Some(format!("{base}/v1/models"))
-    };
-
-    let mut urls: Vec<String> = Vec::new();
-    match strategy {
-        ModelFetchStrategy::Anthropic => {
-            if let Some(v1) = append_v1_models.as_ref() {
-                urls.push(v1.clone());
-            }
-            urls.push(append_models);
-        }
-        ModelFetchStrategy::Bearer | ModelFetchStrategy::GoogleApiKey => {
-            urls.push(append_models);
-            if let Some(v1) = append_v1_models.as_ref() {
-                urls.push(v1.clone());
-            }
-        }
-    }
-
-    let mut seen = HashSet::new();
-    urls.retain(|url| seen.insert(url.clone()));
-    urls
-}
-
-pub(crate) fn parse_model_ids_from_response(payload: &Value) -> Vec<String> {
-    let mut out: Vec<String> = Vec::new();
-
-    if let Some(data) = payload.get("data").and_then(|v| v.as_array()) {
-        for item in data {
-            if let Some(id) = item.get("id").and_then(|v| v.as_str()) {
-                out.push(id.to_string());
-            }
-        }
-    }
-
-    if out.is_empty() {
-        if let Some(models) = payload.get("models").and_then(|v| v.as_array()) {
-            for item in models {
-                if let Some(name) = item.get("name").and_then(|v| v.as_str()) {
-                    out.push(name.strip_prefix("models/").unwrap_or(name).to_string());
-                }
-            }
-        }
-    }
-
-    if out.is_empty() {
-        if let Some(arr) = payload.as_array() {
-            for item in arr {
-                if let Some(id) = item.get("id").and_then(|v| v.as_str()) {
-                    out.push(id.to_string());
-                }
-            }
-        }
-    }
-
-    let mut seen = HashSet::new();
-    out.retain(|model| seen.insert(model.clone()));
-    out
-}
-
+/// 拉取模型列表；`cancel` 置位后在下一个候选地址之前停止，总时限沿用服务层默认值
 pub(crate) async fn fetch_provider_models_for_tui(
     base_url: &str,
     api_key: Option<&str>,
     strategy: ModelFetchStrategy,
+    cancel: Option<&AtomicBool>,
 ) -> Result<Vec<String>, String> {
-    let candidate_urls = build_model_fetch_candidate_urls(base_url, strategy);
-    if candidate_urls.is_empty() {
-        return Err("URL cannot be empty".to_string());
-    }
-
-    let client = reqwest::Client::builder()
-        .timeout(Duration::from_secs(5))
-        .build()
-        .map_err(|e| format!("build http client failed: {e}"))?;
-
-    let key = api_key.map(str::trim).filter(|k| !k.is_empty());
-    let mut last_err = String::from("unknown error");
-
-    for url in candidate_urls {
-        let mut req = client.get(&url);
-        if let Some(key) = key {
-            req = match strategy {
-                ModelFetchStrategy::Bearer => req.header("Authorization", format!("Bearer {key}")),
-                ModelFetchStrategy::Anthropic => req
-                    .header("Authorization", format!("Bearer {key}"))
-                    .header("x-api-key", key)
-                    .header("anthropic-version", "2023-06-01"),
-                ModelFetchStrategy::GoogleApiKey => req.header("x-goog-api-key", key),
-            };
-        }
-
-        match req.send().await {
-            Ok(resp) => {
-                if !resp.status().is_success() {
-                    last_err = format!("HTTP {} ({url})", resp.status());
-                    continue;
-                }
-                match resp.json::<Value>().await {
-                    Ok(payload) => {
-                        let models = parse_model_ids_from_response(&payload);
-                        if models.is_empty() {
-                            last_err = format!("No model list found in response ({url})");
-                        } else {
-                            return Ok(models);
-                        }
-                    }
-                    Err(err) => {
-                        last_err = format!("Invalid JSON response ({url}): {err}");
-                    }
-                }
-            }
-            Err(err) => {
-                last_err = err.to_string();
-            }
-        }
-    }
-
-    Err(last_err)
+    let auth = match strategy {
+        ModelFetchStrategy::Bearer => ModelFetchAuth::Bearer,
+        ModelFetchStrategy::Anthropic => ModelFetchAuth::Anthropic,
+        ModelFetchStrategy::GoogleApiKey => ModelFetchAuth::GoogleApiKey,
+    };
+    ProviderService::fetch_provider_models(base_url, api_key, auth, None, cancel)
+        .await
+        .map_err(|e| e.to_string())
 }
 
 #[derive(Debug, Default, Clone, Copy)]
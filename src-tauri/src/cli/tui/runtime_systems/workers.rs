@@ -429,13 +429,15 @@ fn model_fetch_worker_loop(rx: mpsc::Receiver<ModelFetchReq>, tx: mpsc::Sender<M
             api_key,
             field,
             claude_idx,
+            cancel,
         } = req;
         let strategy = model_fetch_strategy_for_field(field);
-        let result = rt
-            .block_on(async {
-                fetch_provider_models_for_tui(&base_url, api_key.as_deref(), strategy).await
-            })
-            .map_err(|e| e.to_string());
+        let result = rt.block_on(fetch_provider_models_for_tui(
+            &base_url,
+            api_key.as_deref(),
+            strategy,
+            Some(&cancel),
+        ));
 
         let _ = tx.send(ModelFetchMsg::Finished {
             request_id,
@@ -472,8 +474,9 @@ fn local_env_worker_loop(rx: mpsc::Receiver<LocalEnvReq>, tx: mpsc::Sender<Local
         }
 
         match req {
-            LocalEnvReq::Refresh => {
-                let result = crate::services::local_env_check::check_local_environment();
+            LocalEnvReq::Refresh { app_type } => {
+                let result =
+                    crate::services::local_env_check::check_local_environment(Some(&app_type));
                 let _ = tx.send(LocalEnvMsg::Finished { result });
             }
         }
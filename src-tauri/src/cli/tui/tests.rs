@@ -3,7 +3,6 @@ use std::{ffi::OsString, path::Path};
 
 use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use ratatui::{buffer::Buffer, layout::Rect};
-use serde_json::json;
 use serial_test::serial;
 use tempfile::TempDir;
 
@@ -590,33 +589,6 @@ fn model_fetch_strategy_matches_provider_field() {
     );
 }
 
-#[test]
-fn model_fetch_candidate_urls_prefers_v1_for_anthropic_base() {
-    let urls = build_model_fetch_candidate_urls(
-        "https://api.anthropic.com",
-        ModelFetchStrategy::Anthropic,
-    );
-    assert_eq!(
-        urls,
-        vec![
-            "https://api.anthropic.com/v1/models".to_string(),
-            "https://api.anthropic.com/models".to_string()
-        ]
-    );
-}
-
-#[test]
-fn model_fetch_candidate_urls_for_gemini_v1beta_keeps_models_endpoint() {
-    let urls = build_model_fetch_candidate_urls(
-        "https://generativelanguage.googleapis.com/v1beta",
-        ModelFetchStrategy::GoogleApiKey,
-    );
-    assert_eq!(
-        urls,
-        vec!["https://generativelanguage.googleapis.com/v1beta/models".to_string()]
-    );
-}
-
 #[test]
 #[serial(home_settings)]
 fn startup_hidden_requested_app_bootstrap_uses_visible_app_normalization_before_loading_data() {
@@ -641,30 +613,3 @@ fn startup_hidden_requested_app_bootstrap_uses_visible_app_normalization_before_
     assert_eq!(loaded_app_type, Some(AppType::OpenCode));
     assert_eq!(app.app_type, AppType::OpenCode);
 }
-
-#[test]
-fn parse_model_ids_supports_multiple_shapes_and_dedups_stably() {
-    let data_payload = json!({
-        "data": [
-            {"id": "gpt-4o"},
-            {"id": "gpt-4o-mini"},
-            {"id": "gpt-4o"},
-            {"id": "o3"}
-        ]
-    });
-    assert_eq!(
-        parse_model_ids_from_response(&data_payload),
-        vec!["gpt-4o", "gpt-4o-mini", "o3"]
-    );
-
-    let gemini_payload = json!({
-        "models": [
-            {"name": "models/gemini-2.0-pro"},
-            {"name": "models/gemini-2.0-flash"}
-        ]
-    });
-    assert_eq!(
-        parse_model_ids_from_response(&gemini_payload),
-        vec!["gemini-2.0-pro", "gemini-2.0-flash"]
-    );
-}
@@ -518,14 +518,20 @@ fn render_local_env_check_card(
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(rows[1]);
 
-    let cells = [
-        (LocalTool::Claude, "Claude", cols0[0]),
-        (LocalTool::Codex, "Codex", cols0[1]),
-        (LocalTool::Gemini, "Gemini", cols1[0]),
-        (LocalTool::OpenCode, "OpenCode", cols1[1]),
-    ];
-
-    for (tool, display_name, cell_area) in cells {
+    // 检测过之后只展示实际检测的工具（`disabled_env_tools` 中的会被跳过），依次填入网格
+    let tools = [
+        (LocalTool::Claude, "Claude"),
+        (LocalTool::Codex, "Codex"),
+        (LocalTool::Gemini, "Gemini"),
+        (LocalTool::OpenCode, "OpenCode"),
+    ]
+    .into_iter()
+    .filter(|(tool, _)| {
+        app.local_env_results.is_empty() || app.local_env_results.iter().any(|r| r.tool == *tool)
+    });
+    let cells = tools.zip([cols0[0], cols0[1], cols1[0], cols1[1]]);
+
+    for ((tool, display_name), cell_area) in cells {
         let status = if app.local_env_loading {
             None
         } else {
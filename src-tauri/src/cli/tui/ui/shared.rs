@@ -1,5 +1,7 @@
 use super::*;
 
+pub(super) use crate::services::provider::mask_api_key;
+
 pub(super) fn pane_border_style(app: &App, pane: Focus, theme: &super::theme::Theme) -> Style {
     if app.focus == pane {
         Style::default()
@@ -319,16 +321,6 @@ where
     max.saturating_add(left_padding)
 }
 
-pub(super) fn mask_api_key(key: &str) -> String {
-    let mut iter = key.chars();
-    let prefix: String = iter.by_ref().take(8).collect();
-    if iter.next().is_some() {
-        format!("{prefix}...")
-    } else {
-        prefix
-    }
-}
-
 pub(super) fn redacted_secret_placeholder() -> &'static str {
     "[redacted]"
 }
@@ -3017,6 +3017,9 @@ fn backup_picker_overlay_shows_hint() {
         path: std::path::PathBuf::from("/tmp/b1.json"),
         timestamp: "20260131_000000".to_string(),
         display_name: "backup".to_string(),
+        created_at: 1_769_817_600,
+        size: 0,
+        label: None,
     }];
 
     let buf = render(&app, &data);
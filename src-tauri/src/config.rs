@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
+use std::ffi::OsString;
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 
+use tempfile::TempDir;
+
 use crate::error::AppError;
 
 pub(crate) fn home_dir() -> Option<PathBuf> {
@@ -82,6 +85,63 @@ pub fn get_app_config_path() -> PathBuf {
     get_app_config_dir().join("config.json")
 }
 
+/// 临时目录覆盖的环境变量，优先于设置中的 `temp_dir`
+pub const TMPDIR_OVERRIDE_ENV: &str = "CC_SWITCH_TMPDIR";
+
+/// 获取临时目录覆盖：`CC_SWITCH_TMPDIR` 优先，其次是设置中的 `temp_dir`
+pub fn get_temp_dir_override() -> Option<PathBuf> {
+    temp_dir_override_from(
+        std::env::var_os(TMPDIR_OVERRIDE_ENV),
+        crate::settings::get_settings().temp_dir.as_deref(),
+    )
+}
+
+fn temp_dir_override_from(env: Option<OsString>, setting: Option<&str>) -> Option<PathBuf> {
+    env.filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| setting.map(crate::settings::resolve_override_path))
+}
+
+/// 创建临时目录：配置了覆盖时在覆盖目录下创建（不存在则先创建），否则使用系统临时目录。
+///
+/// 显式配置的覆盖目录不可用时直接报错而不回落到系统临时目录，避免大文件悄悄写回小分区。
+/// 错误信息会带上尝试的路径，并提示如何用 `CC_SWITCH_TMPDIR` 换到空间更充足的磁盘。
+pub fn create_temp_dir(context_zh: &str, context_en: &str) -> Result<TempDir, AppError> {
+    create_temp_dir_with(get_temp_dir_override(), context_zh, context_en)
+}
+
+fn create_temp_dir_with(
+    override_dir: Option<PathBuf>,
+    context_zh: &str,
+    context_en: &str,
+) -> Result<TempDir, AppError> {
+    let (result, attempted, hint_zh, hint_en) = match override_dir {
+        Some(dir) => (
+            fs::create_dir_all(&dir).and_then(|()| tempfile::tempdir_in(&dir)),
+            dir,
+            format!("请确认 {TMPDIR_OVERRIDE_ENV} 或设置中的 temp_dir 指向可写目录"),
+            format!(
+                "check that {TMPDIR_OVERRIDE_ENV} or the temp_dir setting points to a writable directory"
+            ),
+        ),
+        None => (
+            tempfile::tempdir(),
+            std::env::temp_dir(),
+            format!("若磁盘已满，可用 {TMPDIR_OVERRIDE_ENV} 或设置中的 temp_dir 指向空间更充足的目录"),
+            format!(
+                "if the disk is full, set {TMPDIR_OVERRIDE_ENV} or the temp_dir setting to a directory on a roomier disk"
+            ),
+        ),
+    };
+    result.map_err(|source| AppError::IoContext {
+        context: format!(
+            "{context_zh}: {path}，{hint_zh} ({context_en} in {path}; {hint_en})",
+            path = attempted.display()
+        ),
+        source,
+    })
+}
+
 /// 清理供应商名称，确保文件名安全
 pub fn sanitize_provider_name(name: &str) -> String {
     name.chars()
@@ -159,6 +219,8 @@ pub fn atomic_write(path: &Path, data: &[u8]) -> Result<(), AppError> {
         let mut f = fs::File::create(&tmp).map_err(|e| AppError::io(&tmp, e))?;
         f.write_all(data).map_err(|e| AppError::io(&tmp, e))?;
         f.flush().map_err(|e| AppError::io(&tmp, e))?;
+        // rename 前先落盘，避免掉电后目标文件指向未写完的数据
+        f.sync_all().map_err(|e| AppError::io(&tmp, e))?;
     }
 
     #[cfg(unix)]
@@ -192,6 +254,39 @@ pub fn atomic_write(path: &Path, data: &[u8]) -> Result<(), AppError> {
     Ok(())
 }
 
+/// 多文件原子写：先记录每个文件的原始内容（或不存在），执行 `f`；
+/// 若 `f` 失败，则把所有文件恢复到执行前的状态，避免出现只写了一半的情况
+pub fn with_files_rollback<T>(
+    paths: &[PathBuf],
+    f: impl FnOnce() -> Result<T, AppError>,
+) -> Result<T, AppError> {
+    let mut originals: Vec<(&PathBuf, Option<Vec<u8>>)> = Vec::with_capacity(paths.len());
+    for path in paths {
+        let bytes = if path.exists() {
+            Some(fs::read(path).map_err(|e| AppError::io(path, e))?)
+        } else {
+            None
+        };
+        originals.push((path, bytes));
+    }
+
+    match f() {
+        Ok(value) => Ok(value),
+        Err(err) => {
+            for (path, bytes) in originals {
+                let restored = match bytes {
+                    Some(bytes) => atomic_write(path, &bytes),
+                    None => delete_file(path),
+                };
+                if let Err(restore_err) = restored {
+                    log::error!("回滚文件失败: {}: {restore_err}", path.display());
+                }
+            }
+            Err(err)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,6 +320,76 @@ mod tests {
         let override_dir = PathBuf::from("/");
         assert!(derive_mcp_path_from_override(&override_dir).is_none());
     }
+
+    #[test]
+    fn temp_dir_override_prefers_env_over_setting() {
+        assert_eq!(
+            temp_dir_override_from(Some(OsString::from("/mnt/big/tmp")), Some("/data/tmp")),
+            Some(PathBuf::from("/mnt/big/tmp"))
+        );
+        assert_eq!(
+            temp_dir_override_from(Some(OsString::new()), Some("/data/tmp")),
+            Some(PathBuf::from("/data/tmp"))
+        );
+        assert_eq!(temp_dir_override_from(None, None), None);
+    }
+
+    #[test]
+    fn create_temp_dir_uses_override_directory() {
+        let root = tempfile::tempdir().expect("tempdir");
+        let override_dir = root.path().join("roomy").join("tmp");
+
+        let tmp = create_temp_dir_with(Some(override_dir.clone()), "创建", "Create")
+            .expect("create temp dir in override");
+
+        assert!(tmp.path().starts_with(&override_dir));
+        assert!(tmp.path().is_dir());
+    }
+
+    #[test]
+    fn create_temp_dir_reports_unusable_override_path() {
+        let root = tempfile::tempdir().expect("tempdir");
+        let blocker = root.path().join("not-a-dir");
+        fs::write(&blocker, "file").expect("write blocker file");
+        let override_dir = blocker.join("tmp");
+
+        let err = create_temp_dir_with(
+            Some(override_dir.clone()),
+            "创建快照临时目录失败",
+            "Failed to create snapshot temp dir",
+        )
+        .expect_err("override below a regular file should fail");
+
+        let message = err.to_string();
+        assert!(
+            message.contains(&override_dir.display().to_string()),
+            "{message}"
+        );
+        assert!(message.contains(TMPDIR_OVERRIDE_ENV), "{message}");
+        assert!(
+            message.contains("Failed to create snapshot temp dir"),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn with_files_rollback_restores_every_file_on_failure() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let existing = dir.path().join("existing.json");
+        let created = dir.path().join("created.md");
+        fs::write(&existing, "original").expect("seed existing file");
+
+        let result: Result<(), AppError> =
+            with_files_rollback(&[existing.clone(), created.clone()], || {
+                write_text_file(&existing, "changed")?;
+                write_text_file(&created, "new")?;
+                Err(AppError::Config("boom".to_string()))
+            });
+
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(&existing).unwrap(), "original");
+        assert!(!created.exists(), "files absent before should be removed");
+    }
 }
 
 /// 复制文件
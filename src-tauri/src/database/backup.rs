@@ -7,6 +7,7 @@ use crate::config::get_app_config_dir;
 use crate::error::AppError;
 use chrono::Utc;
 use rusqlite::backup::Backup;
+use rusqlite::hooks::{AuthAction, AuthContext, Authorization};
 use rusqlite::types::ValueRef;
 use rusqlite::Connection;
 use std::fs;
@@ -15,6 +16,13 @@ use tempfile::NamedTempFile;
 
 const CC_SWITCH_SQL_EXPORT_HEADER: &str = "-- CC Switch SQLite 导出";
 
+/// 部分导出的首行。与完整导出不同，旧版本校验文件头时会拒绝它，
+/// 而不是当作完整备份导入、清空本地数据
+const CC_SWITCH_SQL_PARTIAL_EXPORT_HEADER: &str = "-- CC Switch SQLite 部分导出";
+
+/// 部分导出在文件头中记录所含分区的注释前缀，如 `-- sections: mcp,prompts`
+const SQL_EXPORT_SECTIONS_HEADER: &str = "-- sections:";
+
 const SYNC_SKIP_TABLES: &[&str] = &[
     "proxy_request_logs",
     "stream_check_logs",
@@ -30,16 +38,85 @@ const SYNC_PRESERVE_TABLES: &[&str] = &[
     "usage_daily_rollups",
 ];
 
+/// 同步导入后核对行数的实体表（不含本地保留的日志 / 统计表）
+const SYNC_VERIFY_TABLES: &[&str] = &[
+    "providers",
+    "provider_endpoints",
+    "mcp_servers",
+    "prompts",
+    "skills",
+    "skill_repos",
+];
+
+/// 上传前判断本地数据库是否为空时统计的内容表
+const SYNC_CONTENT_TABLES: &[&str] = &["providers", "mcp_servers", "prompts"];
+
+/// 合并导入时属于本机状态的列：导入的新行置为 0，已有行保留本地值
+const MERGE_LOCAL_STATE_COLUMNS: &[(&str, &str)] = &[
+    ("providers", "is_current"),
+    ("providers", "in_failover_queue"),
+    ("prompts", "enabled"),
+];
+
+/// 使用自增 ID 的表：合并时不沿用导出文件中的 ID，按业务键跳过本地已有的行
+const MERGE_NATURAL_KEYS: &[(&str, &str, &[&str])] = &[(
+    "provider_endpoints",
+    "id",
+    &["provider_id", "app_type", "url"],
+)];
+
+/// 同步导入后行数与导入 SQL 不一致的实体表
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RowCountMismatch {
+    pub table: &'static str,
+    pub expected: i64,
+    pub actual: i64,
+}
+
+impl std::fmt::Display for RowCountMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} -> {}", self.table, self.expected, self.actual)
+    }
+}
+
 impl Database {
+    /// 供应商、MCP 服务器与提示词的总行数，上传前用于识别意外为空的数据库
+    pub(crate) fn sync_content_count(&self) -> Result<u64, AppError> {
+        let conn = lock_conn!(self.conn);
+        let mut total = 0u64;
+        for &table in SYNC_CONTENT_TABLES {
+            if !Self::table_exists(&conn, table)? {
+                continue;
+            }
+            let count: i64 = conn
+                .query_row(&format!("SELECT COUNT(*) FROM \"{table}\""), [], |row| {
+                    row.get(0)
+                })
+                .map_err(|e| AppError::Database(e.to_string()))?;
+            total += count.max(0) as u64;
+        }
+        Ok(total)
+    }
+
     /// 导出为 SQL 字符串（内存操作，不写文件）
     pub fn export_sql_string(&self) -> Result<String, AppError> {
         let snapshot = self.snapshot_to_memory()?;
-        Self::dump_sql(&snapshot, &[])
+        Self::dump_sql(&snapshot, |_| true, &[])
     }
 
     pub fn export_sql_string_for_sync(&self) -> Result<String, AppError> {
         let snapshot = self.snapshot_to_memory()?;
-        Self::dump_sql(&snapshot, SYNC_SKIP_TABLES)
+        Self::dump_sql(&snapshot, |table| !SYNC_SKIP_TABLES.contains(&table), &[])
+    }
+
+    /// 部分导出：保留完整表结构，但只导出 `tables` 中的数据，并在文件头记录 `sections`
+    pub fn export_sql_string_partial(
+        &self,
+        sections: &[&str],
+        tables: &[&str],
+    ) -> Result<String, AppError> {
+        let snapshot = self.snapshot_to_memory()?;
+        Self::dump_sql(&snapshot, |table| tables.contains(&table), sections)
     }
 
     /// 导出为 SQLite 兼容的 SQL 文本文件
@@ -68,7 +145,7 @@ impl Database {
         preserve_tables: &[&str],
     ) -> Result<String, AppError> {
         let sql_content = sql_raw.trim_start_matches('\u{feff}');
-        Self::validate_cc_switch_sql_export(sql_content)?;
+        Self::validate_cc_switch_sql_export(sql_content, false)?;
 
         // 导入前备份现有数据库
         let backup_path = self.backup_database_file()?;
@@ -88,9 +165,7 @@ impl Database {
         let temp_conn =
             Connection::open(&temp_path).map_err(|e| AppError::Database(e.to_string()))?;
 
-        temp_conn
-            .execute_batch(sql_content)
-            .map_err(|e| AppError::Database(format!("执行 SQL 导入失败: {e}")))?;
+        Self::execute_sql_export(&temp_conn, sql_content)?;
 
         // 补齐缺失表/索引并进行基础校验
         Self::create_tables_on_conn(&temp_conn)?;
@@ -130,6 +205,328 @@ impl Database {
         self.import_sql_string(&sql_raw)
     }
 
+    /// 部分导出文件头记录的分区列表（逗号分隔的原始文本）；完整导出返回 `None`
+    pub(crate) fn sql_export_sections(sql_raw: &str) -> Option<&str> {
+        let sql = sql_raw.trim_start_matches('\u{feff}').trim_start();
+        if !sql.starts_with(CC_SWITCH_SQL_PARTIAL_EXPORT_HEADER) {
+            return None;
+        }
+        let sections = sql
+            .lines()
+            .take_while(|line| line.starts_with("--"))
+            .find_map(|line| line.strip_prefix(SQL_EXPORT_SECTIONS_HEADER))
+            .map(str::trim);
+        // 缺少分区行的部分导出交给分区解析报错，不能当作完整导出
+        Some(sections.unwrap_or_default())
+    }
+
+    /// 将部分导出中 `tables` 的数据合并进当前数据库，返回生成的备份 ID（若无备份则为空字符串）。
+    ///
+    /// 主键相同的行以导出文件为准，其余本地数据保持不变；
+    /// 当前供应商等本机状态不会被导入的行改变。任一表合并失败时整体回滚。
+    pub fn merge_sql_string(&self, sql_raw: &str, tables: &[&str]) -> Result<String, AppError> {
+        let source = Self::load_sql_export_in_memory(sql_raw)?;
+        // 旧版本导出的表先迁移到当前结构，保证与主库的列一致
+        Self::create_tables_on_conn(&source)?;
+        Self::apply_schema_migrations_on_conn(&source)?;
+        for &(table, column) in MERGE_LOCAL_STATE_COLUMNS {
+            if tables.contains(&table) {
+                source
+                    .execute(&format!("UPDATE \"{table}\" SET \"{column}\" = 0"), [])
+                    .map_err(|e| AppError::Database(e.to_string()))?;
+            }
+        }
+
+        let backup_path = self.backup_database_file()?;
+
+        {
+            let mut conn = lock_conn!(self.conn);
+            let tx = conn
+                .transaction()
+                .map_err(|e| AppError::Database(e.to_string()))?;
+            for table in tables {
+                Self::merge_table(&source, &tx, table)?;
+            }
+            tx.commit().map_err(|e| AppError::Database(e.to_string()))?;
+        }
+
+        Ok(backup_path
+            .and_then(|p| p.file_stem().map(|s| s.to_string_lossy().to_string()))
+            .unwrap_or_default())
+    }
+
+    fn merge_table(
+        source_conn: &Connection,
+        target_conn: &Connection,
+        table: &str,
+    ) -> Result<(), AppError> {
+        if !Self::table_exists(source_conn, table)? || !Self::table_exists(target_conn, table)? {
+            return Ok(());
+        }
+
+        let natural_key = MERGE_NATURAL_KEYS
+            .iter()
+            .find(|(name, _, _)| *name == table);
+        let target_columns = Self::get_table_columns(target_conn, table)?;
+        let columns: Vec<String> = Self::get_table_columns(source_conn, table)?
+            .into_iter()
+            .filter(|column| target_columns.contains(column))
+            .filter(|column| natural_key.is_none_or(|(_, id, _)| column != id))
+            .collect();
+        if columns.is_empty() {
+            return Ok(());
+        }
+
+        let cols = columns
+            .iter()
+            .map(|column| format!("\"{column}\""))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let placeholders = (1..=columns.len())
+            .map(|idx| format!("?{idx}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let insert_sql = match natural_key {
+            Some((_, _, keys)) => {
+                let mut conditions = Vec::with_capacity(keys.len());
+                for key in keys.iter() {
+                    let idx = columns
+                        .iter()
+                        .position(|column| column == key)
+                        .ok_or_else(|| AppError::Database(format!("表 {table} 缺少列 {key}")))?;
+                    conditions.push(format!("\"{key}\" IS ?{}", idx + 1));
+                }
+                format!(
+                    "INSERT INTO \"{table}\" ({cols}) SELECT {placeholders} \
+                     WHERE NOT EXISTS (SELECT 1 FROM \"{table}\" WHERE {})",
+                    conditions.join(" AND ")
+                )
+            }
+            None => {
+                let updates = columns
+                    .iter()
+                    .filter(|column| !MERGE_LOCAL_STATE_COLUMNS.contains(&(table, column.as_str())))
+                    .map(|column| format!("\"{column}\" = excluded.\"{column}\""))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!(
+                    "INSERT INTO \"{table}\" ({cols}) VALUES ({placeholders}) \
+                     ON CONFLICT DO UPDATE SET {updates}"
+                )
+            }
+        };
+
+        let mut stmt = source_conn
+            .prepare(&format!("SELECT {cols} FROM \"{table}\""))
+            .map_err(|e| AppError::Database(format!("读取表 {table} 失败: {e}")))?;
+        let mut rows = stmt
+            .query([])
+            .map_err(|e| AppError::Database(format!("查询表 {table} 数据失败: {e}")))?;
+
+        while let Some(row) = rows.next().map_err(|e| AppError::Database(e.to_string()))? {
+            let mut values = Vec::with_capacity(columns.len());
+            for idx in 0..columns.len() {
+                values.push(
+                    row.get::<_, rusqlite::types::Value>(idx)
+                        .map_err(|e| AppError::Database(e.to_string()))?,
+                );
+            }
+
+            target_conn
+                .execute(&insert_sql, rusqlite::params_from_iter(values.iter()))
+                .map_err(|e| AppError::Database(format!("合并表 {table} 数据失败: {e}")))?;
+        }
+
+        Ok(())
+    }
+
+    /// 在独立的内存库中逐条执行 CC Switch SQL 导出，不触碰主库。
+    ///
+    /// 用于导入前检查文件内容；任一语句失败时，错误信息包含出错语句所在行号。
+    pub(crate) fn load_sql_export_in_memory(sql_raw: &str) -> Result<Connection, AppError> {
+        let sql = sql_raw.trim_start_matches('\u{feff}');
+        Self::validate_cc_switch_sql_export(sql, true)?;
+
+        let conn = Connection::open_in_memory().map_err(|e| AppError::Database(e.to_string()))?;
+        Self::execute_sql_export(&conn, sql)?;
+        Ok(conn)
+    }
+
+    /// 逐条执行 SQL 导出中的语句。
+    ///
+    /// 导出文件可能来自他人或任意 URL，执行前逐条检查语句类型，只允许导出本身会生成的
+    /// 建表 / 建索引、INSERT、事务控制与 `PRAGMA user_version` / `foreign_keys`，
+    /// 拒绝 ATTACH、其他 PRAGMA、触发器与视图等可能写到别处或改变连接行为的语句。
+    fn execute_sql_export(conn: &Connection, sql: &str) -> Result<(), AppError> {
+        // 关键字检查之外再由 SQLite 在编译语句时拦截，执行完毕后移除，不影响之后的迁移
+        conn.authorizer(Some(Self::sql_export_authorizer));
+        let result = Self::execute_sql_export_statements(conn, sql);
+        conn.authorizer(None::<fn(AuthContext<'_>) -> Authorization>);
+        result
+    }
+
+    /// 拒绝 ATTACH / DETACH、触发器、视图、虚拟表，以及主库以外的库和其他 PRAGMA
+    fn sql_export_authorizer(ctx: AuthContext<'_>) -> Authorization {
+        if ctx.database_name.is_some_and(|name| name != "main") {
+            return Authorization::Deny;
+        }
+        match ctx.action {
+            AuthAction::Attach { .. }
+            | AuthAction::Detach { .. }
+            | AuthAction::CreateTrigger { .. }
+            | AuthAction::CreateTempTrigger { .. }
+            | AuthAction::CreateView { .. }
+            | AuthAction::CreateTempView { .. }
+            | AuthAction::CreateVtable { .. } => Authorization::Deny,
+            AuthAction::Pragma { pragma_name, .. }
+                if !pragma_name.eq_ignore_ascii_case("user_version")
+                    && !pragma_name.eq_ignore_ascii_case("foreign_keys") =>
+            {
+                Authorization::Deny
+            }
+            _ => Authorization::Allow,
+        }
+    }
+
+    fn execute_sql_export_statements(conn: &Connection, sql: &str) -> Result<(), AppError> {
+        let mut batch = rusqlite::Batch::new(conn, sql);
+        // 已成功执行部分在原文中的结束位置，用于推算出错行号
+        let mut consumed = 0usize;
+        loop {
+            let mut stmt = match batch.next() {
+                Ok(Some(stmt)) => stmt,
+                Ok(None) => break,
+                Err(rusqlite::Error::SqliteFailure(e, _))
+                    if e.code == rusqlite::ErrorCode::AuthorizationForStatementDenied =>
+                {
+                    let rest = &sql[consumed..];
+                    let start = consumed + (rest.len() - rest.trim_start().len());
+                    return Err(Self::disallowed_statement_error(sql, start, &sql[start..]));
+                }
+                Err(e) => return Err(Self::sql_export_error(sql, consumed, e)),
+            };
+            let text = stmt.expanded_sql().unwrap_or_default();
+            let text = text.trim();
+            let start = sql[consumed..]
+                .find(text)
+                .map(|pos| consumed + pos)
+                .unwrap_or(consumed);
+            if !Self::is_allowed_export_statement(text) {
+                return Err(Self::disallowed_statement_error(sql, start, text));
+            }
+            if let Err(e) = stmt.raw_execute() {
+                return Err(Self::sql_export_error(sql, start, e));
+            }
+            consumed = start + text.len();
+        }
+        Ok(())
+    }
+
+    /// 按语句开头的关键字判断是否为 [`Self::dump_sql`] 会生成的语句
+    fn is_allowed_export_statement(text: &str) -> bool {
+        let mut body = text.trim_start();
+        loop {
+            if let Some(rest) = body.strip_prefix("--") {
+                body = rest
+                    .split_once('\n')
+                    .map_or("", |(_, rest)| rest)
+                    .trim_start();
+            } else if let Some(rest) = body.strip_prefix("/*") {
+                body = rest
+                    .split_once("*/")
+                    .map_or("", |(_, rest)| rest)
+                    .trim_start();
+            } else {
+                break;
+            }
+        }
+
+        let words: Vec<String> = body
+            .split(|c: char| c.is_whitespace() || matches!(c, '(' | '=' | ';'))
+            .filter(|word| !word.is_empty())
+            .take(3)
+            .map(|word| word.to_ascii_uppercase())
+            .collect();
+        let words: Vec<&str> = words.iter().map(String::as_str).collect();
+        matches!(
+            words.as_slice(),
+            ["CREATE", "TABLE" | "INDEX", ..]
+                | ["CREATE", "UNIQUE", "INDEX"]
+                | ["INSERT", "INTO", ..]
+                | ["BEGIN" | "COMMIT", ..]
+                | ["PRAGMA", "USER_VERSION" | "FOREIGN_KEYS", ..]
+        )
+    }
+
+    fn disallowed_statement_error(sql: &str, offset: usize, text: &str) -> AppError {
+        let line = sql[..offset].matches('\n').count() + 1;
+        let preview: String = text
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with("--"))
+            .unwrap_or_default()
+            .chars()
+            .take(60)
+            .collect();
+        AppError::localized(
+            "backup.sql.statement_not_allowed",
+            format!("SQL 导出文件第 {line} 行包含不允许的语句: {preview}"),
+            format!("SQL export contains a disallowed statement at line {line}: {preview}"),
+        )
+    }
+
+    /// 同步导入后重新导出数据库，核对实体表行数是否与导入的 `expected_sql` 一致。
+    ///
+    /// 返回行数不一致的表（一致时为空），提示文案由调用方按界面语言生成。
+    pub(crate) fn verify_sync_import(
+        &self,
+        expected_sql: &str,
+    ) -> Result<Vec<RowCountMismatch>, AppError> {
+        let expected = Self::sql_export_row_counts(expected_sql)?;
+        let actual = Self::sql_export_row_counts(&self.export_sql_string_for_sync()?)?;
+
+        Ok(expected
+            .iter()
+            .zip(&actual)
+            .filter(|((_, want), (_, got))| want != got)
+            .map(|(&(table, expected), &(_, actual))| RowCountMismatch {
+                table,
+                expected,
+                actual,
+            })
+            .collect())
+    }
+
+    /// 统计 SQL 导出中 [`SYNC_VERIFY_TABLES`] 各表的行数，缺失的表记为 0
+    fn sql_export_row_counts(sql_raw: &str) -> Result<Vec<(&'static str, i64)>, AppError> {
+        let conn = Self::load_sql_export_in_memory(sql_raw)?;
+        SYNC_VERIFY_TABLES
+            .iter()
+            .map(|&table| {
+                if !Self::table_exists(&conn, table)? {
+                    return Ok((table, 0));
+                }
+                let count = conn
+                    .query_row(&format!("SELECT COUNT(*) FROM \"{table}\""), [], |row| {
+                        row.get(0)
+                    })
+                    .map_err(|e| AppError::Database(e.to_string()))?;
+                Ok((table, count))
+            })
+            .collect()
+    }
+
+    fn sql_export_error(sql: &str, offset: usize, err: rusqlite::Error) -> AppError {
+        let rest = &sql[offset..];
+        let stmt_start = offset + (rest.len() - rest.trim_start().len());
+        let line = sql[..stmt_start].matches('\n').count() + 1;
+        AppError::localized(
+            "backup.sql.parse_failed",
+            format!("SQL 导出文件第 {line} 行解析失败: {err}"),
+            format!("Failed to parse SQL export at line {line}: {err}"),
+        )
+    }
+
     /// 创建内存快照以避免长时间持有数据库锁
     pub(crate) fn snapshot_to_memory(&self) -> Result<Connection, AppError> {
         let conn = lock_conn!(self.conn);
@@ -147,11 +544,22 @@ impl Database {
         Ok(snapshot)
     }
 
-    fn validate_cc_switch_sql_export(sql: &str) -> Result<(), AppError> {
+    /// 校验文件头；`allow_partial` 为假时拒绝部分导出，避免把它当作完整备份覆盖本地数据
+    fn validate_cc_switch_sql_export(sql: &str, allow_partial: bool) -> Result<(), AppError> {
         let trimmed = sql.trim_start();
         if trimmed.starts_with(CC_SWITCH_SQL_EXPORT_HEADER) {
             return Ok(());
         }
+        if trimmed.starts_with(CC_SWITCH_SQL_PARTIAL_EXPORT_HEADER) {
+            if allow_partial {
+                return Ok(());
+            }
+            return Err(AppError::localized(
+                "backup.sql.partial_export",
+                "这是部分导出文件，只能合并导入，不能作为完整备份恢复。",
+                "This is a partial export; it can only be merged, not restored as a full backup.",
+            ));
+        }
 
         Err(AppError::localized(
             "backup.sql.invalid_format",
@@ -304,17 +712,32 @@ impl Database {
         Ok(())
     }
 
-    /// 导出数据库为 SQL 文本
-    fn dump_sql(conn: &Connection, skip_data_tables: &[&str]) -> Result<String, AppError> {
+    /// 导出数据库为 SQL 文本；`include_data` 决定导出哪些表的数据，`sections` 非空时写入部分导出文件头
+    fn dump_sql(
+        conn: &Connection,
+        include_data: impl Fn(&str) -> bool,
+        sections: &[&str],
+    ) -> Result<String, AppError> {
         let mut output = String::new();
         let timestamp = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
         let user_version: i64 = conn
             .query_row("PRAGMA user_version;", [], |row| row.get(0))
             .unwrap_or(0);
 
+        let header = if sections.is_empty() {
+            CC_SWITCH_SQL_EXPORT_HEADER
+        } else {
+            CC_SWITCH_SQL_PARTIAL_EXPORT_HEADER
+        };
         output.push_str(&format!(
-            "-- CC Switch SQLite 导出\n-- 生成时间: {timestamp}\n-- user_version: {user_version}\n"
+            "{header}\n-- 生成时间: {timestamp}\n-- user_version: {user_version}\n"
         ));
+        if !sections.is_empty() {
+            output.push_str(&format!(
+                "{SQL_EXPORT_SECTIONS_HEADER} {}\n",
+                sections.join(",")
+            ));
+        }
         output.push_str("PRAGMA foreign_keys=OFF;\n");
         output.push_str(&format!("PRAGMA user_version={user_version};\n"));
         output.push_str("BEGIN TRANSACTION;\n");
@@ -353,7 +776,7 @@ impl Database {
 
         // 导出数据
         for table in tables {
-            if skip_data_tables.iter().any(|skip| *skip == table) {
+            if !include_data(&table) {
                 continue;
             }
 
@@ -437,7 +860,7 @@ impl Database {
 
 #[cfg(test)]
 mod tests {
-    use super::Database;
+    use super::{Database, RowCountMismatch};
     use crate::error::AppError;
 
     #[test]
@@ -526,4 +949,207 @@ mod tests {
 
         Ok(())
     }
+
+    fn insert_provider(db: &Database, id: &str) -> Result<(), AppError> {
+        let conn = crate::database::lock_conn!(db.conn);
+        conn.execute(
+            "INSERT INTO providers (id, app_type, name, settings_config, meta)
+             VALUES (?1, 'claude', ?1, '{}', '{}')",
+            [id],
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn merge_partial_export_keeps_local_state_and_skips_duplicate_endpoints() -> Result<(), AppError>
+    {
+        let remote_db = Database::memory()?;
+        insert_provider(&remote_db, "p1")?;
+        {
+            let conn = crate::database::lock_conn!(remote_db.conn);
+            conn.execute(
+                "UPDATE providers SET name = 'Remote P1', is_current = 1 WHERE id = 'p1'",
+                [],
+            )?;
+            conn.execute(
+                "INSERT INTO provider_endpoints (provider_id, app_type, url, added_at)
+                 VALUES ('p1', 'claude', 'https://relay.example', 1)",
+                [],
+            )?;
+        }
+        let remote_sql = remote_db
+            .export_sql_string_partial(&["providers"], &["providers", "provider_endpoints"])?;
+        assert_eq!(
+            Database::sql_export_sections(&remote_sql),
+            Some("providers")
+        );
+
+        let local_db = Database::memory()?;
+        insert_provider(&local_db, "p0")?;
+        insert_provider(&local_db, "p1")?;
+        local_db.set_current_provider("claude", "p0")?;
+
+        local_db.merge_sql_string(&remote_sql, &["providers", "provider_endpoints"])?;
+        local_db.merge_sql_string(&remote_sql, &["providers", "provider_endpoints"])?;
+
+        let conn = crate::database::lock_conn!(local_db.conn);
+        let (name, is_current): (String, bool) = conn.query_row(
+            "SELECT name, is_current FROM providers WHERE id = 'p1'",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        assert_eq!(name, "Remote P1", "bundle rows should win on conflict");
+        assert!(!is_current, "imported rows must not become current");
+        let current: String =
+            conn.query_row("SELECT id FROM providers WHERE is_current = 1", [], |row| {
+                row.get(0)
+            })?;
+        assert_eq!(current, "p0");
+        let endpoints: i64 =
+            conn.query_row("SELECT COUNT(*) FROM provider_endpoints", [], |row| {
+                row.get(0)
+            })?;
+        assert_eq!(endpoints, 1, "merging twice must not duplicate endpoints");
+        Ok(())
+    }
+
+    #[test]
+    fn partial_export_is_rejected_as_full_backup_and_keeps_enabled_prompt() -> Result<(), AppError>
+    {
+        let remote_db = Database::memory()?;
+        {
+            let conn = crate::database::lock_conn!(remote_db.conn);
+            conn.execute(
+                "INSERT INTO prompts (id, app_type, name, content, enabled)
+                 VALUES ('remote', 'claude', 'Remote', 'remote prompt', 1)",
+                [],
+            )?;
+        }
+        let remote_sql = remote_db.export_sql_string_partial(&["prompts"], &["prompts"])?;
+        // 旧版本只认完整导出的文件头，部分导出必须在校验阶段就被拒绝
+        assert!(!remote_sql.starts_with(super::CC_SWITCH_SQL_EXPORT_HEADER));
+
+        let local_db = Database::memory()?;
+        {
+            let conn = crate::database::lock_conn!(local_db.conn);
+            conn.execute(
+                "INSERT INTO prompts (id, app_type, name, content, enabled)
+                 VALUES ('local', 'claude', 'Local', 'local prompt', 1)",
+                [],
+            )?;
+        }
+        let err = local_db
+            .import_sql_string(&remote_sql)
+            .expect_err("partial export must not be restored as a full backup");
+        assert!(err.to_string().contains("partial export"), "{err}");
+
+        local_db.merge_sql_string(&remote_sql, &["prompts"])?;
+        let conn = crate::database::lock_conn!(local_db.conn);
+        let enabled: Vec<String> = conn
+            .prepare("SELECT id FROM prompts WHERE enabled = 1 ORDER BY id")?
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<_, _>>()?;
+        assert_eq!(
+            enabled,
+            ["local"],
+            "imported prompts must not become enabled"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn sql_export_rejects_statements_the_exporter_never_writes() -> Result<(), AppError> {
+        let source = Database::memory()?;
+        insert_provider(&source, "p1")?;
+        let sql = source.export_sql_string()?;
+        let tmp = tempfile::tempdir().expect("create temp dir");
+        let attached = tmp.path().join("attached.db");
+
+        for injected in [
+            format!(
+                "ATTACH DATABASE '{}' AS x;\nCREATE TABLE x.t(a);",
+                attached.display()
+            ),
+            "PRAGMA writable_schema=ON;".to_string(),
+            "CREATE TRIGGER t AFTER INSERT ON providers BEGIN DELETE FROM providers; END;"
+                .to_string(),
+            "CREATE VIEW v AS SELECT * FROM providers;".to_string(),
+        ] {
+            let hostile = sql.replacen("COMMIT;\n", &format!("{injected}\nCOMMIT;\n"), 1);
+            assert_ne!(hostile, sql);
+
+            let err = Database::load_sql_export_in_memory(&hostile)
+                .expect_err("hostile statement must be rejected");
+            assert!(
+                matches!(&err, AppError::Localized { key, .. } if *key == "backup.sql.statement_not_allowed"),
+                "{injected}: {err}"
+            );
+            let target = Database::memory()?;
+            assert!(target.import_sql_string(&hostile).is_err(), "{injected}");
+        }
+        assert!(!attached.exists(), "ATTACH must never create files");
+
+        Database::load_sql_export_in_memory(&sql)?;
+        Ok(())
+    }
+
+    #[test]
+    fn sql_export_authorizer_blocks_attach_even_when_keyword_check_is_bypassed() {
+        let conn = rusqlite::Connection::open_in_memory().expect("open memory db");
+        let tmp = tempfile::tempdir().expect("create temp dir");
+        let attached = tmp.path().join("attached.db");
+
+        conn.authorizer(Some(Database::sql_export_authorizer));
+        let attach = conn.execute_batch(&format!(
+            "ATTACH DATABASE '{}' AS x; CREATE TABLE x.t(a);",
+            attached.display()
+        ));
+        assert!(attach.is_err(), "ATTACH must be denied by the authorizer");
+        assert!(conn.execute_batch("PRAGMA writable_schema=ON;").is_err());
+        conn.execute_batch("PRAGMA user_version=3; CREATE TABLE t(a); INSERT INTO t VALUES (1);")
+            .expect("export statements stay allowed");
+        assert!(!attached.exists());
+    }
+
+    #[test]
+    fn verify_sync_import_passes_when_counts_match() -> Result<(), AppError> {
+        let remote_db = Database::memory()?;
+        insert_provider(&remote_db, "p1")?;
+        insert_provider(&remote_db, "p2")?;
+        let remote_sql = remote_db.export_sql_string_for_sync()?;
+
+        let local_db = Database::memory()?;
+        local_db.import_sql_string_for_sync(&remote_sql)?;
+
+        assert!(local_db.verify_sync_import(&remote_sql)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn verify_sync_import_warns_when_rows_went_missing() -> Result<(), AppError> {
+        let remote_db = Database::memory()?;
+        insert_provider(&remote_db, "p1")?;
+        insert_provider(&remote_db, "p2")?;
+        let remote_sql = remote_db.export_sql_string_for_sync()?;
+
+        let local_db = Database::memory()?;
+        local_db.import_sql_string_for_sync(&remote_sql)?;
+        // 模拟导入静默丢行
+        {
+            let conn = crate::database::lock_conn!(local_db.conn);
+            conn.execute("DELETE FROM providers WHERE id = 'p2'", [])?;
+        }
+
+        let mismatches = local_db.verify_sync_import(&remote_sql)?;
+        assert_eq!(
+            mismatches,
+            vec![RowCountMismatch {
+                table: "providers",
+                expected: 2,
+                actual: 1,
+            }]
+        );
+        assert_eq!(mismatches[0].to_string(), "providers: 2 -> 1");
+        Ok(())
+    }
 }
@@ -52,6 +52,43 @@ pub enum AppError {
         zh: String,
         en: String,
     },
+    /// 下载内容与声明的大小/哈希不一致：数据本身有问题，重试不会成功
+    #[error(
+        "artifact {artifact} {} (expected: {expected}, got: {actual}) (Artifact {artifact} {})",
+        .kind.zh(),
+        .kind.en()
+    )]
+    IntegrityMismatch {
+        artifact: String,
+        kind: IntegrityKind,
+        expected: String,
+        actual: String,
+    },
+}
+
+/// [`AppError::IntegrityMismatch`] 中不一致的校验项
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityKind {
+    /// 字节数不一致，`expected` / `actual` 为十进制字节数
+    Size,
+    /// SHA256 不一致，`expected` / `actual` 为十六进制摘要
+    Sha256,
+}
+
+impl IntegrityKind {
+    fn zh(self) -> &'static str {
+        match self {
+            Self::Size => "大小不匹配",
+            Self::Sha256 => "SHA256 校验失败",
+        }
+    }
+
+    fn en(self) -> &'static str {
+        match self {
+            Self::Size => "size mismatch",
+            Self::Sha256 => "SHA256 verification failed",
+        }
+    }
 }
 
 impl AppError {
@@ -83,6 +120,28 @@ impl AppError {
             en: en.into(),
         }
     }
+
+    pub fn size_mismatch(artifact: impl Into<String>, expected: u64, actual: u64) -> Self {
+        Self::IntegrityMismatch {
+            artifact: artifact.into(),
+            kind: IntegrityKind::Size,
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        }
+    }
+
+    pub fn hash_mismatch(
+        artifact: impl Into<String>,
+        expected: impl Into<String>,
+        actual: impl Into<String>,
+    ) -> Self {
+        Self::IntegrityMismatch {
+            artifact: artifact.into(),
+            kind: IntegrityKind::Sha256,
+            expected: expected.into(),
+            actual: actual.into(),
+        }
+    }
 }
 
 impl<T> From<PoisonError<T>> for AppError {
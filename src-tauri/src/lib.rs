@@ -12,6 +12,7 @@ mod gemini_config;
 mod gemini_mcp;
 mod import_export;
 mod init_status;
+pub mod logging;
 mod mcp;
 mod openclaw_config;
 mod opencode_config;
@@ -41,7 +42,7 @@ pub use codex_config::{get_codex_auth_path, get_codex_config_path, write_codex_l
 pub use config::{get_claude_mcp_path, get_claude_settings_path, read_json_file};
 pub use database::{Database, FailoverQueueItem};
 pub use deeplink::{import_provider_from_deeplink, parse_deeplink_url, DeepLinkImportRequest};
-pub use error::AppError;
+pub use error::{AppError, IntegrityKind};
 pub use import_export::export_config_to_file;
 pub use mcp::{
     import_from_claude, import_from_codex, import_from_gemini, remove_server_from_claude,
@@ -52,14 +53,22 @@ pub use mcp::{
 pub use provider::{Provider, ProviderMeta};
 pub use proxy::{ProxyConfig, ProxyServerInfo, ProxyStatus};
 pub use services::{
-    ConfigService, EndpointLatency, HealthStatus, McpService, PromptService, ProviderService,
-    ProxyService, SkillService, SpeedtestService, StreamCheckConfig, StreamCheckResult,
-    StreamCheckService, SyncDecision, WebDavSyncService, WebDavSyncSummary,
+    ConfigSections, ConfigService, DiagnosticCheck, DiagnosticReport, DiagnosticStatus,
+    DiagnosticStep, DuplicateGroup, DuplicateMember, EndpointLatency, HealthStatus, KeyStatus,
+    McpService, ModelTestApiStyle, ModelTestResult, PreflightArtifact, PreflightReport,
+    PromptService, ProviderDetails, ProviderService, ProxyService, SkillService, SkillsDiff,
+    SkillsRestoreRecovery, SpeedtestService, StreamCheckConfig, StreamCheckResult,
+    StreamCheckService, SyncDecision, SyncMethod, SyncScheduler, WebDavSyncService,
+    WebDavSyncSummary, WritableStatus,
 };
 pub use settings::{
-    get_enable_claude_plugin_integration, get_skip_claude_onboarding, get_webdav_sync_settings,
-    set_enable_claude_plugin_integration, set_skip_claude_onboarding, set_webdav_sync_settings,
-    update_settings, update_webdav_sync_status, webdav_jianguoyun_preset, AppSettings,
-    WebDavSyncSettings, WebDavSyncStatus,
+    export_template, get_active_webdav_target, get_enable_claude_plugin_integration,
+    get_skip_claude_onboarding, get_switch_resets_live, get_webdav_sync_settings,
+    get_webdav_target, list_webdav_targets, machine_uuid, set_active_webdav_target,
+    set_enable_claude_plugin_integration, set_skip_claude_onboarding, set_switch_resets_live,
+    set_webdav_sync_settings, settings_health, update_settings, update_webdav_sync_status,
+    upsert_webdav_target, webdav_jianguoyun_preset, AppSettings, CompressionAlgo, ConflictPolicy,
+    CustomEndpoint, LogLevel, SettingsHealth, SkillsStorage, WebDavSyncSettings, WebDavSyncStatus,
+    DEFAULT_WEBDAV_TARGET,
 };
 pub use store::AppState;
@@ -0,0 +1,131 @@
+//! 进程日志初始化。
+//!
+//! 全局过滤规则来自 `RUST_LOG`（未设置时使用调用方给出的默认值）；设置中配置了
+//! `sync_log_level` 时，WebDAV 同步相关模块额外受其控制，可在运行时调整，无需重新设置环境变量。
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+
+use log::{LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+use crate::settings::LogLevel;
+
+/// 受 `sync_log_level` 控制的模块（含其子模块）
+const SYNC_LOG_TARGETS: &[&str] = &[
+    concat!(env!("CARGO_CRATE_NAME"), "::services::webdav"),
+    concat!(env!("CARGO_CRATE_NAME"), "::services::webdav_sync"),
+];
+
+/// 未配置 `sync_log_level` 时的取值，同步模块完全按全局规则过滤
+const SYNC_LEVEL_UNSET: usize = usize::MAX;
+
+static SYNC_LEVEL: AtomicUsize = AtomicUsize::new(SYNC_LEVEL_UNSET);
+/// `RUST_LOG` / 默认值解析出的全局最高级别，安装日志后才有值
+static BASE_MAX_LEVEL: OnceLock<LevelFilter> = OnceLock::new();
+
+/// 按全局过滤规则输出，同步模块的记录在 `sync_log_level` 允许时总是输出
+struct SyncAwareLogger {
+    /// 仅用于按 `RUST_LOG` 判断是否输出，不直接写日志
+    filter: env_logger::Logger,
+    /// 接受所有级别的实际输出端
+    writer: env_logger::Logger,
+}
+
+impl Log for SyncAwareLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        sync_allows(metadata) || self.filter.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if sync_allows(record.metadata()) || self.filter.matches(record) {
+            self.writer.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.writer.flush();
+    }
+}
+
+/// 安装全局日志，输出到 stderr；`default_filter` 在未设置 `RUST_LOG` 时生效
+pub fn init(default_filter: &str) -> Result<(), SetLoggerError> {
+    init_with_target(default_filter, env_logger::Target::Stderr)
+}
+
+/// 同 [`init`]，但输出到指定目标（如测试中收集日志的管道）
+pub fn init_with_target(
+    default_filter: &str,
+    target: env_logger::Target,
+) -> Result<(), SetLoggerError> {
+    let filter =
+        env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(default_filter))
+            .build();
+    let writer = env_logger::Builder::new()
+        .filter_level(LevelFilter::Trace)
+        .target(target)
+        .build();
+    let base = filter.filter();
+    log::set_boxed_logger(Box::new(SyncAwareLogger { filter, writer }))?;
+    let _ = BASE_MAX_LEVEL.set(base);
+    set_sync_log_level(crate::settings::get_settings().sync_log_level);
+    Ok(())
+}
+
+/// 调整同步模块的日志级别，立即生效；`None` 表示跟随 `RUST_LOG` / `-v`
+pub fn set_sync_log_level(level: Option<LogLevel>) {
+    let level = level.map(LogLevel::to_level_filter);
+    SYNC_LEVEL.store(
+        level.map_or(SYNC_LEVEL_UNSET, |level| level as usize),
+        Ordering::Relaxed,
+    );
+    if let Some(base) = BASE_MAX_LEVEL.get() {
+        log::set_max_level(level.map_or(*base, |level| (*base).max(level)));
+    }
+}
+
+fn sync_level() -> Option<LevelFilter> {
+    LevelFilter::iter().nth(SYNC_LEVEL.load(Ordering::Relaxed))
+}
+
+fn sync_allows(metadata: &Metadata) -> bool {
+    is_sync_target(metadata.target()) && sync_level().is_some_and(|level| metadata.level() <= level)
+}
+
+fn is_sync_target(target: &str) -> bool {
+    SYNC_LOG_TARGETS.iter().any(|module| {
+        target
+            .strip_prefix(module)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sync_targets_include_submodules_but_not_lookalikes() {
+        assert!(is_sync_target("cc_switch_lib::services::webdav"));
+        assert!(is_sync_target("cc_switch_lib::services::webdav_sync"));
+        assert!(is_sync_target(
+            "cc_switch_lib::services::webdav_sync::archive"
+        ));
+        assert!(!is_sync_target("cc_switch_lib::services::webdav_syncx"));
+        assert!(!is_sync_target("cc_switch_lib::services::provider"));
+    }
+
+    #[test]
+    fn level_filter_round_trips_through_the_atomic() {
+        for level in [
+            LogLevel::Off,
+            LogLevel::Warn,
+            LogLevel::Trace,
+            LogLevel::Info,
+        ] {
+            set_sync_log_level(Some(level));
+            assert_eq!(sync_level(), Some(level.to_level_filter()));
+        }
+        set_sync_log_level(None);
+        assert_eq!(sync_level(), None);
+    }
+}
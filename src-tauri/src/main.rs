@@ -1,3 +1,4 @@
+use cc_switch_lib::cli::commands::config::ConfigCommand;
 use cc_switch_lib::cli::{Cli, Commands};
 use cc_switch_lib::AppError;
 use clap::Parser;
@@ -13,7 +14,7 @@ fn main() {
     } else {
         "error" // 默认只显示错误日志，避免 INFO 日志干扰命令输出
     };
-    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(log_level)).init();
+    let _ = cc_switch_lib::logging::init(log_level);
 
     // 执行命令
     if let Err(e) = run(cli) {
@@ -25,6 +26,7 @@ fn main() {
 fn run(cli: Cli) -> Result<(), AppError> {
     if !matches!(cli.command, Some(Commands::Completions { .. })) {
         let _state = cc_switch_lib::AppState::try_new_with_startup_recovery()?;
+        warn_settings_health(&cli.command);
     }
 
     match cli.command {
@@ -48,3 +50,14 @@ fn run(cli: Cli) -> Result<(), AppError> {
         }
     }
 }
+
+/// 默认日志级别下 log::warn! 不会输出，设置文件过大的提示直接打印到 stderr；
+/// `config validate` 会自行列出这些提示
+fn warn_settings_health(command: &Option<Commands>) {
+    if matches!(command, Some(Commands::Config(ConfigCommand::Validate))) {
+        return;
+    }
+    for line in cc_switch_lib::settings_health().warnings() {
+        eprintln!("{}", cc_switch_lib::cli::ui::warning(&line));
+    }
+}
@@ -1370,3 +1370,46 @@ pub fn remove_server_from_opencode(id: &str) -> Result<(), AppError> {
 
     crate::opencode_config::remove_mcp_server(id)
 }
+
+/// 指定应用存放 MCP 服务器的 live 配置文件路径（OpenClaw 不托管 MCP，返回 None）
+pub fn live_config_path(app: &AppType) -> Option<std::path::PathBuf> {
+    match app {
+        AppType::Claude => Some(crate::config::get_claude_mcp_path()),
+        AppType::Codex => Some(crate::codex_config::get_codex_config_path()),
+        AppType::Gemini => Some(crate::gemini_config::get_gemini_settings_path()),
+        AppType::OpenCode => Some(crate::opencode_config::get_opencode_config_path()),
+        AppType::OpenClaw => None,
+    }
+}
+
+/// 列出指定应用 live 配置中当前存在的 MCP 服务器 ID（包括未由 cc-switch 托管的条目）
+pub fn live_server_ids(app: &AppType) -> Result<Vec<String>, AppError> {
+    let ids = match app {
+        AppType::Claude => crate::claude_mcp::read_mcp_servers_map()?
+            .into_keys()
+            .collect(),
+        AppType::Codex => {
+            let text = crate::codex_config::read_codex_config_text()?;
+            if text.trim().is_empty() {
+                Vec::new()
+            } else {
+                let path = crate::codex_config::get_codex_config_path();
+                let root: toml::Table =
+                    toml::from_str(&text).map_err(|e| AppError::toml(&path, e))?;
+                root.get("mcp_servers")
+                    .and_then(|v| v.as_table())
+                    .map(|servers| servers.keys().cloned().collect())
+                    .unwrap_or_default()
+            }
+        }
+        AppType::Gemini => crate::gemini_mcp::read_mcp_servers_map()?
+            .into_keys()
+            .collect(),
+        AppType::OpenCode => crate::opencode_config::get_mcp_servers()?
+            .keys()
+            .cloned()
+            .collect(),
+        AppType::OpenClaw => Vec::new(),
+    };
+    Ok(ids)
+}
@@ -1,15 +1,20 @@
 use super::provider::ProviderService;
+use super::{webdav, webdav_sync};
 use crate::app_config::{AppType, MultiAppConfig};
 use crate::database::Database;
 use crate::error::AppError;
 use crate::provider::Provider;
 use crate::store::AppState;
-use chrono::Utc;
+use chrono::{NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use sha2::{Digest, Sha256};
 use std::fs;
 use std::path::{Path, PathBuf};
 
 const MAX_BACKUPS: usize = 10;
+/// 备份元数据旁路文件后缀：`{backup_id}.meta.json`
+const BACKUP_META_SUFFIX: &str = ".meta.json";
 
 /// 备份信息
 #[derive(Debug, Clone)]
@@ -22,6 +27,196 @@ pub struct BackupInfo {
     pub timestamp: String,
     /// 显示名称（用于 UI）
     pub display_name: String,
+    /// 创建时间（Unix 秒）
+    pub created_at: i64,
+    /// 备份文件大小（字节）
+    pub size: u64,
+    /// 创建时附加的标签（旧备份没有元数据时为 None）
+    pub label: Option<String>,
+}
+
+/// 与备份 SQL 同目录保存的元数据，用于列表展示与恢复前的完整性校验
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BackupMeta {
+    created_at: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    size: u64,
+    sha256: String,
+}
+
+/// 导出文件（CC Switch SQL 导出）的检查结果，生成过程中不会修改任何本地数据
+#[derive(Debug, Clone)]
+pub struct BundleReport {
+    /// 导出文件记录的数据库 schema 版本（`PRAGMA user_version`）
+    pub schema_version: i32,
+    /// 当前程序支持的 schema 版本
+    pub supported_schema_version: i32,
+    /// 导出时间（取自文件头注释，旧文件可能没有）
+    pub exported_at: Option<String>,
+    pub providers: usize,
+    pub mcp_servers: usize,
+    pub prompts: usize,
+    /// 部分导出包含的分区；完整导出为 `None`
+    pub sections: Option<ConfigSections>,
+    /// 不影响解析、但导入前值得注意的问题
+    pub warnings: Vec<String>,
+}
+
+/// 部分导出时选择的配置分区，导入时只合并这些分区，其余本地数据保持不变
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConfigSections {
+    /// 供应商（含自定义端点）
+    pub providers: bool,
+    /// MCP 服务器
+    pub mcp: bool,
+    /// 提示词
+    pub prompts: bool,
+}
+
+/// 分区名（写入导出文件头）与其包含的数据表，按导入顺序排列
+const CONFIG_SECTION_TABLES: &[(&str, &[&str])] = &[
+    ("providers", &["providers", "provider_endpoints"]),
+    ("mcp", &["mcp_servers"]),
+    ("prompts", &["prompts"]),
+];
+
+impl ConfigSections {
+    /// 解析逗号分隔的分区名，如 `mcp,prompts`
+    pub fn parse(value: &str) -> Result<Self, AppError> {
+        let mut sections = Self::default();
+        for name in value
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+        {
+            match name {
+                "providers" => sections.providers = true,
+                "mcp" => sections.mcp = true,
+                "prompts" => sections.prompts = true,
+                other => {
+                    return Err(AppError::localized(
+                        "config.sections.unknown",
+                        format!("未知的配置分区: {other}（可选 providers、mcp、prompts）"),
+                        format!(
+                            "Unknown config section: {other} (expected providers, mcp, prompts)"
+                        ),
+                    ))
+                }
+            }
+        }
+        if sections.is_empty() {
+            return Err(AppError::localized(
+                "config.sections.empty",
+                "至少需要选择一个配置分区",
+                "Select at least one config section",
+            ));
+        }
+        Ok(sections)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !(self.providers || self.mcp || self.prompts)
+    }
+
+    /// 已选择的分区名，按固定顺序排列
+    pub fn names(&self) -> Vec<&'static str> {
+        CONFIG_SECTION_TABLES
+            .iter()
+            .map(|(name, _)| *name)
+            .filter(|name| self.contains(name))
+            .collect()
+    }
+
+    fn tables(&self) -> Vec<&'static str> {
+        CONFIG_SECTION_TABLES
+            .iter()
+            .filter(|(name, _)| self.contains(name))
+            .flat_map(|(_, tables)| tables.iter().copied())
+            .collect()
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        match name {
+            "providers" => self.providers,
+            "mcp" => self.mcp,
+            "prompts" => self.prompts,
+            _ => false,
+        }
+    }
+}
+
+/// 供应商在配置中的可比较指纹：base_url 与 API Key 的哈希（不含明文 Key）。
+///
+/// 用于检测 live 配置是否被手动改动而与 cc-switch 记录的当前供应商不一致。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderFingerprint {
+    /// 去掉末尾 `/` 后的 base_url
+    pub base_url: Option<String>,
+    /// API Key 的 SHA256 前 12 位十六进制
+    pub key_hash: Option<String>,
+}
+
+impl ProviderFingerprint {
+    /// 从 `settings_config` 形状的配置中提取指纹；累加模式应用（OpenCode/OpenClaw）
+    /// 的 live 文件同时包含多个供应商，不存在单一“当前供应商”，返回 `None`。
+    fn from_settings(app_type: &AppType, settings: &Value) -> Option<Self> {
+        let (base_url, key) = match app_type {
+            AppType::Claude => {
+                let env = settings.get("env");
+                let field = |name: &str| env.and_then(|e| e.get(name)).and_then(Value::as_str);
+                (
+                    field("ANTHROPIC_BASE_URL").map(str::to_string),
+                    field("ANTHROPIC_AUTH_TOKEN").or_else(|| field("ANTHROPIC_API_KEY")),
+                )
+            }
+            AppType::Codex => {
+                let base_url = settings
+                    .get("config")
+                    .and_then(Value::as_str)
+                    .and_then(codex_base_url);
+                let key = settings
+                    .get("auth")
+                    .and_then(|a| a.get("OPENAI_API_KEY"))
+                    .and_then(Value::as_str);
+                (base_url, key)
+            }
+            AppType::Gemini => {
+                let env = settings.get("env");
+                let field = |name: &str| env.and_then(|e| e.get(name)).and_then(Value::as_str);
+                (
+                    field("GOOGLE_GEMINI_BASE_URL").map(str::to_string),
+                    field("GEMINI_API_KEY"),
+                )
+            }
+            AppType::OpenCode | AppType::OpenClaw => return None,
+        };
+
+        let fingerprint = Self {
+            base_url: base_url
+                .map(|url| url.trim().trim_end_matches('/').to_string())
+                .filter(|url| !url.is_empty()),
+            key_hash: key
+                .map(str::trim)
+                .filter(|key| !key.is_empty())
+                .map(|key| format!("{:x}", Sha256::digest(key.as_bytes()))[..12].to_string()),
+        };
+        (fingerprint.base_url.is_some() || fingerprint.key_hash.is_some()).then_some(fingerprint)
+    }
+}
+
+/// 读取 Codex config.toml 中当前 model_provider 的 base_url（没有时回退到顶层 base_url）
+fn codex_base_url(config_toml: &str) -> Option<String> {
+    let table: toml::Table = config_toml.parse().ok()?;
+    let provider_url = table
+        .get("model_provider")
+        .and_then(|v| v.as_str())
+        .and_then(|name| table.get("model_providers")?.get(name)?.get("base_url"))
+        .and_then(|v| v.as_str());
+    provider_url
+        .or_else(|| table.get("base_url").and_then(|v| v.as_str()))
+        .map(str::to_string)
 }
 
 /// 配置导入导出相关业务逻辑
@@ -32,11 +227,13 @@ impl ConfigService {
     ///
     /// # 参数
     /// - `config_path`: 兼容参数（忽略），保留给旧调用方
-    /// - `custom_name`: 可选的自定义名称
+    /// - `custom_name`: 可选的自定义名称，同时原样记录为备份标签
     ///
     /// # 命名规则
-    /// - 有自定义名称：`{custom_name}_{timestamp}.sql`
+    /// - 有自定义名称：`{custom_name}_{timestamp}.sql`（名称中不适合做文件名的字符替换为 `_`）
     /// - 无自定义名称：`backup_{timestamp}.sql`
+    ///
+    /// 同时写入 `{backup_id}.meta.json`，记录创建时间、标签、大小与 SHA256。
     pub fn create_backup(
         config_path: &Path,
         custom_name: Option<String>,
@@ -46,9 +243,13 @@ impl ConfigService {
             return Ok(String::new());
         }
 
-        let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
-        let backup_id = if let Some(name) = custom_name {
-            format!("{}_{}", name, timestamp)
+        let now = Utc::now();
+        let timestamp = now.format("%Y%m%d_%H%M%S");
+        let label = custom_name
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty());
+        let backup_id = if let Some(name) = &label {
+            format!("{}_{}", Self::sanitize_backup_name(name), timestamp)
         } else {
             format!("backup_{}", timestamp)
         };
@@ -65,6 +266,15 @@ impl ConfigService {
         let db = Database::init()?;
         db.export_sql(&backup_path)?;
 
+        let bytes = fs::read(&backup_path).map_err(|e| AppError::io(&backup_path, e))?;
+        let meta = BackupMeta {
+            created_at: now.timestamp(),
+            label,
+            size: bytes.len() as u64,
+            sha256: sha256_hex(&bytes),
+        };
+        crate::config::write_json_file(&Self::meta_path(&backup_dir, &backup_id), &meta)?;
+
         Self::cleanup_old_backups(&backup_dir, MAX_BACKUPS)?;
 
         Ok(backup_id)
@@ -98,21 +308,48 @@ impl ConfigService {
 
                 // 提取时间戳（假设格式为 xxx_YYYYMMDD_HHMMSS）
                 let timestamp = Self::extract_timestamp(&filename)?;
-
-                // 生成显示名称
-                let display_name = Self::format_display_name(&filename, &timestamp);
+                let meta = Self::read_meta(&backup_dir, &filename);
+                let file_meta = entry.metadata().ok();
+
+                // 生成显示名称：有标签时使用原始标签，否则按文件名推断
+                let display_name = match meta.as_ref().and_then(|m| m.label.as_deref()) {
+                    Some(label) => {
+                        Self::format_display_name(&format!("{label}_{timestamp}"), &timestamp)
+                    }
+                    None => Self::format_display_name(&filename, &timestamp),
+                };
+                let created_at = meta
+                    .as_ref()
+                    .map(|m| m.created_at)
+                    .or_else(|| {
+                        NaiveDateTime::parse_from_str(&timestamp, "%Y%m%d_%H%M%S")
+                            .ok()
+                            .map(|t| t.and_utc().timestamp())
+                    })
+                    .or_else(|| {
+                        let modified = file_meta.as_ref()?.modified().ok()?;
+                        Some(chrono::DateTime::<Utc>::from(modified).timestamp())
+                    })
+                    .unwrap_or_default();
 
                 Some(BackupInfo {
                     id: filename.clone(),
                     path: path.clone(),
                     timestamp,
                     display_name,
+                    created_at,
+                    size: file_meta.map(|m| m.len()).unwrap_or_default(),
+                    label: meta.and_then(|m| m.label),
                 })
             })
             .collect();
 
-        // 按时间戳降序排序（最新的在前）
-        backups.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        // 按创建时间降序排序（最新的在前）
+        backups.sort_by(|a, b| {
+            b.created_at
+                .cmp(&a.created_at)
+                .then_with(|| b.timestamp.cmp(&a.timestamp))
+        });
 
         Ok(backups)
     }
@@ -130,10 +367,60 @@ impl ConfigService {
         if !backup_path.exists() {
             return Err(AppError::Message(format!("备份文件不存在: {}", backup_id)));
         }
+        Self::verify_backup_integrity(&backup_dir, backup_id, &backup_path)?;
 
         Self::import_config_from_path(&backup_path, state)
     }
 
+    /// 有元数据时校验备份文件的 SHA256；旧备份没有元数据则跳过校验。
+    fn verify_backup_integrity(
+        backup_dir: &Path,
+        backup_id: &str,
+        backup_path: &Path,
+    ) -> Result<(), AppError> {
+        let Some(meta) = Self::read_meta(backup_dir, backup_id) else {
+            return Ok(());
+        };
+        let bytes = fs::read(backup_path).map_err(|e| AppError::io(backup_path, e))?;
+        if sha256_hex(&bytes) != meta.sha256 {
+            return Err(AppError::localized(
+                "config.backup.checksum_mismatch",
+                format!("备份文件校验失败（内容已被修改或损坏）: {backup_id}"),
+                format!("Backup checksum mismatch (file modified or corrupted): {backup_id}"),
+            ));
+        }
+        Ok(())
+    }
+
+    fn meta_path(backup_dir: &Path, backup_id: &str) -> PathBuf {
+        backup_dir.join(format!("{backup_id}{BACKUP_META_SUFFIX}"))
+    }
+
+    fn read_meta(backup_dir: &Path, backup_id: &str) -> Option<BackupMeta> {
+        let path = Self::meta_path(backup_dir, backup_id);
+        if !path.exists() {
+            return None;
+        }
+        match crate::config::read_json_file(&path) {
+            Ok(meta) => Some(meta),
+            Err(e) => {
+                log::warn!("Failed to read backup metadata {}: {e}", path.display());
+                None
+            }
+        }
+    }
+
+    /// 备份名称用于文件名，替换路径分隔符及各平台不允许的字符
+    fn sanitize_backup_name(name: &str) -> String {
+        name.chars()
+            .map(|c| match c {
+                '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+                c if c.is_control() => '_',
+                c => c,
+            })
+            .collect()
+    }
+
     /// 从文件名提取时间戳字符串
     fn extract_timestamp(filename: &str) -> Option<String> {
         // 尝试匹配格式：xxx_YYYYMMDD_HHMMSS
@@ -218,6 +505,13 @@ impl ConfigService {
                     entry.path().display(),
                     err
                 );
+                continue;
+            }
+            if let Some(id) = entry.path().file_stem().and_then(|stem| stem.to_str()) {
+                let meta_path = Self::meta_path(backup_dir, id);
+                if meta_path.exists() {
+                    let _ = fs::remove_file(meta_path);
+                }
             }
         }
 
@@ -230,19 +524,246 @@ impl ConfigService {
         db.export_sql(target_path)
     }
 
+    /// 只导出选中的分区（如仅 MCP 服务器或提示词），便于分享给他人。
+    ///
+    /// 文件仍是 CC Switch SQL 导出：`user_version` 记录 schema 版本，首行标明为部分导出，
+    /// `-- sections:` 记录包含的分区。导入时只合并这些分区而不替换整个数据库；
+    /// 不认识部分导出首行的旧版本会直接拒绝导入。
+    pub fn export_sections(sections: ConfigSections, target_path: &Path) -> Result<(), AppError> {
+        if sections.is_empty() {
+            return Err(AppError::localized(
+                "config.sections.empty",
+                "至少需要选择一个配置分区",
+                "Select at least one config section",
+            ));
+        }
+        let db = Database::init()?;
+        let dump = db.export_sql_string_partial(&sections.names(), &sections.tables())?;
+
+        if let Some(parent) = target_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
+        }
+        crate::config::atomic_write(target_path, dump.as_bytes())
+    }
+
+    /// 检查导出文件能否导入以及包含的内容，只在内存库中执行，不修改本地数据库。
+    pub fn inspect_bundle(file_path: &Path) -> Result<BundleReport, AppError> {
+        let sql_raw = Self::read_bundle(file_path)?;
+        Self::inspect_bundle_sql(&sql_raw)
+    }
+
+    fn read_bundle(file_path: &Path) -> Result<String, AppError> {
+        if !file_path.exists() {
+            return Err(AppError::InvalidInput(format!(
+                "SQL 文件不存在: {}",
+                file_path.display()
+            )));
+        }
+        fs::read_to_string(file_path).map_err(|e| AppError::io(file_path, e))
+    }
+
+    /// 部分导出包含的分区；完整导出返回 `None`。
+    ///
+    /// 含有本版本不认识的分区时报错，避免静默丢弃其中的数据。
+    fn bundle_sections(sql_raw: &str) -> Result<Option<ConfigSections>, AppError> {
+        Database::sql_export_sections(sql_raw)
+            .map(ConfigSections::parse)
+            .transpose()
+    }
+
+    fn inspect_bundle_sql(sql_raw: &str) -> Result<BundleReport, AppError> {
+        let conn = Database::load_sql_export_in_memory(sql_raw)?;
+
+        let schema_version: i32 = conn
+            .query_row("PRAGMA user_version;", [], |row| row.get(0))
+            .map_err(|e| AppError::Database(e.to_string()))?;
+        let count_rows = |table: &str| -> Result<usize, AppError> {
+            if !Database::table_exists(&conn, table)? {
+                return Ok(0);
+            }
+            conn.query_row(&format!("SELECT COUNT(*) FROM \"{table}\""), [], |row| {
+                row.get::<_, i64>(0)
+            })
+            .map(|count| count.max(0) as usize)
+            .map_err(|e| AppError::Database(e.to_string()))
+        };
+
+        let mut report = BundleReport {
+            schema_version,
+            supported_schema_version: crate::database::SCHEMA_VERSION,
+            exported_at: sql_raw
+                .lines()
+                .take_while(|line| line.starts_with("--"))
+                .find_map(|line| line.strip_prefix("-- 生成时间:"))
+                .map(|value| value.trim().to_string()),
+            providers: count_rows("providers")?,
+            mcp_servers: count_rows("mcp_servers")?,
+            prompts: count_rows("prompts")?,
+            sections: Self::bundle_sections(sql_raw)?,
+            warnings: Vec::new(),
+        };
+
+        if report.schema_version > report.supported_schema_version {
+            report.warnings.push(format!(
+                "导出文件来自更新版本的 CC Switch（schema v{}，当前支持 v{}），导入后部分数据可能无法识别 (Bundle was exported by a newer CC Switch: schema v{}, this version supports v{})",
+                report.schema_version,
+                report.supported_schema_version,
+                report.schema_version,
+                report.supported_schema_version
+            ));
+        }
+        if report.sections.is_none() && report.providers == 0 && report.mcp_servers == 0 {
+            report.warnings.push(
+                "导出文件不包含任何供应商或 MCP 服务器，导入会被拒绝 (Bundle has no providers or MCP servers; import will be rejected)"
+                    .to_string(),
+            );
+        }
+
+        Ok(report)
+    }
+
+    /// 导入导出文件；部分导出（见 [`Self::export_sections`]）只合并其中的分区。
     pub fn import_config_from_path(file_path: &Path, state: &AppState) -> Result<String, AppError> {
+        let sql_raw = Self::read_bundle(file_path)?;
+        let backup_id = Self::backup_before_import()?;
+
+        // Import SQL into DB (also performs an internal binary snapshot backup).
+        Self::import_bundle_sql(&sql_raw, state)?;
+
+        Ok(backup_id)
+    }
+
+    /// 完整导出替换整个数据库，部分导出只合并其包含的分区
+    fn import_bundle_sql(sql_raw: &str, state: &AppState) -> Result<(), AppError> {
+        match Self::bundle_sections(sql_raw)? {
+            Some(sections) => state.db.merge_sql_string(sql_raw, &sections.tables())?,
+            None => state.db.import_sql_string(sql_raw)?,
+        };
+        Ok(())
+    }
+
+    /// 从 http(s) URL 下载导出文件并导入，便于团队共享一份基础配置。
+    ///
+    /// 下载大小上限与远端 manifest 相同，返回 HTML 等非导出内容时直接拒绝；
+    /// 导入前先经过 [`Self::inspect_bundle`] 的同一套校验，并自动备份当前数据库。
+    /// 本地已有供应商或 MCP 服务器时，只有 `overwrite` 为真才会覆盖；部分导出只合并，不需要 `overwrite`。
+    /// 返回备份 ID 与导出文件的检查结果。
+    pub fn import_from_url(
+        url: &str,
+        overwrite: bool,
+        state: &AppState,
+    ) -> Result<(String, BundleReport), AppError> {
+        let sql_raw = Self::download_bundle(url)?;
+        let report = Self::inspect_bundle_sql(&sql_raw)?;
+
+        if !overwrite && report.sections.is_none() && Self::has_local_data(state)? {
+            return Err(AppError::localized(
+                "config.import_url.would_overwrite",
+                "本地已有供应商或 MCP 服务器，导入会覆盖现有数据；确认覆盖请加上 --overwrite",
+                "Local providers or MCP servers exist and would be replaced; pass --overwrite to continue",
+            ));
+        }
+
+        let backup_id = Self::backup_before_import()?;
+        Self::import_bundle_sql(&sql_raw, state)?;
+        Ok((backup_id, report))
+    }
+
+    fn download_bundle(url: &str) -> Result<String, AppError> {
+        let display_url = webdav::redact_url(url.trim());
+        let parsed = url::Url::parse(url.trim()).map_err(|e| {
+            AppError::localized(
+                "config.import_url.invalid",
+                format!("导入地址不是合法 URL: {display_url}: {e}"),
+                format!("Import URL is not valid: {display_url}: {e}"),
+            )
+        })?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(AppError::localized(
+                "config.import_url.unsupported_scheme",
+                format!("只支持 http/https 导入地址: {display_url}"),
+                format!("Only http/https import URLs are supported: {display_url}"),
+            ));
+        }
+
+        let client = webdav::build_client(None)?;
+        let Some((bytes, _, content_type)) = webdav_sync::run_http(webdav::get_bytes_typed(
+            &client,
+            parsed.as_str(),
+            &None,
+            Some(webdav_sync::MAX_MANIFEST_BYTES),
+        ))?
+        else {
+            return Err(AppError::localized(
+                "config.import_url.not_found",
+                format!("导入地址不存在 (404): {display_url}"),
+                format!("Import URL was not found (404): {display_url}"),
+            ));
+        };
+
+        // 地址指错时服务端常返回 HTML 页面，给出明确提示而不是 SQL 解析错误
+        let is_html = content_type
+            .as_deref()
+            .is_some_and(|ct| ct.to_ascii_lowercase().contains("html"))
+            || bytes.trim_ascii_start().first() == Some(&b'<');
+        if is_html {
+            let content_type = content_type.as_deref().unwrap_or("unknown");
+            return Err(AppError::localized(
+                "config.import_url.not_bundle",
+                format!(
+                    "导入地址返回了 HTML 内容 (Content-Type: {content_type})，不是 CC Switch 导出文件: {display_url}"
+                ),
+                format!(
+                    "Import URL returned HTML content (Content-Type: {content_type}), not a CC Switch export: {display_url}"
+                ),
+            ));
+        }
+        String::from_utf8(bytes).map_err(|e| {
+            AppError::localized(
+                "config.import_url.not_utf8",
+                format!("导入地址返回的内容不是 UTF-8 文本: {display_url}: {e}"),
+                format!("Import URL did not return UTF-8 text: {display_url}: {e}"),
+            )
+        })
+    }
+
+    fn has_local_data(state: &AppState) -> Result<bool, AppError> {
+        for app in AppType::all() {
+            if !state.db.get_all_providers(app.as_str())?.is_empty() {
+                return Ok(true);
+            }
+        }
+        Ok(!state.db.get_all_mcp_servers()?.is_empty())
+    }
+
+    /// 导入前备份当前数据库（SQL），返回备份 ID
+    fn backup_before_import() -> Result<String, AppError> {
         let db_path = crate::config::get_app_config_dir().join("cc-switch.db");
         if !db_path.exists() {
             return Err(AppError::Config("数据库不存在，无法导入".to_string()));
         }
+        Self::create_backup(&db_path, None)
+    }
 
-        // Pre-import backup (SQL).
-        let backup_id = Self::create_backup(&db_path, None)?;
-
-        // Import SQL into DB (also performs an internal binary snapshot backup).
-        state.db.import_sql(file_path)?;
+    /// 读取应用的 live 配置文件并提取供应商指纹。
+    ///
+    /// live 文件不存在、无法解析，或应用为累加模式时返回 `None`。
+    pub fn live_provider_fingerprint(app_type: AppType) -> Option<ProviderFingerprint> {
+        if app_type.is_additive_mode() {
+            return None;
+        }
+        let live = ProviderService::read_live_settings(app_type.clone())
+            .map_err(|e| log::debug!("读取 {} live 配置失败: {e}", app_type.as_str()))
+            .ok()?;
+        ProviderFingerprint::from_settings(&app_type, &live)
+    }
 
-        Ok(backup_id)
+    /// 供应商自身配置的指纹，用于与 [`Self::live_provider_fingerprint`] 比较
+    pub fn provider_fingerprint(
+        app_type: &AppType,
+        provider: &Provider,
+    ) -> Option<ProviderFingerprint> {
+        ProviderFingerprint::from_settings(app_type, &provider.settings_config)
     }
 
     /// 同步当前供应商到对应的 live 配置。
@@ -286,7 +807,7 @@ impl ConfigService {
             AppType::Codex => Self::sync_codex_live(config, &current_id, &provider)?,
             AppType::Claude => Self::sync_claude_live(config, &current_id, &provider)?,
             AppType::Gemini => Self::sync_gemini_live(config, &current_id, &provider)?,
-            AppType::OpenCode => {}
+            AppType::OpenCode => Self::sync_opencode_live(config, &current_id, &provider)?,
             AppType::OpenClaw => {}
         }
 
@@ -329,6 +850,28 @@ impl ConfigService {
         Ok(())
     }
 
+    fn sync_opencode_live(
+        config: &mut MultiAppConfig,
+        provider_id: &str,
+        provider: &Provider,
+    ) -> Result<(), AppError> {
+        ProviderService::write_opencode_live(provider)?;
+
+        // 读回 OpenCode 规范化后的供应商条目
+        let live_after = crate::opencode_config::get_providers()?
+            .get(provider_id)
+            .cloned();
+        if let Some(live_after) = live_after {
+            if let Some(manager) = config.get_manager_mut(&AppType::OpenCode) {
+                if let Some(target) = manager.providers.get_mut(provider_id) {
+                    target.settings_config = live_after;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     fn sync_claude_live(
         config: &mut MultiAppConfig,
         provider_id: &str,
@@ -385,3 +928,7 @@ impl ConfigService {
         Ok(())
     }
 }
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
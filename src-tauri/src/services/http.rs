@@ -0,0 +1,236 @@
+//! 服务层共用的 HTTP 客户端：WebDAV 同步与模型列表拉取共享同一套重试与代理处理。
+//!
+//! WebDAV 客户端关闭了自动重定向，由 [`send_following_redirect`] 按方法跟随；
+//! 其余客户端沿用 reqwest 的默认重定向策略。调用方只负责各自的鉴权与响应解析。
+
+use std::time::{Duration, Instant};
+
+use reqwest::header::{AUTHORIZATION, COOKIE, PROXY_AUTHORIZATION, WWW_AUTHENTICATE};
+use reqwest::{Client, ClientBuilder, Method, Response, StatusCode};
+use url::Url;
+
+use crate::error::AppError;
+use crate::proxy::http_client::{get_current_proxy_url, mask_url};
+
+/// 502 / 503 时最多额外重试的次数
+const RETRY_ATTEMPTS: u32 = 2;
+/// 首次重试前的等待时间，之后按次数线性增加
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(300);
+/// GET / HEAD 最多跟随的重定向次数，与 reqwest 默认策略一致
+const MAX_IDEMPOTENT_REDIRECTS: usize = 10;
+
+/// 请求日志的标记：`label` 为日志前缀，`target` 为日志目标，
+/// 使记录仍受调用方模块的日志级别控制（如 WebDAV 的 `sync_log_level`）
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct LogTag {
+    pub label: &'static str,
+    pub target: &'static str,
+}
+
+/// 创建客户端构建器：配置了全局代理时经由该代理，否则沿用系统代理设置。
+/// 重定向保持 reqwest 的默认策略，需要按原方法跟随的调用方（WebDAV）自行关闭。
+pub(crate) fn client_builder() -> Result<ClientBuilder, AppError> {
+    let builder = Client::builder();
+    let Some(proxy_url) = get_current_proxy_url() else {
+        return Ok(builder);
+    };
+    let proxy = reqwest::Proxy::all(&proxy_url).map_err(|e| {
+        AppError::localized(
+            "http.proxy_invalid",
+            format!("代理地址无效: {}: {e}", mask_url(&proxy_url)),
+            format!("Invalid proxy URL {}: {e}", mask_url(&proxy_url)),
+        )
+    })?;
+    Ok(builder.proxy(proxy))
+}
+
+/// 发送请求；服务器返回 301/302/307/308 时按原方法与请求体向 `Location` 重试。
+///
+/// reqwest 默认会把 301/302 的 PROPFIND / PUT 改写成 GET，因此 WebDAV 客户端关闭了自动重定向，由这里跟随：
+/// - GET / HEAD 与默认策略一样最多跟随 10 次，可以跳转到其他主机；
+/// - PUT / MOVE / PROPFIND / DELETE 等只跟随一次，且只在同一主机内（地址规范化、升级到 https）。
+///
+/// 离开原主机（或降级到 http）时去掉 `Authorization`、Cookie 等凭据头，凭据不会发给其他主机。
+/// 不跟随时（循环、跨主机的写操作、次数用尽）直接返回该响应，由调用方按状态码报错。
+/// 请求日志按 `tag` 标记。
+pub(crate) async fn send_following_redirect(
+    client: &Client,
+    builder: reqwest::RequestBuilder,
+    tag: LogTag,
+) -> Result<Response, reqwest::Error> {
+    execute_following_redirect(client, builder.build()?, tag).await
+}
+
+/// 发送幂等请求（GET / HEAD），跟随重定向，并在 502 / 503 时有限次重试。
+///
+/// 给定 `deadline` 时，每次请求的超时收紧到剩余时间，来不及再等一轮时不再重试；
+/// 放弃重试时返回最后一次的响应，由调用方按状态码报错。
+pub(crate) async fn send_with_retry(
+    client: &Client,
+    builder: reqwest::RequestBuilder,
+    deadline: Option<Instant>,
+    tag: LogTag,
+) -> Result<Response, reqwest::Error> {
+    let request = builder.build()?;
+    let mut attempt = 0;
+    loop {
+        let Some(mut current) = request.try_clone() else {
+            // 流式请求体无法重放，只发送一次
+            return execute_following_redirect(client, request, tag).await;
+        };
+        if let Some(deadline) = deadline {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let timeout = current
+                .timeout()
+                .map_or(remaining, |timeout| (*timeout).min(remaining));
+            *current.timeout_mut() = Some(timeout);
+        }
+
+        let resp = execute_following_redirect(client, current, tag).await?;
+        if attempt >= RETRY_ATTEMPTS || !is_retryable(resp.status()) {
+            return Ok(resp);
+        }
+        attempt += 1;
+        let delay = RETRY_BASE_DELAY * attempt;
+        if deadline.is_some_and(|deadline| Instant::now() + delay >= deadline) {
+            return Ok(resp);
+        }
+        log::debug!(
+            target: tag.target,
+            "[{}] {} {} -> {}, retrying ({attempt}/{RETRY_ATTEMPTS})",
+            tag.label,
+            request.method(),
+            redact_url(request.url().as_str()),
+            resp.status().as_u16()
+        );
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// 隐藏 URL 中的密码，用于日志与错误信息
+pub fn redact_url(url: &str) -> String {
+    match Url::parse(url) {
+        Ok(mut parsed) => {
+            if parsed.password().is_some() {
+                let _ = parsed.set_password(Some("***"));
+            }
+            parsed.to_string()
+        }
+        Err(_) => url.to_string(),
+    }
+}
+
+fn is_retryable(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE
+    )
+}
+
+async fn execute_following_redirect(
+    client: &Client,
+    mut request: reqwest::Request,
+    tag: LogTag,
+) -> Result<Response, reqwest::Error> {
+    let idempotent = matches!(*request.method(), Method::GET | Method::HEAD);
+    let max_redirects = if idempotent {
+        MAX_IDEMPOTENT_REDIRECTS
+    } else {
+        1
+    };
+    let mut visited = vec![request.url().clone()];
+    loop {
+        let retry = request.try_clone();
+        let resp = execute_traced(client, request, tag).await?;
+        if visited.len() > max_redirects {
+            return Ok(resp);
+        }
+        let Some(mut retry) = retry else {
+            return Ok(resp);
+        };
+        let Some(target) = redirect_target(retry.url(), &resp) else {
+            return Ok(resp);
+        };
+        if visited.contains(&target) {
+            return Ok(resp);
+        }
+        if !keeps_credentials(retry.url(), &target) {
+            if !idempotent {
+                return Ok(resp);
+            }
+            for header in [AUTHORIZATION, COOKIE, PROXY_AUTHORIZATION, WWW_AUTHENTICATE] {
+                retry.headers_mut().remove(header);
+            }
+        }
+        visited.push(target.clone());
+        *retry.url_mut() = target;
+        request = retry;
+    }
+}
+
+/// 发送单个请求，并在 trace 级别记录方法、脱敏后的地址、状态码与耗时
+async fn execute_traced(
+    client: &Client,
+    request: reqwest::Request,
+    tag: LogTag,
+) -> Result<Response, reqwest::Error> {
+    if !log::log_enabled!(target: tag.target, log::Level::Trace) {
+        return client.execute(request).await;
+    }
+    let method = request.method().clone();
+    let url = redact_url(request.url().as_str());
+    let started = Instant::now();
+    let result = client.execute(request).await;
+    let elapsed_ms = started.elapsed().as_millis();
+    match &result {
+        Ok(resp) => log::trace!(
+            target: tag.target,
+            "[{}] {method} {url} -> {} in {elapsed_ms} ms",
+            tag.label,
+            resp.status().as_u16()
+        ),
+        Err(e) => log::trace!(
+            target: tag.target,
+            "[{}] {method} {url} failed after {elapsed_ms} ms: {e}",
+            tag.label
+        ),
+    }
+    result
+}
+
+/// 解析重定向目标：只接受 http(s)，且目标地址不能内嵌账号密码
+fn redirect_target(current: &Url, resp: &Response) -> Option<Url> {
+    if !matches!(
+        resp.status(),
+        StatusCode::MOVED_PERMANENTLY
+            | StatusCode::FOUND
+            | StatusCode::TEMPORARY_REDIRECT
+            | StatusCode::PERMANENT_REDIRECT
+    ) {
+        return None;
+    }
+    let location = resp
+        .headers()
+        .get(reqwest::header::LOCATION)?
+        .to_str()
+        .ok()?;
+    let target = current.join(location).ok()?;
+    let acceptable = matches!(target.scheme(), "http" | "https")
+        && target.username().is_empty()
+        && target.password().is_none();
+    acceptable.then_some(target)
+}
+
+/// 跳转后仍可携带凭据：同一主机，协议相同（端口不变）或从 http 升级到 https
+fn keeps_credentials(current: &Url, target: &Url) -> bool {
+    if target.host_str() != current.host_str() {
+        return false;
+    }
+    match (current.scheme(), target.scheme()) {
+        (from, to) if from == to => {
+            target.port_or_known_default() == current.port_or_known_default()
+        }
+        ("http", "https") => true,
+        _ => false,
+    }
+}
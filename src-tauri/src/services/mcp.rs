@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use regex::Regex;
+
 use crate::app_config::{AppType, McpServer, MultiAppConfig};
 use crate::error::AppError;
 use crate::mcp;
@@ -88,6 +90,49 @@ impl McpService {
         }
     }
 
+    /// 删除 id 匹配 glob 模式（`*`、`?`、`[...]`）的所有服务器，返回按字典序排列的已删除 id。
+    ///
+    /// 匹配结果中有已启用的服务器时，除非 `force`，否则整体拒绝，不删除任何服务器。
+    pub fn delete_matching(
+        state: &AppState,
+        pattern: &str,
+        force: bool,
+    ) -> Result<Vec<String>, AppError> {
+        let matcher = compile_glob(pattern)?;
+        let mut matched: Vec<(String, bool)> = Self::get_all_servers(state)?
+            .into_values()
+            .filter(|server| matcher.is_match(&server.id))
+            .map(|server| (server.id, !server.apps.is_empty()))
+            .collect();
+        matched.sort();
+
+        if !force {
+            let enabled: Vec<&str> = matched
+                .iter()
+                .filter(|(_, enabled)| *enabled)
+                .map(|(id, _)| id.as_str())
+                .collect();
+            if !enabled.is_empty() {
+                let list = enabled.join(", ");
+                return Err(AppError::localized(
+                    "mcp.delete_matching.enabled",
+                    format!("以下匹配的服务器仍处于启用状态，未删除任何服务器: {list}"),
+                    format!(
+                        "These matching servers are still enabled; nothing was deleted: {list}"
+                    ),
+                ));
+            }
+        }
+
+        let mut deleted = Vec::with_capacity(matched.len());
+        for (id, _) in matched {
+            if Self::delete_server(state, &id)? {
+                deleted.push(id);
+            }
+        }
+        Ok(deleted)
+    }
+
     /// 切换指定应用的启用状态
     pub fn toggle_app(
         state: &AppState,
@@ -203,6 +248,31 @@ impl McpService {
         Ok(())
     }
 
+    /// 将指定应用的 live MCP 配置重置为 cc-switch 中对该应用启用的服务器：
+    /// 移除 live 中其余（包括手动添加的）条目，再写入所有启用项
+    pub fn reset_live_for_app(state: &AppState, app: &AppType) -> Result<(), AppError> {
+        if !crate::sync_policy::should_sync_live(app) {
+            return Ok(());
+        }
+
+        let servers = Self::get_all_servers(state)?;
+        let enabled: Vec<&McpServer> = servers
+            .values()
+            .filter(|server| server.apps.is_enabled_for(app))
+            .collect();
+
+        for id in mcp::live_server_ids(app)? {
+            if !enabled.iter().any(|server| server.id == id) {
+                Self::remove_server_from_app(state, &id, app)?;
+            }
+        }
+        for server in enabled {
+            Self::sync_server_to_app(state, server, app)?;
+        }
+
+        Ok(())
+    }
+
     // ========================================================================
     // 兼容层：支持旧的 v3.6.x 命令（已废弃，将在 v4.0 移除）
     // ========================================================================
@@ -287,3 +357,54 @@ impl McpService {
         Ok(count)
     }
 }
+
+/// 将 glob 模式转为整串匹配的正则：`*` 任意字符串，`?` 单个字符，
+/// `[...]` 字符集（`[!...]` 取反），其余字符按字面匹配
+fn compile_glob(pattern: &str) -> Result<Regex, AppError> {
+    let invalid = |reason_zh: &str, reason_en: &str| {
+        AppError::localized(
+            "mcp.glob.invalid",
+            format!("无效的匹配模式 '{pattern}': {reason_zh}"),
+            format!("Invalid pattern '{pattern}': {reason_en}"),
+        )
+    };
+    if pattern.trim().is_empty() {
+        return Err(invalid("模式不能为空", "pattern must not be empty"));
+    }
+
+    let mut regex = String::from("^");
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            '[' => {
+                let mut class = String::new();
+                if chars.next_if(|c| *c == '!' || *c == '^').is_some() {
+                    class.push('^');
+                }
+                let mut closed = false;
+                for c in chars.by_ref() {
+                    if c == ']' && !class.is_empty() && class != "^" {
+                        closed = true;
+                        break;
+                    }
+                    if matches!(c, '\\' | '[' | ']' | '&' | '~') {
+                        class.push('\\');
+                    }
+                    class.push(c);
+                }
+                if !closed {
+                    return Err(invalid("字符集缺少 ']'", "unclosed '[' character class"));
+                }
+                regex.push('[');
+                regex.push_str(&class);
+                regex.push(']');
+            }
+            _ => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+
+    Regex::new(&regex).map_err(|e| invalid(&e.to_string(), &e.to_string()))
+}
@@ -1,6 +1,7 @@
 pub mod config;
 pub mod env_checker;
 pub mod env_manager;
+pub(crate) mod http;
 pub mod local_env_check;
 pub mod mcp;
 pub mod prompt;
@@ -12,12 +13,19 @@ pub mod stream_check;
 pub mod webdav;
 pub mod webdav_sync;
 
-pub use config::ConfigService;
+pub use config::{ConfigSections, ConfigService};
 pub use mcp::McpService;
 pub use prompt::PromptService;
-pub use provider::ProviderService;
+pub use provider::{
+    DuplicateGroup, DuplicateMember, KeyStatus, ModelFetchAuth, ModelTestApiStyle, ModelTestResult,
+    ProviderDetails, ProviderService,
+};
 pub use proxy::ProxyService;
-pub use skill::SkillService;
+pub use skill::{SkillService, SkillsRestoreRecovery, SyncMethod};
 pub use speedtest::{EndpointLatency, SpeedtestService};
 pub use stream_check::{HealthStatus, StreamCheckConfig, StreamCheckResult, StreamCheckService};
-pub use webdav_sync::{SyncDecision, WebDavSyncService, WebDavSyncSummary};
+pub use webdav_sync::{
+    DiagnosticCheck, DiagnosticReport, DiagnosticStatus, DiagnosticStep, PreflightArtifact,
+    PreflightReport, SkillsDiff, SyncDecision, SyncScheduler, WebDavSyncService, WebDavSyncSummary,
+    WritableStatus,
+};
@@ -199,6 +199,21 @@ impl PromptService {
         Ok(())
     }
 
+    /// 按当前启用的提示词重写实时文件；没有启用项时清空已存在的文件
+    pub fn reset_live_file(state: &AppState, app: AppType) -> Result<(), AppError> {
+        let enabled_content = Self::get_prompts(state, app.clone())?
+            .into_values()
+            .find(|prompt| prompt.enabled)
+            .map(|prompt| prompt.content);
+
+        let target_path = prompt_file_path(&app)?;
+        match enabled_content {
+            Some(content) => write_text_file(&target_path, &content),
+            None if target_path.exists() => write_text_file(&target_path, ""),
+            None => Ok(()),
+        }
+    }
+
     pub fn import_from_file(state: &AppState, app: AppType) -> Result<String, AppError> {
         let file_path = prompt_file_path(&app)?;
 
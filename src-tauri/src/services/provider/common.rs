@@ -1,5 +1,41 @@
 use super::*;
 
+/// 生成唯一的 Provider ID
+/// 基于名称转换为 kebab-case，如有冲突则追加数字后缀
+pub fn generate_provider_id(name: &str, existing_ids: &[String]) -> String {
+    // 转换为 kebab-case
+    let base_id = name
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else if c.is_whitespace() {
+                '-'
+            } else {
+                '-'
+            }
+        })
+        .collect::<String>()
+        .trim_matches('-')
+        .to_string();
+
+    // 检查唯一性
+    if !existing_ids.contains(&base_id) {
+        return base_id;
+    }
+
+    // 追加数字后缀
+    let mut counter = 1;
+    loop {
+        let candidate = format!("{}-{}", base_id, counter);
+        if !existing_ids.contains(&candidate) {
+            return candidate;
+        }
+        counter += 1;
+    }
+}
+
 pub fn migrate_legacy_codex_config(cfg_text: &str, provider: &Provider) -> Option<String> {
     let trimmed = cfg_text.trim();
     if trimmed.is_empty() {
@@ -0,0 +1,156 @@
+use serde::Serialize;
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::Provider;
+use crate::services::stream_check::StreamCheckService;
+use crate::store::AppState;
+
+use super::{ModelTestApiStyle, ProviderService};
+
+/// 从供应商配置中解析出的连接信息
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderDetails {
+    pub id: String,
+    pub name: String,
+    pub base_url: Option<String>,
+    pub model: Option<String>,
+    pub api_style: ModelTestApiStyle,
+    pub api_key: Option<String>,
+}
+
+impl ProviderService {
+    /// 获取指定供应商解析后的连接信息（包含明文 API Key）
+    pub fn get(state: &AppState, app_type: AppType, id: &str) -> Result<ProviderDetails, AppError> {
+        let config = state.config.read().map_err(AppError::from)?;
+        let provider = config
+            .get_manager(&app_type)
+            .ok_or_else(|| Self::app_not_found(&app_type))?
+            .providers
+            .get(id)
+            .ok_or_else(|| {
+                AppError::localized(
+                    "provider.not_found",
+                    format!("供应商不存在: {id}"),
+                    format!("Provider not found: {id}"),
+                )
+            })?;
+
+        Ok(ProviderDetails {
+            id: provider.id.clone(),
+            name: provider.name.clone(),
+            base_url: Self::extract_base_url(provider, &app_type)
+                .ok()
+                .map(|url| url.trim().to_string())
+                .filter(|url| !url.is_empty()),
+            model: stored_model(provider, &app_type),
+            api_style: api_style(provider, &app_type),
+            api_key: Self::extract_api_key(provider, &app_type)
+                .ok()
+                .map(|key| key.trim().to_string())
+                .filter(|key| !key.is_empty()),
+        })
+    }
+
+    /// 同 [`ProviderService::get`]，但 API Key 仅保留前缀，适合展示或日志输出
+    pub fn get_redacted(
+        state: &AppState,
+        app_type: AppType,
+        id: &str,
+    ) -> Result<ProviderDetails, AppError> {
+        let mut details = Self::get(state, app_type, id)?;
+        details.api_key = details.api_key.as_deref().map(mask_api_key);
+        Ok(details)
+    }
+}
+
+fn stored_model(provider: &Provider, app_type: &AppType) -> Option<String> {
+    match app_type {
+        AppType::Claude => StreamCheckService::extract_env_model(provider, "ANTHROPIC_MODEL"),
+        AppType::Codex => StreamCheckService::extract_codex_model(provider),
+        AppType::Gemini => StreamCheckService::extract_env_model(provider, "GEMINI_MODEL"),
+        AppType::OpenCode => provider
+            .settings_config
+            .get("models")
+            .and_then(|value| value.as_object())
+            .and_then(|models| models.keys().next().cloned()),
+        AppType::OpenClaw => provider
+            .settings_config
+            .get("models")
+            .and_then(|value| value.as_array())
+            .and_then(|models| models.first())
+            .and_then(|model| model.get("id").and_then(|value| value.as_str()))
+            .map(str::to_string),
+    }
+}
+
+fn api_style(provider: &Provider, app_type: &AppType) -> ModelTestApiStyle {
+    match app_type {
+        AppType::Claude => match provider
+            .meta
+            .as_ref()
+            .and_then(|meta| meta.api_format.as_deref())
+        {
+            Some("openai_chat") => ModelTestApiStyle::OpenAiChat,
+            Some("openai_responses") => ModelTestApiStyle::OpenAiResponses,
+            _ => ModelTestApiStyle::Anthropic,
+        },
+        AppType::Codex => {
+            let wire_api = provider
+                .settings_config
+                .get("config")
+                .and_then(|value| value.as_str())
+                .and_then(|text| text.parse::<toml_edit::DocumentMut>().ok())
+                .and_then(|doc| codex_wire_api(&doc));
+            match wire_api.as_deref() {
+                Some("chat") => ModelTestApiStyle::OpenAiChat,
+                _ => ModelTestApiStyle::OpenAiResponses,
+            }
+        }
+        AppType::Gemini => ModelTestApiStyle::Gemini,
+        AppType::OpenCode => match provider
+            .settings_config
+            .get("npm")
+            .and_then(|value| value.as_str())
+        {
+            Some("@ai-sdk/anthropic") => ModelTestApiStyle::Anthropic,
+            Some("@ai-sdk/google") => ModelTestApiStyle::Gemini,
+            _ => ModelTestApiStyle::OpenAiChat,
+        },
+        AppType::OpenClaw => match provider
+            .settings_config
+            .get("api")
+            .and_then(|value| value.as_str())
+        {
+            Some("anthropic-messages") => ModelTestApiStyle::Anthropic,
+            Some("openai-responses") => ModelTestApiStyle::OpenAiResponses,
+            Some("google-generative-ai") => ModelTestApiStyle::Gemini,
+            _ => ModelTestApiStyle::OpenAiChat,
+        },
+    }
+}
+
+/// 读取 Codex config.toml 中当前 model_provider 的 wire_api（兼容旧版顶层写法）
+fn codex_wire_api(doc: &toml_edit::DocumentMut) -> Option<String> {
+    let from_section = doc
+        .get("model_provider")
+        .and_then(|item| item.as_str())
+        .and_then(|name| doc.get("model_providers")?.get(name)?.get("wire_api"))
+        .and_then(|item| item.as_str());
+    from_section
+        .or_else(|| doc.get("wire_api").and_then(|item| item.as_str()))
+        .map(str::to_string)
+}
+
+/// 过短的 Key 整体隐藏，避免前缀即为完整密钥
+/// 遮蔽 API Key：只保留前 8 个字符，CLI 与 TUI 的展示共用
+pub fn mask_api_key(key: &str) -> String {
+    let mut iter = key.chars();
+    let prefix: String = iter.by_ref().take(8).collect();
+    if iter.next().is_some() {
+        format!("{prefix}...")
+    } else {
+        prefix
+    }
+}
@@ -1,8 +1,14 @@
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use indexmap::IndexMap;
+use regex::{NoExpand, Regex};
+use serde::Serialize;
+use serde_json::{json, Value};
+
 use crate::app_config::AppType;
 use crate::error::AppError;
-use crate::provider::ProviderMeta;
+use crate::provider::{Provider, ProviderMeta};
+use crate::services::SpeedtestService;
 use crate::settings::CustomEndpoint;
 use crate::store::AppState;
 
@@ -128,6 +134,220 @@ impl ProviderService {
     }
 }
 
+impl ProviderService {
+    /// 对供应商当前端点及自定义端点测速，切换到延迟最低的健康端点并返回其 URL。
+    ///
+    /// 没有健康端点时返回错误，不修改任何配置。
+    pub async fn auto_select_fastest(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+    ) -> Result<String, AppError> {
+        let mut provider = {
+            let cfg = state.config.read().map_err(AppError::from)?;
+            let manager = cfg
+                .get_manager(&app_type)
+                .ok_or_else(|| Self::app_not_found(&app_type))?;
+            manager.providers.get(provider_id).cloned().ok_or_else(|| {
+                AppError::localized(
+                    "provider.not_found",
+                    format!("供应商不存在: {provider_id}"),
+                    format!("Provider not found: {provider_id}"),
+                )
+            })?
+        };
+
+        let current = Self::extract_base_url(&provider, &app_type)
+            .map(|url| normalize_endpoint(&url))
+            .unwrap_or_default();
+        let mut candidates = Vec::new();
+        if !current.is_empty() {
+            candidates.push(current.clone());
+        }
+        if let Some(meta) = provider.meta.as_ref() {
+            let mut custom: Vec<_> = meta.custom_endpoints.values().collect();
+            custom.sort_by_key(|endpoint| std::cmp::Reverse(endpoint.added_at));
+            for endpoint in custom {
+                let url = normalize_endpoint(&endpoint.url);
+                if !url.is_empty() && !candidates.contains(&url) {
+                    candidates.push(url);
+                }
+            }
+        }
+        if candidates.is_empty() {
+            return Err(AppError::localized(
+                "provider.endpoint.none_configured",
+                format!("供应商 {provider_id} 没有可测速的端点"),
+                format!("Provider {provider_id} has no endpoints to test"),
+            ));
+        }
+
+        let total = candidates.len();
+        let results = SpeedtestService::race(candidates, None).await?;
+        let Some(fastest) = results.iter().find(|result| result.is_healthy()) else {
+            let details = results
+                .iter()
+                .map(|result| format!("  - {}", result.summary()))
+                .collect::<Vec<_>>()
+                .join("\n");
+            return Err(AppError::localized(
+                "provider.endpoint.none_healthy",
+                format!("{total} 个端点均不可用，未切换端点:\n{details}"),
+                format!(
+                    "None of the {total} endpoints is healthy; endpoint left unchanged:\n{details}"
+                ),
+            ));
+        };
+        let chosen = normalize_endpoint(&fastest.url);
+
+        if chosen != current {
+            set_base_url(&mut provider, &app_type, &chosen)?;
+            Self::update(state, app_type.clone(), provider)?;
+        }
+        Self::update_endpoint_last_used(state, app_type, provider_id, chosen.clone())?;
+
+        Ok(chosen)
+    }
+}
+
+/// 指向同一 base URL 的一组供应商
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateGroup {
+    /// 归一化后的 base URL
+    pub base_url: String,
+    pub members: Vec<DuplicateMember>,
+}
+
+/// [`DuplicateGroup`] 中的供应商；API Key 只记录是否配置，不输出内容
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateMember {
+    pub id: String,
+    pub name: String,
+    pub has_api_key: bool,
+}
+
+impl ProviderService {
+    /// 按归一化 base URL 对供应商分组，返回成员多于一个的组（按首次出现的顺序）。
+    ///
+    /// 未配置 base URL 的供应商（如官方登录）不参与分组。
+    pub fn find_duplicate_endpoints(
+        state: &AppState,
+        app_type: AppType,
+    ) -> Result<Vec<DuplicateGroup>, AppError> {
+        let cfg = state.config.read().map_err(AppError::from)?;
+        let manager = cfg
+            .get_manager(&app_type)
+            .ok_or_else(|| Self::app_not_found(&app_type))?;
+
+        let mut groups: IndexMap<String, Vec<DuplicateMember>> = IndexMap::new();
+        for provider in manager.providers.values() {
+            let Some(base_url) = Self::extract_base_url(provider, &app_type)
+                .ok()
+                .map(|url| normalize_base_url_for_compare(&url))
+                .filter(|url| !url.is_empty())
+            else {
+                continue;
+            };
+            let has_api_key =
+                Self::extract_api_key(provider, &app_type).is_ok_and(|key| !key.trim().is_empty());
+            groups.entry(base_url).or_default().push(DuplicateMember {
+                id: provider.id.clone(),
+                name: provider.name.clone(),
+                has_api_key,
+            });
+        }
+
+        Ok(groups
+            .into_iter()
+            .filter(|(_, members)| members.len() > 1)
+            .map(|(base_url, members)| DuplicateGroup { base_url, members })
+            .collect())
+    }
+}
+
+/// 比较用的 base URL：去掉首尾空白和末尾 `/`，scheme 与 host 转小写，路径大小写保留
+fn normalize_base_url_for_compare(url: &str) -> String {
+    let url = normalize_endpoint(url);
+    let (scheme, rest) = match url.split_once("://") {
+        Some((scheme, rest)) => (format!("{}://", scheme.to_ascii_lowercase()), rest),
+        None => (String::new(), url.as_str()),
+    };
+    let (host, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    format!("{scheme}{}{path}", host.to_ascii_lowercase())
+}
+
+fn normalize_endpoint(url: &str) -> String {
+    url.trim().trim_end_matches('/').to_string()
+}
+
+/// 将端点写回供应商配置中对应应用的 base URL 字段
+pub(super) fn set_base_url(
+    provider: &mut Provider,
+    app_type: &AppType,
+    url: &str,
+) -> Result<(), AppError> {
+    let settings = &mut provider.settings_config;
+    if !settings.is_object() {
+        *settings = json!({});
+    }
+    let ensure_object = |value: &mut Value, key: &str| {
+        let entry = value
+            .as_object_mut()
+            .expect("settings_config is an object")
+            .entry(key)
+            .or_insert_with(|| json!({}));
+        if !entry.is_object() {
+            *entry = json!({});
+        }
+    };
+
+    match app_type {
+        AppType::Claude => {
+            ensure_object(settings, "env");
+            settings["env"]["ANTHROPIC_BASE_URL"] = json!(url);
+        }
+        AppType::Gemini => {
+            ensure_object(settings, "env");
+            settings["env"]["GOOGLE_GEMINI_BASE_URL"] = json!(url);
+        }
+        AppType::OpenCode => {
+            ensure_object(settings, "options");
+            settings["options"]["baseURL"] = json!(url);
+        }
+        AppType::OpenClaw => {
+            settings["baseUrl"] = json!(url);
+        }
+        AppType::Codex => {
+            let config_toml = settings
+                .get("config")
+                .and_then(|v| v.as_str())
+                .unwrap_or("");
+            let re = Regex::new(r#"base_url\s*=\s*["'][^"']+["']"#).map_err(|e| {
+                AppError::localized(
+                    "provider.regex_init_failed",
+                    format!("正则初始化失败: {e}"),
+                    format!("Failed to initialize regex: {e}"),
+                )
+            })?;
+            if !re.is_match(config_toml) {
+                return Err(AppError::localized(
+                    "provider.codex.base_url.missing",
+                    "config.toml 中缺少 base_url 配置",
+                    "base_url is missing from config.toml",
+                ));
+            }
+            let replacement = format!("base_url = \"{url}\"");
+            let updated = re
+                .replacen(config_toml, 1, NoExpand(&replacement))
+                .into_owned();
+            settings["config"] = json!(updated);
+        }
+    }
+    Ok(())
+}
+
 fn now_millis() -> i64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
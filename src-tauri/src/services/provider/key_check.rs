@@ -0,0 +1,369 @@
+use std::time::Duration;
+
+use futures::future::join_all;
+use indexmap::IndexMap;
+use reqwest::{Client, RequestBuilder, StatusCode};
+use serde::Serialize;
+use serde_json::json;
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::Provider;
+use crate::services::stream_check::{AuthInfo, StreamCheckConfig};
+use crate::services::StreamCheckService;
+use crate::store::AppState;
+
+use super::models::versioned_url;
+use super::ProviderService;
+
+/// 单次密钥校验请求的超时时间
+const KEY_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+const KEY_CHECK_PROMPT: &str = "hi";
+
+/// 密钥有效性检查结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum KeyStatus {
+    /// 服务端接受了该密钥
+    Valid,
+    /// 401 / 403（或 Gemini 以 400 `API_KEY_INVALID` 拒绝），密钥已失效或无权限
+    Unauthorized,
+    /// 429，密钥有效但当前被限流
+    RateLimited,
+    /// 连接失败、超时或服务端异常，无法判断密钥状态
+    Unreachable,
+    /// 服务端以其他 4xx 拒绝了请求，无法据此判断密钥是否有效
+    Unknown,
+}
+
+impl ProviderService {
+    /// 用一次最廉价的鉴权请求检查已保存供应商的密钥是否仍然有效。
+    ///
+    /// 优先请求模型列表；端点不支持时（404 / 405）退回 1 token 的最小补全请求。
+    pub async fn check_key_validity(
+        state: &AppState,
+        app_type: AppType,
+        id: &str,
+    ) -> Result<KeyStatus, AppError> {
+        let provider = Self::list(state, app_type.clone())?
+            .shift_remove(id)
+            .ok_or_else(|| {
+                AppError::localized(
+                    "provider.not_found",
+                    format!("供应商不存在: {id}"),
+                    format!("Provider not found: {id}"),
+                )
+            })?;
+        check_provider_key(&app_type, &provider).await
+    }
+
+    /// 并发检查指定应用下所有供应商的密钥，跳过未配置地址或密钥的供应商
+    pub async fn check_all_keys(
+        state: &AppState,
+        app_type: AppType,
+    ) -> Result<IndexMap<String, KeyStatus>, AppError> {
+        let providers = Self::list(state, app_type.clone())?;
+        let checks = providers.values().map(|provider| {
+            let app_type = &app_type;
+            async move {
+                let status = check_provider_key(app_type, provider).await;
+                (provider.id.clone(), status)
+            }
+        });
+
+        let mut statuses = IndexMap::new();
+        for (id, status) in join_all(checks).await {
+            match status {
+                Ok(status) => {
+                    statuses.insert(id, status);
+                }
+                Err(err) => log::debug!("skip key check for provider '{id}': {err}"),
+            }
+        }
+        Ok(statuses)
+    }
+}
+
+/// 对单个供应商发起密钥检查；缺少地址或密钥时返回错误
+pub(crate) async fn check_provider_key(
+    app_type: &AppType,
+    provider: &Provider,
+) -> Result<KeyStatus, AppError> {
+    let base_url = StreamCheckService::extract_base_url(provider, app_type)?;
+    let base_url = base_url.trim().trim_end_matches('/').to_string();
+    if base_url.is_empty() {
+        return Err(AppError::localized(
+            "fetch.invalid_url",
+            "URL 不能为空",
+            "URL cannot be empty",
+        ));
+    }
+    let auth = StreamCheckService::extract_auth(provider, app_type, &base_url)?;
+    let client = StreamCheckService::build_client_for_provider(provider)?;
+
+    let response = match send(models_request(&client, app_type, &base_url, &auth)).await {
+        Some((status, _)) if matches!(status.as_u16(), 404 | 405) => {
+            let model = StreamCheckService::resolve_test_model(
+                app_type,
+                provider,
+                &StreamCheckConfig::default(),
+            );
+            send(completion_request(
+                &client, app_type, &base_url, &auth, &model,
+            ))
+            .await
+        }
+        other => other,
+    };
+    Ok(response.map_or(KeyStatus::Unreachable, |(status, body)| {
+        key_status_from_http(status, &body)
+    }))
+}
+
+/// 发送请求，返回 HTTP 状态码与响应体；连接失败或超时返回 None
+async fn send(request: RequestBuilder) -> Option<(StatusCode, String)> {
+    match request.timeout(KEY_CHECK_TIMEOUT).send().await {
+        Ok(resp) => {
+            let status = resp.status();
+            Some((status, resp.text().await.unwrap_or_default()))
+        }
+        Err(err) => {
+            log::debug!("key check request failed: {err}");
+            None
+        }
+    }
+}
+
+/// 将 HTTP 响应映射为密钥状态。
+///
+/// 只有 2xx 视为密钥有效。Gemini 对无效密钥返回 400 `API_KEY_INVALID`，
+/// 其余 4xx（如 400 参数错误、402 欠费）无法说明密钥是否有效。
+fn key_status_from_http(status: StatusCode, body: &str) -> KeyStatus {
+    match status.as_u16() {
+        _ if status.is_success() => KeyStatus::Valid,
+        401 | 403 => KeyStatus::Unauthorized,
+        400 if body.contains("API_KEY_INVALID") => KeyStatus::Unauthorized,
+        429 => KeyStatus::RateLimited,
+        404 | 405 => KeyStatus::Unreachable,
+        _ if status.is_server_error() => KeyStatus::Unreachable,
+        _ => KeyStatus::Unknown,
+    }
+}
+
+fn models_request(
+    client: &Client,
+    app_type: &AppType,
+    base_url: &str,
+    auth: &AuthInfo,
+) -> RequestBuilder {
+    let url = match app_type {
+        AppType::Gemini => format!("{}/models", gemini_base(base_url)),
+        _ => versioned_url(base_url, "models"),
+    };
+    apply_auth(client.get(url), app_type, auth)
+}
+
+fn completion_request(
+    client: &Client,
+    app_type: &AppType,
+    base_url: &str,
+    auth: &AuthInfo,
+    model: &str,
+) -> RequestBuilder {
+    let request = match app_type {
+        AppType::Claude => client
+            .post(versioned_url(base_url, "messages"))
+            .json(&json!({
+                "model": model,
+                "max_tokens": 1,
+                "messages": [{ "role": "user", "content": KEY_CHECK_PROMPT }],
+            })),
+        AppType::Codex => client
+            .post(versioned_url(base_url, "responses"))
+            .json(&json!({
+                "model": model,
+                // Responses API 要求 max_output_tokens >= 16
+                "max_output_tokens": 16,
+                "input": KEY_CHECK_PROMPT,
+            })),
+        AppType::Gemini => {
+            let model = model.strip_prefix("models/").unwrap_or(model);
+            client
+                .post(format!(
+                    "{}/models/{model}:generateContent",
+                    gemini_base(base_url)
+                ))
+                .json(&json!({
+                    "contents": [{ "role": "user", "parts": [{ "text": KEY_CHECK_PROMPT }] }],
+                    "generationConfig": { "maxOutputTokens": 1 },
+                }))
+        }
+        AppType::OpenCode | AppType::OpenClaw => client
+            .post(versioned_url(base_url, "chat/completions"))
+            .json(&json!({
+                "model": model,
+                "max_tokens": 1,
+                "messages": [{ "role": "user", "content": KEY_CHECK_PROMPT }],
+            })),
+    };
+    apply_auth(request, app_type, auth)
+}
+
+/// 鉴权头复用流式健康检查的构造方式；Claude 请求额外带上 API 版本头
+fn apply_auth(request: RequestBuilder, app_type: &AppType, auth: &AuthInfo) -> RequestBuilder {
+    let request = StreamCheckService::apply_auth(request, auth);
+    match app_type {
+        AppType::Claude => request.header("anthropic-version", "2023-06-01"),
+        _ => request,
+    }
+}
+
+fn gemini_base(base_url: &str) -> String {
+    if base_url.ends_with("/v1beta") || base_url.ends_with("/v1") {
+        base_url.to_string()
+    } else {
+        format!("{base_url}/v1beta")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        http::{HeaderMap, StatusCode},
+        routing::{get, post},
+        Router,
+    };
+    use serde_json::Value;
+
+    async fn serve(router: Router) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind mock server");
+        let addr = listener.local_addr().expect("mock server addr");
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, router).await;
+        });
+        format!("http://{addr}")
+    }
+
+    fn codex_provider(base_url: &str) -> Provider {
+        Provider::with_id(
+            "p1".to_string(),
+            "Provider One".to_string(),
+            json!({
+                "auth": { "OPENAI_API_KEY": "sk-test" },
+                "config": format!("model = \"gpt-5\"\nbase_url = \"{base_url}/v1\"\n"),
+            }),
+            None,
+        )
+    }
+
+    async fn status_for(code: StatusCode) -> KeyStatus {
+        let router = Router::new().route(
+            "/v1/models",
+            get(move |headers: HeaderMap| async move {
+                assert_eq!(
+                    headers.get("authorization").and_then(|v| v.to_str().ok()),
+                    Some("Bearer sk-test")
+                );
+                code
+            }),
+        );
+        let base = serve(router).await;
+        check_provider_key(&AppType::Codex, &codex_provider(&base))
+            .await
+            .expect("key check should not error")
+    }
+
+    #[tokio::test]
+    async fn check_provider_key_maps_http_status_to_key_status() {
+        assert_eq!(status_for(StatusCode::OK).await, KeyStatus::Valid);
+        assert_eq!(
+            status_for(StatusCode::UNAUTHORIZED).await,
+            KeyStatus::Unauthorized
+        );
+        assert_eq!(
+            status_for(StatusCode::TOO_MANY_REQUESTS).await,
+            KeyStatus::RateLimited
+        );
+    }
+
+    #[tokio::test]
+    async fn check_provider_key_reports_connection_errors_as_unreachable() {
+        // 绑定后立即释放端口，确保连接被拒绝
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind port");
+        let base = format!("http://{}", listener.local_addr().expect("addr"));
+        drop(listener);
+
+        let status = check_provider_key(&AppType::Codex, &codex_provider(&base))
+            .await
+            .expect("key check should not error");
+        assert_eq!(status, KeyStatus::Unreachable);
+    }
+
+    #[tokio::test]
+    async fn check_provider_key_falls_back_to_minimal_completion_without_models_endpoint() {
+        let router = Router::new().route(
+            "/v1/messages",
+            post(
+                |headers: HeaderMap, axum::Json(body): axum::Json<Value>| async move {
+                    assert_eq!(body["max_tokens"], 1);
+                    if headers.get("x-api-key").and_then(|v| v.to_str().ok()) == Some("sk-ant") {
+                        StatusCode::OK
+                    } else {
+                        StatusCode::UNAUTHORIZED
+                    }
+                },
+            ),
+        );
+        let base = serve(router).await;
+        let provider = Provider::with_id(
+            "claude".to_string(),
+            "Claude Relay".to_string(),
+            json!({ "env": {
+                "ANTHROPIC_BASE_URL": base,
+                "ANTHROPIC_AUTH_TOKEN": "sk-ant",
+            }}),
+            None,
+        );
+
+        let status = check_provider_key(&AppType::Claude, &provider)
+            .await
+            .expect("key check should not error");
+        assert_eq!(status, KeyStatus::Valid);
+    }
+
+    #[test]
+    fn key_status_from_http_only_treats_success_as_valid() {
+        assert_eq!(
+            key_status_from_http(StatusCode::NO_CONTENT, ""),
+            KeyStatus::Valid
+        );
+        assert_eq!(
+            key_status_from_http(StatusCode::PAYMENT_REQUIRED, ""),
+            KeyStatus::Unknown
+        );
+        assert_eq!(
+            key_status_from_http(StatusCode::BAD_REQUEST, r#"{"error":"bad model"}"#),
+            KeyStatus::Unknown
+        );
+        assert_eq!(
+            key_status_from_http(StatusCode::FORBIDDEN, ""),
+            KeyStatus::Unauthorized
+        );
+        assert_eq!(
+            key_status_from_http(StatusCode::BAD_GATEWAY, ""),
+            KeyStatus::Unreachable
+        );
+    }
+
+    #[test]
+    fn key_status_from_http_detects_gemini_invalid_key() {
+        let body = r#"{"error":{"code":400,"message":"API key not valid. Please pass a valid API key.","status":"INVALID_ARGUMENT","details":[{"@type":"type.googleapis.com/google.rpc.ErrorInfo","reason":"API_KEY_INVALID","domain":"googleapis.com"}]}}"#;
+        assert_eq!(
+            key_status_from_http(StatusCode::BAD_REQUEST, body),
+            KeyStatus::Unauthorized
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use serde_json::Value;
+use url::Url;
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::Provider;
+use crate::store::AppState;
+
+use super::ProviderService;
+
+impl ProviderService {
+    /// 将 live 配置中尚未被 cc-switch 管理的供应商导入为供应商记录，返回新导入的 ID。
+    ///
+    /// - Claude / Codex / Gemini：live 配置整体视为一个供应商；若已有供应商的端点与密钥
+    ///   （或完整配置）相同则跳过。管理器当前没有选中供应商时，导入的供应商成为当前供应商。
+    /// - OpenCode / OpenClaw（additive 模式）：逐个导入 live 中存在但本地缺失的供应商 ID。
+    ///
+    /// 密钥按原样保存。
+    pub fn import_from_live(state: &AppState, app_type: AppType) -> Result<Vec<String>, AppError> {
+        match app_type {
+            AppType::OpenCode => Self::import_opencode_from_live(state),
+            AppType::OpenClaw => Self::import_openclaw_from_live(state),
+            _ => Self::import_single_from_live(state, app_type),
+        }
+    }
+
+    fn import_single_from_live(
+        state: &AppState,
+        app_type: AppType,
+    ) -> Result<Vec<String>, AppError> {
+        let settings_config = Self::read_live_settings_for_import(&app_type)?;
+        let mut provider = Provider::with_id(String::new(), String::new(), settings_config, None);
+        let credentials = Self::extract_credentials(&provider, &app_type).ok();
+
+        let common_config_snippet = {
+            let config = state.config.read().map_err(AppError::from)?;
+            if let Some(manager) = config.get_manager(&app_type) {
+                let already_present = manager.providers.values().any(|existing| {
+                    existing.settings_config == provider.settings_config
+                        || credentials.is_some()
+                            && Self::extract_credentials(existing, &app_type).ok() == credentials
+                });
+                if already_present {
+                    return Ok(Vec::new());
+                }
+            }
+            config.common_config_snippets.get(&app_type).cloned()
+        };
+
+        let name = credentials
+            .as_ref()
+            .and_then(|(_, base_url)| Url::parse(base_url).ok())
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or_else(|| format!("{app_type} live"));
+        provider.name = name;
+        provider.category = Some("custom".to_string());
+        provider.settings_config = Self::normalize_settings_config_for_storage(
+            &app_type,
+            &provider,
+            provider.settings_config.clone(),
+            common_config_snippet.as_deref(),
+        )?;
+
+        let id = {
+            let mut config = state.config.write().map_err(AppError::from)?;
+            config.ensure_app(&app_type);
+            let manager = config
+                .get_manager_mut(&app_type)
+                .ok_or_else(|| Self::app_not_found(&app_type))?;
+            let existing_ids: Vec<String> = manager.providers.keys().cloned().collect();
+            let id =
+                super::generate_provider_id(&format!("imported {}", provider.name), &existing_ids);
+            provider.id = id.clone();
+            manager.providers.insert(id.clone(), provider);
+            if manager.current.is_empty() {
+                manager.current = id.clone();
+            }
+            id
+        };
+
+        state.save()?;
+        Ok(vec![id])
+    }
+
+    fn import_opencode_from_live(state: &AppState) -> Result<Vec<String>, AppError> {
+        if !crate::opencode_config::get_opencode_config_path().exists() {
+            return Ok(Vec::new());
+        }
+        let providers = crate::opencode_config::get_providers()?;
+
+        let mut imported = Vec::new();
+        {
+            let mut config = state.config.write().map_err(AppError::from)?;
+            config.ensure_app(&AppType::OpenCode);
+            let manager = config
+                .get_manager_mut(&AppType::OpenCode)
+                .ok_or_else(|| Self::app_not_found(&AppType::OpenCode))?;
+            for (id, settings_config) in providers {
+                if id.trim().is_empty() || manager.providers.contains_key(&id) {
+                    continue;
+                }
+                let name = settings_config
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .unwrap_or(&id)
+                    .to_string();
+                manager.providers.insert(
+                    id.clone(),
+                    Provider::with_id(id.clone(), name, settings_config, None),
+                );
+                imported.push(id);
+            }
+        }
+
+        if !imported.is_empty() {
+            state.save()?;
+        }
+        Ok(imported)
+    }
+
+    /// 复用 OpenClaw 的 live 镜像逻辑（会校验并规范化条目），只返回其中新增的 ID。
+    fn import_openclaw_from_live(state: &AppState) -> Result<Vec<String>, AppError> {
+        let known_ids: Vec<String> = {
+            let config = state.config.read().map_err(AppError::from)?;
+            config
+                .get_manager(&AppType::OpenClaw)
+                .map(|manager| manager.providers.keys().cloned().collect())
+                .unwrap_or_default()
+        };
+
+        super::live::sync_openclaw_providers_from_live(state)?;
+
+        let config = state.config.read().map_err(AppError::from)?;
+        Ok(config
+            .get_manager(&AppType::OpenClaw)
+            .map(|manager| {
+                manager
+                    .providers
+                    .keys()
+                    .filter(|id| !known_ids.contains(id))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+}
@@ -5,10 +5,13 @@ mod codex;
 #[cfg(test)]
 mod codex_openai_auth_tests;
 mod common;
+mod details;
 mod endpoints;
 mod gemini;
 mod gemini_auth;
+mod key_check;
 mod live;
+mod live_import;
 mod models;
 #[cfg(test)]
 mod tests;
@@ -31,11 +34,15 @@ use crate::store::AppState;
 use gemini_auth::GeminiAuthType;
 use live::LiveSnapshot;
 
-pub use common::migrate_legacy_codex_config;
+pub use common::{generate_provider_id, migrate_legacy_codex_config};
 use common::{
     is_codex_official_provider, merge_json_values, strip_codex_common_config_from_full_text,
     strip_common_values,
 };
+pub use details::{mask_api_key, ProviderDetails};
+pub use endpoints::{DuplicateGroup, DuplicateMember};
+pub use key_check::KeyStatus;
+pub use models::{ModelFetchAuth, ModelTestApiStyle, ModelTestResult};
 
 /// 供应商相关业务逻辑
 pub struct ProviderService;
@@ -66,6 +73,7 @@ struct PostCommitAction {
     refresh_snapshot: bool,
     common_config_snippet: Option<String>,
     takeover_active: bool,
+    reset_live: bool,
 }
 
 impl ProviderService {
@@ -210,6 +218,9 @@ impl ProviderService {
             use crate::services::mcp::McpService;
             McpService::sync_all_enabled(state)?;
         }
+        if action.reset_live && crate::sync_policy::should_sync_live(&action.app_type) {
+            Self::reset_mcp_and_prompt_live(state, &action.app_type)?;
+        }
         if !action.takeover_active
             && action.refresh_snapshot
             && crate::sync_policy::should_sync_live(&action.app_type)
@@ -224,6 +235,18 @@ impl ProviderService {
         Ok(())
     }
 
+    /// 按 `switch_resets_live` 设置重置 MCP 与提示词 live 文件；任一步失败时整组文件回滚
+    fn reset_mcp_and_prompt_live(state: &AppState, app_type: &AppType) -> Result<(), AppError> {
+        let mut paths = Vec::new();
+        paths.extend(crate::mcp::live_config_path(app_type));
+        paths.push(crate::prompt_files::prompt_file_path(app_type)?);
+
+        crate::config::with_files_rollback(&paths, || {
+            crate::services::mcp::McpService::reset_live_for_app(state, app_type)?;
+            crate::services::prompt::PromptService::reset_live_file(state, app_type.clone())
+        })
+    }
+
     fn refresh_provider_snapshot(
         state: &AppState,
         app_type: &AppType,
@@ -574,6 +597,7 @@ impl ProviderService {
             refresh_snapshot: false,
             common_config_snippet: config.common_config_snippets.get(app_type).cloned(),
             takeover_active,
+            reset_live: false,
         }))
     }
 
@@ -975,6 +999,7 @@ impl ProviderService {
                     refresh_snapshot: false,
                     common_config_snippet,
                     takeover_active: false,
+                    reset_live: false,
                 })
             } else if let Some(current_provider_id) = current_provider_id {
                 Self::build_post_commit_action_for_current_provider(
@@ -1084,6 +1109,7 @@ impl ProviderService {
                     refresh_snapshot: false,
                     common_config_snippet,
                     takeover_active: false,
+                    reset_live: false,
                 })
             } else if let Some(current_provider_id) = current_provider_id {
                 Self::build_post_commit_action_for_current_provider(
@@ -1149,7 +1175,46 @@ impl ProviderService {
             }
         }
 
-        let settings_config = match app_type {
+        let settings_config = Self::read_live_settings_for_import(&app_type)?;
+
+        let mut provider = Provider::with_id(
+            "default".to_string(),
+            "default".to_string(),
+            settings_config,
+            None,
+        );
+        provider.category = Some("custom".to_string());
+
+        let common_config_snippet = {
+            let config = state.config.read().map_err(AppError::from)?;
+            config.common_config_snippets.get(&app_type).cloned()
+        };
+        provider.settings_config = Self::normalize_settings_config_for_storage(
+            &app_type,
+            &provider,
+            provider.settings_config.clone(),
+            common_config_snippet.as_deref(),
+        )?;
+
+        {
+            let mut config = state.config.write().map_err(AppError::from)?;
+            config.ensure_app(&app_type);
+            let manager = config
+                .get_manager_mut(&app_type)
+                .ok_or_else(|| Self::app_not_found(&app_type))?;
+            manager
+                .providers
+                .insert(provider.id.clone(), provider.clone());
+            manager.current = provider.id.clone();
+        }
+
+        state.save()?;
+        Ok(())
+    }
+
+    /// 读取 live 配置并转换为供应商 settings_config（仅非 additive 模式应用）
+    fn read_live_settings_for_import(app_type: &AppType) -> Result<Value, AppError> {
+        Ok(match app_type {
             AppType::Codex => {
                 let auth_path = get_codex_auth_path();
                 if !auth_path.exists() {
@@ -1209,43 +1274,10 @@ impl ProviderService {
                     "config": config_obj
                 })
             }
-            AppType::OpenCode => unreachable!("additive mode apps are handled earlier"),
-            AppType::OpenClaw => unreachable!("additive mode apps are handled earlier"),
-        };
-
-        let mut provider = Provider::with_id(
-            "default".to_string(),
-            "default".to_string(),
-            settings_config,
-            None,
-        );
-        provider.category = Some("custom".to_string());
-
-        let common_config_snippet = {
-            let config = state.config.read().map_err(AppError::from)?;
-            config.common_config_snippets.get(&app_type).cloned()
-        };
-        provider.settings_config = Self::normalize_settings_config_for_storage(
-            &app_type,
-            &provider,
-            provider.settings_config.clone(),
-            common_config_snippet.as_deref(),
-        )?;
-
-        {
-            let mut config = state.config.write().map_err(AppError::from)?;
-            config.ensure_app(&app_type);
-            let manager = config
-                .get_manager_mut(&app_type)
-                .ok_or_else(|| Self::app_not_found(&app_type))?;
-            manager
-                .providers
-                .insert(provider.id.clone(), provider.clone());
-            manager.current = provider.id.clone();
-        }
-
-        state.save()?;
-        Ok(())
+            AppType::OpenCode | AppType::OpenClaw => {
+                unreachable!("additive mode apps are handled by the caller")
+            }
+        })
     }
 
     /// 读取当前 live 配置
@@ -1491,6 +1523,7 @@ impl ProviderService {
                         .get(&app_type_clone)
                         .cloned(),
                     takeover_active: false,
+                    reset_live: crate::settings::get_switch_resets_live(),
                 };
 
                 return Ok(((), Some(action)));
@@ -1526,6 +1559,7 @@ impl ProviderService {
                         .get(&app_type_clone)
                         .cloned(),
                     takeover_active: true,
+                    reset_live: false,
                 };
 
                 return Ok(((), Some(action)));
@@ -1548,12 +1582,38 @@ impl ProviderService {
                 refresh_snapshot: true,
                 common_config_snippet: config.common_config_snippets.get(&app_type_clone).cloned(),
                 takeover_active: false,
+                reset_live: crate::settings::get_switch_resets_live(),
             };
 
             Ok(((), Some(action)))
         })
     }
 
+    /// 将供应商写入 OpenCode 的 `provider.<id>`（OpenCode 为叠加模式，不影响其他供应商）。
+    ///
+    /// 兼容两种存储形态：供应商配置本身，或从完整 opencode.json 导入的整份配置。
+    pub(crate) fn write_opencode_live(provider: &Provider) -> Result<(), AppError> {
+        let config_to_write = if let Some(obj) = provider.settings_config.as_object() {
+            if obj.contains_key("$schema") || obj.contains_key("provider") {
+                obj.get("provider")
+                    .and_then(|providers| providers.get(&provider.id))
+                    .cloned()
+                    .unwrap_or_else(|| provider.settings_config.clone())
+            } else {
+                provider.settings_config.clone()
+            }
+        } else {
+            provider.settings_config.clone()
+        };
+
+        match serde_json::from_value::<crate::provider::OpenCodeProviderConfig>(
+            config_to_write.clone(),
+        ) {
+            Ok(config) => crate::opencode_config::set_typed_provider(&provider.id, &config),
+            Err(_) => crate::opencode_config::set_provider(&provider.id, config_to_write),
+        }
+    }
+
     fn write_live_snapshot(
         app_type: &AppType,
         provider: &Provider,
@@ -1587,27 +1647,7 @@ impl ProviderService {
                     None
                 },
             ),
-            AppType::OpenCode => {
-                let config_to_write = if let Some(obj) = provider.settings_config.as_object() {
-                    if obj.contains_key("$schema") || obj.contains_key("provider") {
-                        obj.get("provider")
-                            .and_then(|providers| providers.get(&provider.id))
-                            .cloned()
-                            .unwrap_or_else(|| provider.settings_config.clone())
-                    } else {
-                        provider.settings_config.clone()
-                    }
-                } else {
-                    provider.settings_config.clone()
-                };
-
-                match serde_json::from_value::<crate::provider::OpenCodeProviderConfig>(
-                    config_to_write.clone(),
-                ) {
-                    Ok(config) => crate::opencode_config::set_typed_provider(&provider.id, &config),
-                    Err(_) => crate::opencode_config::set_provider(&provider.id, config_to_write),
-                }
-            }
+            AppType::OpenCode => Self::write_opencode_live(provider),
             AppType::OpenClaw => {
                 let settings_config = provider.settings_config.clone();
                 let looks_like_provider = settings_config.get("baseUrl").is_some()
@@ -1,18 +1,80 @@
-use reqwest::Client;
-use serde_json::Value;
+use serde::Serialize;
+use serde_json::{json, Value};
 use std::collections::HashSet;
-use std::time::Duration;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
 use crate::error::AppError;
+use crate::services::http;
 
 use super::ProviderService;
 
+/// 拉取模型列表时单个候选 URL 的超时时间
+const FETCH_MODELS_TIMEOUT: Duration = Duration::from_secs(5);
+/// 拉取前探测主机可达性的超时时间，主机不可达时不再逐个尝试候选 URL
+const FETCH_MODELS_PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+/// 未指定时整个拉取流程（探测 + 所有候选 URL）的总时限
+const FETCH_MODELS_DEFAULT_DEADLINE: Duration = Duration::from_secs(10);
+
+/// 模型试调用的超时时间，只需确认能否生成首个 token
+const MODEL_TEST_TIMEOUT: Duration = Duration::from_secs(8);
+const MODEL_TEST_PROMPT: &str = "hi";
+/// 请求日志标记
+const MODELS_LOG_TAG: http::LogTag = http::LogTag {
+    label: "Models",
+    target: module_path!(),
+};
+/// 失败时保留的响应体长度上限
+const MODEL_TEST_ERROR_BODY_CHARS: usize = 300;
+
+/// 试调用模型时使用的请求格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ModelTestApiStyle {
+    /// Anthropic Messages API (`/v1/messages`)
+    Anthropic,
+    /// OpenAI Chat Completions (`/v1/chat/completions`)
+    OpenAiChat,
+    /// OpenAI Responses (`/v1/responses`)
+    OpenAiResponses,
+    /// Gemini `generateContent`
+    Gemini,
+}
+
+/// 拉取模型列表时使用的鉴权方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelFetchAuth {
+    /// `Authorization: Bearer`
+    Bearer,
+    /// Bearer 与 `x-api-key` 同时附带，并带上 `anthropic-version`；优先尝试 `/v1/models`
+    Anthropic,
+    /// Gemini 的 `x-goog-api-key`
+    GoogleApiKey,
+}
+
+/// 模型试调用结果
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelTestResult {
+    pub success: bool,
+    pub status: Option<u16>,
+    pub latency: u128,
+    pub error: Option<String>,
+}
+
 impl ProviderService {
-    /// 尝试从远端拉取模型列表
+    /// 尝试从远端拉取模型列表。
+    ///
+    /// `auth` 决定鉴权头与候选 URL 的顺序；`deadline` 为所有候选 URL 共享的总时限
+    /// （默认 [`FETCH_MODELS_DEFAULT_DEADLINE`]），每次请求的超时不超过剩余时间；
+    /// `cancel` 在每个候选之前检查，置位后立即返回。
     pub async fn fetch_provider_models(
         base_url: &str,
         api_key: Option<&str>,
+        auth: ModelFetchAuth,
+        deadline: Option<Duration>,
+        cancel: Option<&AtomicBool>,
     ) -> Result<Vec<String>, AppError> {
+        let deadline_at = Instant::now() + deadline.unwrap_or(FETCH_MODELS_DEFAULT_DEADLINE);
         let base_url = base_url.trim().trim_end_matches('/');
         if base_url.is_empty() {
             return Err(AppError::localized(
@@ -22,113 +84,778 @@ impl ProviderService {
             ));
         }
 
-        let mut candidate_urls = Vec::new();
+        let candidate_urls = model_list_candidate_urls(base_url, auth);
+        let api_key = api_key.map(str::trim).filter(|key| !key.is_empty());
 
-        // 如果用户直接填了 /v1/models 或者 /models，我们就直接用
-        if base_url.ends_with("/models") {
-            candidate_urls.push(base_url.to_string());
-        } else {
-            // 智能适配：如果没带 /models，尝试追加
-            candidate_urls.push(format!("{}/models", base_url));
-            if !base_url.ends_with("/v1") && !base_url.ends_with("/v1beta") {
-                candidate_urls.push(format!("{}/v1/models", base_url));
-            }
-        }
+        ensure_not_cancelled(cancel)?;
+        probe_host_reachable(base_url, remaining_until(deadline_at)).await?;
 
-        let client = Client::builder()
-            .timeout(Duration::from_secs(5))
+        let client = http::client_builder()?
+            .timeout(FETCH_MODELS_TIMEOUT)
             .build()
             .map_err(|e| AppError::Message(e.to_string()))?;
 
-        let mut last_err_zh = None;
-        let mut last_err_en = None;
+        let mut attempts: Vec<AttemptOutcome> = Vec::new();
 
         for url in candidate_urls {
-            let mut req = client.get(&url);
+            ensure_not_cancelled(cancel)?;
+            let remaining = remaining_until(deadline_at);
+            if remaining.is_zero() {
+                attempts.push(AttemptOutcome {
+                    url,
+                    status_or_error: "已超过总时限，未尝试 (skipped, overall deadline reached)"
+                        .to_string(),
+                });
+                continue;
+            }
+
+            let mut req = client
+                .get(&url)
+                .timeout(remaining.min(FETCH_MODELS_TIMEOUT));
             if let Some(key) = api_key {
-                let key = key.trim();
-                // 同时添加 OpenAI 的 Bearer 和 Anthropic 的 x-api-key 格式，代理服务通常会接受其中之一
-                req = req
-                    .header("Authorization", format!("Bearer {}", key))
-                    .header("x-api-key", key);
+                req = match auth {
+                    ModelFetchAuth::Bearer => req.header("Authorization", format!("Bearer {key}")),
+                    // 同时添加 Bearer 和 x-api-key，Anthropic 中转通常会接受其中之一
+                    ModelFetchAuth::Anthropic => req
+                        .header("Authorization", format!("Bearer {key}"))
+                        .header("x-api-key", key)
+                        .header("anthropic-version", "2023-06-01"),
+                    ModelFetchAuth::GoogleApiKey => req.header("x-goog-api-key", key),
+                };
             }
 
-            match req.send().await {
+            let status_or_error = match http::send_with_retry(
+                &client,
+                req,
+                Some(deadline_at),
+                MODELS_LOG_TAG,
+            )
+            .await
+            {
                 Ok(resp) => {
-                    if resp.status().is_success() {
+                    let status = resp.status();
+                    if status.is_success() {
                         if let Ok(json) = resp.json::<Value>().await {
-                            let mut models = Vec::new();
-
-                            // 测试格式 1: OpenAI 兼容格式 {"data": [{"id": "gpt-4o"}]}
-                            if let Some(data) = json.get("data").and_then(|d| d.as_array()) {
-                                for item in data {
-                                    if let Some(id) = item.get("id").and_then(|i| i.as_str()) {
-                                        models.push(id.to_string());
-                                    }
-                                }
-                            }
-
-                            // 测试格式 2: Gemini 格式 {"models": [{"name": "models/gemini-pro"}]}
-                            if models.is_empty() {
-                                if let Some(data) = json.get("models").and_then(|d| d.as_array()) {
-                                    for item in data {
-                                        if let Some(name) =
-                                            item.get("name").and_then(|i| i.as_str())
-                                        {
-                                            let id = name.strip_prefix("models/").unwrap_or(name);
-                                            models.push(id.to_string());
-                                        }
-                                    }
-                                }
-                            }
-
-                            // 测试格式 3: 直接的数组格式 [{"id": "llama-3"}]
-                            if models.is_empty() {
-                                if let Some(arr) = json.as_array() {
-                                    for item in arr {
-                                        if let Some(id) = item.get("id").and_then(|i| i.as_str()) {
-                                            models.push(id.to_string());
-                                        }
-                                    }
-                                }
-                            }
-
+                            let mut models = parse_model_ids(&json);
                             if !models.is_empty() {
                                 // 保序去重，避免非相邻重复项残留。
                                 let mut seen = HashSet::new();
                                 models.retain(|model| seen.insert(model.clone()));
                                 return Ok(models);
-                            } else {
-                                last_err_zh =
-                                    Some(format!("未能在响应中找到模型列表 (URL: {})", url));
-                                last_err_en =
-                                    Some(format!("No model list found in response (URL: {})", url));
                             }
+                            format!(
+                                "HTTP {status}, 未能在响应中找到模型列表 (no model list found in response)"
+                            )
                         } else {
-                            last_err_zh = Some(format!("无法解析 JSON 响应 (URL: {})", url));
-                            last_err_en =
-                                Some(format!("Failed to parse JSON response (URL: {})", url));
+                            format!(
+                                "HTTP {status}, 无法解析 JSON 响应 (failed to parse JSON response)"
+                            )
                         }
                     } else {
-                        let err = format!("HTTP {} (URL: {})", resp.status(), url);
-                        last_err_zh = Some(err.clone());
-                        last_err_en = Some(err);
+                        format!("HTTP {status}")
                     }
                 }
-                Err(e) => {
-                    let err = e.to_string();
-                    last_err_zh = Some(err.clone());
-                    last_err_en = Some(err);
-                }
-            }
+                Err(e) => e.to_string(),
+            };
+            attempts.push(AttemptOutcome {
+                url,
+                status_or_error,
+            });
         }
 
-        let err_zh = last_err_zh.unwrap_or_else(|| "未知错误".to_string());
-        let err_en = last_err_en.unwrap_or_else(|| "Unknown error".to_string());
+        let details = attempts
+            .iter()
+            .map(|attempt| format!("  - {attempt}"))
+            .collect::<Vec<_>>()
+            .join("\n");
         Err(AppError::localized(
             "fetch.failed",
-            format!("拉取失败: {}", err_zh),
-            format!("Fetch failed: {}", err_en),
+            format!("拉取失败，所有候选地址均未返回模型列表:\n{details}"),
+            format!("Fetch failed, attempted URLs:\n{details}"),
         ))
     }
+
+    /// 用 1 个 token 的最小请求试调用模型，确认密钥不仅能列出模型，也能实际调用。
+    ///
+    /// HTTP 层面的失败（如 402 / 429）体现在返回结果中；仅参数无效时返回错误。
+    pub async fn test_model(
+        base_url: &str,
+        api_key: &str,
+        model: &str,
+        api_style: ModelTestApiStyle,
+    ) -> Result<ModelTestResult, AppError> {
+        let base_url = base_url.trim().trim_end_matches('/');
+        if base_url.is_empty() {
+            return Err(AppError::localized(
+                "fetch.invalid_url",
+                "URL 不能为空",
+                "URL cannot be empty",
+            ));
+        }
+        let model = model.trim();
+        if model.is_empty() {
+            return Err(AppError::localized(
+                "model_test.empty_model",
+                "模型名称不能为空",
+                "Model name cannot be empty",
+            ));
+        }
+        let api_key = api_key.trim();
+
+        let client = http::client_builder()?
+            .timeout(MODEL_TEST_TIMEOUT)
+            .build()
+            .map_err(|e| AppError::Message(e.to_string()))?;
+
+        let request = match api_style {
+            ModelTestApiStyle::Anthropic => client
+                .post(versioned_url(base_url, "messages"))
+                .header("Authorization", format!("Bearer {api_key}"))
+                .header("x-api-key", api_key)
+                .header("anthropic-version", "2023-06-01")
+                .json(&json!({
+                    "model": model,
+                    "max_tokens": 1,
+                    "messages": [{ "role": "user", "content": MODEL_TEST_PROMPT }],
+                })),
+            ModelTestApiStyle::OpenAiChat => client
+                .post(versioned_url(base_url, "chat/completions"))
+                .header("Authorization", format!("Bearer {api_key}"))
+                .json(&json!({
+                    "model": model,
+                    "max_tokens": 1,
+                    "messages": [{ "role": "user", "content": MODEL_TEST_PROMPT }],
+                })),
+            ModelTestApiStyle::OpenAiResponses => client
+                .post(versioned_url(base_url, "responses"))
+                .header("Authorization", format!("Bearer {api_key}"))
+                .json(&json!({
+                    "model": model,
+                    // Responses API 要求 max_output_tokens >= 16
+                    "max_output_tokens": 16,
+                    "input": MODEL_TEST_PROMPT,
+                })),
+            ModelTestApiStyle::Gemini => {
+                let base = if base_url.ends_with("/v1beta") || base_url.ends_with("/v1") {
+                    base_url.to_string()
+                } else {
+                    format!("{base_url}/v1beta")
+                };
+                let model = model.strip_prefix("models/").unwrap_or(model);
+                client
+                    .post(format!("{base}/models/{model}:generateContent"))
+                    .header("x-goog-api-key", api_key)
+                    .json(&json!({
+                        "contents": [{ "role": "user", "parts": [{ "text": MODEL_TEST_PROMPT }] }],
+                        "generationConfig": { "maxOutputTokens": 1 },
+                    }))
+            }
+        };
+
+        let start = Instant::now();
+        let result = match request.send().await {
+            Ok(resp) => {
+                let status = resp.status();
+                let error = if status.is_success() {
+                    None
+                } else {
+                    let body = resp.text().await.unwrap_or_default();
+                    let body: String = body
+                        .trim()
+                        .chars()
+                        .take(MODEL_TEST_ERROR_BODY_CHARS)
+                        .collect();
+                    Some(if body.is_empty() {
+                        format!("HTTP {status}")
+                    } else {
+                        format!("HTTP {status}: {body}")
+                    })
+                };
+                ModelTestResult {
+                    success: status.is_success(),
+                    status: Some(status.as_u16()),
+                    latency: start.elapsed().as_millis(),
+                    error,
+                }
+            }
+            Err(e) => ModelTestResult {
+                success: false,
+                status: None,
+                latency: start.elapsed().as_millis(),
+                error: Some(if e.is_timeout() {
+                    "请求超时 (Request timed out)".to_string()
+                } else {
+                    e.to_string()
+                }),
+            },
+        };
+        Ok(result)
+    }
+}
+
+/// 模型列表的候选地址：已以 `/models` 结尾时直接使用；否则追加 `/models`，
+/// 不带版本段时再尝试 `/v1/models`（Anthropic 优先尝试后者）
+fn model_list_candidate_urls(base_url: &str, auth: ModelFetchAuth) -> Vec<String> {
+    if base_url.ends_with("/models") {
+        return vec![base_url.to_string()];
+    }
+    let mut urls = vec![format!("{base_url}/models")];
+    if !base_url.ends_with("/v1") && !base_url.ends_with("/v1beta") {
+        let v1 = format!("{base_url}/v1/models");
+        if auth == ModelFetchAuth::Anthropic {
+            urls.insert(0, v1);
+        } else {
+            urls.push(v1);
+        }
+    }
+    urls
+}
+
+/// 距离总时限的剩余时间，已超时为零
+fn remaining_until(deadline_at: Instant) -> Duration {
+    deadline_at.saturating_duration_since(Instant::now())
+}
+
+fn ensure_not_cancelled(cancel: Option<&AtomicBool>) -> Result<(), AppError> {
+    if cancel.is_some_and(|flag| flag.load(Ordering::Relaxed)) {
+        return Err(AppError::localized(
+            "fetch.cancelled",
+            "已取消拉取模型列表",
+            "Model fetch was cancelled",
+        ));
+    }
+    Ok(())
+}
+
+/// 对 base_url 所在主机发一次短超时的 HEAD 请求；只要有任何 HTTP 响应即视为可达。
+/// URL 无法解析时交由后续完整流程报告错误。超时不超过 `remaining`。
+async fn probe_host_reachable(base_url: &str, remaining: Duration) -> Result<(), AppError> {
+    let Ok(mut url) = reqwest::Url::parse(base_url) else {
+        return Ok(());
+    };
+    url.set_path("/");
+    url.set_query(None);
+    url.set_fragment(None);
+
+    let client = http::client_builder()?
+        .timeout(FETCH_MODELS_PROBE_TIMEOUT.min(remaining))
+        .build()
+        .map_err(|e| AppError::Message(e.to_string()))?;
+
+    match client.head(url.clone()).send().await {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            let host = url.host_str().unwrap_or(base_url).to_string();
+            let reason = if e.is_timeout() {
+                "请求超时 (Request timed out)".to_string()
+            } else {
+                e.to_string()
+            };
+            Err(AppError::localized(
+                "fetch.unreachable",
+                format!("主机不可达: {host} ({reason})"),
+                format!("Host unreachable: {host} ({reason})"),
+            ))
+        }
+    }
+}
+
+/// 单个候选 URL 的拉取结果，失败时汇总进错误信息，便于区分路径错误与密钥错误
+#[derive(Debug)]
+struct AttemptOutcome {
+    url: String,
+    status_or_error: String,
+}
+
+impl std::fmt::Display for AttemptOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.url, self.status_or_error)
+    }
+}
+
+/// 从模型列表响应中提取模型 ID，兼容 OpenAI、Gemini 与纯数组格式
+fn parse_model_ids(json: &Value) -> Vec<String> {
+    // 测试格式 1: OpenAI 兼容格式 {"data": [{"id": "gpt-4o"}]}
+    if let Some(data) = json.get("data").and_then(|d| d.as_array()) {
+        let models: Vec<String> = data
+            .iter()
+            .filter_map(|item| item.get("id").and_then(|i| i.as_str()))
+            .map(str::to_string)
+            .collect();
+        if !models.is_empty() {
+            return models;
+        }
+    }
+
+    // 测试格式 2: Gemini 格式 {"models": [{"name": "models/gemini-pro"}]}
+    if let Some(data) = json.get("models").and_then(|d| d.as_array()) {
+        let models: Vec<String> = data
+            .iter()
+            .filter_map(|item| item.get("name").and_then(|i| i.as_str()))
+            .map(|name| name.strip_prefix("models/").unwrap_or(name).to_string())
+            .collect();
+        if !models.is_empty() {
+            return models;
+        }
+    }
+
+    // 测试格式 3: 直接的数组格式 [{"id": "llama-3"}]
+    json.as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|item| item.get("id").and_then(|i| i.as_str()))
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 在 base_url 后追加 API 路径；未带 `/v1` 时自动补上
+pub(super) fn versioned_url(base_url: &str, path: &str) -> String {
+    if base_url.ends_with("/v1") {
+        format!("{base_url}/{path}")
+    } else {
+        format!("{base_url}/v1/{path}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        http::StatusCode,
+        response::IntoResponse,
+        routing::{get, post},
+        Json, Router,
+    };
+    use std::sync::Arc;
+
+    async fn serve(router: Router) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind mock server");
+        let addr = listener.local_addr().expect("mock server addr");
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, router).await;
+        });
+        format!("http://{addr}")
+    }
+
+    #[tokio::test]
+    async fn fetch_provider_models_fails_fast_when_host_is_unreachable() {
+        // 接受连接但从不响应，模拟挂死的主机
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind silent server");
+        let base = format!(
+            "http://{}",
+            listener.local_addr().expect("silent server addr")
+        );
+
+        let start = Instant::now();
+        let err = ProviderService::fetch_provider_models(
+            &base,
+            Some("sk-test"),
+            ModelFetchAuth::Bearer,
+            None,
+            None,
+        )
+        .await
+        .expect_err("silent host should be reported as unreachable");
+        let elapsed = start.elapsed();
+
+        assert!(
+            err.to_string().contains("Host unreachable"),
+            "unexpected error: {err}"
+        );
+        assert!(
+            elapsed < FETCH_MODELS_PROBE_TIMEOUT + Duration::from_secs(1),
+            "should fail within the probe timeout, took {elapsed:?}"
+        );
+        drop(listener);
+    }
+
+    #[tokio::test]
+    async fn fetch_provider_models_runs_full_lookup_when_host_is_reachable() {
+        let router = Router::new().route(
+            "/v1/models",
+            get(|| async { Json(json!({ "data": [{ "id": "gpt-4o" }, { "id": "gpt-4o" }] })) }),
+        );
+        let base = serve(router).await;
+
+        let models =
+            ProviderService::fetch_provider_models(&base, None, ModelFetchAuth::Bearer, None, None)
+                .await
+                .expect("reachable host should return models");
+        assert_eq!(models, vec!["gpt-4o".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn fetch_provider_models_follows_redirect_with_auth_headers() {
+        let router = Router::new()
+            .route(
+                "/v1/models",
+                get(|| async {
+                    (
+                        StatusCode::FOUND,
+                        [(axum::http::header::LOCATION, "/relay/v1/models")],
+                    )
+                }),
+            )
+            .route(
+                "/relay/v1/models",
+                get(|headers: axum::http::HeaderMap| async move {
+                    assert_eq!(
+                        headers.get("x-api-key").and_then(|v| v.to_str().ok()),
+                        Some("sk-test")
+                    );
+                    Json(json!({ "data": [{ "id": "relayed-model" }] }))
+                }),
+            );
+        let base = serve(router).await;
+
+        let models = ProviderService::fetch_provider_models(
+            &format!("{base}/v1"),
+            Some("sk-test"),
+            ModelFetchAuth::Anthropic,
+            None,
+            None,
+        )
+        .await
+        .expect("redirected models endpoint should be followed");
+        assert_eq!(models, vec!["relayed-model".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn fetch_provider_models_retries_service_unavailable() {
+        let hits = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let router = Router::new().route(
+            "/v1/models",
+            get({
+                let hits = hits.clone();
+                move || async move {
+                    if hits.fetch_add(1, Ordering::SeqCst) == 0 {
+                        StatusCode::SERVICE_UNAVAILABLE.into_response()
+                    } else {
+                        Json(json!({ "data": [{ "id": "gpt-4o" }] })).into_response()
+                    }
+                }
+            }),
+        );
+        let base = serve(router).await;
+
+        let models = ProviderService::fetch_provider_models(
+            &format!("{base}/v1"),
+            None,
+            ModelFetchAuth::Bearer,
+            None,
+            None,
+        )
+        .await
+        .expect("transient 503 should be retried");
+        assert_eq!(models, vec!["gpt-4o".to_string()]);
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn fetch_provider_models_error_lists_every_attempted_url() {
+        let router = Router::new()
+            .route("/models", get(|| async { StatusCode::NOT_FOUND }))
+            .route("/v1/models", get(|| async { StatusCode::UNAUTHORIZED }));
+        let base = serve(router).await;
+
+        let err = ProviderService::fetch_provider_models(
+            &base,
+            Some("sk-wrong"),
+            ModelFetchAuth::Bearer,
+            None,
+            None,
+        )
+        .await
+        .expect_err("all candidates fail");
+        let message = err.to_string();
+
+        assert!(
+            message.contains(&format!("{base}/models: HTTP 404 Not Found")),
+            "missing /models attempt: {message}"
+        );
+        assert!(
+            message.contains(&format!("{base}/v1/models: HTTP 401 Unauthorized")),
+            "missing /v1/models attempt: {message}"
+        );
+        let AppError::Localized { zh, .. } = &err else {
+            panic!("expected a localized error: {err:?}");
+        };
+        assert!(
+            zh.contains(&format!("{base}/v1/models: HTTP 401 Unauthorized")),
+            "zh message should list attempts too: {zh}"
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_provider_models_honors_overall_deadline_across_candidates() {
+        // 两个候选 URL 都比总时限慢，探测用的 HEAD / 立即响应
+        let slow = || async {
+            tokio::time::sleep(Duration::from_secs(3)).await;
+            Json(json!({ "data": [{ "id": "too-late" }] }))
+        };
+        let router = Router::new()
+            .route("/", get(|| async { StatusCode::OK }))
+            .route("/models", get(slow))
+            .route("/v1/models", get(slow));
+        let base = serve(router).await;
+        let deadline = Duration::from_millis(800);
+
+        let start = Instant::now();
+        let err = ProviderService::fetch_provider_models(
+            &base,
+            Some("sk-test"),
+            ModelFetchAuth::Bearer,
+            Some(deadline),
+            None,
+        )
+        .await
+        .expect_err("slow candidates should hit the deadline");
+        let elapsed = start.elapsed();
+
+        assert!(
+            elapsed < deadline + Duration::from_millis(500),
+            "should return within the overall deadline, took {elapsed:?}"
+        );
+        assert!(
+            err.to_string()
+                .contains(&format!("{base}/v1/models: 已超过总时限")),
+            "later candidates should be skipped: {err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_provider_models_stops_between_candidates_when_cancelled() {
+        let cancel = Arc::new(AtomicBool::new(false));
+        let second_hit = Arc::new(AtomicBool::new(false));
+        let router = Router::new()
+            .route(
+                "/models",
+                get({
+                    let cancel = cancel.clone();
+                    move || async move {
+                        // 用户在首个候选请求期间离开页面
+                        cancel.store(true, Ordering::Relaxed);
+                        StatusCode::NOT_FOUND
+                    }
+                }),
+            )
+            .route(
+                "/v1/models",
+                get({
+                    let second_hit = second_hit.clone();
+                    move || async move {
+                        second_hit.store(true, Ordering::Relaxed);
+                        Json(json!({ "data": [{ "id": "gpt-4o" }] }))
+                    }
+                }),
+            );
+        let base = serve(router).await;
+
+        let err = ProviderService::fetch_provider_models(
+            &base,
+            None,
+            ModelFetchAuth::Bearer,
+            None,
+            Some(&cancel),
+        )
+        .await
+        .expect_err("cancelled fetch should stop");
+        assert!(
+            err.to_string().contains("cancelled"),
+            "unexpected error: {err}"
+        );
+        assert!(!second_hit.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn model_list_candidate_urls_prefers_v1_for_anthropic_base() {
+        assert_eq!(
+            model_list_candidate_urls("https://api.anthropic.com", ModelFetchAuth::Anthropic),
+            vec![
+                "https://api.anthropic.com/v1/models".to_string(),
+                "https://api.anthropic.com/models".to_string()
+            ]
+        );
+        assert_eq!(
+            model_list_candidate_urls("https://api.example.com", ModelFetchAuth::Bearer),
+            vec![
+                "https://api.example.com/models".to_string(),
+                "https://api.example.com/v1/models".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn model_list_candidate_urls_for_gemini_v1beta_keeps_models_endpoint() {
+        assert_eq!(
+            model_list_candidate_urls(
+                "https://generativelanguage.googleapis.com/v1beta",
+                ModelFetchAuth::GoogleApiKey
+            ),
+            vec!["https://generativelanguage.googleapis.com/v1beta/models".to_string()]
+        );
+    }
+
+    #[test]
+    fn parse_model_ids_supports_openai_and_gemini_shapes() {
+        assert_eq!(
+            parse_model_ids(&json!({ "data": [{ "id": "gpt-4o" }, { "id": "o3" }] })),
+            vec!["gpt-4o", "o3"]
+        );
+        assert_eq!(
+            parse_model_ids(&json!({
+                "models": [
+                    { "name": "models/gemini-2.0-pro" },
+                    { "name": "models/gemini-2.0-flash" }
+                ]
+            })),
+            vec!["gemini-2.0-pro", "gemini-2.0-flash"]
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_provider_models_sends_google_api_key_header() {
+        let router = Router::new().route(
+            "/v1beta/models",
+            get(|headers: axum::http::HeaderMap| async move {
+                assert_eq!(
+                    headers.get("x-goog-api-key").and_then(|v| v.to_str().ok()),
+                    Some("gm-key")
+                );
+                assert!(headers.get("authorization").is_none());
+                Json(json!({ "models": [{ "name": "models/gemini-2.0-flash" }] }))
+            }),
+        );
+        let base = serve(router).await;
+
+        let models = ProviderService::fetch_provider_models(
+            &format!("{base}/v1beta"),
+            Some("gm-key"),
+            ModelFetchAuth::GoogleApiKey,
+            None,
+            None,
+        )
+        .await
+        .expect("gemini models endpoint should be used");
+        assert_eq!(models, vec!["gemini-2.0-flash".to_string()]);
+    }
+
+    #[test]
+    fn versioned_url_appends_v1_only_when_missing() {
+        assert_eq!(
+            versioned_url("https://api.example.com", "messages"),
+            "https://api.example.com/v1/messages"
+        );
+        assert_eq!(
+            versioned_url("https://api.example.com/v1", "chat/completions"),
+            "https://api.example.com/v1/chat/completions"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_model_reports_success_for_each_api_style() {
+        let router = Router::new()
+            .route(
+                "/v1/messages",
+                post(|Json(body): Json<Value>| async move {
+                    assert_eq!(body["max_tokens"], 1);
+                    Json(json!({ "type": "message" }))
+                }),
+            )
+            .route(
+                "/v1/chat/completions",
+                post(|Json(body): Json<Value>| async move {
+                    assert_eq!(body["messages"][0]["role"], "user");
+                    Json(json!({ "choices": [] }))
+                }),
+            )
+            .route(
+                "/v1/responses",
+                post(|Json(body): Json<Value>| async move {
+                    assert!(body.get("input").is_some());
+                    Json(json!({ "output": [] }))
+                }),
+            )
+            .route(
+                "/v1beta/models/gemini-pro:generateContent",
+                post(|Json(body): Json<Value>| async move {
+                    assert_eq!(body["generationConfig"]["maxOutputTokens"], 1);
+                    Json(json!({ "candidates": [] }))
+                }),
+            );
+        let base = serve(router).await;
+
+        for (style, model) in [
+            (ModelTestApiStyle::Anthropic, "claude-sonnet"),
+            (ModelTestApiStyle::OpenAiChat, "gpt-4o"),
+            (ModelTestApiStyle::OpenAiResponses, "gpt-5"),
+            (ModelTestApiStyle::Gemini, "models/gemini-pro"),
+        ] {
+            let result = ProviderService::test_model(&base, "sk-test", model, style)
+                .await
+                .expect("test_model should not error");
+            assert!(result.success, "{style:?} should succeed: {result:?}");
+            assert_eq!(result.status, Some(200));
+            assert!(result.error.is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_model_reports_payment_and_rate_limit_failures() {
+        let router = Router::new()
+            .route(
+                "/v1/messages",
+                post(|| async {
+                    (
+                        StatusCode::PAYMENT_REQUIRED,
+                        r#"{"error":{"message":"insufficient balance"}}"#,
+                    )
+                }),
+            )
+            .route(
+                "/v1/chat/completions",
+                post(|| async { (StatusCode::TOO_MANY_REQUESTS, "") }),
+            );
+        let base = serve(router).await;
+
+        let payment = ProviderService::test_model(
+            &base,
+            "sk-test",
+            "claude-sonnet",
+            ModelTestApiStyle::Anthropic,
+        )
+        .await
+        .expect("402 is reported in the result");
+        assert!(!payment.success);
+        assert_eq!(payment.status, Some(402));
+        assert!(payment
+            .error
+            .as_deref()
+            .is_some_and(|e| e.contains("insufficient balance")));
+
+        let limited =
+            ProviderService::test_model(&base, "sk-test", "gpt-4o", ModelTestApiStyle::OpenAiChat)
+                .await
+                .expect("429 is reported in the result");
+        assert!(!limited.success);
+        assert_eq!(limited.status, Some(429));
+        assert_eq!(limited.error.as_deref(), Some("HTTP 429 Too Many Requests"));
+    }
+
+    #[tokio::test]
+    async fn test_model_rejects_empty_model() {
+        let err = ProviderService::test_model(
+            "https://api.example.com",
+            "sk-test",
+            "  ",
+            ModelTestApiStyle::OpenAiChat,
+        )
+        .await
+        .expect_err("empty model should be rejected");
+        assert!(err.to_string().contains("Model name cannot be empty"));
+    }
 }
@@ -2602,6 +2602,214 @@ fn import_default_config_strips_codex_common_snippet_before_persisting_snapshot(
     );
 }
 
+#[test]
+#[serial]
+fn import_from_live_adopts_claude_settings_from_override_dir_once() {
+    let temp_home = TempDir::new().expect("create temp home");
+    let _env = EnvGuard::set_home(temp_home.path());
+    let custom_dir = temp_home.path().join("custom-claude");
+    let settings = crate::settings::AppSettings {
+        claude_config_dir: Some(custom_dir.display().to_string()),
+        ..Default::default()
+    };
+    settings.save().expect("save override settings");
+    crate::settings::reload_test_settings();
+
+    std::fs::create_dir_all(&custom_dir).expect("create custom claude dir");
+    write_json_file(
+        &custom_dir.join("settings.json"),
+        &json!({
+            "env": {
+                "ANTHROPIC_AUTH_TOKEN": "sk-live-claude",
+                "ANTHROPIC_BASE_URL": "https://relay.example.com/api"
+            }
+        }),
+    )
+    .expect("write claude live settings");
+
+    let state = state_from_config(MultiAppConfig::default());
+    let imported =
+        ProviderService::import_from_live(&state, AppType::Claude).expect("import claude live");
+    assert_eq!(imported, vec!["imported-relay-example-com".to_string()]);
+
+    {
+        let cfg = state.config.read().expect("read config");
+        let manager = cfg.get_manager(&AppType::Claude).expect("claude manager");
+        let provider = manager
+            .providers
+            .get(&imported[0])
+            .expect("imported provider");
+        assert_eq!(provider.name, "relay.example.com");
+        assert_eq!(
+            provider.settings_config["env"]["ANTHROPIC_AUTH_TOKEN"], "sk-live-claude",
+            "secrets are stored as-is"
+        );
+        assert_eq!(manager.current, imported[0]);
+    }
+
+    assert!(
+        ProviderService::import_from_live(&state, AppType::Claude)
+            .expect("re-import claude live")
+            .is_empty(),
+        "a provider with the same endpoint and key must not be imported twice"
+    );
+}
+
+#[test]
+#[serial]
+fn import_from_live_reads_codex_auth_and_config_and_keeps_current_provider() {
+    let temp_home = TempDir::new().expect("create temp home");
+    let _env = EnvGuard::set_home(temp_home.path());
+    std::fs::create_dir_all(crate::codex_config::get_codex_config_dir())
+        .expect("create ~/.codex (initialized)");
+    write_json_file(
+        &get_codex_auth_path(),
+        &json!({ "OPENAI_API_KEY": "sk-live-codex" }),
+    )
+    .expect("write auth.json");
+    std::fs::write(
+        get_codex_config_path(),
+        "model_provider = \"relay\"\nmodel = \"gpt-5\"\n\n[model_providers.relay]\nname = \"relay\"\nbase_url = \"https://codex.example.com/v1\"\nwire_api = \"responses\"\n",
+    )
+    .expect("write config.toml");
+
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Codex);
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Codex)
+            .expect("codex manager");
+        manager.current = "existing".to_string();
+        manager.providers.insert(
+            "existing".to_string(),
+            Provider::with_id(
+                "existing".to_string(),
+                "Existing".to_string(),
+                json!({
+                    "auth": { "OPENAI_API_KEY": "sk-other" },
+                    "config": "model_provider = \"other\"\n\n[model_providers.other]\nbase_url = \"https://other.example.com/v1\"\n"
+                }),
+                None,
+            ),
+        );
+    }
+    let state = state_from_config(config);
+
+    let imported =
+        ProviderService::import_from_live(&state, AppType::Codex).expect("import codex live");
+    assert_eq!(imported, vec!["imported-codex-example-com".to_string()]);
+
+    let cfg = state.config.read().expect("read config");
+    let manager = cfg.get_manager(&AppType::Codex).expect("codex manager");
+    assert_eq!(manager.current, "existing", "current provider is untouched");
+    let provider = manager
+        .providers
+        .get(&imported[0])
+        .expect("imported provider");
+    assert_eq!(
+        provider.settings_config["auth"]["OPENAI_API_KEY"],
+        "sk-live-codex"
+    );
+    assert!(provider.settings_config["config"]
+        .as_str()
+        .expect("codex config text")
+        .contains("base_url = \"https://codex.example.com/v1\""));
+}
+
+#[test]
+#[serial]
+fn import_from_live_reads_gemini_env_and_skips_existing_credentials() {
+    let temp_home = TempDir::new().expect("create temp home");
+    let _env = EnvGuard::set_home(temp_home.path());
+    std::fs::create_dir_all(crate::gemini_config::get_gemini_dir())
+        .expect("create ~/.gemini (initialized)");
+    crate::gemini_config::write_gemini_env_atomic(&std::collections::HashMap::from([
+        ("GEMINI_API_KEY".to_string(), "live-gemini-key".to_string()),
+        (
+            "GOOGLE_GEMINI_BASE_URL".to_string(),
+            "https://gemini.example.com".to_string(),
+        ),
+    ]))
+    .expect("write gemini env");
+
+    let state = state_from_config(MultiAppConfig::default());
+    let imported =
+        ProviderService::import_from_live(&state, AppType::Gemini).expect("import gemini live");
+    assert_eq!(imported, vec!["imported-gemini-example-com".to_string()]);
+    {
+        let cfg = state.config.read().expect("read config");
+        let provider = cfg
+            .get_manager(&AppType::Gemini)
+            .expect("gemini manager")
+            .providers
+            .get(&imported[0])
+            .expect("imported provider");
+        assert_eq!(
+            provider.settings_config["env"]["GEMINI_API_KEY"],
+            "live-gemini-key"
+        );
+    }
+
+    // live 配置仅多了无关设置，端点与密钥相同，仍视为已存在
+    write_json_file(
+        &crate::gemini_config::get_gemini_settings_path(),
+        &json!({ "theme": "dark" }),
+    )
+    .expect("write gemini settings.json");
+    assert!(ProviderService::import_from_live(&state, AppType::Gemini)
+        .expect("re-import gemini live")
+        .is_empty());
+}
+
+#[test]
+#[serial]
+fn import_from_live_adds_only_missing_opencode_providers() {
+    let temp_home = TempDir::new().expect("create temp home");
+    let _env = EnvGuard::set_home(temp_home.path());
+    std::fs::create_dir_all(crate::opencode_config::get_opencode_dir())
+        .expect("create opencode dir");
+    crate::opencode_config::write_opencode_config(&json!({
+        "provider": {
+            "known": {
+                "npm": "@ai-sdk/openai-compatible",
+                "options": { "baseURL": "https://known.example.com/v1", "apiKey": "k1" }
+            },
+            "fresh": {
+                "npm": "@ai-sdk/openai-compatible",
+                "name": "Fresh Relay",
+                "options": { "baseURL": "https://fresh.example.com/v1", "apiKey": "k2" }
+            }
+        }
+    }))
+    .expect("write opencode config");
+
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::OpenCode);
+    config
+        .get_manager_mut(&AppType::OpenCode)
+        .expect("opencode manager")
+        .providers
+        .insert(
+            "known".to_string(),
+            Provider::with_id("known".to_string(), "Known".to_string(), json!({}), None),
+        );
+    let state = state_from_config(config);
+
+    let imported =
+        ProviderService::import_from_live(&state, AppType::OpenCode).expect("import opencode live");
+    assert_eq!(imported, vec!["fresh".to_string()]);
+
+    let cfg = state.config.read().expect("read config");
+    let manager = cfg
+        .get_manager(&AppType::OpenCode)
+        .expect("opencode manager");
+    assert_eq!(manager.providers["fresh"].name, "Fresh Relay");
+    assert_eq!(
+        manager.providers["known"].name, "Known",
+        "existing providers are left untouched"
+    );
+}
+
 #[test]
 fn extract_credentials_returns_expected_values() {
     let provider = Provider::with_id(
@@ -3165,3 +3373,285 @@ fn import_default_config_strips_gemini_common_snippet_before_persisting_snapshot
         "provider-specific Gemini config should remain after import"
     );
 }
+
+fn state_with_claude_provider() -> AppState {
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Claude);
+    let mut provider = Provider::with_id(
+        "p1".to_string(),
+        "Relay".to_string(),
+        json!({
+            "env": {
+                "ANTHROPIC_AUTH_TOKEN": "sk-relay-secret-token",
+                "ANTHROPIC_BASE_URL": "https://relay.example",
+                "ANTHROPIC_MODEL": "claude-sonnet"
+            }
+        }),
+        None,
+    );
+    provider.meta = Some(crate::provider::ProviderMeta {
+        api_format: Some("openai_chat".to_string()),
+        ..Default::default()
+    });
+    config
+        .get_manager_mut(&AppType::Claude)
+        .expect("claude manager")
+        .providers
+        .insert("p1".to_string(), provider);
+    state_from_config(config)
+}
+
+#[test]
+fn get_returns_decoded_provider_details() {
+    let state = state_with_claude_provider();
+
+    let details = ProviderService::get(&state, AppType::Claude, "p1").expect("provider exists");
+
+    assert_eq!(details.id, "p1");
+    assert_eq!(details.name, "Relay");
+    assert_eq!(details.base_url.as_deref(), Some("https://relay.example"));
+    assert_eq!(details.model.as_deref(), Some("claude-sonnet"));
+    assert_eq!(details.api_style, ModelTestApiStyle::OpenAiChat);
+    assert_eq!(details.api_key.as_deref(), Some("sk-relay-secret-token"));
+}
+
+#[test]
+fn get_reports_missing_provider_id() {
+    let state = state_with_claude_provider();
+
+    let err = ProviderService::get(&state, AppType::Claude, "missing")
+        .expect_err("unknown id should fail");
+
+    assert!(
+        err.to_string().contains("missing"),
+        "error should name the missing provider id: {err}"
+    );
+}
+
+#[test]
+fn get_redacted_never_exposes_api_key() {
+    let state = state_with_claude_provider();
+
+    let details =
+        ProviderService::get_redacted(&state, AppType::Claude, "p1").expect("provider exists");
+    let serialized = serde_json::to_string(&details).expect("serialize details");
+
+    assert_eq!(details.api_key.as_deref(), Some("sk-relay..."));
+    assert_eq!(details.base_url.as_deref(), Some("https://relay.example"));
+    assert!(
+        !serialized.contains("sk-relay-secret-token"),
+        "redacted details must not leak the key: {serialized}"
+    );
+}
+
+async fn spawn_endpoint(delay_ms: u64, status: u16) -> (String, tokio::task::JoinHandle<()>) {
+    use axum::{http::StatusCode as AxumStatus, routing::any, Router};
+
+    let router = Router::new().fallback(any(move || async move {
+        tokio::time::sleep(std::time::Duration::from_millis(delay_ms)).await;
+        (AxumStatus::from_u16(status).expect("valid status"), "ok")
+    }));
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("bind test listener");
+    let addr = listener.local_addr().expect("listener addr");
+    let server = tokio::spawn(async move {
+        let _ = axum::serve(listener, router).await;
+    });
+    (format!("http://{addr}"), server)
+}
+
+fn claude_state_with_endpoints(base_url: &str, custom: &[&str]) -> AppState {
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Claude);
+    let mut meta = crate::provider::ProviderMeta::default();
+    for (idx, url) in custom.iter().enumerate() {
+        meta.custom_endpoints.insert(
+            url.to_string(),
+            crate::settings::CustomEndpoint {
+                url: url.to_string(),
+                added_at: idx as i64,
+                last_used: None,
+            },
+        );
+    }
+    let provider = Provider::with_id(
+        "p1".to_string(),
+        "Relay".to_string(),
+        json!({
+            "env": {
+                "ANTHROPIC_AUTH_TOKEN": "token",
+                "ANTHROPIC_BASE_URL": base_url
+            }
+        }),
+        None,
+    );
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.providers.insert(
+            "p1".to_string(),
+            Provider {
+                meta: Some(meta),
+                ..provider
+            },
+        );
+        manager.current = "p1".to_string();
+    }
+    state_from_config(config)
+}
+
+fn stored_claude_base_url(state: &AppState) -> String {
+    let cfg = state.config.read().expect("read config");
+    let provider = cfg
+        .get_manager(&AppType::Claude)
+        .and_then(|manager| manager.providers.get("p1"))
+        .expect("provider p1");
+    ProviderService::extract_base_url(provider, &AppType::Claude).expect("base url")
+}
+
+#[test]
+#[serial]
+fn auto_select_fastest_switches_to_lowest_latency_healthy_endpoint() {
+    let temp_home = TempDir::new().expect("create temp home");
+    let _env = EnvGuard::set_home(temp_home.path());
+    let runtime = tokio::runtime::Runtime::new().expect("create tokio runtime");
+
+    let (slow, slow_server) = runtime.block_on(spawn_endpoint(400, 200));
+    let (broken, broken_server) = runtime.block_on(spawn_endpoint(0, 503));
+    let (missing, missing_server) = runtime.block_on(spawn_endpoint(0, 404));
+    let (fast, fast_server) = runtime.block_on(spawn_endpoint(20, 401));
+    let state = claude_state_with_endpoints(&slow, &[&broken, &missing, &fast]);
+
+    let chosen = runtime
+        .block_on(ProviderService::auto_select_fastest(
+            &state,
+            AppType::Claude,
+            "p1",
+        ))
+        .expect("a healthy endpoint should be selected");
+    slow_server.abort();
+    broken_server.abort();
+    missing_server.abort();
+    fast_server.abort();
+
+    assert_eq!(
+        chosen, fast,
+        "5xx and 404 endpoints must be skipped despite being fastest"
+    );
+    assert_eq!(stored_claude_base_url(&state), fast);
+    let cfg = state.config.read().expect("read config");
+    let meta = cfg.get_manager(&AppType::Claude).unwrap().providers["p1"]
+        .meta
+        .clone()
+        .expect("meta kept");
+    assert!(meta.custom_endpoints[&fast].last_used.is_some());
+    assert_eq!(meta.custom_endpoints.len(), 3, "custom endpoints are kept");
+}
+
+#[test]
+#[serial]
+fn auto_select_fastest_leaves_provider_unchanged_when_no_endpoint_is_healthy() {
+    let temp_home = TempDir::new().expect("create temp home");
+    let _env = EnvGuard::set_home(temp_home.path());
+    let runtime = tokio::runtime::Runtime::new().expect("create tokio runtime");
+
+    let (current, current_server) = runtime.block_on(spawn_endpoint(0, 502));
+    let (custom, custom_server) = runtime.block_on(spawn_endpoint(0, 404));
+    let state = claude_state_with_endpoints(&current, &[&custom, "http://127.0.0.1:1"]);
+
+    let err = runtime
+        .block_on(ProviderService::auto_select_fastest(
+            &state,
+            AppType::Claude,
+            "p1",
+        ))
+        .expect_err("no healthy endpoint should fail");
+    current_server.abort();
+    custom_server.abort();
+
+    let message = err.to_string();
+    assert!(
+        message.contains('3'),
+        "error should mention tested endpoints: {message}"
+    );
+    assert!(
+        message.contains(&format!("{custom}: HTTP 404")),
+        "error should show why each endpoint is unhealthy: {message}"
+    );
+    assert_eq!(stored_claude_base_url(&state), current);
+}
+
+#[test]
+fn set_base_url_rewrites_codex_config_toml() {
+    let mut provider = Provider::with_id(
+        "codex".into(),
+        "Codex".into(),
+        json!({
+            "config": "model_provider = \"relay\"\n\n[model_providers.relay]\nbase_url = \"https://old.example/v1\"\nwire_api = \"responses\"\n"
+        }),
+        None,
+    );
+    endpoints::set_base_url(&mut provider, &AppType::Codex, "https://new.example/v1")
+        .expect("rewrite base_url");
+    assert_eq!(
+        ProviderService::extract_base_url(&provider, &AppType::Codex).unwrap(),
+        "https://new.example/v1"
+    );
+}
+
+fn claude_state_with_providers(providers: &[(&str, &str, Option<&str>)]) -> AppState {
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Claude);
+    let manager = config
+        .get_manager_mut(&AppType::Claude)
+        .expect("claude manager");
+    for (id, base_url, key) in providers {
+        let mut env = json!({ "ANTHROPIC_BASE_URL": base_url });
+        if let Some(key) = key {
+            env["ANTHROPIC_AUTH_TOKEN"] = json!(key);
+        }
+        manager.providers.insert(
+            id.to_string(),
+            Provider::with_id(id.to_string(), id.to_string(), json!({ "env": env }), None),
+        );
+    }
+    state_from_config(config)
+}
+
+#[test]
+fn find_duplicate_endpoints_groups_same_url_with_different_keys() {
+    let state = claude_state_with_providers(&[
+        ("a", "https://Relay.Example.com/v1/", Some("key-a")),
+        ("b", " https://relay.example.com/v1", Some("key-b")),
+        ("c", "https://relay.example.com/v1", None),
+        ("d", "https://other.example.com/v1", Some("key-d")),
+        ("e", "https://relay.example.com/V1", Some("key-e")),
+    ]);
+
+    let groups = ProviderService::find_duplicate_endpoints(&state, AppType::Claude)
+        .expect("find duplicates");
+
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].base_url, "https://relay.example.com/v1");
+    let members: Vec<_> = groups[0]
+        .members
+        .iter()
+        .map(|member| (member.id.as_str(), member.has_api_key))
+        .collect();
+    assert_eq!(members, vec![("a", true), ("b", true), ("c", false)]);
+}
+
+#[test]
+fn find_duplicate_endpoints_ignores_distinct_urls() {
+    let state = claude_state_with_providers(&[
+        ("a", "https://one.example.com", Some("key")),
+        ("b", "https://two.example.com", Some("key")),
+    ]);
+
+    let groups = ProviderService::find_duplicate_endpoints(&state, AppType::Claude)
+        .expect("find duplicates");
+
+    assert!(groups.is_empty());
+}
@@ -187,7 +187,10 @@ impl ProviderService {
         Ok(())
     }
 
-    fn extract_api_key(provider: &Provider, app_type: &AppType) -> Result<String, AppError> {
+    pub(super) fn extract_api_key(
+        provider: &Provider,
+        app_type: &AppType,
+    ) -> Result<String, AppError> {
         match app_type {
             AppType::Claude => {
                 let env = provider
@@ -279,7 +282,10 @@ impl ProviderService {
         }
     }
 
-    fn extract_base_url(provider: &Provider, app_type: &AppType) -> Result<String, AppError> {
+    pub(super) fn extract_base_url(
+        provider: &Provider,
+        app_type: &AppType,
+    ) -> Result<String, AppError> {
         match app_type {
             AppType::Claude => provider
                 .settings_config
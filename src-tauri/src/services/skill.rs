@@ -372,6 +372,17 @@ fn merge_repos_from_lock(
     }
 }
 
+/// [`SkillService::recover_interrupted_restore`] 的处理结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkillsRestoreRecovery {
+    /// 没有遗留的 `.bak` 目录
+    Clean,
+    /// 新内容未确认写完（SSOT 缺失或不完整），已从 `.bak` 回滚
+    RestoredBackup,
+    /// 新内容已确认写完，已删除遗留的 `.bak`
+    RemovedStaleBackup,
+}
+
 // ============================================================================
 // SkillService
 // ============================================================================
@@ -416,11 +427,61 @@ impl SkillService {
     // ---------------------------------------------------------------------
 
     pub fn get_ssot_dir() -> Result<PathBuf, AppError> {
-        let dir = get_app_config_dir().join("skills");
+        let dir = Self::ssot_dir_path();
         fs::create_dir_all(&dir).map_err(|e| AppError::io(&dir, e))?;
         Ok(dir)
     }
 
+    fn ssot_dir_path() -> PathBuf {
+        crate::settings::get_skills_ssot_override_dir()
+            .unwrap_or_else(|| get_app_config_dir().join("skills"))
+    }
+
+    /// 整体替换 SSOT 时临时存放旧内容的兄弟目录（如 `skills.bak`）
+    pub(crate) fn ssot_backup_dir(ssot: &Path) -> PathBuf {
+        ssot.with_extension("bak")
+    }
+
+    /// 新内容完整写入 SSOT 后才创建的标记文件（如 `skills.restore-complete`），存在时 `.bak` 才可删除
+    pub(crate) fn ssot_restore_marker(ssot: &Path) -> PathBuf {
+        ssot.with_extension("restore-complete")
+    }
+
+    /// 修复上次中断的 skills 恢复（如 WebDAV 下载时进程崩溃）遗留的状态。
+    ///
+    /// 恢复过程会先把 SSOT 改名为 `.bak` 再写入新内容，写完后创建完成标记，再删除 `.bak` 与标记。
+    /// 启动时若发现遗留的 `.bak`：有完成标记说明新内容完整，删除 `.bak`；
+    /// 否则 SSOT 可能只写了一部分，丢弃它并把 `.bak` 改回 SSOT。`.bak` 在回滚成功前始终保留。
+    pub fn recover_interrupted_restore() -> Result<SkillsRestoreRecovery, AppError> {
+        let ssot = Self::ssot_dir_path();
+        let bak = Self::ssot_backup_dir(&ssot);
+        let marker = Self::ssot_restore_marker(&ssot);
+        if !bak.is_dir() {
+            if marker.exists() {
+                fs::remove_file(&marker).map_err(|e| AppError::io(&marker, e))?;
+            }
+            return Ok(SkillsRestoreRecovery::Clean);
+        }
+
+        if marker.exists() {
+            fs::remove_dir_all(&bak).map_err(|e| AppError::io(&bak, e))?;
+            fs::remove_file(&marker).map_err(|e| AppError::io(&marker, e))?;
+            log::info!("已删除 skills 恢复遗留的备份目录 {}", bak.display());
+            return Ok(SkillsRestoreRecovery::RemovedStaleBackup);
+        }
+
+        if ssot.exists() {
+            fs::remove_dir_all(&ssot).map_err(|e| AppError::io(&ssot, e))?;
+        }
+        fs::rename(&bak, &ssot).map_err(|e| AppError::io(&bak, e))?;
+        log::warn!(
+            "检测到中断的 skills 恢复，已从 {} 还原 SSOT 目录 {}",
+            bak.display(),
+            ssot.display()
+        );
+        Ok(SkillsRestoreRecovery::RestoredBackup)
+    }
+
     pub fn get_app_skills_dir(app: &AppType) -> Result<PathBuf, AppError> {
         // Override directories follow the same pattern as upstream: <override>/skills
         match app {
@@ -18,6 +18,27 @@ pub struct EndpointLatency {
     pub error: Option<String>,
 }
 
+impl EndpointLatency {
+    /// 返回 2xx 视为健康；测速请求不带鉴权，401 / 405 说明端点存在、只是拒绝了匿名 GET，
+    /// 同样视为健康。其余 4xx（如 404）多为地址配错，与 5xx 一样视为不健康
+    pub fn is_healthy(&self) -> bool {
+        self.error.is_none()
+            && self.latency.is_some()
+            && self
+                .status
+                .is_some_and(|status| (200..300).contains(&status) || matches!(status, 401 | 405))
+    }
+
+    /// 单行概要，如 `https://a.example: HTTP 404`，用于说明端点为何不健康
+    pub fn summary(&self) -> String {
+        match (&self.error, self.status) {
+            (Some(error), _) => format!("{}: {error}", self.url),
+            (None, Some(status)) => format!("{}: HTTP {status}", self.url),
+            (None, None) => format!("{}: N/A", self.url),
+        }
+    }
+}
+
 /// 网络测速相关业务
 pub struct SpeedtestService;
 
@@ -95,6 +116,16 @@ impl SpeedtestService {
         Ok(join_all(tasks).await)
     }
 
+    /// 并发测速一组端点，结果按延迟从低到高排序，失败的端点排在最后。
+    pub async fn race(
+        urls: Vec<String>,
+        timeout_secs: Option<u64>,
+    ) -> Result<Vec<EndpointLatency>, AppError> {
+        let mut results = Self::test_endpoints(urls, timeout_secs).await?;
+        results.sort_by_key(|result| result.latency.unwrap_or(u128::MAX));
+        Ok(results)
+    }
+
     fn build_client(timeout_secs: u64) -> Result<Client, AppError> {
         Client::builder()
             .timeout(Duration::from_secs(timeout_secs))
@@ -149,6 +180,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn is_healthy_accepts_success_and_auth_only_statuses() {
+        let latency = |status: u16| EndpointLatency {
+            url: "https://a.example".into(),
+            latency: Some(10),
+            status: Some(status),
+            error: None,
+        };
+        for status in [200, 204, 401, 405] {
+            assert!(latency(status).is_healthy(), "HTTP {status}");
+        }
+        for status in [301, 400, 403, 404, 500, 503] {
+            assert!(!latency(status).is_healthy(), "HTTP {status}");
+        }
+        assert_eq!(latency(404).summary(), "https://a.example: HTTP 404");
+    }
+
     #[test]
     fn test_endpoints_handles_empty_list() {
         let result = run_async(SpeedtestService::test_endpoints(Vec::new(), Some(5)))
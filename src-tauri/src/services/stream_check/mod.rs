@@ -10,4 +10,5 @@ mod tests;
 mod types;
 
 pub use service::StreamCheckService;
+pub(crate) use types::AuthInfo;
 pub use types::{HealthStatus, StreamCheckConfig, StreamCheckResult};
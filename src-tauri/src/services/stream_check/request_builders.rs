@@ -1,5 +1,5 @@
 use futures::StreamExt;
-use reqwest::Client;
+use reqwest::{Client, RequestBuilder};
 use serde_json::json;
 
 use crate::error::AppError;
@@ -10,6 +10,28 @@ use super::service::StreamCheckService;
 use super::types::{AuthInfo, AuthStrategy};
 
 impl StreamCheckService {
+    /// 按鉴权策略附加鉴权头；流式检查与密钥检查共用，避免两处的鉴权方式不一致
+    pub(crate) fn apply_auth(request: RequestBuilder, auth: &AuthInfo) -> RequestBuilder {
+        match auth.strategy {
+            AuthStrategy::Anthropic => request
+                .header("authorization", format!("Bearer {}", auth.api_key))
+                .header("x-api-key", &auth.api_key),
+            AuthStrategy::ClaudeAuth | AuthStrategy::Bearer => {
+                request.header("authorization", format!("Bearer {}", auth.api_key))
+            }
+            AuthStrategy::Google => request.header("x-goog-api-key", &auth.api_key),
+            AuthStrategy::GoogleOAuth => request.header(
+                "authorization",
+                format!(
+                    "Bearer {}",
+                    auth.access_token
+                        .as_deref()
+                        .unwrap_or(auth.api_key.as_str())
+                ),
+            ),
+        }
+    }
+
     pub(crate) async fn check_claude_stream(
         client: &Client,
         base_url: &str,
@@ -65,12 +87,11 @@ impl StreamCheckService {
             })
         };
 
-        let mut request = client
-            .post(&url)
-            .header("authorization", format!("Bearer {}", auth.api_key));
+        let request = client.post(&url);
 
         let response = if is_openai_compatible {
             request
+                .header("authorization", format!("Bearer {}", auth.api_key))
                 .header("content-type", "application/json")
                 .header("accept", "application/json")
                 .timeout(timeout)
@@ -82,11 +103,7 @@ impl StreamCheckService {
             let os_name = Self::get_os_name();
             let arch_name = Self::get_arch_name();
 
-            if auth.strategy == AuthStrategy::Anthropic {
-                request = request.header("x-api-key", &auth.api_key);
-            }
-
-            request
+            Self::apply_auth(request, auth)
                 .header("anthropic-version", "2023-06-01")
                 .header(
                     "anthropic-beta",
@@ -163,9 +180,7 @@ impl StreamCheckService {
         }
 
         for (index, url) in urls.iter().enumerate() {
-            let response = client
-                .post(url)
-                .header("authorization", format!("Bearer {}", auth.api_key))
+            let response = Self::apply_auth(client.post(url), auth)
                 .header("content-type", "application/json")
                 .header("accept", "text/event-stream")
                 .header("accept-encoding", "identity")
@@ -229,26 +244,15 @@ impl StreamCheckService {
             }]
         });
 
-        let request = client
-            .post(&url)
+        let request = Self::apply_auth(client.post(&url), auth)
             .header("content-type", "application/json")
             .header("accept", "text/event-stream")
             .timeout(timeout)
             .json(&body);
 
         let request = match auth.strategy {
-            AuthStrategy::GoogleOAuth => request
-                .header(
-                    "authorization",
-                    format!(
-                        "Bearer {}",
-                        auth.access_token
-                            .as_deref()
-                            .unwrap_or(auth.api_key.as_str())
-                    ),
-                )
-                .header("x-goog-api-client", "GeminiCLI/1.0"),
-            _ => request.header("x-goog-api-key", &auth.api_key),
+            AuthStrategy::GoogleOAuth => request.header("x-goog-api-client", "GeminiCLI/1.0"),
+            _ => request,
         };
 
         let response = request.send().await.map_err(Self::map_request_error)?;
@@ -3,24 +3,49 @@
 //! 提供底层 HTTP 操作：PUT / GET / HEAD / PROPFIND / MKCOL，
 //! 以及 URL 构建、认证、连接测试等公共工具。
 
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
 use std::time::Duration;
 
 use futures::StreamExt;
-use reqwest::{Client, Method, StatusCode};
+use regex::Regex;
+use reqwest::header::HeaderMap;
+use reqwest::{Client, Method, Response, StatusCode};
 use url::Url;
 use uuid::Uuid;
 
 use crate::error::AppError;
 
+use super::http;
+pub use super::http::redact_url;
+
 // ---------------------------------------------------------------------------
 // 常量
 // ---------------------------------------------------------------------------
 
-/// 普通请求（PROPFIND / MKCOL / HEAD 等）超时
+/// 建立 TCP/TLS 连接的超时；与读取超时分开，便于区分"连不上"与"传输慢"
+const CONNECT_TIMEOUT_SECS: u64 = 10;
+
+/// 普通请求（PROPFIND / MKCOL / HEAD 等）总超时
 const DEFAULT_TIMEOUT_SECS: u64 = 30;
 
-/// 大文件传输（PUT / GET）超时
-const TRANSFER_TIMEOUT_SECS: u64 = 300;
+/// 单次读取的空闲超时：只要持续收到数据就不会中断，用于检测停滞的连接
+const READ_IDLE_TIMEOUT_SECS: u64 = 60;
+
+/// 大文件传输（PUT / GET）的总时长上限，仅用于防止永久挂起；正常进度由读取空闲超时把关
+const TRANSFER_TIMEOUT_SECS: u64 = 6 * 60 * 60;
+
+/// 请求日志标记，trace 记录受 `sync_log_level` 控制
+const LOG_TAG: http::LogTag = http::LogTag {
+    label: "WebDAV",
+    target: module_path!(),
+};
+
+/// MKCOL 失败后确认目录是否存在的探测次数与间隔
+const MKCOL_VERIFY_ATTEMPTS: usize = 2;
+const MKCOL_VERIFY_RETRY_DELAY: std::time::Duration = std::time::Duration::from_millis(300);
 
 // ---------------------------------------------------------------------------
 // 认证
@@ -140,6 +165,153 @@ fn detect_service_from_base_url(base_url: &str) -> Option<WebDavService> {
         .and_then(|url| detect_webdav_service(&url))
 }
 
+/// WebDAV 服务端实现。坚果云 / Nutstore 按主机名识别（见 [`detect_provider`]），
+/// 自建类服务从响应头推断。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WebDavServerKind {
+    Jianguoyun,
+    Nutstore,
+    Nextcloud,
+    OwnCloud,
+    Seafile,
+    #[default]
+    Generic,
+}
+
+impl WebDavServerKind {
+    /// 依次检查 `X-Nextcloud-*` 等专有头、`Server` 头和 `DAV` 头中的能力标记
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let names = headers.keys().map(|name| name.as_str());
+        for name in names {
+            if name.starts_with("x-nextcloud") {
+                return Self::Nextcloud;
+            }
+            if name.starts_with("x-owncloud") {
+                return Self::OwnCloud;
+            }
+        }
+        let header = |name: &str| {
+            headers
+                .get_all(name)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .collect::<Vec<_>>()
+                .join(",")
+                .to_ascii_lowercase()
+        };
+        let server = header("server");
+        if server.contains("nextcloud") {
+            return Self::Nextcloud;
+        }
+        if server.contains("owncloud") {
+            return Self::OwnCloud;
+        }
+        if server.contains("seafile") || server.contains("seafdav") {
+            return Self::Seafile;
+        }
+        // DAV 头是逗号分隔的能力列表，按完整条目的前缀匹配，避免 "sync-" 之类误判
+        let dav = header("dav");
+        let has_capability = |prefixes: &[&str]| {
+            dav.split(',')
+                .map(str::trim)
+                .any(|token| prefixes.iter().any(|prefix| token.starts_with(prefix)))
+        };
+        if has_capability(&["nextcloud-", "nc-"]) {
+            return Self::Nextcloud;
+        }
+        if has_capability(&["oc-"]) {
+            return Self::OwnCloud;
+        }
+        Self::Generic
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Jianguoyun => WebDavService::Jianguoyun.provider_name(),
+            Self::Nutstore => WebDavService::Nutstore.provider_name(),
+            Self::Nextcloud => "Nextcloud",
+            Self::OwnCloud => "ownCloud",
+            Self::Seafile => "Seafile",
+            Self::Generic => "WebDAV",
+        }
+    }
+
+    /// 这类服务通常拒绝账户登录密码，需要改用应用密码
+    pub fn uses_app_password(self) -> bool {
+        self != Self::Generic
+    }
+
+    fn auth_hint(self) -> Option<String> {
+        match self {
+            Self::Nextcloud | Self::OwnCloud => Some(format!(
+                "。{} 开启两步验证或使用 SSO 登录时必须使用「应用密码」（设置 → 安全 中生成），账户登录密码会被拒绝。",
+                self.name()
+            )),
+            Self::Seafile => Some(
+                "。Seafile 的 WebDAV（SeafDAV）需由管理员开启，用户名为账户邮箱；开启两步验证时需使用 WebDAV 专用密码。"
+                    .to_string(),
+            ),
+            // 坚果云 / Nutstore 的提示由 WebDavService 按主机名给出
+            Self::Jianguoyun | Self::Nutstore | Self::Generic => None,
+        }
+    }
+
+    fn missing_dir_hint(self) -> Option<String> {
+        match self {
+            Self::Nextcloud | Self::OwnCloud => Some(format!(
+                "。{} 的 base_url 应指向用户文件目录，例如 https://<host>/remote.php/dav/files/<用户名>/",
+                self.name()
+            )),
+            Self::Seafile => Some(
+                "。Seafile 的 base_url 应以 /seafdav/ 开头并指向某个资料库，例如 https://<host>/seafdav/<资料库>/"
+                    .to_string(),
+            ),
+            Self::Jianguoyun | Self::Nutstore | Self::Generic => None,
+        }
+    }
+
+    fn followup_hint(self) -> Option<String> {
+        match self {
+            Self::Nextcloud | Self::OwnCloud => Some(format!(
+                "。{} 请使用「应用密码」，并确认 base_url 指向 /remote.php/dav/files/<用户名>/ 下的目录。",
+                self.name()
+            )),
+            Self::Seafile => Some(
+                "。Seafile 请确认服务端已开启 SeafDAV，且 base_url 指向 /seafdav/ 下的资料库。"
+                    .to_string(),
+            ),
+            Self::Jianguoyun | Self::Nutstore | Self::Generic => None,
+        }
+    }
+}
+
+/// 进程内记录各服务器（按 origin）最近识别出的类型，供无响应头可用的错误提示使用
+fn server_kinds() -> &'static Mutex<HashMap<String, WebDavServerKind>> {
+    static KINDS: OnceLock<Mutex<HashMap<String, WebDavServerKind>>> = OnceLock::new();
+    KINDS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn url_origin(url: &str) -> Option<String> {
+    Url::parse(url)
+        .ok()
+        .map(|url| url.origin().ascii_serialization())
+}
+
+fn remember_server_kind(url: &str, kind: WebDavServerKind) {
+    if kind == WebDavServerKind::Generic {
+        return;
+    }
+    if let (Some(origin), Ok(mut kinds)) = (url_origin(url), server_kinds().lock()) {
+        kinds.insert(origin, kind);
+    }
+}
+
+fn known_server_kind(url: &str) -> WebDavServerKind {
+    url_origin(url)
+        .and_then(|origin| server_kinds().lock().ok()?.get(&origin).copied())
+        .unwrap_or_default()
+}
+
 pub fn build_remote_url(base_url: &str, segments: &[String]) -> Result<String, AppError> {
     let mut url = Url::parse(base_url)
         .map_err(|e| AppError::InvalidInput(format!("WebDAV base_url 不是合法 URL: {e}")))?;
@@ -161,34 +333,132 @@ pub fn path_segments(raw: &str) -> impl Iterator<Item = &str> {
         .filter(|segment| !segment.is_empty())
 }
 
+/// 在发起任何请求前根据 base_url 推断服务端类型，供添加向导预填对应的提示。
+/// 坚果云 / Nutstore 按主机名识别；其余优先使用本进程已从响应头识别出的类型，
+/// 否则按路径前缀猜测（`/remote.php/` 视为 Nextcloud，`/seafdav/` 视为 Seafile）。
+pub fn detect_provider(base_url: &str) -> WebDavServerKind {
+    let Ok(url) = Url::parse(base_url.trim()) else {
+        return WebDavServerKind::Generic;
+    };
+    match detect_webdav_service(&url) {
+        Some(WebDavService::Jianguoyun) => return WebDavServerKind::Jianguoyun,
+        Some(WebDavService::Nutstore) => return WebDavServerKind::Nutstore,
+        None => {}
+    }
+    let known = known_server_kind(url.as_str());
+    if known != WebDavServerKind::Generic {
+        return known;
+    }
+    match url.path_segments().and_then(|mut segments| segments.next()) {
+        Some("remote.php") => WebDavServerKind::Nextcloud,
+        Some(segment) if segment.eq_ignore_ascii_case("seafdav") => WebDavServerKind::Seafile,
+        _ => WebDavServerKind::Generic,
+    }
+}
+
 pub fn is_jianguoyun(base_url: &str) -> bool {
-    matches!(
-        detect_service_from_base_url(base_url),
-        Some(WebDavService::Jianguoyun)
+    detect_provider(base_url) == WebDavServerKind::Jianguoyun
+}
+
+// ---------------------------------------------------------------------------
+// HTTP 客户端
+// ---------------------------------------------------------------------------
+
+/// 创建 WebDAV HTTP 客户端。一次同步流程内应复用同一个客户端，以复用连接、避免重复 TLS 握手。
+///
+/// 连接超时与读取空闲超时分开设置；客户端默认使用控制请求总超时，
+/// PUT / GET 传输请求按请求单独放宽总时限，只要数据仍在流动就不会被中断。
+///
+/// `ca_cert_path` 指向 PEM 格式的 CA 证书（可包含多个），在系统信任根之外额外信任，
+/// 用于内网自建 CA 签发证书的服务器，无需关闭证书校验。
+pub fn build_client(ca_cert_path: Option<&Path>) -> Result<Client, AppError> {
+    build_client_with_timeouts(
+        Duration::from_secs(CONNECT_TIMEOUT_SECS),
+        Duration::from_secs(READ_IDLE_TIMEOUT_SECS),
+        ca_cert_path,
     )
 }
 
-fn redact_url(url: &str) -> String {
-    match Url::parse(url) {
-        Ok(mut parsed) => {
-            if parsed.password().is_some() {
-                let _ = parsed.set_password(Some("***"));
-            }
-            parsed.to_string()
+fn build_client_with_timeouts(
+    connect_timeout: Duration,
+    read_idle_timeout: Duration,
+    ca_cert_path: Option<&Path>,
+) -> Result<Client, AppError> {
+    // 自动重定向会把 301/302 的 PROPFIND / PUT 改写成 GET，交由 send_following_redirect 按方法跟随
+    let mut builder = http::client_builder()?
+        .redirect(reqwest::redirect::Policy::none())
+        .connect_timeout(connect_timeout)
+        .read_timeout(read_idle_timeout)
+        .timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS));
+    if let Some(path) = ca_cert_path {
+        for cert in load_ca_certificates(path)? {
+            builder = builder.add_root_certificate(cert);
         }
-        Err(_) => url.to_string(),
     }
+    builder.build().map_err(|e| match ca_cert_path {
+        // rustls 在构建客户端时才解析证书内容，此时的失败多半来自该文件
+        Some(path) => invalid_ca_cert_error(path, &e.to_string()),
+        None => AppError::Message(format!("创建 WebDAV HTTP 客户端失败: {e}")),
+    })
 }
 
-// ---------------------------------------------------------------------------
-// HTTP 客户端
-// ---------------------------------------------------------------------------
+/// 读取 PEM 证书包；文件缺失、格式错误或不含任何证书时报错并指明路径
+fn load_ca_certificates(path: &Path) -> Result<Vec<reqwest::Certificate>, AppError> {
+    let pem = std::fs::read(path).map_err(|e| {
+        AppError::localized(
+            "webdav.ca_cert_read_failed",
+            format!("读取 CA 证书文件失败: {}: {e}", path.display()),
+            format!("Failed to read CA certificate file {}: {e}", path.display()),
+        )
+    })?;
+    let certs = reqwest::Certificate::from_pem_bundle(&pem)
+        .map_err(|e| invalid_ca_cert_error(path, &e.to_string()))?;
+    if certs.is_empty() {
+        return Err(invalid_ca_cert_error(
+            path,
+            "no PEM certificate (-----BEGIN CERTIFICATE-----) found",
+        ));
+    }
+    Ok(certs)
+}
 
-fn build_client(timeout_secs: u64) -> Result<Client, AppError> {
-    Client::builder()
-        .timeout(Duration::from_secs(timeout_secs.max(1)))
-        .build()
-        .map_err(|e| AppError::Message(format!("创建 WebDAV HTTP 客户端失败: {e}")))
+fn invalid_ca_cert_error(path: &Path, detail: &str) -> AppError {
+    AppError::localized(
+        "webdav.ca_cert_invalid",
+        format!(
+            "CA 证书文件无效，需要 PEM 格式的证书: {}: {detail}",
+            path.display()
+        ),
+        format!(
+            "Invalid CA certificate file, expected PEM certificates: {}: {detail}",
+            path.display()
+        ),
+    )
+}
+
+/// 见 [`http::send_following_redirect`]，使用 WebDAV 的日志标记
+async fn send_following_redirect(
+    client: &Client,
+    builder: reqwest::RequestBuilder,
+) -> Result<Response, reqwest::Error> {
+    http::send_following_redirect(client, builder, LOG_TAG).await
+}
+
+/// 将请求错误转换为可读信息，区分连接超时、连接失败与读取超时
+fn describe_request_error(operation: &str, e: &reqwest::Error) -> String {
+    if e.is_connect() && e.is_timeout() {
+        format!("WebDAV {operation} 连接超时，未能在限定时间内建立连接: {e}")
+    } else if e.is_connect() {
+        format!("WebDAV {operation} 无法连接到服务器: {e}")
+    } else if e.is_timeout() {
+        format!("WebDAV {operation} 读取超时，服务器已连接但响应停滞: {e}")
+    } else {
+        format!("WebDAV {operation} 请求失败: {e}")
+    }
+}
+
+fn transfer_timeout() -> Duration {
+    Duration::from_secs(TRANSFER_TIMEOUT_SECS)
 }
 
 fn apply_auth(builder: reqwest::RequestBuilder, auth: &WebDavAuth) -> reqwest::RequestBuilder {
@@ -208,18 +478,61 @@ pub fn webdav_status_error(
     status: StatusCode,
     url: &str,
 ) -> AppError {
+    AppError::Message(status_error_message(base_url, operation, status, url))
+}
+
+/// 与 [`webdav_status_error`] 相同，但会从响应体（通常是 207 Multi-Status）中
+/// 提取服务器给出的原因并附加到错误信息中。
+pub fn webdav_status_error_with_body(
+    base_url: &str,
+    operation: &str,
+    status: StatusCode,
+    url: &str,
+    body: &str,
+) -> AppError {
+    let mut message = status_error_message(base_url, operation, status, url);
+    if let Some(reason) = multistatus_reason(body) {
+        message.push_str(&format!("。服务器响应: {reason}"));
+    }
+    AppError::Message(message)
+}
+
+async fn response_status_error(
+    base_url: &str,
+    operation: &str,
+    resp: Response,
+    url: &str,
+) -> AppError {
+    let status = resp.status();
+    remember_server_kind(base_url, WebDavServerKind::from_headers(resp.headers()));
+    let body = resp.text().await.unwrap_or_default();
+    webdav_status_error_with_body(base_url, operation, status, url, &body)
+}
+
+fn status_error_message(base_url: &str, operation: &str, status: StatusCode, url: &str) -> String {
     let display_url = redact_url(url);
     let mut message = format!("WebDAV {operation} 失败: {status} ({display_url})");
     let service = detect_service_from_base_url(base_url);
+    let kind = known_server_kind(base_url);
+    let missing_dir = status == StatusCode::NOT_FOUND || status.is_redirection();
+    let kind_dir_hint = service
+        .is_none()
+        .then(|| kind.missing_dir_hint())
+        .flatten()
+        .filter(|_| missing_dir);
 
     if matches!(status, StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) {
         if let Some(service) = service {
             message.push_str(&service.auth_hint());
+        } else if let Some(hint) = kind.auth_hint() {
+            message.push_str(&hint);
         } else {
             message.push_str("。请检查 WebDAV 用户名、密码，以及该目录的读写权限。");
         }
+    } else if let Some(hint) = kind_dir_hint {
+        message.push_str(&hint);
     } else if let Some(service) = service {
-        if status == StatusCode::NOT_FOUND || status.is_redirection() {
+        if missing_dir {
             message.push_str(&service.writable_dir_hint());
         } else if operation == "MKCOL" && status == StatusCode::CONFLICT {
             if service == WebDavService::Jianguoyun {
@@ -237,35 +550,259 @@ pub fn webdav_status_error(
     } else if operation == "MKCOL" && status == StatusCode::CONFLICT {
         message.push_str("。请确认上级目录存在，或将 remote_root/profile 调整到可写路径。");
     }
-    AppError::Message(message)
+    message
 }
 
 fn with_service_hint(base_url: &str, message: impl Into<String>) -> String {
     let mut msg = message.into();
     if let Some(service) = detect_service_from_base_url(base_url) {
         msg.push_str(&service.followup_hint());
+    } else if let Some(hint) = known_server_kind(base_url).followup_hint() {
+        msg.push_str(&hint);
     }
     msg
 }
 
 // ---------------------------------------------------------------------------
-// 连接测试
+// Multistatus 解析
 // ---------------------------------------------------------------------------
 
-pub async fn test_connection(base_url: &str, auth: &WebDavAuth) -> Result<(), AppError> {
-    let client = build_client(DEFAULT_TIMEOUT_SECS)?;
+/// 最多在错误信息中展示的资源条目数
+const MAX_REASON_ENTRIES: usize = 3;
+
+/// 单条 description 的最大展示长度（字符）
+const MAX_REASON_DESCRIPTION_CHARS: usize = 200;
+
+/// 207 Multi-Status 响应中单个 `<response>` 的关键信息。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MultiStatusEntry {
+    pub href: Option<String>,
+    pub status: Option<u16>,
+    pub description: Option<String>,
+}
+
+impl MultiStatusEntry {
+    fn is_failure(&self) -> bool {
+        self.status
+            .and_then(|code| StatusCode::from_u16(code).ok())
+            .is_some_and(|status| !status.is_success())
+    }
+
+    fn summary(&self) -> Option<String> {
+        let status = self.status.map(|code| {
+            match StatusCode::from_u16(code)
+                .ok()
+                .and_then(|s| s.canonical_reason())
+            {
+                Some(reason) => format!("{code} {reason}"),
+                None => code.to_string(),
+            }
+        });
+        let head = match (status, self.href.as_deref()) {
+            (Some(status), Some(href)) => Some(format!("{status} ({href})")),
+            (Some(status), None) => Some(status),
+            (None, Some(href)) if self.description.is_some() => Some(href.to_string()),
+            _ => None,
+        };
+        match (head, self.description.as_deref()) {
+            (Some(head), Some(desc)) => Some(format!("{head}: {desc}")),
+            (Some(head), None) => Some(head),
+            (None, Some(desc)) => Some(desc.to_string()),
+            (None, None) => None,
+        }
+    }
+}
+
+/// 解析 WebDAV multistatus 响应体。
+///
+/// 不依赖命名空间前缀（`d:` / `D:` / 无前缀均可）。除了 `<response>` 条目外，
+/// 顶层的 `<responsedescription>` 以及 SabreDAV 风格的 `<s:message>` 也会作为
+/// 无 href 的条目返回。
+pub fn parse_multistatus(body: &str) -> Vec<MultiStatusEntry> {
+    static RESPONSE_RE: OnceLock<Regex> = OnceLock::new();
+    let response_re = RESPONSE_RE.get_or_init(|| {
+        Regex::new(r"(?is)<(?:[\w.-]+:)?response(?:\s[^>]*)?>(.*?)</(?:[\w.-]+:)?response\s*>")
+            .expect("RESPONSE_RE must compile")
+    });
+
+    let mut entries = Vec::new();
+    for caps in response_re.captures_iter(body) {
+        let fragment = caps.get(1).map_or("", |m| m.as_str());
+        let entry = MultiStatusEntry {
+            href: xml_element_text(fragment, XmlElement::Href),
+            status: xml_element_text(fragment, XmlElement::Status)
+                .and_then(|line| parse_status_line(&line)),
+            description: xml_element_text(fragment, XmlElement::ResponseDescription),
+        };
+        if entry != MultiStatusEntry::default() {
+            entries.push(entry);
+        }
+    }
+
+    let remainder = response_re.replace_all(body, "");
+    let top_level = xml_element_text(&remainder, XmlElement::ResponseDescription)
+        .or_else(|| xml_element_text(&remainder, XmlElement::Message));
+    if let Some(description) = top_level {
+        entries.push(MultiStatusEntry {
+            description: Some(description),
+            ..MultiStatusEntry::default()
+        });
+    }
+    entries
+}
+
+/// 从响应体中提炼一句简短原因；优先展示失败的资源条目。
+pub fn multistatus_reason(body: &str) -> Option<String> {
+    let entries = parse_multistatus(body);
+    let failures = entries
+        .iter()
+        .filter(|entry| entry.is_failure() || entry.status.is_none())
+        .filter_map(MultiStatusEntry::summary)
+        .collect::<Vec<_>>();
+    let candidates = if failures.is_empty() {
+        entries
+            .iter()
+            .filter_map(MultiStatusEntry::summary)
+            .collect::<Vec<_>>()
+    } else {
+        failures
+    };
+    if candidates.is_empty() {
+        return None;
+    }
+
+    let omitted = candidates.len().saturating_sub(MAX_REASON_ENTRIES);
+    let mut reason = candidates
+        .into_iter()
+        .take(MAX_REASON_ENTRIES)
+        .collect::<Vec<_>>()
+        .join("; ");
+    if omitted > 0 {
+        reason.push_str(&format!("; (+{omitted})"));
+    }
+    Some(reason)
+}
+
+/// multistatus 中需要提取文本的元素（按本地名匹配，忽略命名空间前缀）
+#[derive(Debug, Clone, Copy)]
+enum XmlElement {
+    Href,
+    Status,
+    ResponseDescription,
+    /// SabreDAV 的 `<s:message>`
+    Message,
+}
+
+impl XmlElement {
+    /// 每个元素的正则只编译一次，解析每个 `<response>` 时复用
+    fn regex(self) -> &'static Regex {
+        static HREF_RE: OnceLock<Regex> = OnceLock::new();
+        static STATUS_RE: OnceLock<Regex> = OnceLock::new();
+        static RESPONSE_DESCRIPTION_RE: OnceLock<Regex> = OnceLock::new();
+        static MESSAGE_RE: OnceLock<Regex> = OnceLock::new();
+        let (cell, local_name) = match self {
+            Self::Href => (&HREF_RE, "href"),
+            Self::Status => (&STATUS_RE, "status"),
+            Self::ResponseDescription => (&RESPONSE_DESCRIPTION_RE, "responsedescription"),
+            Self::Message => (&MESSAGE_RE, "message"),
+        };
+        cell.get_or_init(|| {
+            Regex::new(&format!(
+                r"(?is)<(?:[\w.-]+:)?{local_name}(?:\s[^>]*)?>(.*?)</(?:[\w.-]+:)?{local_name}\s*>"
+            ))
+            .expect("XML element regex must compile")
+        })
+    }
+}
+
+fn xml_element_text(fragment: &str, element: XmlElement) -> Option<String> {
+    let raw = element.regex().captures(fragment)?.get(1)?.as_str();
+    let text = decode_xml_entities(raw)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if text.is_empty() {
+        return None;
+    }
+    if text.chars().count() > MAX_REASON_DESCRIPTION_CHARS {
+        let truncated = text
+            .chars()
+            .take(MAX_REASON_DESCRIPTION_CHARS)
+            .collect::<String>();
+        return Some(format!("{truncated}…"));
+    }
+    Some(text)
+}
+
+fn parse_status_line(line: &str) -> Option<u16> {
+    line.split_whitespace()
+        .filter(|token| token.len() == 3)
+        .find_map(|token| token.parse::<u16>().ok())
+}
+
+fn decode_xml_entities(raw: &str) -> String {
+    raw.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// 探测资源是否存在时只需要 `resourcetype`，避免服务端返回全部属性
+const PROPFIND_EXISTENCE_PROPS: &[&str] = &["resourcetype"];
+
+/// 构造只请求指定 DAV 属性的 PROPFIND 请求体
+fn propfind_body(props: &[&str]) -> String {
+    let mut body = String::from(
+        r#"<?xml version="1.0" encoding="utf-8"?><D:propfind xmlns:D="DAV:"><D:prop>"#,
+    );
+    for prop in props {
+        body.push_str(&format!("<D:{prop}/>"));
+    }
+    body.push_str("</D:prop></D:propfind>");
+    body
+}
+
+/// 构造 Depth: 0 的 PROPFIND 请求，附带 `application/xml` 请求体
+fn propfind_request(
+    client: &Client,
+    url: &str,
+    auth: &WebDavAuth,
+    props: &[&str],
+) -> Result<reqwest::RequestBuilder, AppError> {
     let method = Method::from_bytes(b"PROPFIND").map_err(|e| AppError::Message(e.to_string()))?;
-    let mut req = client.request(method, base_url).header("Depth", "0");
-    req = apply_auth(req, auth);
-    let resp = req.send().await.map_err(|e| {
+    let req = client
+        .request(method, url)
+        .header("Depth", "0")
+        .header("Content-Type", "application/xml; charset=utf-8")
+        .body(propfind_body(props));
+    Ok(apply_auth(req, auth))
+}
+
+// ---------------------------------------------------------------------------
+// 连接测试
+// ---------------------------------------------------------------------------
+
+/// PROPFIND 基础目录确认服务器可达且认证通过，并返回从响应头识别出的服务端类型
+pub async fn test_connection(
+    client: &Client,
+    base_url: &str,
+    auth: &WebDavAuth,
+) -> Result<WebDavServerKind, AppError> {
+    let req = propfind_request(client, base_url, auth, PROPFIND_EXISTENCE_PROPS)?;
+    let resp = send_following_redirect(client, req).await.map_err(|e| {
         AppError::Message(with_service_hint(
             base_url,
-            format!("WebDAV 连接测试失败: {e}"),
+            describe_request_error("PROPFIND", &e),
         ))
     })?;
     match resp.status() {
-        StatusCode::OK | StatusCode::MULTI_STATUS | StatusCode::NO_CONTENT => Ok(()),
-        status => Err(webdav_status_error(base_url, "PROPFIND", status, base_url)),
+        StatusCode::OK | StatusCode::MULTI_STATUS | StatusCode::NO_CONTENT => {
+            let kind = WebDavServerKind::from_headers(resp.headers());
+            remember_server_kind(base_url, kind);
+            Ok(kind)
+        }
+        _ => Err(response_status_error(base_url, "PROPFIND", resp, base_url).await),
     }
 }
 
@@ -274,26 +811,27 @@ pub async fn test_connection(base_url: &str, auth: &WebDavAuth) -> Result<(), Ap
 // ---------------------------------------------------------------------------
 
 pub async fn put_bytes(
+    client: &Client,
     url: &str,
     auth: &WebDavAuth,
     bytes: Vec<u8>,
     content_type: &str,
 ) -> Result<(), AppError> {
     let base_url = url;
-    let client = build_client(TRANSFER_TIMEOUT_SECS)?;
     let mut req = client
         .put(url)
+        .timeout(transfer_timeout())
         .header("Content-Type", content_type)
         .body(bytes);
     req = apply_auth(req, auth);
-    let resp = req.send().await.map_err(|e| {
+    let resp = send_following_redirect(client, req).await.map_err(|e| {
         AppError::Message(with_service_hint(
             base_url,
-            format!("WebDAV PUT 请求失败: {e}"),
+            describe_request_error("PUT", &e),
         ))
     })?;
     if !resp.status().is_success() {
-        return Err(webdav_status_error(base_url, "PUT", resp.status(), url));
+        return Err(response_status_error(base_url, "PUT", resp, url).await);
     }
     Ok(())
 }
@@ -302,64 +840,176 @@ pub async fn put_bytes(
 // GET
 // ---------------------------------------------------------------------------
 
-pub async fn get_bytes(
+async fn send_get(
+    client: &Client,
     url: &str,
     auth: &WebDavAuth,
-    max_bytes: Option<u64>,
-) -> Result<Option<(Vec<u8>, Option<String>)>, AppError> {
+) -> Result<Option<Response>, AppError> {
     let base_url = url;
-    let client = build_client(TRANSFER_TIMEOUT_SECS)?;
-    let mut req = client.get(url);
+    let mut req = client.get(url).timeout(transfer_timeout());
     req = apply_auth(req, auth);
-    let resp = req.send().await.map_err(|e| {
-        AppError::Message(with_service_hint(
-            base_url,
-            format!("WebDAV GET 请求失败: {e}"),
-        ))
-    })?;
+    let resp = http::send_with_retry(client, req, None, LOG_TAG)
+        .await
+        .map_err(|e| {
+            AppError::Message(with_service_hint(
+                base_url,
+                describe_request_error("GET", &e),
+            ))
+        })?;
     if resp.status() == StatusCode::NOT_FOUND {
         return Ok(None);
     }
     if !resp.status().is_success() {
-        return Err(webdav_status_error(base_url, "GET", resp.status(), url));
+        return Err(response_status_error(base_url, "GET", resp, url).await);
     }
-    let etag = resp
-        .headers()
+    Ok(Some(resp))
+}
+
+fn response_etag(resp: &Response) -> Option<String> {
+    resp.headers()
         .get("etag")
         .and_then(|v| v.to_str().ok())
+        .map(|s| s.to_string())
+}
+
+pub async fn get_bytes(
+    client: &Client,
+    url: &str,
+    auth: &WebDavAuth,
+    max_bytes: Option<u64>,
+) -> Result<Option<(Vec<u8>, Option<String>)>, AppError> {
+    Ok(get_bytes_typed(client, url, auth, max_bytes)
+        .await?
+        .map(|(bytes, etag, _)| (bytes, etag)))
+}
+
+/// 与 [`get_bytes`] 相同，额外返回响应的 `Content-Type`（如有），便于调用方识别内容类型
+pub async fn get_bytes_typed(
+    client: &Client,
+    url: &str,
+    auth: &WebDavAuth,
+    max_bytes: Option<u64>,
+) -> Result<Option<(Vec<u8>, Option<String>, Option<String>)>, AppError> {
+    let Some(resp) = send_get(client, url, auth).await? else {
+        return Ok(None);
+    };
+    let etag = response_etag(&resp);
+    let content_type = resp
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
         .map(|s| s.to_string());
 
     if let Some(limit) = max_bytes {
-        if let Some(len) = resp.content_length() {
-            if len > limit {
-                return Err(AppError::Message(format!(
-                    "WebDAV 响应超过大小限制 ({limit} bytes)"
-                )));
-            }
-        }
-        let mut bytes = Vec::new();
-        let mut stream = resp.bytes_stream();
-        while let Some(chunk) = stream.next().await {
-            let chunk =
-                chunk.map_err(|e| AppError::Message(format!("读取 WebDAV 响应失败: {e}")))?;
-            if (bytes.len() as u64).saturating_add(chunk.len() as u64) > limit {
-                return Err(AppError::Message(format!(
-                    "WebDAV 响应超过大小限制 ({limit} bytes)"
-                )));
-            }
-            bytes.extend_from_slice(&chunk);
-        }
-        Ok(Some((bytes, etag)))
+        Ok(Some((
+            read_body_limited(resp, limit).await?,
+            etag,
+            content_type,
+        )))
     } else {
         let bytes = resp
             .bytes()
             .await
-            .map_err(|e| AppError::Message(format!("读取 WebDAV 响应失败: {e}")))?;
-        Ok(Some((bytes.to_vec(), etag)))
+            .map_err(|e| AppError::Message(describe_request_error("GET", &e)))?;
+        Ok(Some((bytes.to_vec(), etag, content_type)))
+    }
+}
+
+/// 只请求资源开头的 `len` 字节（`Range: bytes=0-{len-1}`）。
+///
+/// 返回内容以及是否为部分内容：服务端返回 206 时为 `true`；
+/// 忽略 Range 而返回 200 时读取完整响应（受 `max_bytes` 限制）并返回 `false`。
+/// 416（资源为空等）按空的部分内容处理，由调用方决定是否回退到完整 GET。
+/// 404 时返回 `None`。
+pub async fn get_bytes_range(
+    client: &Client,
+    url: &str,
+    auth: &WebDavAuth,
+    len: u64,
+    max_bytes: u64,
+) -> Result<Option<(Vec<u8>, bool)>, AppError> {
+    let mut req = client.get(url).timeout(transfer_timeout()).header(
+        reqwest::header::RANGE,
+        format!("bytes=0-{}", len.saturating_sub(1)),
+    );
+    req = apply_auth(req, auth);
+    let resp = send_following_redirect(client, req).await.map_err(|e| {
+        AppError::Message(with_service_hint(url, describe_request_error("GET", &e)))
+    })?;
+    match resp.status() {
+        StatusCode::NOT_FOUND => Ok(None),
+        StatusCode::RANGE_NOT_SATISFIABLE => Ok(Some((Vec::new(), true))),
+        StatusCode::PARTIAL_CONTENT => Ok(Some((read_body_limited(resp, len).await?, true))),
+        status if status.is_success() => {
+            Ok(Some((read_body_limited(resp, max_bytes).await?, false)))
+        }
+        _ => Err(response_status_error(url, "GET", resp, url).await),
+    }
+}
+
+/// 读取响应体，超过 `limit` 字节立即报错（不会缓冲超限部分）
+async fn read_body_limited(resp: Response, limit: u64) -> Result<Vec<u8>, AppError> {
+    if resp.content_length().is_some_and(|len| len > limit) {
+        return Err(AppError::Message(format!(
+            "WebDAV 响应超过大小限制 ({limit} bytes)"
+        )));
     }
+    let mut bytes = Vec::new();
+    let mut stream = resp.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| AppError::Message(describe_request_error("GET", &e)))?;
+        if (bytes.len() as u64).saturating_add(chunk.len() as u64) > limit {
+            return Err(AppError::Message(format!(
+                "WebDAV 响应超过大小限制 ({limit} bytes)"
+            )));
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    Ok(bytes)
+}
+
+/// 流式 GET：边接收边写入 `writer`，不在内存中缓冲完整响应。
+///
+/// 返回写入的字节数与 ETag；404 时返回 `None`。
+pub async fn get_to_writer(
+    client: &Client,
+    url: &str,
+    auth: &WebDavAuth,
+    max_bytes: u64,
+    writer: &mut impl Write,
+) -> Result<Option<(u64, Option<String>)>, AppError> {
+    let Some(resp) = send_get(client, url, auth).await? else {
+        return Ok(None);
+    };
+    let etag = response_etag(&resp);
+
+    if resp.content_length().is_some_and(|len| len > max_bytes) {
+        return Err(AppError::Message(format!(
+            "WebDAV 响应超过大小限制 ({max_bytes} bytes)"
+        )));
+    }
+    let mut written = 0u64;
+    let mut stream = resp.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| AppError::Message(describe_request_error("GET", &e)))?;
+        if written.saturating_add(chunk.len() as u64) > max_bytes {
+            return Err(AppError::Message(format!(
+                "WebDAV 响应超过大小限制 ({max_bytes} bytes)"
+            )));
+        }
+        writer
+            .write_all(&chunk)
+            .map_err(|e| AppError::Message(format!("写入 WebDAV 响应失败: {e}")))?;
+        written += chunk.len() as u64;
+    }
+    writer
+        .flush()
+        .map_err(|e| AppError::Message(format!("写入 WebDAV 响应失败: {e}")))?;
+    Ok(Some((written, etag)))
 }
 
 pub async fn verify_readback_matches(
+    client: &Client,
     base_url: &str,
     url: &str,
     auth: &WebDavAuth,
@@ -367,7 +1017,7 @@ pub async fn verify_readback_matches(
     resource_name: &str,
 ) -> Result<(), AppError> {
     let max_bytes = u64::try_from(expected_bytes.len()).unwrap_or(u64::MAX);
-    let Some((readback, _)) = get_bytes(url, auth, Some(max_bytes)).await? else {
+    let Some((readback, _)) = get_bytes(client, url, auth, Some(max_bytes)).await? else {
         return Err(AppError::Message(with_service_hint(
             base_url,
             format!(
@@ -394,15 +1044,32 @@ pub async fn verify_readback_matches(
 // HEAD
 // ---------------------------------------------------------------------------
 
-pub async fn head_etag(url: &str, auth: &WebDavAuth) -> Result<Option<String>, AppError> {
+pub async fn head_etag(
+    client: &Client,
+    url: &str,
+    auth: &WebDavAuth,
+) -> Result<Option<String>, AppError> {
+    Ok(head_resource(client, url, auth).await?.flatten())
+}
+
+/// HEAD 检查资源是否存在（404 → false）
+pub async fn head_exists(client: &Client, url: &str, auth: &WebDavAuth) -> Result<bool, AppError> {
+    Ok(head_resource(client, url, auth).await?.is_some())
+}
+
+/// 发送 HEAD：资源不存在返回 `None`，存在时返回其 ETag（可能没有）
+async fn head_resource(
+    client: &Client,
+    url: &str,
+    auth: &WebDavAuth,
+) -> Result<Option<Option<String>>, AppError> {
     let base_url = url;
-    let client = build_client(DEFAULT_TIMEOUT_SECS)?;
     let mut req = client.head(url);
     req = apply_auth(req, auth);
-    let resp = req.send().await.map_err(|e| {
+    let resp = send_following_redirect(client, req).await.map_err(|e| {
         AppError::Message(with_service_hint(
             base_url,
-            format!("WebDAV HEAD 请求失败: {e}"),
+            describe_request_error("HEAD", &e),
         ))
     })?;
     if resp.status() == StatusCode::NOT_FOUND {
@@ -411,11 +1078,12 @@ pub async fn head_etag(url: &str, auth: &WebDavAuth) -> Result<Option<String>, A
     if !resp.status().is_success() {
         return Err(webdav_status_error(base_url, "HEAD", resp.status(), url));
     }
-    Ok(resp
-        .headers()
-        .get("etag")
-        .and_then(|v| v.to_str().ok())
-        .map(|s| s.to_string()))
+    Ok(Some(
+        resp.headers()
+            .get("etag")
+            .and_then(|v| v.to_str().ok())
+            .map(|s| s.to_string()),
+    ))
 }
 
 // ---------------------------------------------------------------------------
@@ -430,18 +1098,16 @@ enum RemoteDirProbe {
 }
 
 async fn propfind_remote_dir(
+    client: &Client,
     url: &str,
     auth: &WebDavAuth,
     base_url: &str,
 ) -> Result<RemoteDirProbe, AppError> {
-    let client = build_client(DEFAULT_TIMEOUT_SECS)?;
-    let method = Method::from_bytes(b"PROPFIND").map_err(|e| AppError::Message(e.to_string()))?;
-    let mut req = client.request(method, url).header("Depth", "0");
-    req = apply_auth(req, auth);
-    let resp = req.send().await.map_err(|e| {
+    let req = propfind_request(client, url, auth, PROPFIND_EXISTENCE_PROPS)?;
+    let resp = send_following_redirect(client, req).await.map_err(|e| {
         AppError::Message(with_service_hint(
             base_url,
-            format!("WebDAV PROPFIND 请求失败: {e}"),
+            describe_request_error("PROPFIND", &e),
         ))
     })?;
     match resp.status() {
@@ -450,23 +1116,23 @@ async fn propfind_remote_dir(
         }
         StatusCode::NOT_FOUND => Ok(RemoteDirProbe::Missing),
         StatusCode::METHOD_NOT_ALLOWED => Ok(RemoteDirProbe::Unsupported),
-        status => Err(webdav_status_error(base_url, "PROPFIND", status, url)),
+        _ => Err(response_status_error(base_url, "PROPFIND", resp, url).await),
     }
 }
 
 async fn mkcol_remote_dir(
+    client: &Client,
     url: &str,
     auth: &WebDavAuth,
     base_url: &str,
 ) -> Result<StatusCode, AppError> {
-    let client = build_client(DEFAULT_TIMEOUT_SECS)?;
     let method = Method::from_bytes(b"MKCOL").map_err(|e| AppError::Message(e.to_string()))?;
     let mut req = client.request(method, url);
     req = apply_auth(req, auth);
-    let resp = req.send().await.map_err(|e| {
+    let resp = send_following_redirect(client, req).await.map_err(|e| {
         AppError::Message(with_service_hint(
             base_url,
-            format!("WebDAV MKCOL 请求失败: {e}"),
+            describe_request_error("MKCOL", &e),
         ))
     })?;
     Ok(resp.status())
@@ -484,42 +1150,98 @@ fn should_verify_after_mkcol(status: StatusCode) -> bool {
     )
 }
 
-/// DELETE a remote collection (directory). Returns Ok(true) if deleted,
+/// DELETE a remote resource. Returns Ok(true) if deleted,
 /// Ok(false) if 404/410 (already gone), Err on other failures.
-pub async fn delete_resource(url: &str, auth: &WebDavAuth) -> Result<bool, AppError> {
-    let client = build_client(DEFAULT_TIMEOUT_SECS)?;
+///
+/// `base_url` is the configured WebDAV root and is only used to pick
+/// provider-specific hints (e.g. Jianguoyun) for error messages.
+pub async fn delete_resource(
+    client: &Client,
+    base_url: &str,
+    url: &str,
+    auth: &WebDavAuth,
+) -> Result<bool, AppError> {
+    Ok(send_delete(client, base_url, url, auth).await?.is_some())
+}
+
+/// 发送 DELETE；404/410 返回 `None`，207 中有成员删除失败时报错
+async fn send_delete(
+    client: &Client,
+    base_url: &str,
+    url: &str,
+    auth: &WebDavAuth,
+) -> Result<Option<()>, AppError> {
     let req = apply_auth(client.request(Method::DELETE, url), auth);
-    let resp = req.send().await.map_err(|e| {
+    let resp = send_following_redirect(client, req).await.map_err(|e| {
         AppError::Message(with_service_hint(
-            url,
-            format!("WebDAV DELETE 请求失败: {}: {e}", redact_url(url)),
+            base_url,
+            format!(
+                "{}: {}",
+                describe_request_error("DELETE", &e),
+                redact_url(url)
+            ),
         ))
     })?;
     let status = resp.status();
     match status {
-        s if s.is_success() => Ok(true),
-        StatusCode::NOT_FOUND | StatusCode::GONE => Ok(false),
-        _ => Err(webdav_status_error(url, "DELETE", status, url)),
+        // DELETE 集合时 207 表示部分成员删除失败（RFC 4918 §9.6.1）
+        StatusCode::MULTI_STATUS => {
+            let body = resp.text().await.unwrap_or_default();
+            let entries = parse_multistatus(&body);
+            if entries.iter().any(|entry| {
+                entry.is_failure() && entry.status != Some(StatusCode::NOT_FOUND.as_u16())
+            }) {
+                Err(webdav_status_error_with_body(
+                    base_url, "DELETE", status, url, &body,
+                ))
+            } else {
+                Ok(Some(()))
+            }
+        }
+        s if s.is_success() => Ok(Some(())),
+        StatusCode::NOT_FOUND | StatusCode::GONE => Ok(None),
+        _ => Err(response_status_error(base_url, "DELETE", resp, url).await),
     }
 }
 
-pub async fn delete_collection(url: &str, auth: &WebDavAuth) -> Result<bool, AppError> {
-    delete_resource(url, auth).await
+/// DELETE a remote collection (directory) together with all its members.
+/// Returns `None` if the collection was already gone.
+pub async fn delete_collection(
+    client: &Client,
+    base_url: &str,
+    url: &str,
+    auth: &WebDavAuth,
+) -> Result<Option<()>, AppError> {
+    send_delete(client, base_url, url, auth).await
 }
 
 pub async fn verify_round_trip_readability(
+    client: &Client,
     base_url: &str,
     dir_segments: &[String],
     auth: &WebDavAuth,
 ) -> Result<(), AppError> {
     let probe_name = format!("cc-switch-probe-{}.tmp", Uuid::new_v4());
+    write_probe_file(client, base_url, dir_segments, auth, &probe_name).await
+}
+
+/// 在目录下 PUT 一个探测文件、读回比对内容，无论成败都尝试 DELETE 清理。
+/// 清理失败只记日志，不覆盖探测本身的结果。
+pub async fn write_probe_file(
+    client: &Client,
+    base_url: &str,
+    dir_segments: &[String],
+    auth: &WebDavAuth,
+    probe_name: &str,
+) -> Result<(), AppError> {
     let mut probe_segments = dir_segments.to_vec();
-    probe_segments.push(probe_name);
+    probe_segments.push(probe_name.to_string());
     let probe_url = build_remote_url(base_url, &probe_segments)?;
     let probe_bytes = format!("cc-switch-webdav-probe:{}", Uuid::new_v4()).into_bytes();
 
     let probe_result = async {
         put_bytes(
+            client,
             &probe_url,
             auth,
             probe_bytes.clone(),
@@ -527,13 +1249,13 @@ pub async fn verify_round_trip_readability(
         )
         .await?;
 
-        verify_readback_matches(base_url, &probe_url, auth, &probe_bytes, "probe").await?;
+        verify_readback_matches(client, base_url, &probe_url, auth, &probe_bytes, "probe").await?;
 
         Ok(())
     }
     .await;
 
-    let cleanup_result = delete_resource(&probe_url, auth).await;
+    let cleanup_result = delete_resource(client, base_url, &probe_url, auth).await;
 
     match probe_result {
         Ok(()) => {
@@ -566,6 +1288,7 @@ pub async fn verify_round_trip_readability(
 }
 
 pub async fn ensure_remote_directories(
+    client: &Client,
     base_url: &str,
     segments: &[String],
     auth: &WebDavAuth,
@@ -574,32 +1297,92 @@ pub async fn ensure_remote_directories(
     for segment in segments {
         current.push(segment.clone());
         let url = build_remote_url(base_url, &current)?;
-        ensure_single_dir(&url, auth, base_url).await?;
+        ensure_single_dir(client, &url, auth, base_url).await?;
     }
     Ok(())
 }
 
-async fn ensure_single_dir(url: &str, auth: &WebDavAuth, base_url: &str) -> Result<(), AppError> {
-    match propfind_remote_dir(url, auth, base_url).await? {
+/// 只创建 `segments` 的最后一级目录，调用方需保证上级目录均已存在
+pub async fn ensure_remote_directory(
+    client: &Client,
+    base_url: &str,
+    segments: &[String],
+    auth: &WebDavAuth,
+) -> Result<(), AppError> {
+    let url = build_remote_url(base_url, segments)?;
+    ensure_single_dir(client, &url, auth, base_url).await
+}
+
+/// 只用 PROPFIND 探测目录是否存在，不会创建目录。服务端不支持 PROPFIND 时返回 `None`。
+pub async fn probe_remote_directory(
+    client: &Client,
+    base_url: &str,
+    segments: &[String],
+    auth: &WebDavAuth,
+) -> Result<Option<bool>, AppError> {
+    let url = build_remote_url(base_url, segments)?;
+    Ok(
+        match propfind_remote_dir(client, &url, auth, base_url).await? {
+            RemoteDirProbe::Exists => Some(true),
+            RemoteDirProbe::Missing => Some(false),
+            RemoteDirProbe::Unsupported => None,
+        },
+    )
+}
+
+async fn ensure_single_dir(
+    client: &Client,
+    url: &str,
+    auth: &WebDavAuth,
+    base_url: &str,
+) -> Result<(), AppError> {
+    match propfind_remote_dir(client, url, auth, base_url).await? {
         RemoteDirProbe::Exists => return Ok(()),
         RemoteDirProbe::Missing | RemoteDirProbe::Unsupported => {}
     }
 
-    let status = mkcol_remote_dir(url, auth, base_url).await?;
+    let status = mkcol_remote_dir(client, url, auth, base_url).await?;
     match status {
         StatusCode::CREATED => Ok(()),
         status if should_verify_after_mkcol(status) => {
-            match propfind_remote_dir(url, auth, base_url).await? {
-                RemoteDirProbe::Exists => Ok(()),
-                RemoteDirProbe::Missing | RemoteDirProbe::Unsupported => {
-                    Err(webdav_status_error(base_url, "MKCOL", status, url))
-                }
-            }
+            verify_dir_after_failed_mkcol(client, url, auth, base_url, status).await
         }
         _ => Err(webdav_status_error(base_url, "MKCOL", status, url)),
     }
 }
 
+/// MKCOL 返回 405/409 等状态时，目录可能已被另一台设备并发创建。
+/// 重新探测：存在即视为成功；仍然不存在时稍等后再确认一次，避免服务端可见性延迟导致误判。
+async fn verify_dir_after_failed_mkcol(
+    client: &Client,
+    url: &str,
+    auth: &WebDavAuth,
+    base_url: &str,
+    mkcol_status: StatusCode,
+) -> Result<(), AppError> {
+    for attempt in 0..MKCOL_VERIFY_ATTEMPTS {
+        if attempt > 0 {
+            tokio::time::sleep(MKCOL_VERIFY_RETRY_DELAY).await;
+        }
+        match propfind_remote_dir(client, url, auth, base_url).await? {
+            RemoteDirProbe::Exists => {
+                log::info!(
+                    "[WebDAV] MKCOL returned {mkcol_status} but directory exists (likely created concurrently): {}",
+                    redact_url(url)
+                );
+                return Ok(());
+            }
+            // PROPFIND 不可用时无法确认；按 RFC 4918，MKCOL 405 表示资源已存在
+            RemoteDirProbe::Unsupported if mkcol_status == StatusCode::METHOD_NOT_ALLOWED => {
+                return Ok(());
+            }
+            RemoteDirProbe::Unsupported => break,
+            RemoteDirProbe::Missing => {}
+        }
+    }
+    Err(webdav_status_error(base_url, "MKCOL", mkcol_status, url))
+}
+
 // ---------------------------------------------------------------------------
 // 测试
 // ---------------------------------------------------------------------------
@@ -625,6 +1408,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_remote_url_round_trips_every_valid_segment() {
+        let base = "https://dav.example.com/dav";
+        let samples = [
+            "cc-switch-sync",
+            "team a",
+            "团队 同步",
+            "laptop-😀",
+            "a+b=c&d",
+            "q?x#frag",
+            "semi;colon:at@",
+            "quotes'\"[brackets]",
+            "trailing.dot.",
+        ];
+        for sample in samples {
+            let settings = crate::settings::WebDavSyncSettings {
+                base_url: base.to_string(),
+                remote_root: sample.to_string(),
+                profile: sample.to_string(),
+                ..Default::default()
+            };
+            settings
+                .validate()
+                .unwrap_or_else(|e| panic!("{sample:?} should be a valid segment: {e}"));
+
+            let segments = vec![sample.to_string(), "v2".to_string(), sample.to_string()];
+            let url = build_remote_url(base, &segments).expect("build remote url");
+            let parsed = Url::parse(&url).expect("parse built url");
+            let decoded: Vec<String> = parsed
+                .path_segments()
+                .expect("hierarchical url")
+                .skip(1)
+                .map(|segment| {
+                    percent_encoding::percent_decode_str(segment)
+                        .decode_utf8()
+                        .expect("utf-8 segment")
+                        .into_owned()
+                })
+                .collect();
+            assert_eq!(decoded, segments, "round trip through {url}");
+        }
+    }
+
     #[test]
     fn path_segments_splits_correctly() {
         let segs: Vec<&str> = path_segments("/a/b/c/").collect();
@@ -644,6 +1470,45 @@ mod tests {
         assert!(!is_jianguoyun("https://dav.example.com/dav"));
     }
 
+    #[test]
+    fn detect_provider_maps_urls_to_server_kinds() {
+        remember_server_kind(
+            "https://files.detect-kind.test/dav",
+            WebDavServerKind::OwnCloud,
+        );
+        let cases = [
+            (
+                "https://dav.jianguoyun.com/dav/",
+                WebDavServerKind::Jianguoyun,
+            ),
+            (
+                "https://DAV.JIANGUOYUN.COM/dav",
+                WebDavServerKind::Jianguoyun,
+            ),
+            (
+                "https://dav.nutstore.net/dav/team",
+                WebDavServerKind::Nutstore,
+            ),
+            (
+                "https://cloud.example.com/remote.php/dav/files/demo/",
+                WebDavServerKind::Nextcloud,
+            ),
+            (
+                "https://sea.example.com/seafdav/lib",
+                WebDavServerKind::Seafile,
+            ),
+            (
+                "https://files.detect-kind.test/dav/sync",
+                WebDavServerKind::OwnCloud,
+            ),
+            ("https://dav.example.com/dav", WebDavServerKind::Generic),
+            ("not a url", WebDavServerKind::Generic),
+        ];
+        for (url, expected) in cases {
+            assert_eq!(detect_provider(url), expected, "url: {url}");
+        }
+    }
+
     #[test]
     fn webdav_status_error_uses_nutstore_name_in_hints() {
         let err = webdav_status_error(
@@ -658,6 +1523,85 @@ mod tests {
         assert!(!message.contains("坚果云"), "unexpected error: {message}");
     }
 
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in pairs {
+            map.append(*name, reqwest::header::HeaderValue::from_static(value));
+        }
+        map
+    }
+
+    #[test]
+    fn server_kind_is_detected_from_sample_headers() {
+        let cases = [
+            (
+                headers(&[("server", "Nextcloud")]),
+                WebDavServerKind::Nextcloud,
+            ),
+            (
+                headers(&[("server", "Apache"), ("x-nextcloud-request-id", "abc")]),
+                WebDavServerKind::Nextcloud,
+            ),
+            (
+                headers(&[("dav", "1, 3, extended-mkcol, nextcloud-checksum-update")]),
+                WebDavServerKind::Nextcloud,
+            ),
+            (
+                headers(&[("dav", "1, 2, nc-calendar-search")]),
+                WebDavServerKind::Nextcloud,
+            ),
+            (
+                headers(&[("dav", "1, 3, oc-resource-sharing")]),
+                WebDavServerKind::OwnCloud,
+            ),
+            (
+                headers(&[("dav", "1, 2, access-control, sync-collection")]),
+                WebDavServerKind::Generic,
+            ),
+            (
+                headers(&[("server", "ownCloud/10.13")]),
+                WebDavServerKind::OwnCloud,
+            ),
+            (
+                headers(&[("server", "SeafDAV/1.0")]),
+                WebDavServerKind::Seafile,
+            ),
+            (
+                headers(&[("server", "nginx/1.25.3")]),
+                WebDavServerKind::Generic,
+            ),
+            (headers(&[]), WebDavServerKind::Generic),
+        ];
+        for (headers, expected) in cases {
+            assert_eq!(
+                WebDavServerKind::from_headers(&headers),
+                expected,
+                "headers: {headers:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn status_error_uses_remembered_server_kind_for_hints() {
+        let base_url = "https://cloud.kind-hint.test/remote.php/dav/files/demo";
+        remember_server_kind(base_url, WebDavServerKind::Nextcloud);
+
+        let auth = webdav_status_error(base_url, "PUT", StatusCode::UNAUTHORIZED, base_url);
+        assert!(auth.to_string().contains("应用密码"), "unexpected: {auth}");
+        let missing = webdav_status_error(base_url, "PROPFIND", StatusCode::NOT_FOUND, base_url);
+        assert!(
+            missing.to_string().contains("/remote.php/dav/files/"),
+            "unexpected: {missing}"
+        );
+
+        // 主机名识别的坚果云优先于响应头推断
+        let jianguoyun = "https://dav.jianguoyun.com/dav/sync";
+        remember_server_kind(jianguoyun, WebDavServerKind::Nextcloud);
+        let err = webdav_status_error(jianguoyun, "PUT", StatusCode::UNAUTHORIZED, jianguoyun);
+        assert!(err.to_string().contains("坚果云"), "unexpected: {err}");
+        assert!(!err.to_string().contains("Nextcloud"), "unexpected: {err}");
+    }
+
     #[test]
     fn auth_from_credentials_empty_username_returns_none() {
         assert!(auth_from_credentials("", "pass").is_none());
@@ -715,6 +1659,215 @@ mod tests {
         assert!(err.to_string().contains("/dav"), "unexpected error: {err}");
     }
 
+    const LOCKED_MULTISTATUS: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:multistatus xmlns:d="DAV:">
+  <d:response>
+    <d:href>/dav/cc-switch-sync/v2/default/db.sql</d:href>
+    <d:status>HTTP/1.1 423 Locked</d:status>
+    <d:responsedescription>
+      Resource is locked by another client
+    </d:responsedescription>
+  </d:response>
+</d:multistatus>"#;
+
+    #[test]
+    fn parse_multistatus_extracts_per_resource_status() {
+        let entries = parse_multistatus(LOCKED_MULTISTATUS);
+        assert_eq!(
+            entries,
+            vec![MultiStatusEntry {
+                href: Some("/dav/cc-switch-sync/v2/default/db.sql".to_string()),
+                status: Some(423),
+                description: Some("Resource is locked by another client".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_multistatus_reads_top_level_description_with_any_prefix() {
+        let body = r#"<D:multistatus xmlns:D="DAV:">
+  <D:response><D:href>/a</D:href><D:status>HTTP/1.1 200 OK</D:status></D:response>
+  <D:responsedescription>Quota &amp; limits exceeded</D:responsedescription>
+</D:multistatus>"#;
+        let reason = multistatus_reason(body).expect("reason");
+        assert_eq!(reason, "Quota & limits exceeded");
+    }
+
+    #[test]
+    fn webdav_status_error_with_body_includes_locked_reason() {
+        let err = webdav_status_error_with_body(
+            "https://dav.example.com/dav",
+            "DELETE",
+            StatusCode::LOCKED,
+            "https://dav.example.com/dav/cc-switch-sync/v2/default",
+            LOCKED_MULTISTATUS,
+        );
+
+        let message = err.to_string();
+        assert!(message.contains("DELETE"), "unexpected error: {message}");
+        assert!(
+            message.contains("423 Locked (/dav/cc-switch-sync/v2/default/db.sql)"),
+            "unexpected error: {message}"
+        );
+        assert!(
+            message.contains("Resource is locked by another client"),
+            "unexpected error: {message}"
+        );
+    }
+
+    #[test]
+    fn webdav_status_error_with_body_ignores_non_xml_body() {
+        let err = webdav_status_error_with_body(
+            "https://dav.example.com/dav",
+            "PUT",
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "https://dav.example.com/dav/file",
+            "Internal Server Error",
+        );
+        assert!(!err.to_string().contains("服务器响应"));
+    }
+
+    #[tokio::test]
+    async fn delete_collection_error_reports_operation_and_redacted_url() {
+        use axum::{http::StatusCode as AxumStatus, routing::any, Router};
+
+        let router = Router::new().fallback(any(|| async {
+            (AxumStatus::INTERNAL_SERVER_ERROR, "raw-upstream-body")
+        }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind test listener");
+        let addr = listener.local_addr().expect("listener addr");
+        let server = tokio::spawn(async move {
+            let _ = axum::serve(listener, router).await;
+        });
+
+        let base_url = format!("http://demo:hunter2@{addr}/dav");
+        let url = format!("{base_url}/cc-switch-sync/v1/default");
+        let err = delete_collection(
+            &build_client(None).expect("build client"),
+            &base_url,
+            &url,
+            &None,
+        )
+        .await
+        .expect_err("500 on DELETE should fail");
+        server.abort();
+
+        let message = err.to_string();
+        assert!(message.contains("DELETE"), "unexpected error: {message}");
+        assert!(
+            message.contains(&format!(
+                "http://demo:***@{addr}/dav/cc-switch-sync/v1/default"
+            )),
+            "unexpected error: {message}"
+        );
+        assert!(!message.contains("hunter2"), "unexpected error: {message}");
+        assert!(
+            !message.contains("raw-upstream-body"),
+            "unexpected error: {message}"
+        );
+    }
+
+    /// 统计 TCP 连接数的最小 HTTP/1.1 keep-alive 服务：PUT 返回 201，其余返回 200 与固定正文。
+    async fn serve_counting_connections() -> (String, std::sync::Arc<std::sync::atomic::AtomicUsize>)
+    {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind test listener");
+        let addr = listener.local_addr().expect("listener addr");
+        let connections = Arc::new(AtomicUsize::new(0));
+        let accepted = connections.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                accepted.fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(async move {
+                    let mut stream = BufReader::new(stream);
+                    loop {
+                        let mut request_line = String::new();
+                        if stream.read_line(&mut request_line).await.unwrap_or(0) == 0 {
+                            return;
+                        }
+                        let mut content_length = 0usize;
+                        loop {
+                            let mut header = String::new();
+                            if stream.read_line(&mut header).await.unwrap_or(0) == 0 {
+                                return;
+                            }
+                            let header = header.trim_end();
+                            if header.is_empty() {
+                                break;
+                            }
+                            if let Some((name, value)) = header.split_once(':') {
+                                if name.eq_ignore_ascii_case("content-length") {
+                                    content_length = value.trim().parse().unwrap_or(0);
+                                }
+                            }
+                        }
+                        let mut body = vec![0u8; content_length];
+                        if stream.read_exact(&mut body).await.is_err() {
+                            return;
+                        }
+                        let response = if request_line.starts_with("PUT ") {
+                            "HTTP/1.1 201 Created\r\ncontent-length: 0\r\n\r\n".to_string()
+                        } else if request_line.starts_with("HEAD ") {
+                            "HTTP/1.1 200 OK\r\netag: \"v1\"\r\ncontent-length: 2\r\n\r\n"
+                                .to_string()
+                        } else {
+                            "HTTP/1.1 200 OK\r\netag: \"v1\"\r\ncontent-length: 2\r\n\r\nok"
+                                .to_string()
+                        };
+                        if stream
+                            .get_mut()
+                            .write_all(response.as_bytes())
+                            .await
+                            .is_err()
+                        {
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+        (format!("http://{addr}/dav"), connections)
+    }
+
+    #[tokio::test]
+    async fn shared_client_reuses_connection_across_requests() {
+        use std::sync::atomic::Ordering;
+
+        let (base_url, connections) = serve_counting_connections().await;
+        let url = format!("{base_url}/manifest.json");
+        let client = build_client(None).expect("build client");
+
+        put_bytes(&client, &url, &None, b"ok".to_vec(), "application/json")
+            .await
+            .expect("put");
+        let (bytes, etag) = get_bytes(&client, &url, &None, Some(1024))
+            .await
+            .expect("get")
+            .expect("resource exists");
+        assert_eq!(bytes, b"ok");
+        assert_eq!(etag.as_deref(), Some("\"v1\""));
+        assert_eq!(
+            head_etag(&client, &url, &None).await.expect("head"),
+            Some("\"v1\"".to_string())
+        );
+        verify_readback_matches(&client, &base_url, &url, &None, b"ok", "manifest")
+            .await
+            .expect("readback");
+
+        assert_eq!(
+            connections.load(Ordering::SeqCst),
+            1,
+            "transfer and control requests should share one pooled connection"
+        );
+    }
+
     #[test]
     fn mkcol_405_and_409_require_post_verification() {
         assert!(should_verify_after_mkcol(StatusCode::METHOD_NOT_ALLOWED));
@@ -723,4 +1876,486 @@ mod tests {
         assert!(should_verify_after_mkcol(StatusCode::PERMANENT_REDIRECT));
         assert!(!should_verify_after_mkcol(StatusCode::CREATED));
     }
+
+    /// 监听队列已满的本地端口：新的 SYN 会被丢弃，连接既不成功也不被拒绝，
+    /// 行为与不可路由地址一致，但不依赖外部网络环境。
+    async fn unroutable_address() -> (String, Vec<tokio::net::TcpStream>, tokio::net::TcpListener) {
+        let socket = tokio::net::TcpSocket::new_v4().expect("create socket");
+        socket
+            .bind("127.0.0.1:0".parse().unwrap())
+            .expect("bind socket");
+        let listener = socket.listen(0).expect("listen");
+        let addr = listener.local_addr().expect("listener addr");
+        let mut backlog = Vec::new();
+        for _ in 0..8 {
+            match tokio::time::timeout(
+                Duration::from_millis(100),
+                tokio::net::TcpStream::connect(addr),
+            )
+            .await
+            {
+                Ok(Ok(stream)) => backlog.push(stream),
+                _ => break,
+            }
+        }
+        (format!("http://{addr}/dav"), backlog, listener)
+    }
+
+    #[tokio::test]
+    async fn connect_timeout_against_unroutable_address_is_reported_as_connect_failure() {
+        let (base_url, _backlog, _listener) = unroutable_address().await;
+        let client =
+            build_client_with_timeouts(Duration::from_millis(300), Duration::from_secs(30), None)
+                .expect("build client");
+
+        let start = std::time::Instant::now();
+        let err = test_connection(&client, &base_url, &None)
+            .await
+            .expect_err("unreachable address must fail");
+
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "should fail at the connect timeout, took {:?}",
+            start.elapsed()
+        );
+        let message = err.to_string();
+        assert!(message.contains("连接超时"), "unexpected error: {message}");
+        assert!(!message.contains("读取超时"), "unexpected error: {message}");
+    }
+
+    #[tokio::test]
+    async fn stalled_response_is_reported_as_read_timeout() {
+        // 接受连接但从不响应
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind silent server");
+        let base_url = format!("http://{}/dav", listener.local_addr().unwrap());
+        let client =
+            build_client_with_timeouts(Duration::from_secs(5), Duration::from_millis(300), None)
+                .expect("build client");
+
+        let err = test_connection(&client, &base_url, &None)
+            .await
+            .expect_err("stalled server must fail");
+
+        let message = err.to_string();
+        assert!(message.contains("读取超时"), "unexpected error: {message}");
+        assert!(!message.contains("连接超时"), "unexpected error: {message}");
+        drop(listener);
+    }
+
+    #[test]
+    fn propfind_body_requests_only_the_given_props() {
+        assert_eq!(
+            propfind_body(&["resourcetype"]),
+            r#"<?xml version="1.0" encoding="utf-8"?><D:propfind xmlns:D="DAV:"><D:prop><D:resourcetype/></D:prop></D:propfind>"#
+        );
+        assert_eq!(
+            propfind_body(&["quota-available-bytes", "quota-used-bytes"]),
+            r#"<?xml version="1.0" encoding="utf-8"?><D:propfind xmlns:D="DAV:"><D:prop><D:quota-available-bytes/><D:quota-used-bytes/></D:prop></D:propfind>"#
+        );
+    }
+
+    #[tokio::test]
+    async fn propfind_requests_send_xml_body_for_resourcetype() {
+        use axum::{extract::Request, http::StatusCode as AxumStatus, routing::any, Router};
+        use std::sync::{Arc, Mutex};
+
+        let captured = Arc::new(Mutex::new(Vec::<(String, String)>::new()));
+        let seen = captured.clone();
+        let router = Router::new().fallback(any(move |req: Request| {
+            let seen = seen.clone();
+            async move {
+                let content_type = req
+                    .headers()
+                    .get("content-type")
+                    .and_then(|v| v.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string();
+                let body = axum::body::to_bytes(req.into_body(), usize::MAX)
+                    .await
+                    .expect("read body");
+                seen.lock()
+                    .unwrap()
+                    .push((content_type, String::from_utf8_lossy(&body).to_string()));
+                AxumStatus::MULTI_STATUS
+            }
+        }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind test listener");
+        let addr = listener.local_addr().expect("listener addr");
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, router).await;
+        });
+        let base_url = format!("http://{addr}/dav");
+        let client = build_client(None).expect("build client");
+
+        test_connection(&client, &base_url, &None)
+            .await
+            .expect("connection test");
+        ensure_remote_directories(&client, &base_url, &["profile".to_string()], &None)
+            .await
+            .expect("existing directory");
+
+        let captured = captured.lock().unwrap();
+        assert_eq!(captured.len(), 2);
+        for (content_type, body) in captured.iter() {
+            assert!(
+                content_type.starts_with("application/xml"),
+                "unexpected content type: {content_type}"
+            );
+            assert!(body.starts_with(r#"<?xml version="1.0" encoding="utf-8"?>"#));
+            assert_eq!(body.matches("<D:propfind ").count(), 1);
+            assert_eq!(body.matches("</D:propfind>").count(), 1);
+            assert!(body.contains("<D:prop><D:resourcetype/></D:prop>"));
+            assert_eq!(body.matches("/>").count(), 1, "only resourcetype: {body}");
+        }
+    }
+
+    /// 模拟并发创建：首次 PROPFIND 404，MKCOL 返回 `mkcol_status`，之后的 PROPFIND 依次取自 `later_probes`。
+    async fn serve_concurrent_mkcol(
+        mkcol_status: u16,
+        later_probes: Vec<u16>,
+    ) -> (String, std::sync::Arc<std::sync::Mutex<Vec<String>>>) {
+        use axum::{extract::Request, http::StatusCode as AxumStatus, routing::any, Router};
+        use std::sync::{Arc, Mutex};
+
+        let calls = Arc::new(Mutex::new(Vec::<String>::new()));
+        let probes = Arc::new(Mutex::new(later_probes));
+        let seen = calls.clone();
+        let router = Router::new().fallback(any(move |req: Request| {
+            let seen = seen.clone();
+            let probes = probes.clone();
+            async move {
+                let method = req.method().to_string();
+                let mut calls = seen.lock().unwrap();
+                let status = match method.as_str() {
+                    "PROPFIND" if !calls.iter().any(|m| m == "MKCOL") => 404,
+                    "PROPFIND" => {
+                        let mut probes = probes.lock().unwrap();
+                        if probes.is_empty() {
+                            404
+                        } else {
+                            probes.remove(0)
+                        }
+                    }
+                    "MKCOL" => mkcol_status,
+                    _ => 500,
+                };
+                calls.push(method);
+                AxumStatus::from_u16(status).unwrap()
+            }
+        }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind test listener");
+        let addr = listener.local_addr().expect("listener addr");
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, router).await;
+        });
+        (format!("http://{addr}/dav"), calls)
+    }
+
+    #[tokio::test]
+    async fn ensure_dir_treats_conflict_then_existing_dir_as_success() {
+        let (base_url, calls) = serve_concurrent_mkcol(409, vec![207]).await;
+        let client = build_client(None).expect("build client");
+
+        ensure_remote_directories(&client, &base_url, &["profile".to_string()], &None)
+            .await
+            .expect("concurrently created directory should be accepted");
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec!["PROPFIND", "MKCOL", "PROPFIND"]
+        );
+    }
+
+    #[tokio::test]
+    async fn ensure_dir_retries_probe_before_reporting_missing_dir() {
+        let (base_url, calls) = serve_concurrent_mkcol(405, vec![404, 207]).await;
+        let client = build_client(None).expect("build client");
+        ensure_remote_directories(&client, &base_url, &["profile".to_string()], &None)
+            .await
+            .expect("directory visible on retry should be accepted");
+        assert_eq!(calls.lock().unwrap().len(), 4);
+
+        let (base_url, _calls) = serve_concurrent_mkcol(409, vec![404, 404]).await;
+        let err = ensure_remote_directories(&client, &base_url, &["profile".to_string()], &None)
+            .await
+            .expect_err("directory still missing should fail");
+        assert!(err.to_string().contains("MKCOL"), "unexpected error: {err}");
+    }
+
+    /// 记录 `(方法, 路径, 请求体)`；`redirects` 中命中的路径返回 `(状态码, Location)`，其余返回 207 / 201。
+    async fn serve_redirecting(
+        redirects: Vec<(&'static str, u16, String)>,
+    ) -> (
+        std::net::SocketAddr,
+        std::sync::Arc<std::sync::Mutex<Vec<(String, String, Vec<u8>)>>>,
+    ) {
+        use axum::{
+            extract::Request,
+            http::{header::LOCATION, StatusCode as AxumStatus},
+            response::IntoResponse,
+            routing::any,
+            Router,
+        };
+        use std::sync::{Arc, Mutex};
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let seen = calls.clone();
+        let redirects = Arc::new(redirects);
+        let router = Router::new().fallback(any(move |req: Request| {
+            let seen = seen.clone();
+            let redirects = redirects.clone();
+            async move {
+                let method = req.method().to_string();
+                let path = req.uri().path().to_string();
+                let body = axum::body::to_bytes(req.into_body(), usize::MAX)
+                    .await
+                    .expect("read body");
+                seen.lock()
+                    .unwrap()
+                    .push((method.clone(), path.clone(), body.to_vec()));
+                if let Some((_, status, location)) = redirects.iter().find(|(p, ..)| *p == path) {
+                    return (
+                        AxumStatus::from_u16(*status).unwrap(),
+                        [(LOCATION, location.clone())],
+                    )
+                        .into_response();
+                }
+                match method.as_str() {
+                    "PUT" => AxumStatus::CREATED.into_response(),
+                    _ => AxumStatus::MULTI_STATUS.into_response(),
+                }
+            }
+        }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind test listener");
+        let addr = listener.local_addr().expect("listener addr");
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, router).await;
+        });
+        (addr, calls)
+    }
+
+    #[tokio::test]
+    async fn propfind_follows_redirect_to_canonical_collection_url() {
+        let (addr, calls) = serve_redirecting(vec![("/dav", 301, "/dav/".to_string())]).await;
+        let client = build_client(None).expect("build client");
+
+        test_connection(&client, &format!("http://{addr}/dav"), &None)
+            .await
+            .expect("301 then 207 should succeed");
+
+        let calls = calls.lock().unwrap();
+        let requests: Vec<_> = calls
+            .iter()
+            .map(|(method, path, body)| (method.as_str(), path.as_str(), body.is_empty()))
+            .collect();
+        assert_eq!(
+            requests,
+            vec![("PROPFIND", "/dav", false), ("PROPFIND", "/dav/", false)]
+        );
+    }
+
+    #[tokio::test]
+    async fn put_redirect_is_retried_with_original_body() {
+        let (addr, calls) =
+            serve_redirecting(vec![("/dav/a.zip", 308, "/dav/b.zip".to_string())]).await;
+        let client = build_client(None).expect("build client");
+
+        put_bytes(
+            &client,
+            &format!("http://{addr}/dav/a.zip"),
+            &None,
+            b"payload".to_vec(),
+            "application/zip",
+        )
+        .await
+        .expect("PUT should follow 308");
+
+        let calls = calls.lock().unwrap();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[1].0, "PUT");
+        assert_eq!(calls[1].1, "/dav/b.zip");
+        assert_eq!(calls[1].2, b"payload");
+    }
+
+    #[tokio::test]
+    async fn redirect_loops_and_foreign_hosts_are_not_followed() {
+        let (addr, calls) = serve_redirecting(vec![
+            ("/loop-a", 302, "/loop-b".to_string()),
+            ("/loop-b", 302, "/loop-a".to_string()),
+            ("/self", 301, "/self".to_string()),
+            ("/foreign", 301, "http://evil.example.com/dav/".to_string()),
+        ])
+        .await;
+        let client = build_client(None).expect("build client");
+
+        for (path, expected_requests) in [("/loop-a", 2), ("/self", 1), ("/foreign", 1)] {
+            calls.lock().unwrap().clear();
+            let err = test_connection(&client, &format!("http://{addr}{path}"), &None)
+                .await
+                .expect_err("unfollowed redirect should fail");
+            assert!(
+                err.to_string().contains("PROPFIND"),
+                "unexpected error: {err}"
+            );
+            assert_eq!(calls.lock().unwrap().len(), expected_requests, "{path}");
+        }
+    }
+
+    /// 记录每个请求的 `(路径, Authorization 头)`；`redirects` 中命中的路径返回 `(状态码, Location)`，其余返回 200 "ok"。
+    async fn serve_recording_auth(
+        redirects: Vec<(&'static str, u16, String)>,
+    ) -> (
+        std::net::SocketAddr,
+        std::sync::Arc<std::sync::Mutex<Vec<(String, Option<String>)>>>,
+    ) {
+        use axum::{
+            extract::Request,
+            http::{
+                header::{AUTHORIZATION, LOCATION},
+                StatusCode as AxumStatus,
+            },
+            response::IntoResponse,
+            routing::any,
+            Router,
+        };
+        use std::sync::{Arc, Mutex};
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let seen = calls.clone();
+        let redirects = Arc::new(redirects);
+        let router = Router::new().fallback(any(move |req: Request| {
+            let seen = seen.clone();
+            let redirects = redirects.clone();
+            async move {
+                let path = req.uri().path().to_string();
+                let auth = req
+                    .headers()
+                    .get(AUTHORIZATION)
+                    .and_then(|value| value.to_str().ok())
+                    .map(str::to_string);
+                seen.lock().unwrap().push((path.clone(), auth));
+                if let Some((_, status, location)) = redirects.iter().find(|(p, ..)| *p == path) {
+                    return (
+                        AxumStatus::from_u16(*status).unwrap(),
+                        [(LOCATION, location.clone())],
+                    )
+                        .into_response();
+                }
+                (AxumStatus::OK, "ok").into_response()
+            }
+        }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind test listener");
+        let addr = listener.local_addr().expect("listener addr");
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, router).await;
+        });
+        (addr, calls)
+    }
+
+    #[tokio::test]
+    async fn get_follows_redirect_chains_on_the_same_host_with_credentials() {
+        let (addr, calls) = serve_recording_auth(vec![
+            ("/a", 301, "/b".to_string()),
+            ("/b", 302, "/c".to_string()),
+        ])
+        .await;
+        let client = build_client(None).expect("build client");
+        let auth = auth_from_credentials("demo", "secret");
+
+        let (bytes, _) = get_bytes(&client, &format!("http://{addr}/a"), &auth, None)
+            .await
+            .expect("GET should follow both redirects")
+            .expect("target exists");
+        assert_eq!(bytes, b"ok");
+
+        let calls = calls.lock().unwrap();
+        let paths: Vec<_> = calls.iter().map(|(path, _)| path.as_str()).collect();
+        assert_eq!(paths, ["/a", "/b", "/c"]);
+        assert!(calls.iter().all(|(_, auth)| auth.is_some()));
+    }
+
+    #[tokio::test]
+    async fn get_follows_redirect_to_another_host_without_credentials() {
+        let (mirror, mirror_calls) = serve_recording_auth(Vec::new()).await;
+        let (addr, calls) = serve_recording_auth(vec![(
+            "/dav/db.sql",
+            302,
+            format!("http://{mirror}/blob/db.sql"),
+        )])
+        .await;
+        let client = build_client(None).expect("build client");
+        let auth = auth_from_credentials("demo", "secret");
+
+        let (bytes, _) = get_bytes(&client, &format!("http://{addr}/dav/db.sql"), &auth, None)
+            .await
+            .expect("GET should follow a redirect to another host")
+            .expect("target exists");
+        assert_eq!(bytes, b"ok");
+
+        assert!(calls.lock().unwrap()[0].1.is_some());
+        assert_eq!(
+            *mirror_calls.lock().unwrap(),
+            vec![("/blob/db.sql".to_string(), None)],
+            "credentials must not be forwarded to another host"
+        );
+    }
+
+    /// 测试用自签名 CA（P-256，有效期 100 年）
+    const TEST_CA_PEM: &str = "\
+-----BEGIN CERTIFICATE-----\n\
+MIIBjzCCATWgAwIBAgIUEDSPcqOesap74orIu/ec+uhXedswCgYIKoZIzj0EAwIw\n\
+HDEaMBgGA1UEAwwRY2Mtc3dpdGNoIHRlc3QgQ0EwIBcNMjYxMDE4MDAyODE2WhgP\n\
+MjEyNjA5MjQwMDI4MTZaMBwxGjAYBgNVBAMMEWNjLXN3aXRjaCB0ZXN0IENBMFkw\n\
+EwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAEAl3OH9mBlbBmqxgtIzlHxT0Ygl5u/xZL\n\
+RPmNpmZzHUmbHlAH8jg/b5gFtnF2jjrajy8LrP5dv/TTxxN0r6TXzaNTMFEwHQYD\n\
+VR0OBBYEFEa0sFjSK879Q5tUpCIPg6t0gplXMB8GA1UdIwQYMBaAFEa0sFjSK879\n\
+Q5tUpCIPg6t0gplXMA8GA1UdEwEB/wQFMAMBAf8wCgYIKoZIzj0EAwIDSAAwRQIh\n\
+ANhCNGtujs0s9GNeecqh508nkO7Iq0Qe26VnOtkqsIqBAiBkXX96s2B5K4GAKSHj\n\
+pFVMbnaKu+M9gBeLEoVjOAtpxg==\n\
+-----END CERTIFICATE-----\n\
+";
+
+    #[test]
+    fn build_client_trusts_a_valid_ca_bundle() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("corp-ca.pem");
+        std::fs::write(&path, format!("{TEST_CA_PEM}{TEST_CA_PEM}")).expect("write CA bundle");
+
+        build_client(Some(&path)).expect("valid CA bundle should build a client");
+    }
+
+    #[test]
+    fn build_client_reports_unusable_ca_files_with_their_path() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let missing = dir.path().join("missing.pem");
+        let not_pem = dir.path().join("not-pem.pem");
+        std::fs::write(&not_pem, "this is not a certificate").expect("write text file");
+        let bad_body = dir.path().join("bad-body.pem");
+        std::fs::write(
+            &bad_body,
+            "-----BEGIN CERTIFICATE-----\nAAAA\n-----END CERTIFICATE-----\n",
+        )
+        .expect("write broken certificate");
+
+        for path in [&missing, &not_pem, &bad_body] {
+            let err = build_client(Some(path)).expect_err("unusable CA file should fail");
+            let message = err.to_string();
+            assert!(
+                message.contains(&path.display().to_string()),
+                "error should name the file: {message}"
+            );
+        }
+        let err = build_client(Some(&not_pem)).expect_err("text file should fail");
+        assert!(
+            matches!(&err, AppError::Localized { key, .. } if *key == "webdav.ca_cert_invalid"),
+            "unexpected error: {err:?}"
+        );
+    }
 }
@@ -1,37 +1,146 @@
 //! Skills ZIP 打包 / 解压 + 备份回滚
 
-use std::collections::HashSet;
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::ffi::{OsStr, OsString};
 use std::fs;
-use std::io::{Read, Write};
-use std::path::{Path, PathBuf};
+use std::io::{Read, Seek, Write};
+use std::path::{Component, Path, PathBuf};
 
-use tempfile::{tempdir, TempDir};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tempfile::TempDir;
 use zip::{write::SimpleFileOptions, DateTime};
 
+use crate::config::create_temp_dir;
 use crate::error::AppError;
 use crate::services::skill::SkillService;
+use crate::settings::CompressionAlgo;
+
+use super::{size_en, size_zh};
 
 const MAX_ZIP_ENTRIES: usize = 10_000;
 const MAX_ZIP_EXTRACT_BYTES: u64 = 512 * 1024 * 1024; // 512 MB
 
-fn localized(key: &'static str, zh: impl Into<String>, en: impl Into<String>) -> AppError {
-    AppError::localized(key, zh, en)
+/// 非 UTF-8 文件名在 ZIP 中的转义形式：`.nonutf8-` + 原始字节的小写十六进制。
+/// 打包时会跳过 dotfile，真实条目不会以该前缀开头；解压时按此还原原始字节。
+const NON_UTF8_NAME_PREFIX: &str = ".nonutf8-";
+
+/// skills.zip 内嵌的逐文件清单条目名。
+///
+/// 以 `/` 开头的绝对路径不会被 `enclosed_name` 接受：已发布的旧版本解压时会跳过它，
+/// 不会把清单当作普通文件写进 skills 目录；本版本按名称单独读取。
+pub const SKILLS_MANIFEST_ENTRY: &str = "/.skills-manifest.json";
+
+/// skills.zip 的逐文件清单，打包时随遍历生成，供解压校验与 UI 展示。
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillsManifest {
+    pub skill_count: usize,
+    pub file_count: usize,
+    pub total_bytes: u64,
+    pub files: Vec<SkillsManifestFile>,
+    /// 打包时被跳过的条目提示（不写入 ZIP），供同步摘要展示
+    #[serde(skip)]
+    pub warnings: Vec<String>,
+    /// 内容去重索引（SHA256 → 首个存入 ZIP 的条目名），未开启去重时为 None
+    #[serde(skip)]
+    dedup_index: Option<HashMap<String, String>>,
+}
+
+/// 遍历 skills 目录时按打包规则跳过的条目计数，随递归传递
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SkippedEntries {
+    pub dotfiles: usize,
+    pub outside_root_symlinks: usize,
+    pub revisited_dirs: usize,
+}
+
+impl SkippedEntries {
+    /// 每类被跳过的条目汇总为一条提示，没有跳过时为空
+    pub fn warnings(&self) -> Vec<String> {
+        [
+            (self.dotfiles, "dotfiles"),
+            (self.outside_root_symlinks, "symlinks outside skills root"),
+            (
+                self.revisited_dirs,
+                "already visited directories (symlink loops)",
+            ),
+        ]
+        .into_iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, what)| format!("Skills archive: skipped {count} {what}"))
+        .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillsManifestFile {
+    /// ZIP 条目名（非 UTF-8 片段已转义）
+    pub path: String,
+    pub sha256: String,
+    pub size: u64,
+    /// 去重打包时内容与该条目相同，ZIP 中不单独存放，解压时从该条目复制还原
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duplicate_of: Option<String>,
 }
 
-fn io_context_localized(
-    _key: &'static str,
-    zh: impl Into<String>,
-    en: impl Into<String>,
-    source: std::io::Error,
-) -> AppError {
-    let zh_msg = zh.into();
-    let en_msg = en.into();
-    AppError::IoContext {
-        context: format!("{zh_msg} ({en_msg})"),
-        source,
+impl SkillsManifest {
+    fn record_dir(&mut self, rel_str: &str) {
+        if !rel_str.contains('/') {
+            self.skill_count += 1;
+        }
+    }
+
+    /// 记录一个文件，返回其内容是否需要写入 ZIP（去重命中已记录的相同内容时为 false）
+    fn record_file(&mut self, rel_str: &str, bytes: &[u8]) -> bool {
+        let sha256 = format!("{:x}", Sha256::digest(bytes));
+        let duplicate_of =
+            self.dedup_index
+                .as_mut()
+                .and_then(|index| match index.entry(sha256.clone()) {
+                    Entry::Occupied(stored) => Some(stored.get().clone()),
+                    Entry::Vacant(slot) => {
+                        slot.insert(rel_str.to_string());
+                        None
+                    }
+                });
+        let store = duplicate_of.is_none();
+        self.file_count += 1;
+        self.total_bytes += bytes.len() as u64;
+        self.files.push(SkillsManifestFile {
+            path: rel_str.to_string(),
+            sha256,
+            size: bytes.len() as u64,
+            duplicate_of,
+        });
+        store
+    }
+
+    /// 以引用方式记录、未写入 ZIP 的重复文件数
+    pub fn duplicate_count(&self) -> usize {
+        self.files
+            .iter()
+            .filter(|file| file.duplicate_of.is_some())
+            .count()
+    }
+
+    /// 形如 "3 skills, 42 files, 8.1 MB" 的概要
+    pub fn describe(&self) -> String {
+        format!(
+            "{} skills, {} files, {}",
+            self.skill_count,
+            self.file_count,
+            size_en(self.total_bytes)
+        )
     }
 }
 
+fn localized(key: &'static str, zh: impl Into<String>, en: impl Into<String>) -> AppError {
+    AppError::localized(key, zh, en)
+}
+
 // ---------------------------------------------------------------------------
 // Skills 备份 / 回滚
 // ---------------------------------------------------------------------------
@@ -43,19 +152,18 @@ pub struct SkillsBackup {
 }
 
 impl SkillsBackup {
-    pub fn backup_current_skills() -> Result<Self, AppError> {
+    /// 把当前 skills 目录复制到临时目录，复制每个文件后回调 `(已复制, 总数)`。
+    pub fn backup_current_skills_with_progress(
+        progress: &mut dyn FnMut(usize, usize),
+    ) -> Result<Self, AppError> {
         let ssot = SkillService::get_ssot_dir()?;
-        let tmp = tempdir().map_err(|e| {
-            io_context_localized(
-                "webdav.sync.skills_backup_tmpdir_failed",
-                "创建 skills 备份临时目录失败",
-                "Failed to create temporary directory for skills backup",
-                e,
-            )
-        })?;
+        let tmp = create_temp_dir(
+            "创建 skills 备份临时目录失败",
+            "Failed to create temporary directory for skills backup",
+        )?;
         let backup_path = tmp.path().join("skills-backup");
         if ssot.exists() {
-            copy_dir_recursive(&ssot, &backup_path)?;
+            copy_dir_recursive_with_progress(&ssot, &backup_path, progress)?;
         }
         Ok(Self {
             _tmp: tmp,
@@ -64,13 +172,17 @@ impl SkillsBackup {
         })
     }
 
-    pub fn restore(self) -> Result<(), AppError> {
+    /// 用备份替换 skills 目录，复制每个文件后回调 `(已复制, 总数)`。
+    pub fn restore_with_progress(
+        self,
+        progress: &mut dyn FnMut(usize, usize),
+    ) -> Result<(), AppError> {
         if self.ssot_dir.exists() {
             fs::remove_dir_all(&self.ssot_dir).map_err(|e| AppError::io(&self.ssot_dir, e))?;
         }
         if self.backup_path.exists() {
             fs::create_dir_all(&self.ssot_dir).map_err(|e| AppError::io(&self.ssot_dir, e))?;
-            copy_dir_recursive(&self.backup_path, &self.ssot_dir)?;
+            copy_dir_recursive_with_progress(&self.backup_path, &self.ssot_dir, progress)?;
         }
         Ok(())
     }
@@ -80,18 +192,33 @@ impl SkillsBackup {
 // ZIP 打包
 // ---------------------------------------------------------------------------
 
-pub fn zip_skills_ssot(dest_path: &Path) -> Result<(), AppError> {
-    let source = SkillService::get_ssot_dir()?;
+/// 打包指定 skills 目录，并在 ZIP 末尾写入逐文件清单。
+///
+/// `dedup` 开启时相同内容的文件只存一份，其余在清单中记为 `duplicate_of` 引用。
+/// 旧版本只解压实际存放的条目，会静默丢失这些文件，因此上传时 manifest 须使用
+/// 旧版本会拒绝的协议版本。
+/// `compression` 决定各条目的压缩算法，解压时按条目自身记录的算法处理。
+pub fn zip_skills_dir(
+    source: &Path,
+    dest_path: &Path,
+    dedup: bool,
+    compression: CompressionAlgo,
+) -> Result<SkillsManifest, AppError> {
     if let Some(parent) = dest_path.parent() {
         fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
     }
 
     let file = fs::File::create(dest_path).map_err(|e| AppError::io(dest_path, e))?;
     let mut writer = zip::ZipWriter::new(file);
-    let options = zip_file_options();
+    let options = zip_file_options_for(compression);
+    let mut manifest = SkillsManifest::default();
+    if dedup {
+        manifest.dedup_index = Some(HashMap::new());
+    }
+    let mut skipped = SkippedEntries::default();
 
     if source.exists() {
-        let canonical_root = fs::canonicalize(&source).unwrap_or_else(|_| source.clone());
+        let canonical_root = fs::canonicalize(source).unwrap_or_else(|_| source.to_path_buf());
         let mut visited = HashSet::new();
         mark_visited_dir(&canonical_root, &mut visited)?;
         zip_dir_recursive(
@@ -100,8 +227,25 @@ pub fn zip_skills_ssot(dest_path: &Path) -> Result<(), AppError> {
             &mut writer,
             options,
             &mut visited,
+            &mut manifest,
+            &mut skipped,
         )?;
     }
+    manifest.warnings = skipped.warnings();
+    manifest.dedup_index = None;
+
+    let manifest_json =
+        serde_json::to_vec_pretty(&manifest).map_err(|e| AppError::JsonSerialize { source: e })?;
+    writer
+        .start_file(SKILLS_MANIFEST_ENTRY, options)
+        .and_then(|()| writer.write_all(&manifest_json).map_err(Into::into))
+        .map_err(|e| {
+            localized(
+                "webdav.sync.skills_manifest_write_failed",
+                format!("写入 skills 清单失败: {e}"),
+                format!("Failed to write skills manifest: {e}"),
+            )
+        })?;
 
     writer.finish().map_err(|e| {
         localized(
@@ -110,15 +254,94 @@ pub fn zip_skills_ssot(dest_path: &Path) -> Result<(), AppError> {
             format!("Failed to write skills.zip: {e}"),
         )
     })?;
-    Ok(())
+    Ok(manifest)
 }
 
 pub fn zip_file_options() -> SimpleFileOptions {
+    zip_file_options_for(CompressionAlgo::default())
+}
+
+pub fn zip_file_options_for(compression: CompressionAlgo) -> SimpleFileOptions {
+    let method = match compression {
+        CompressionAlgo::Deflate => zip::CompressionMethod::Deflated,
+        CompressionAlgo::Zstd => zip::CompressionMethod::Zstd,
+        CompressionAlgo::Stored => zip::CompressionMethod::Stored,
+    };
     SimpleFileOptions::default()
-        .compression_method(zip::CompressionMethod::Deflated)
+        .compression_method(method)
         .last_modified_time(DateTime::default())
 }
 
+/// 将相对路径转换为 ZIP 条目名，逐段转义非 UTF-8 的文件名。
+fn zip_entry_name(rel: &Path) -> String {
+    rel.components()
+        .map(|component| encode_name_component(component.as_os_str()))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(unix)]
+fn encode_name_component(name: &OsStr) -> String {
+    use std::os::unix::ffi::OsStrExt;
+
+    match name.to_str() {
+        Some(name) => name.to_string(),
+        None => {
+            let hex: String = name.as_bytes().iter().map(|b| format!("{b:02x}")).collect();
+            format!("{NON_UTF8_NAME_PREFIX}{hex}")
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn encode_name_component(name: &OsStr) -> String {
+    if name.to_str().is_none() {
+        log::warn!(
+            "[WebDAV] Skills file name is not valid Unicode and will be stored lossily: {}",
+            name.to_string_lossy()
+        );
+    }
+    name.to_string_lossy().into_owned()
+}
+
+/// `zip_entry_name` 的逆操作：还原被转义的非 UTF-8 文件名。
+fn decode_zip_path(path: &Path) -> PathBuf {
+    path.components()
+        .map(|component| decode_name_component(component.as_os_str()))
+        .collect()
+}
+
+#[cfg(unix)]
+fn decode_name_component(name: &OsStr) -> OsString {
+    use std::os::unix::ffi::OsStringExt;
+
+    name.to_str()
+        .and_then(|name| name.strip_prefix(NON_UTF8_NAME_PREFIX))
+        .and_then(decode_hex)
+        // 还原结果必须仍是单个普通路径段，防止构造出 `..` 或 `/` 逃逸解压目录
+        .filter(|bytes| {
+            bytes != b"." && bytes != b".." && !bytes.iter().any(|b| *b == b'/' || *b == 0)
+        })
+        .map(OsString::from_vec)
+        .unwrap_or_else(|| name.to_os_string())
+}
+
+#[cfg(not(unix))]
+fn decode_name_component(name: &OsStr) -> OsString {
+    name.to_os_string()
+}
+
+#[cfg(unix)]
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.is_empty() || hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
 /// 记录已访问目录的 canonical path，返回 true 表示首次访问。
 fn mark_visited_dir(path: &Path, visited: &mut HashSet<PathBuf>) -> Result<bool, AppError> {
     let canonical = fs::canonicalize(path).map_err(|e| AppError::io(path, e))?;
@@ -131,6 +354,64 @@ pub fn zip_dir_recursive(
     writer: &mut zip::ZipWriter<fs::File>,
     options: SimpleFileOptions,
     visited: &mut HashSet<PathBuf>,
+    manifest: &mut SkillsManifest,
+    skipped: &mut SkippedEntries,
+) -> Result<(), AppError> {
+    walk_skills_tree(
+        root,
+        current,
+        visited,
+        skipped,
+        &mut |rel_str, real_path, is_dir| {
+            if is_dir {
+                // 目录条目总是显式写入（不依赖子条目推断），空目录也能在解压时还原
+                manifest.record_dir(rel_str);
+                writer
+                    .add_directory(format!("{rel_str}/"), options)
+                    .map_err(|e| {
+                        localized(
+                            "webdav.sync.zip_add_directory_failed",
+                            format!("写入 ZIP 目录失败: {e}"),
+                            format!("Failed to write ZIP directory entry: {e}"),
+                        )
+                    })?;
+                return Ok(());
+            }
+
+            let mut f = fs::File::open(real_path).map_err(|e| AppError::io(real_path, e))?;
+            let mut buf = Vec::new();
+            f.read_to_end(&mut buf)
+                .map_err(|e| AppError::io(real_path, e))?;
+            if !manifest.record_file(rel_str, &buf) {
+                return Ok(());
+            }
+            writer.start_file(rel_str, options).map_err(|e| {
+                localized(
+                    "webdav.sync.zip_start_file_failed",
+                    format!("写入 ZIP 文件头失败: {e}"),
+                    format!("Failed to start ZIP file entry: {e}"),
+                )
+            })?;
+            writer.write_all(&buf).map_err(|e| {
+                localized(
+                    "webdav.sync.zip_write_file_failed",
+                    format!("写入 ZIP 文件内容失败: {e}"),
+                    format!("Failed to write ZIP file content: {e}"),
+                )
+            })
+        },
+    )
+}
+
+/// 按打包规则遍历 skills 目录：跳过 dotfile 与指向 root 之外的符号链接，并检测链接循环，
+/// 跳过的条目计入 `skipped`。
+/// 回调参数为 ZIP 条目名（目录不带尾部 `/`）、真实路径以及是否为目录，按条目名排序调用。
+fn walk_skills_tree(
+    root: &Path,
+    current: &Path,
+    visited: &mut HashSet<PathBuf>,
+    skipped: &mut SkippedEntries,
+    visit: &mut dyn FnMut(&str, &Path, bool) -> Result<(), AppError>,
 ) -> Result<(), AppError> {
     let mut entries = fs::read_dir(current)
         .map_err(|e| AppError::io(current, e))?
@@ -145,6 +426,7 @@ pub fn zip_dir_recursive(
 
         // 跳过 dotfiles
         if name_str.starts_with('.') {
+            skipped.dotfiles += 1;
             continue;
         }
 
@@ -156,6 +438,7 @@ pub fn zip_dir_recursive(
                     "[WebDAV] Skipping symlink outside skills root: {}",
                     path.display()
                 );
+                skipped.outside_root_symlinks += 1;
                 continue;
             }
             Err(_) => path.clone(),
@@ -171,7 +454,7 @@ pub fn zip_dir_recursive(
                     format!("Failed to build relative ZIP path: {e}"),
                 )
             })?;
-        let rel_str = rel.to_string_lossy().replace('\\', "/");
+        let rel_str = zip_entry_name(rel);
 
         if real_path.is_dir() {
             // 跳过已访问的目录（符号链接循环检测）
@@ -180,60 +463,172 @@ pub fn zip_dir_recursive(
                     "[WebDAV] Skipping already visited directory: {}",
                     real_path.display()
                 );
+                skipped.revisited_dirs += 1;
                 continue;
             }
-            writer
-                .add_directory(format!("{rel_str}/"), options)
-                .map_err(|e| {
-                    localized(
-                        "webdav.sync.zip_add_directory_failed",
-                        format!("写入 ZIP 目录失败: {e}"),
-                        format!("Failed to write ZIP directory entry: {e}"),
-                    )
-                })?;
-            zip_dir_recursive(root, &real_path, writer, options, visited)?;
+            visit(&rel_str, &real_path, true)?;
+            walk_skills_tree(root, &real_path, visited, skipped, visit)?;
         } else {
-            writer.start_file(&rel_str, options).map_err(|e| {
-                localized(
-                    "webdav.sync.zip_start_file_failed",
-                    format!("写入 ZIP 文件头失败: {e}"),
-                    format!("Failed to start ZIP file entry: {e}"),
-                )
-            })?;
-            let mut f = fs::File::open(&real_path).map_err(|e| AppError::io(&real_path, e))?;
-            let mut buf = Vec::new();
-            f.read_to_end(&mut buf)
-                .map_err(|e| AppError::io(&real_path, e))?;
-            writer.write_all(&buf).map_err(|e| {
-                localized(
-                    "webdav.sync.zip_write_file_failed",
-                    format!("写入 ZIP 文件内容失败: {e}"),
-                    format!("Failed to write ZIP file content: {e}"),
-                )
-            })?;
+            visit(&rel_str, &real_path, false)?;
         }
     }
     Ok(())
 }
 
 // ---------------------------------------------------------------------------
-// ZIP 解压 + 恢复
+// 逐文件读取 / 恢复（Tree 存储模式）
 // ---------------------------------------------------------------------------
 
-pub fn restore_skills_zip(raw: &[u8]) -> Result<(), AppError> {
-    let tmp = tempdir().map_err(|e| {
-        io_context_localized(
-            "webdav.sync.skills_extract_tmpdir_failed",
-            "创建 skills 解压临时目录失败",
-            "Failed to create temporary directory for skills extraction",
-            e,
+/// Tree 存储模式下的 skills 文件集合：`(条目名, 内容)`，条目名与 skills.zip 中一致
+pub type SkillsFiles = Vec<(String, Vec<u8>)>;
+
+pub fn read_skills_ssot_files() -> Result<(SkillsManifest, SkillsFiles), AppError> {
+    read_skills_files(&SkillService::get_ssot_dir()?)
+}
+
+/// 按打包规则读取 skills 目录下的所有文件，返回逐文件清单与文件内容。
+/// 只有文件会被上传，空目录不会保留。
+pub fn read_skills_files(source: &Path) -> Result<(SkillsManifest, SkillsFiles), AppError> {
+    let mut manifest = SkillsManifest::default();
+    let mut files = Vec::new();
+    let mut skipped = SkippedEntries::default();
+    if source.exists() {
+        let root = fs::canonicalize(source).unwrap_or_else(|_| source.to_path_buf());
+        let mut visited = HashSet::new();
+        mark_visited_dir(&root, &mut visited)?;
+        walk_skills_tree(
+            &root,
+            &root,
+            &mut visited,
+            &mut skipped,
+            &mut |rel_str, real_path, is_dir| {
+                if is_dir {
+                    manifest.record_dir(rel_str);
+                    return Ok(());
+                }
+                let bytes = fs::read(real_path).map_err(|e| AppError::io(real_path, e))?;
+                manifest.record_file(rel_str, &bytes);
+                files.push((rel_str.to_string(), bytes));
+                Ok(())
+            },
+        )?;
+    }
+    manifest.warnings = skipped.warnings();
+    Ok((manifest, files))
+}
+
+/// 把逐文件下载的内容组装为内存中的 skills.zip 再恢复，复用 ZIP 解压的路径校验、大小限制与原子替换。
+pub fn restore_skills_files(files: &[(String, Vec<u8>)]) -> Result<(), AppError> {
+    let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = zip_file_options();
+    for (path, bytes) in files {
+        writer
+            .start_file(path.as_str(), options)
+            .and_then(|()| writer.write_all(bytes).map_err(Into::into))
+            .map_err(|e| {
+                localized(
+                    "webdav.sync.skills_tree_assemble_failed",
+                    format!("组装 skills 文件失败: {path}: {e}"),
+                    format!("Failed to assemble skills file {path}: {e}"),
+                )
+            })?;
+    }
+    let mut cursor = writer.finish().map_err(|e| {
+        localized(
+            "webdav.sync.skills_tree_assemble_failed",
+            format!("组装 skills 文件失败: {e}"),
+            format!("Failed to assemble skills files: {e}"),
         )
     })?;
-    let zip_path = tmp.path().join("skills.zip");
-    crate::config::atomic_write(&zip_path, raw)?;
+    cursor.set_position(0);
+    restore_skills_zip(cursor)
+}
+
+// ---------------------------------------------------------------------------
+// 内容哈希（不打包）
+// ---------------------------------------------------------------------------
+
+/// 计算 skills 目录的内容哈希：按打包规则遍历，对每个条目名及文件内容的 SHA256 排序后整体哈希。
+///
+/// 与 skills.zip 的 SHA256 不同，只用于判断本地 skills 是否变化，无需实际打包。
+pub fn hash_skills_tree(source: &Path) -> Result<String, AppError> {
+    let mut lines = Vec::new();
+    if source.exists() {
+        let root = fs::canonicalize(source).unwrap_or_else(|_| source.to_path_buf());
+        let mut visited = HashSet::new();
+        mark_visited_dir(&root, &mut visited)?;
+        walk_skills_tree(
+            &root,
+            &root,
+            &mut visited,
+            &mut SkippedEntries::default(),
+            &mut |rel_str, real_path, is_dir| {
+                if is_dir {
+                    lines.push(format!("{rel_str}/"));
+                } else {
+                    let mut file =
+                        fs::File::open(real_path).map_err(|e| AppError::io(real_path, e))?;
+                    let mut hasher = Sha256::new();
+                    std::io::copy(&mut file, &mut hasher)
+                        .map_err(|e| AppError::io(real_path, e))?;
+                    lines.push(format!("{rel_str}:{:x}", hasher.finalize()));
+                }
+                Ok(())
+            },
+        )?;
+    }
+    lines.sort();
+
+    let mut hasher = Sha256::new();
+    for line in &lines {
+        hasher.update(line.as_bytes());
+        hasher.update(b"\n");
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+// ---------------------------------------------------------------------------
+// ZIP 解压 + 恢复
+// ---------------------------------------------------------------------------
+
+/// 从内存 Cursor 或已下载的临时文件中解压 skills.zip 并替换 SSOT 目录。
+pub fn restore_skills_zip(reader: impl Read + Seek) -> Result<(), AppError> {
+    let tmp = create_temp_dir(
+        "创建 skills 解压临时目录失败",
+        "Failed to create temporary directory for skills extraction",
+    )?;
+
+    let extracted = tmp.path().join("skills-extracted");
+    fs::create_dir_all(&extracted).map_err(|e| AppError::io(&extracted, e))?;
+    extract_skills_zip(reader, &extracted)?;
+
+    // 先处理上次中断遗留的 .bak，它可能是唯一完整的副本，不能直接删除
+    SkillService::recover_interrupted_restore()?;
+    let ssot = SkillService::get_ssot_dir()?;
+    let bak = SkillService::ssot_backup_dir(&ssot);
+    let marker = SkillService::ssot_restore_marker(&ssot);
 
-    let file = fs::File::open(&zip_path).map_err(|e| AppError::io(&zip_path, e))?;
-    let mut archive = zip::ZipArchive::new(file).map_err(|e| {
+    // 原子替换：先 rename 到 .bak，再 copy，失败则回滚
+    fs::rename(&ssot, &bak).map_err(|e| AppError::io(&ssot, e))?;
+
+    let copied = copy_dir_recursive(&extracted, &ssot)
+        .and_then(|()| fs::write(&marker, b"").map_err(|e| AppError::io(&marker, e)));
+    if let Err(e) = copied {
+        let _ = fs::remove_dir_all(&ssot);
+        let _ = fs::rename(&bak, &ssot);
+        return Err(e);
+    }
+
+    // 完成标记已落盘，此后中断时启动恢复会保留新内容并清理 .bak
+    if fs::remove_dir_all(&bak).is_ok() {
+        let _ = fs::remove_file(&marker);
+    }
+    Ok(())
+}
+
+/// 打开 skills.zip 并检查条目数上限
+fn open_skills_zip<R: Read + Seek>(reader: R) -> Result<zip::ZipArchive<R>, AppError> {
+    let archive = zip::ZipArchive::new(reader).map_err(|e| {
         localized(
             "webdav.sync.skills_zip_parse_failed",
             format!("解析 skills.zip 失败: {e}"),
@@ -254,23 +649,52 @@ pub fn restore_skills_zip(raw: &[u8]) -> Result<(), AppError> {
             ),
         ));
     }
+    Ok(archive)
+}
 
-    let extracted = tmp.path().join("skills-extracted");
-    fs::create_dir_all(&extracted).map_err(|e| AppError::io(&extracted, e))?;
+fn zip_entry_read_error(e: zip::result::ZipError) -> AppError {
+    localized(
+        "webdav.sync.skills_zip_entry_read_failed",
+        format!("读取 ZIP 项失败: {e}"),
+        format!("Failed to read ZIP entry: {e}"),
+    )
+}
+
+fn parse_skills_manifest(json: &[u8]) -> Result<SkillsManifest, AppError> {
+    serde_json::from_slice(json).map_err(|e| {
+        localized(
+            "webdav.sync.skills_manifest_parse_failed",
+            format!("解析 skills 清单失败: {e}"),
+            format!("Failed to parse skills manifest: {e}"),
+        )
+    })
+}
+
+/// 解压 skills.zip 到指定目录，带条目数与总大小限制。
+fn extract_skills_zip(reader: impl Read + Seek, extracted: &Path) -> Result<(), AppError> {
+    let mut archive = open_skills_zip(reader)?;
 
     let mut total_bytes: u64 = 0;
+    let mut manifest_json: Option<Vec<u8>> = None;
+    let mut extracted_files = BTreeSet::new();
     for idx in 0..archive.len() {
-        let mut entry = archive.by_index(idx).map_err(|e| {
-            localized(
-                "webdav.sync.skills_zip_entry_read_failed",
-                format!("读取 ZIP 项失败: {e}"),
-                format!("Failed to read ZIP entry: {e}"),
-            )
-        })?;
+        let mut entry = archive.by_index(idx).map_err(zip_entry_read_error)?;
+        if entry.name() == SKILLS_MANIFEST_ENTRY {
+            let mut buf = Vec::new();
+            copy_entry_with_total_limit(
+                &mut entry,
+                &mut buf,
+                &mut total_bytes,
+                MAX_ZIP_EXTRACT_BYTES,
+                Path::new(SKILLS_MANIFEST_ENTRY),
+            )?;
+            manifest_json = Some(buf);
+            continue;
+        }
         let Some(safe_name) = entry.enclosed_name() else {
             continue;
         };
-        let out_path = extracted.join(safe_name);
+        let out_path = extracted.join(decode_zip_path(&safe_name));
         if entry.is_dir() {
             fs::create_dir_all(&out_path).map_err(|e| AppError::io(&out_path, e))?;
             continue;
@@ -286,28 +710,163 @@ pub fn restore_skills_zip(raw: &[u8]) -> Result<(), AppError> {
             MAX_ZIP_EXTRACT_BYTES,
             &out_path,
         )?;
+        extracted_files.insert(entry.name().to_string());
     }
 
-    let ssot = SkillService::get_ssot_dir()?;
-    let bak = ssot.with_extension("bak");
+    // 旧版本打包的 skills.zip 没有清单，跳过校验
+    if let Some(json) = manifest_json {
+        let manifest = parse_skills_manifest(&json)?;
+        restore_duplicate_files(&manifest, extracted, &mut extracted_files, &mut total_bytes)?;
+        verify_skills_manifest(&manifest, extracted, &extracted_files)?;
+    }
+    Ok(())
+}
 
-    // 原子替换：先 rename 到 .bak，再 copy，失败则回滚
-    if ssot.exists() {
-        if bak.exists() {
-            let _ = fs::remove_dir_all(&bak);
+/// 不解压地读取 skills.zip 中各文件的 SHA256，键为 ZIP 条目名。
+///
+/// 优先使用内嵌的逐文件清单（去重引用的文件同样列出）；旧版本打包的 ZIP
+/// 没有清单时逐个条目计算。
+pub fn skills_zip_file_hashes(
+    reader: impl Read + Seek,
+) -> Result<BTreeMap<String, String>, AppError> {
+    let mut archive = open_skills_zip(reader)?;
+    let mut total_bytes: u64 = 0;
+
+    if let Ok(mut entry) = archive.by_name(SKILLS_MANIFEST_ENTRY) {
+        let mut json = Vec::new();
+        copy_entry_with_total_limit(
+            &mut entry,
+            &mut json,
+            &mut total_bytes,
+            MAX_ZIP_EXTRACT_BYTES,
+            Path::new(SKILLS_MANIFEST_ENTRY),
+        )?;
+        let manifest = parse_skills_manifest(&json)?;
+        return Ok(manifest
+            .files
+            .into_iter()
+            .map(|file| (file.path, file.sha256))
+            .collect());
+    }
+
+    let mut hashes = BTreeMap::new();
+    for idx in 0..archive.len() {
+        let mut entry = archive.by_index(idx).map_err(zip_entry_read_error)?;
+        let Some(safe_name) = entry.enclosed_name() else {
+            continue;
+        };
+        if entry.is_dir() {
+            continue;
         }
-        fs::rename(&ssot, &bak).map_err(|e| AppError::io(&ssot, e))?;
+        let mut hasher = Sha256::new();
+        copy_entry_with_total_limit(
+            &mut entry,
+            &mut hasher,
+            &mut total_bytes,
+            MAX_ZIP_EXTRACT_BYTES,
+            &safe_name,
+        )?;
+        hashes.insert(entry.name().to_string(), format!("{:x}", hasher.finalize()));
+    }
+    Ok(hashes)
+}
+
+fn manifest_mismatch(path: &str) -> AppError {
+    localized(
+        "webdav.sync.skills_manifest_mismatch",
+        format!("skills.zip 内容与清单不一致: {path}"),
+        format!("skills.zip contents do not match its manifest: {path}"),
+    )
+}
+
+/// 还原去重打包时以引用记录的文件：从 ZIP 中实际存放的同内容条目复制一份，
+/// 与普通条目共用条目数与总大小限制。
+fn restore_duplicate_files(
+    manifest: &SkillsManifest,
+    extracted: &Path,
+    extracted_files: &mut BTreeSet<String>,
+    total_bytes: &mut u64,
+) -> Result<(), AppError> {
+    let duplicates: Vec<_> = manifest
+        .files
+        .iter()
+        .filter_map(|file| Some((file, file.duplicate_of.as_deref()?)))
+        .collect();
+    if duplicates.is_empty() {
+        return Ok(());
+    }
+    let total_entries = extracted_files.len() + duplicates.len();
+    if total_entries > MAX_ZIP_ENTRIES {
+        return Err(localized(
+            "webdav.sync.skills_zip_too_many_entries",
+            format!("skills.zip 条目数过多（{total_entries}），上限 {MAX_ZIP_ENTRIES}"),
+            format!(
+                "skills.zip has too many entries ({total_entries}), limit is {MAX_ZIP_ENTRIES}"
+            ),
+        ));
     }
 
-    if let Err(e) = copy_dir_recursive(&extracted, &ssot) {
-        if bak.exists() {
-            let _ = fs::remove_dir_all(&ssot);
-            let _ = fs::rename(&bak, &ssot);
+    let stored = extracted_files.clone();
+    for (file, source) in duplicates {
+        if !stored.contains(source)
+            || extracted_files.contains(&file.path)
+            || !is_plain_relative_path(&file.path)
+        {
+            return Err(manifest_mismatch(&file.path));
         }
-        return Err(e);
+        let source_path = extracted.join(decode_zip_path(Path::new(source)));
+        let out_path = extracted.join(decode_zip_path(Path::new(&file.path)));
+        if let Some(parent) = out_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
+        }
+        let mut reader = fs::File::open(&source_path).map_err(|e| AppError::io(&source_path, e))?;
+        let mut out = fs::File::create(&out_path).map_err(|e| AppError::io(&out_path, e))?;
+        copy_entry_with_total_limit(
+            &mut reader,
+            &mut out,
+            total_bytes,
+            MAX_ZIP_EXTRACT_BYTES,
+            &out_path,
+        )?;
+        extracted_files.insert(file.path.clone());
+    }
+    Ok(())
+}
+
+/// 清单中的路径只能由普通片段组成，不允许绝对路径、`.` 或 `..`
+fn is_plain_relative_path(path: &str) -> bool {
+    !path.is_empty()
+        && Path::new(path)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+}
+
+/// 校验解压结果与清单一致：文件集合相同且每个文件的 SHA256 匹配。
+fn verify_skills_manifest(
+    manifest: &SkillsManifest,
+    extracted: &Path,
+    extracted_files: &BTreeSet<String>,
+) -> Result<(), AppError> {
+    let listed: BTreeSet<&str> = manifest.files.iter().map(|f| f.path.as_str()).collect();
+    if let Some(extra) = extracted_files
+        .iter()
+        .find(|name| !listed.contains(name.as_str()))
+    {
+        return Err(manifest_mismatch(extra));
     }
 
-    let _ = fs::remove_dir_all(&bak);
+    for file in &manifest.files {
+        if !extracted_files.contains(&file.path) {
+            return Err(manifest_mismatch(&file.path));
+        }
+        let path = extracted.join(decode_zip_path(Path::new(&file.path)));
+        let mut reader = fs::File::open(&path).map_err(|e| AppError::io(&path, e))?;
+        let mut hasher = Sha256::new();
+        std::io::copy(&mut reader, &mut hasher).map_err(|e| AppError::io(&path, e))?;
+        if format!("{:x}", hasher.finalize()) != file.sha256 {
+            return Err(manifest_mismatch(&file.path));
+        }
+    }
     Ok(())
 }
 
@@ -330,11 +889,16 @@ fn copy_entry_with_total_limit(
         }
 
         if total_bytes.saturating_add(n as u64) > max_total_bytes {
-            let max_mb = max_total_bytes / 1024 / 1024;
             return Err(localized(
                 "webdav.sync.skills_zip_too_large",
-                format!("skills.zip 解压后体积超过上限（{max_mb} MB）"),
-                format!("skills.zip extracted size exceeds limit ({max_mb} MB)"),
+                format!(
+                    "skills.zip 解压后体积超过上限（{}）",
+                    size_zh(max_total_bytes)
+                ),
+                format!(
+                    "skills.zip extracted size exceeds limit ({})",
+                    size_en(max_total_bytes)
+                ),
             ));
         }
 
@@ -352,14 +916,52 @@ fn copy_entry_with_total_limit(
 // ---------------------------------------------------------------------------
 
 pub fn copy_dir_recursive(src: &Path, dest: &Path) -> Result<(), AppError> {
-    let mut visited = HashSet::new();
-    copy_dir_recursive_inner(src, dest, &mut visited)
+    copy_dir_recursive_with_progress(src, dest, &mut |_, _| {})
+}
+
+/// 先统计待复制文件总数，再在每个文件复制完成后回调 `(已复制, 总数)`。
+pub fn copy_dir_recursive_with_progress(
+    src: &Path,
+    dest: &Path,
+    progress: &mut dyn FnMut(usize, usize),
+) -> Result<(), AppError> {
+    let total = count_files_recursive(src, &mut HashSet::new())?;
+    let mut copy = CopyProgress {
+        copied: 0,
+        total,
+        callback: progress,
+    };
+    copy_dir_recursive_inner(src, dest, &mut HashSet::new(), &mut copy)
+}
+
+struct CopyProgress<'a> {
+    copied: usize,
+    total: usize,
+    callback: &'a mut dyn FnMut(usize, usize),
+}
+
+/// 与 `copy_dir_recursive_inner` 使用相同的遍历规则（跳过重复访问的目录），保证总数一致。
+fn count_files_recursive(src: &Path, visited: &mut HashSet<PathBuf>) -> Result<usize, AppError> {
+    if !src.exists() || !mark_visited_dir(src, visited)? {
+        return Ok(0);
+    }
+    let mut count = 0;
+    for entry in fs::read_dir(src).map_err(|e| AppError::io(src, e))? {
+        let path = entry.map_err(|e| AppError::io(src, e))?.path();
+        if path.is_dir() {
+            count += count_files_recursive(&path, visited)?;
+        } else {
+            count += 1;
+        }
+    }
+    Ok(count)
 }
 
 fn copy_dir_recursive_inner(
     src: &Path,
     dest: &Path,
     visited: &mut HashSet<PathBuf>,
+    progress: &mut CopyProgress<'_>,
 ) -> Result<(), AppError> {
     if !src.exists() {
         return Ok(());
@@ -377,9 +979,13 @@ fn copy_dir_recursive_inner(
         let path = entry.path();
         let dest_path = dest.join(entry.file_name());
         if path.is_dir() {
-            copy_dir_recursive_inner(&path, &dest_path, visited)?;
+            copy_dir_recursive_inner(&path, &dest_path, visited, progress)?;
         } else {
             fs::copy(&path, &dest_path).map_err(|e| AppError::io(&dest_path, e))?;
+            progress.copied += 1;
+            // 遍历期间目录可能新增文件，总数至少不小于已复制数
+            progress.total = progress.total.max(progress.copied);
+            (progress.callback)(progress.copied, progress.total);
         }
     }
     Ok(())
@@ -415,6 +1021,8 @@ mod tests {
             &mut writer1,
             zip_file_options(),
             &mut visited1,
+            &mut SkillsManifest::default(),
+            &mut SkippedEntries::default(),
         )
         .expect("zip source #1");
         writer1.finish().expect("finish zip1");
@@ -431,6 +1039,8 @@ mod tests {
             &mut writer2,
             zip_file_options(),
             &mut visited2,
+            &mut SkillsManifest::default(),
+            &mut SkippedEntries::default(),
         )
         .expect("zip source #2");
         writer2.finish().expect("finish zip2");
@@ -440,6 +1050,358 @@ mod tests {
         assert_eq!(bytes1, bytes2, "zip output should be deterministic");
     }
 
+    #[test]
+    fn skills_manifest_matches_archive_contents() {
+        let tmp = tempdir().expect("tempdir");
+        let source = tmp.path().join("skills");
+        fs::create_dir_all(source.join("alpha").join("docs")).expect("create alpha");
+        fs::create_dir_all(source.join("beta")).expect("create beta");
+        fs::write(source.join("alpha").join("SKILL.md"), b"alpha skill").expect("write alpha");
+        fs::write(source.join("alpha").join("docs").join("notes.md"), b"notes")
+            .expect("write notes");
+        fs::write(source.join("beta").join("SKILL.md"), b"beta").expect("write beta");
+
+        let zip_path = tmp.path().join("skills.zip");
+        let manifest = zip_skills_dir(&source, &zip_path, false, CompressionAlgo::Deflate)
+            .expect("zip skills");
+        assert_eq!(manifest.skill_count, 2);
+        assert_eq!(manifest.file_count, 3);
+        assert_eq!(manifest.total_bytes, 20);
+        assert_eq!(manifest.describe(), "2 skills, 3 files, 20 B");
+
+        let mut archive =
+            zip::ZipArchive::new(fs::File::open(&zip_path).expect("open zip")).expect("parse zip");
+        let mut embedded = String::new();
+        archive
+            .by_name(SKILLS_MANIFEST_ENTRY)
+            .expect("manifest entry")
+            .read_to_string(&mut embedded)
+            .expect("read manifest");
+        let embedded: SkillsManifest = serde_json::from_str(&embedded).expect("parse manifest");
+        assert_eq!(embedded, manifest);
+
+        for file in &manifest.files {
+            let mut bytes = Vec::new();
+            archive
+                .by_name(&file.path)
+                .expect("listed entry")
+                .read_to_end(&mut bytes)
+                .expect("read entry");
+            assert_eq!(bytes.len() as u64, file.size);
+            assert_eq!(format!("{:x}", Sha256::digest(&bytes)), file.sha256);
+        }
+        let archived_files = archive
+            .file_names()
+            .filter(|name| !name.ends_with('/') && *name != SKILLS_MANIFEST_ENTRY)
+            .count();
+        assert_eq!(archived_files, manifest.files.len());
+
+        let extracted = tmp.path().join("extracted");
+        extract_skills_zip(fs::File::open(&zip_path).expect("open zip"), &extracted)
+            .expect("extract zip");
+        assert!(!extracted.join(".skills-manifest.json").exists());
+
+        // 旧版本不认识内嵌清单，解压时必须跳过它，而不是写进 skills 目录
+        let released = tmp.path().join("released");
+        extract_like_released_clients(&zip_path, &released);
+        assert!(!released.join(".skills-manifest.json").exists());
+        assert!(released.join("beta").join("SKILL.md").exists());
+    }
+
+    #[test]
+    fn every_compression_algo_round_trips_identical_contents() {
+        let tmp = tempdir().expect("tempdir");
+        let source = tmp.path().join("skills");
+        fs::create_dir_all(source.join("alpha").join("docs")).expect("create alpha");
+        fs::create_dir_all(source.join("beta").join("empty")).expect("create beta");
+        fs::write(
+            source.join("alpha").join("SKILL.md"),
+            "# Alpha\n".repeat(512),
+        )
+        .expect("write alpha");
+        fs::write(
+            source.join("alpha").join("docs").join("blob.bin"),
+            [0u8, 1, 2, 255],
+        )
+        .expect("write blob");
+        fs::write(source.join("beta").join("SKILL.md"), b"beta").expect("write beta");
+        let expected = hash_skills_tree(&source).expect("hash source");
+
+        for (algo, method) in [
+            (CompressionAlgo::Deflate, zip::CompressionMethod::Deflated),
+            (CompressionAlgo::Zstd, zip::CompressionMethod::Zstd),
+            (CompressionAlgo::Stored, zip::CompressionMethod::Stored),
+        ] {
+            let zip_path = tmp.path().join(format!("{algo:?}.zip"));
+            zip_skills_dir(&source, &zip_path, false, algo).expect("zip skills");
+
+            let mut archive = zip::ZipArchive::new(fs::File::open(&zip_path).expect("open zip"))
+                .expect("parse zip");
+            let entry = archive.by_name("alpha/SKILL.md").expect("skill entry");
+            assert_eq!(entry.compression(), method, "{algo:?}");
+            drop(entry);
+
+            let extracted = tmp.path().join(format!("{algo:?}-extracted"));
+            extract_skills_zip(fs::File::open(&zip_path).expect("open zip"), &extracted)
+                .expect("extract zip");
+            assert!(extracted.join("beta").join("empty").is_dir(), "{algo:?}");
+            assert_eq!(
+                hash_skills_tree(&extracted).expect("hash extracted"),
+                expected,
+                "{algo:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn empty_directories_survive_zip_and_restore_copy() {
+        let tmp = tempdir().expect("tempdir");
+        let source = tmp.path().join("skills");
+        fs::create_dir_all(source.join("placeholder")).expect("create placeholder");
+        fs::create_dir_all(source.join("demo").join("empty")).expect("create nested empty");
+        fs::write(source.join("demo").join("SKILL.md"), b"demo").expect("write skill");
+
+        let zip_path = tmp.path().join("skills.zip");
+        zip_skills_dir(&source, &zip_path, false, CompressionAlgo::Deflate).expect("zip skills");
+
+        let archive =
+            zip::ZipArchive::new(fs::File::open(&zip_path).expect("open zip")).expect("parse zip");
+        let names: Vec<_> = archive.file_names().collect();
+        assert!(names.contains(&"placeholder/"), "{names:?}");
+        assert!(names.contains(&"demo/empty/"), "{names:?}");
+
+        let extracted = tmp.path().join("extracted");
+        extract_skills_zip(fs::File::open(&zip_path).expect("open zip"), &extracted)
+            .expect("extract zip");
+        let restored = tmp.path().join("restored");
+        copy_dir_recursive(&extracted, &restored).expect("copy extracted tree");
+
+        assert!(restored.join("placeholder").is_dir());
+        assert!(restored.join("demo").join("empty").is_dir());
+        assert_eq!(
+            fs::read(restored.join("demo").join("SKILL.md")).expect("read skill"),
+            b"demo"
+        );
+    }
+
+    #[test]
+    fn dedup_stores_identical_files_once_and_restores_every_copy() {
+        let tmp = tempdir().expect("tempdir");
+        let source = tmp.path().join("skills");
+        let shared = vec![7u8; 4096];
+        for skill in ["alpha", "beta", "gamma"] {
+            fs::create_dir_all(source.join(skill).join("assets")).expect("create skill");
+            fs::write(source.join(skill).join("assets").join("logo.png"), &shared)
+                .expect("write shared asset");
+            fs::write(source.join(skill).join("SKILL.md"), skill).expect("write skill");
+        }
+
+        let zip_path = tmp.path().join("skills.zip");
+        let manifest =
+            zip_skills_dir(&source, &zip_path, true, CompressionAlgo::Deflate).expect("zip skills");
+        assert_eq!(manifest.file_count, 6);
+        assert_eq!(manifest.duplicate_count(), 2);
+        let beta_logo = manifest
+            .files
+            .iter()
+            .find(|file| file.path == "beta/assets/logo.png")
+            .expect("beta logo listed");
+        assert_eq!(
+            beta_logo.duplicate_of.as_deref(),
+            Some("alpha/assets/logo.png")
+        );
+
+        let archive =
+            zip::ZipArchive::new(fs::File::open(&zip_path).expect("open zip")).expect("parse zip");
+        let names: Vec<_> = archive.file_names().collect();
+        assert!(names.contains(&"alpha/assets/logo.png"), "{names:?}");
+        assert!(!names.contains(&"beta/assets/logo.png"), "{names:?}");
+        assert!(!names.contains(&"gamma/assets/logo.png"), "{names:?}");
+
+        let extracted = tmp.path().join("extracted");
+        extract_skills_zip(fs::File::open(&zip_path).expect("open zip"), &extracted)
+            .expect("extract zip");
+        for skill in ["alpha", "beta", "gamma"] {
+            assert_eq!(
+                fs::read(extracted.join(skill).join("assets").join("logo.png"))
+                    .expect("read restored asset"),
+                shared
+            );
+            assert_eq!(
+                fs::read(extracted.join(skill).join("SKILL.md")).expect("read skill"),
+                skill.as_bytes()
+            );
+        }
+    }
+
+    /// 发布版（去重与内嵌清单之前）解压 skills.zip 的方式：逐个解压 `enclosed_name` 合法的条目，不读清单
+    fn extract_like_released_clients(zip_path: &Path, dest: &Path) {
+        let mut archive =
+            zip::ZipArchive::new(fs::File::open(zip_path).expect("open zip")).expect("parse zip");
+        for idx in 0..archive.len() {
+            let mut entry = archive.by_index(idx).expect("read entry");
+            let Some(safe_name) = entry.enclosed_name() else {
+                continue;
+            };
+            let out_path = dest.join(safe_name);
+            if entry.is_dir() {
+                fs::create_dir_all(&out_path).expect("create dir");
+                continue;
+            }
+            fs::create_dir_all(out_path.parent().expect("parent")).expect("create parent");
+            let mut out = fs::File::create(&out_path).expect("create file");
+            std::io::copy(&mut entry, &mut out).expect("extract entry");
+        }
+    }
+
+    #[test]
+    fn released_clients_lose_deduplicated_files_silently() {
+        let tmp = tempdir().expect("tempdir");
+        let source = tmp.path().join("skills");
+        for skill in ["alpha", "beta"] {
+            fs::create_dir_all(source.join(skill)).expect("create skill");
+            fs::write(source.join(skill).join("logo.png"), b"shared").expect("write asset");
+        }
+
+        let zip_path = tmp.path().join("skills.zip");
+        zip_skills_dir(&source, &zip_path, true, CompressionAlgo::Deflate).expect("zip skills");
+        let extracted = tmp.path().join("extracted");
+        extract_like_released_clients(&zip_path, &extracted);
+
+        // 旧版本解压不报错却缺文件，所以去重上传必须让旧版本在读取 manifest 时就拒绝
+        assert!(extracted.join("alpha").join("logo.png").exists());
+        assert!(!extracted.join("beta").join("logo.png").exists());
+
+        let plain_zip = tmp.path().join("plain.zip");
+        zip_skills_dir(&source, &plain_zip, false, CompressionAlgo::Deflate).expect("zip skills");
+        let plain = tmp.path().join("plain");
+        extract_like_released_clients(&plain_zip, &plain);
+        assert!(plain.join("beta").join("logo.png").exists());
+    }
+
+    #[test]
+    fn dedup_reference_must_point_at_a_stored_entry() {
+        let tmp = tempdir().expect("tempdir");
+        let zip_path = tmp.path().join("skills.zip");
+        let manifest = SkillsManifest {
+            skill_count: 1,
+            file_count: 1,
+            total_bytes: 5,
+            files: vec![SkillsManifestFile {
+                path: "../escape.md".to_string(),
+                sha256: format!("{:x}", Sha256::digest(b"hello")),
+                size: 5,
+                duplicate_of: Some("demo/SKILL.md".to_string()),
+            }],
+            ..SkillsManifest::default()
+        };
+        let mut writer = zip::ZipWriter::new(fs::File::create(&zip_path).expect("create zip"));
+        writer
+            .start_file("demo/SKILL.md", zip_file_options())
+            .expect("start entry");
+        writer.write_all(b"hello").expect("write entry");
+        writer
+            .start_file(SKILLS_MANIFEST_ENTRY, zip_file_options())
+            .expect("start manifest");
+        writer
+            .write_all(&serde_json::to_vec(&manifest).expect("serialize manifest"))
+            .expect("write manifest");
+        writer.finish().expect("finish zip");
+
+        let extracted = tmp.path().join("extracted");
+        extract_skills_zip(fs::File::open(&zip_path).expect("open zip"), &extracted)
+            .expect_err("traversal in a duplicate reference should be rejected");
+        assert!(!tmp.path().join("escape.md").exists());
+    }
+
+    #[test]
+    fn zip_file_hashes_fall_back_to_entries_without_a_manifest() {
+        let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        writer
+            .start_file("demo/SKILL.md", zip_file_options())
+            .expect("start entry");
+        writer.write_all(b"hello").expect("write entry");
+        writer
+            .add_directory("demo/empty/", zip_file_options())
+            .expect("add directory");
+        let bytes = writer.finish().expect("finish zip").into_inner();
+
+        let hashes =
+            skills_zip_file_hashes(std::io::Cursor::new(bytes)).expect("hash legacy archive");
+        assert_eq!(
+            hashes,
+            BTreeMap::from([(
+                "demo/SKILL.md".to_string(),
+                format!("{:x}", Sha256::digest(b"hello"))
+            )])
+        );
+    }
+
+    #[test]
+    fn extract_rejects_archive_that_disagrees_with_manifest() {
+        let tmp = tempdir().expect("tempdir");
+        let zip_path = tmp.path().join("skills.zip");
+        let manifest = SkillsManifest {
+            skill_count: 1,
+            file_count: 1,
+            total_bytes: 5,
+            files: vec![SkillsManifestFile {
+                path: "demo/SKILL.md".to_string(),
+                sha256: format!("{:x}", Sha256::digest(b"hello")),
+                size: 5,
+                duplicate_of: None,
+            }],
+            warnings: Vec::new(),
+            dedup_index: None,
+        };
+
+        let mut writer = zip::ZipWriter::new(fs::File::create(&zip_path).expect("create zip"));
+        writer
+            .start_file("demo/SKILL.md", zip_file_options())
+            .expect("start entry");
+        writer.write_all(b"tampered").expect("write entry");
+        writer
+            .start_file(SKILLS_MANIFEST_ENTRY, zip_file_options())
+            .expect("start manifest");
+        writer
+            .write_all(&serde_json::to_vec(&manifest).expect("serialize manifest"))
+            .expect("write manifest");
+        writer.finish().expect("finish zip");
+
+        let err = extract_skills_zip(
+            fs::File::open(&zip_path).expect("open zip"),
+            &tmp.path().join("extracted"),
+        )
+        .expect_err("tampered archive should be rejected");
+        assert!(err.to_string().contains("demo/SKILL.md"), "{err}");
+    }
+
+    #[test]
+    fn copy_progress_reports_each_file_with_increasing_count() {
+        let tmp = tempdir().expect("create temp dir");
+        let source = tmp.path().join("skills");
+        fs::create_dir_all(source.join("a").join("deep")).expect("create nested dirs");
+        fs::create_dir_all(source.join("empty")).expect("create empty dir");
+        fs::write(source.join("root.md"), b"root").expect("write root");
+        fs::write(source.join("a").join("SKILL.md"), b"a").expect("write a");
+        fs::write(source.join("a").join("deep").join("x.txt"), b"x").expect("write x");
+
+        let mut calls = Vec::new();
+        copy_dir_recursive_with_progress(
+            &source,
+            &tmp.path().join("copy"),
+            &mut |copied, total| calls.push((copied, total)),
+        )
+        .expect("copy with progress");
+
+        assert_eq!(calls, vec![(1, 3), (2, 3), (3, 3)]);
+        assert_eq!(
+            fs::read(tmp.path().join("copy").join("a").join("deep").join("x.txt"))
+                .expect("read copied file"),
+            b"x"
+        );
+    }
+
     #[test]
     fn mark_visited_dir_tracks_canonical_duplicates() {
         let temp = tempdir().expect("tempdir");
@@ -473,4 +1435,123 @@ mod tests {
             "should not write when the first chunk exceeds limit"
         );
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_file_names_survive_zip_round_trip() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let tmp = tempdir().expect("tempdir");
+        let source = tmp.path().join("skills");
+        let raw_name = OsStr::from_bytes(b"caf\xe9.md");
+        fs::create_dir_all(source.join("demo")).expect("create source dirs");
+        fs::write(source.join("demo").join(raw_name), b"latin-1 name").expect("write file");
+
+        let zip_path = tmp.path().join("skills.zip");
+        let mut writer = zip::ZipWriter::new(fs::File::create(&zip_path).expect("create zip"));
+        let mut visited = HashSet::new();
+        mark_visited_dir(&source, &mut visited).expect("mark root");
+        zip_dir_recursive(
+            &source,
+            &source,
+            &mut writer,
+            zip_file_options(),
+            &mut visited,
+            &mut SkillsManifest::default(),
+            &mut SkippedEntries::default(),
+        )
+        .expect("zip source");
+        writer.finish().expect("finish zip");
+
+        let archive =
+            zip::ZipArchive::new(fs::File::open(&zip_path).expect("open zip")).expect("parse zip");
+        assert!(
+            archive
+                .file_names()
+                .any(|name| name == "demo/.nonutf8-636166e92e6d64"),
+            "non-UTF-8 name should be stored escaped: {:?}",
+            archive.file_names().collect::<Vec<_>>()
+        );
+
+        let extracted = tmp.path().join("extracted");
+        extract_skills_zip(fs::File::open(&zip_path).expect("open zip"), &extracted)
+            .expect("extract zip");
+        assert_eq!(
+            fs::read(extracted.join("demo").join(raw_name)).expect("restored file"),
+            b"latin-1 name"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn zip_reports_skipped_entries_as_warnings() {
+        let tmp = tempdir().expect("tempdir");
+        let source = tmp.path().join("skills");
+        let outside = tmp.path().join("outside");
+        fs::create_dir_all(source.join("demo")).expect("create skill dir");
+        fs::create_dir_all(&outside).expect("create outside dir");
+        fs::write(source.join("demo").join("SKILL.md"), b"# demo").expect("write skill");
+        fs::write(source.join(".DS_Store"), b"ignored").expect("write dotfile");
+        std::os::unix::fs::symlink(&outside, source.join("linked")).expect("symlink outside");
+
+        let manifest = zip_skills_dir(
+            &source,
+            &tmp.path().join("skills.zip"),
+            false,
+            CompressionAlgo::Deflate,
+        )
+        .expect("zip skills");
+        assert_eq!(manifest.file_count, 1);
+        assert_eq!(
+            manifest.warnings,
+            vec![
+                "Skills archive: skipped 1 dotfiles".to_string(),
+                "Skills archive: skipped 1 symlinks outside skills root".to_string(),
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn decode_name_component_rejects_escaped_traversal() {
+        // ".." 与 "a/b" 的十六进制
+        for escaped in [".nonutf8-2e2e", ".nonutf8-612f62", ".nonutf8-zz"] {
+            assert_eq!(
+                decode_name_component(OsStr::new(escaped)),
+                OsString::from(escaped)
+            );
+        }
+    }
+
+    #[test]
+    fn hash_skills_tree_changes_only_when_content_changes() {
+        let tmp = tempdir().expect("tempdir");
+        let source = tmp.path().join("skills");
+        fs::create_dir_all(source.join("demo")).expect("create skill dir");
+        fs::write(source.join("demo").join("SKILL.md"), b"# demo").expect("write skill");
+        fs::write(source.join(".DS_Store"), b"ignored").expect("write dotfile");
+
+        let first = hash_skills_tree(&source).expect("hash #1");
+        assert_eq!(first, hash_skills_tree(&source).expect("hash #2"));
+
+        fs::write(source.join(".DS_Store"), b"still ignored").expect("rewrite dotfile");
+        assert_eq!(
+            first,
+            hash_skills_tree(&source).expect("hash after dotfile edit")
+        );
+
+        fs::write(source.join("demo").join("SKILL.md"), b"# demo v2").expect("edit skill");
+        let edited = hash_skills_tree(&source).expect("hash after edit");
+        assert_ne!(first, edited);
+
+        fs::rename(
+            source.join("demo").join("SKILL.md"),
+            source.join("demo").join("README.md"),
+        )
+        .expect("rename skill file");
+        assert_ne!(
+            edited,
+            hash_skills_tree(&source).expect("hash after rename")
+        );
+    }
 }
@@ -0,0 +1,218 @@
+//! 从外部凭据文件读取 WebDAV 用户名 / 密码
+//!
+//! 支持两种格式：
+//! - JSON：`{"username": "...", "password": "..."}`，或用 `token` 代替 `password`
+//! - netrc：`machine <host> login <user> password <secret>`，优先匹配 base_url 的主机，其次 `default`
+//!
+//! 文件中的值优先于 settings.json 中的值，且只在内存中生效，不会写回 settings.json。
+
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+use url::Url;
+
+use crate::error::AppError;
+use crate::settings::{resolve_override_path, WebDavSyncSettings};
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(super) struct FileCredentials {
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonCredentials {
+    #[serde(default, alias = "login")]
+    username: Option<String>,
+    #[serde(default)]
+    password: Option<String>,
+    #[serde(default)]
+    token: Option<String>,
+}
+
+/// 若配置了 `credentials_file`，用文件中的凭据覆盖 `settings` 中的用户名 / 密码。
+pub(super) fn apply_credentials_file(settings: &mut WebDavSyncSettings) -> Result<(), AppError> {
+    let Some(raw_path) = settings.credentials_file.as_deref() else {
+        return Ok(());
+    };
+    let path = resolve_override_path(raw_path);
+    let host = Url::parse(&settings.base_url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string));
+    let credentials = load_credentials_file(&path, host.as_deref())?;
+
+    if let Some(username) = credentials.username {
+        settings.username = username;
+    }
+    if let Some(password) = credentials.password {
+        settings.password = password;
+    }
+    Ok(())
+}
+
+pub(super) fn load_credentials_file(
+    path: &Path,
+    host: Option<&str>,
+) -> Result<FileCredentials, AppError> {
+    let raw = fs::read_to_string(path).map_err(|e| AppError::io(path, e))?;
+    warn_if_world_readable(path);
+    parse_credentials(&raw, host).ok_or_else(|| {
+        AppError::localized(
+            "webdav.sync.credentials_file_invalid",
+            format!(
+                "WebDAV 凭据文件中没有可用的用户名或密码: {}",
+                path.display()
+            ),
+            format!(
+                "WebDAV credentials file has no usable username or password: {}",
+                path.display()
+            ),
+        )
+    })
+}
+
+fn parse_credentials(raw: &str, host: Option<&str>) -> Option<FileCredentials> {
+    let credentials = if raw.trim_start().starts_with('{') {
+        let json: JsonCredentials = serde_json::from_str(raw).ok()?;
+        FileCredentials {
+            username: non_empty(json.username),
+            password: non_empty(json.password.or(json.token)),
+        }
+    } else {
+        parse_netrc(raw, host)?
+    };
+    (credentials.username.is_some() || credentials.password.is_some()).then_some(credentials)
+}
+
+/// 解析 netrc 风格内容；没有 `machine` / `default` 时整个文件视为一个条目。
+fn parse_netrc(raw: &str, host: Option<&str>) -> Option<FileCredentials> {
+    let mut entries: Vec<(Option<String>, FileCredentials)> = Vec::new();
+    let mut tokens = raw
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .flat_map(str::split_whitespace);
+
+    while let Some(token) = tokens.next() {
+        match token {
+            "machine" => entries.push((tokens.next().map(str::to_string), Default::default())),
+            "default" => entries.push((None, Default::default())),
+            "login" | "username" | "password" | "token" => {
+                let Some(value) = tokens.next() else {
+                    break;
+                };
+                if entries.is_empty() {
+                    entries.push((None, Default::default()));
+                }
+                let (_, entry) = entries.last_mut()?;
+                if token == "login" || token == "username" {
+                    entry.username = Some(value.to_string());
+                } else {
+                    entry.password = Some(value.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let index = host
+        .and_then(|host| {
+            entries
+                .iter()
+                .position(|(machine, _)| machine.as_deref() == Some(host))
+        })
+        .or_else(|| entries.iter().position(|(machine, _)| machine.is_none()))?;
+    Some(entries.swap_remove(index).1)
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+#[cfg(unix)]
+fn warn_if_world_readable(path: &Path) {
+    use std::os::unix::fs::PermissionsExt;
+
+    if let Ok(meta) = fs::metadata(path) {
+        if meta.permissions().mode() & 0o004 != 0 {
+            log::warn!(
+                "[WebDAV] Credentials file is world-readable; consider `chmod 600 {}`",
+                path.display()
+            );
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn warn_if_world_readable(_path: &Path) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_json_credentials_with_password_or_token() {
+        assert_eq!(
+            parse_credentials(r#"{"username": "demo", "password": "secret"}"#, None),
+            Some(FileCredentials {
+                username: Some("demo".to_string()),
+                password: Some("secret".to_string()),
+            })
+        );
+        assert_eq!(
+            parse_credentials(r#"{"token": "app-token"}"#, None),
+            Some(FileCredentials {
+                username: None,
+                password: Some("app-token".to_string()),
+            })
+        );
+        assert_eq!(parse_credentials(r#"{"username": "  "}"#, None), None);
+    }
+
+    #[test]
+    fn parse_netrc_prefers_matching_machine_then_default() {
+        let raw = "\
+# WebDAV accounts
+machine other.example.com login nobody password nope
+machine dav.example.com
+  login demo
+  password secret
+default login fallback password fallback-secret
+";
+        assert_eq!(
+            parse_credentials(raw, Some("dav.example.com")),
+            Some(FileCredentials {
+                username: Some("demo".to_string()),
+                password: Some("secret".to_string()),
+            })
+        );
+        assert_eq!(
+            parse_credentials(raw, Some("unknown.example.com")),
+            Some(FileCredentials {
+                username: Some("fallback".to_string()),
+                password: Some("fallback-secret".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_netrc_without_machine_uses_whole_file_but_never_another_host() {
+        assert_eq!(
+            parse_credentials("login demo\npassword secret\n", Some("dav.example.com")),
+            Some(FileCredentials {
+                username: Some("demo".to_string()),
+                password: Some("secret".to_string()),
+            })
+        );
+        assert_eq!(
+            parse_credentials(
+                "machine other.example.com login x password y",
+                Some("dav.example.com")
+            ),
+            None,
+            "credentials for another host must not be used"
+        );
+    }
+}
@@ -0,0 +1,280 @@
+//! WebDAV 同步诊断：按顺序执行配置、认证、目录、写入与 manifest 检查，汇总为一份报告
+
+use std::time::Instant;
+
+use reqwest::Client;
+use serde::Serialize;
+
+use crate::error::AppError;
+use crate::services::webdav;
+use crate::settings::WebDavSyncSettings;
+
+use super::{
+    build_client, ensure_remote_dir, find_remote_snapshot, load_webdav_settings,
+    remote_dir_segments, RemoteLayout, WRITE_TEST_PROBE,
+};
+
+/// 诊断项，按执行顺序排列
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DiagnosticCheck {
+    /// 读取并校验同步设置（含 base URL 解析与凭据文件）
+    Settings,
+    /// PROPFIND 基础目录，确认服务器可达且认证通过
+    Credentials,
+    /// 按地址与响应头识别服务商
+    Provider,
+    /// 确认（或按设置创建）远端 profile 目录
+    RemoteDirectory,
+    /// 写入、读回并删除探测文件
+    WriteTest,
+    /// 远端是否已有可读取的 manifest
+    RemoteManifest,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DiagnosticStatus {
+    Pass,
+    Fail,
+    /// 前置检查失败，或未开启 `continue_on_failure` 时已有检查失败
+    Skip,
+}
+
+/// 单个诊断项的结果；`detail` 为通过时的说明或失败原因
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticStep {
+    pub check: DiagnosticCheck,
+    pub status: DiagnosticStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    pub elapsed_ms: u64,
+}
+
+/// [`super::WebDavSyncService::diagnose`] 的结果，`steps` 按执行顺序排列且包含全部诊断项
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticReport {
+    pub steps: Vec<DiagnosticStep>,
+}
+
+impl DiagnosticReport {
+    /// 没有失败的诊断项
+    pub fn passed(&self) -> bool {
+        self.steps
+            .iter()
+            .all(|step| step.status != DiagnosticStatus::Fail)
+    }
+
+    fn failed(&self) -> bool {
+        !self.passed()
+    }
+
+    fn push(&mut self, check: DiagnosticCheck, started: Instant, result: Result<String, AppError>) {
+        let (status, detail) = match result {
+            Ok(detail) => (DiagnosticStatus::Pass, detail),
+            Err(e) => (DiagnosticStatus::Fail, e.to_string()),
+        };
+        self.steps.push(DiagnosticStep {
+            check,
+            status,
+            detail: Some(detail).filter(|detail| !detail.is_empty()),
+            elapsed_ms: started.elapsed().as_millis() as u64,
+        });
+    }
+
+    fn skip(&mut self, check: DiagnosticCheck) {
+        self.steps.push(DiagnosticStep {
+            check,
+            status: DiagnosticStatus::Skip,
+            detail: None,
+            elapsed_ms: 0,
+        });
+    }
+}
+
+/// 依次执行全部诊断项。
+///
+/// 设置无法读取时其余各项都无法执行；远端目录不可用时跳过写入测试。
+/// `continue_on_failure` 为 false 时，第一项失败后其余各项都记为跳过。
+pub(super) async fn diagnose(continue_on_failure: bool) -> DiagnosticReport {
+    let mut report = DiagnosticReport::default();
+
+    let started = Instant::now();
+    let loaded = load_webdav_settings().and_then(|settings| {
+        let client = build_client(&settings)?;
+        Ok((settings, client))
+    });
+    let (settings, client) = match loaded {
+        Ok((settings, client)) => {
+            report.push(
+                DiagnosticCheck::Settings,
+                started,
+                Ok(webdav::redact_url(&settings.base_url)),
+            );
+            (settings, client)
+        }
+        Err(e) => {
+            report.push(DiagnosticCheck::Settings, started, Err(e));
+            for check in [
+                DiagnosticCheck::Credentials,
+                DiagnosticCheck::Provider,
+                DiagnosticCheck::RemoteDirectory,
+                DiagnosticCheck::WriteTest,
+                DiagnosticCheck::RemoteManifest,
+            ] {
+                report.skip(check);
+            }
+            return report;
+        }
+    };
+    let auth = webdav::auth_from_credentials(&settings.username, &settings.password);
+    let should_run = |report: &DiagnosticReport| continue_on_failure || !report.failed();
+
+    let mut server_kind = None;
+    if should_run(&report) {
+        let started = Instant::now();
+        let result = webdav::test_connection(&client, &settings.base_url, &auth).await;
+        if let Ok(kind) = &result {
+            server_kind = Some(*kind);
+        }
+        report.push(
+            DiagnosticCheck::Credentials,
+            started,
+            result.map(|_| String::new()),
+        );
+    } else {
+        report.skip(DiagnosticCheck::Credentials);
+    }
+
+    if should_run(&report) {
+        let started = Instant::now();
+        report.push(
+            DiagnosticCheck::Provider,
+            started,
+            Ok(detect_server_kind(&settings, server_kind)
+                .name()
+                .to_string()),
+        );
+    } else {
+        report.skip(DiagnosticCheck::Provider);
+    }
+
+    let dir_segments = remote_dir_segments(&settings, RemoteLayout::Current);
+    let mut dir_ready = false;
+    if should_run(&report) {
+        let started = Instant::now();
+        let result = ensure_remote_dir(&client, &settings, &dir_segments, &auth).await;
+        dir_ready = result.is_ok();
+        report.push(
+            DiagnosticCheck::RemoteDirectory,
+            started,
+            result.map(|()| {
+                if settings.auto_create_dirs {
+                    "created or already present".to_string()
+                } else {
+                    "already present".to_string()
+                }
+            }),
+        );
+    } else {
+        report.skip(DiagnosticCheck::RemoteDirectory);
+    }
+
+    if dir_ready && should_run(&report) {
+        let started = Instant::now();
+        let result = webdav::write_probe_file(
+            &client,
+            &settings.base_url,
+            &dir_segments,
+            &auth,
+            WRITE_TEST_PROBE,
+        )
+        .await;
+        report.push(
+            DiagnosticCheck::WriteTest,
+            started,
+            result.map(|()| String::new()),
+        );
+    } else {
+        report.skip(DiagnosticCheck::WriteTest);
+    }
+
+    if should_run(&report) {
+        let started = Instant::now();
+        let result = describe_remote_manifest(&settings, &client, &auth).await;
+        report.push(DiagnosticCheck::RemoteManifest, started, result);
+    } else {
+        report.skip(DiagnosticCheck::RemoteManifest);
+    }
+
+    report
+}
+
+/// 坚果云等按地址即可识别的服务以地址为准，其余使用连接检查时从响应头识别的结果
+fn detect_server_kind(
+    settings: &WebDavSyncSettings,
+    from_headers: Option<webdav::WebDavServerKind>,
+) -> webdav::WebDavServerKind {
+    match webdav::detect_provider(&settings.base_url) {
+        webdav::WebDavServerKind::Generic => from_headers.unwrap_or_default(),
+        kind => kind,
+    }
+}
+
+async fn describe_remote_manifest(
+    settings: &WebDavSyncSettings,
+    client: &Client,
+    auth: &webdav::WebDavAuth,
+) -> Result<String, AppError> {
+    Ok(match find_remote_snapshot(settings, client, auth).await? {
+        Some(remote) => format!(
+            "snapshot {} from {} at {}",
+            remote.manifest.snapshot_id, remote.manifest.device_name, remote.manifest.created_at
+        ),
+        None => "no manifest yet (nothing has been uploaded)".to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(check: DiagnosticCheck, status: DiagnosticStatus) -> DiagnosticStep {
+        DiagnosticStep {
+            check,
+            status,
+            detail: None,
+            elapsed_ms: 0,
+        }
+    }
+
+    #[test]
+    fn report_passes_unless_a_step_failed() {
+        let mut report = DiagnosticReport {
+            steps: vec![
+                step(DiagnosticCheck::Settings, DiagnosticStatus::Pass),
+                step(DiagnosticCheck::WriteTest, DiagnosticStatus::Skip),
+            ],
+        };
+        assert!(report.passed());
+
+        report.steps.push(step(
+            DiagnosticCheck::RemoteManifest,
+            DiagnosticStatus::Fail,
+        ));
+        assert!(!report.passed());
+    }
+
+    #[test]
+    fn report_serializes_checks_in_camel_case() {
+        let report = DiagnosticReport {
+            steps: vec![step(DiagnosticCheck::WriteTest, DiagnosticStatus::Skip)],
+        };
+        let value = serde_json::to_value(&report).expect("serialize report");
+        assert_eq!(value["steps"][0]["check"], "writeTest");
+        assert_eq!(value["steps"][0]["status"], "skip");
+        assert!(value["steps"][0].get("detail").is_none());
+    }
+}
@@ -0,0 +1,189 @@
+//! 下载 artifact 的承载方式：小文件留在内存，大文件落到临时文件
+
+use std::fs;
+use std::io::{self, Cursor, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use sha2::{Digest, Sha256};
+use tempfile::NamedTempFile;
+
+use crate::error::AppError;
+
+/// 超过该大小的 artifact 下载时直接写入临时文件，避免整份缓冲在内存中。
+pub const SPILL_TO_DISK_THRESHOLD_BYTES: u64 = 64 * 1024 * 1024; // 64 MB
+
+#[derive(Debug)]
+pub enum ArtifactPayload {
+    Memory(Vec<u8>),
+    /// `len` 为下载时实际写入的字节数，读取前据此校验文件是否完整
+    TempFile {
+        file: NamedTempFile,
+        len: u64,
+    },
+}
+
+impl ArtifactPayload {
+    pub fn is_temp_file(&self) -> bool {
+        matches!(self, Self::TempFile { .. })
+    }
+
+    /// 读取完整内容（仅用于必须整体处理的 artifact，例如 SQL）。
+    pub fn read_all(&self) -> Result<Vec<u8>, AppError> {
+        match self {
+            Self::Memory(bytes) => Ok(bytes.clone()),
+            Self::TempFile { file, .. } => {
+                fs::read(file.path()).map_err(|e| AppError::io(file.path(), e))
+            }
+        }
+    }
+
+    pub fn reader(&self) -> Result<ArtifactReader<'_>, AppError> {
+        match self {
+            Self::Memory(bytes) => Ok(ArtifactReader::Memory(Cursor::new(bytes))),
+            Self::TempFile { file, len } => {
+                verify_written_len(file.path(), *len)?;
+                file.reopen()
+                    .map(ArtifactReader::File)
+                    .map_err(|e| AppError::io(file.path(), e))
+            }
+        }
+    }
+
+    pub fn write_to(&self, dest: &Path) -> Result<(), AppError> {
+        let expected = match self {
+            Self::Memory(bytes) => {
+                crate::config::atomic_write(dest, bytes)?;
+                bytes.len() as u64
+            }
+            Self::TempFile { file, len } => {
+                verify_written_len(file.path(), *len)?;
+                if let Some(parent) = dest.parent() {
+                    fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
+                }
+                fs::copy(file.path(), dest).map_err(|e| AppError::io(dest, e))?;
+                *len
+            }
+        };
+        verify_written_len(dest, expected)
+    }
+}
+
+/// 校验落盘文件的大小与写入的字节数一致，避免把截断的文件交给后续解析。
+pub fn verify_written_len(path: &Path, expected: u64) -> Result<(), AppError> {
+    let actual = fs::metadata(path).map_err(|e| AppError::io(path, e))?.len();
+    if actual == expected {
+        return Ok(());
+    }
+    Err(AppError::localized(
+        "webdav.sync.artifact_short_write",
+        format!(
+            "文件写入不完整: {}（实际 {actual} 字节，期望 {expected} 字节）",
+            path.display()
+        ),
+        format!(
+            "Incomplete write detected for {} ({actual} bytes on disk, expected {expected})",
+            path.display()
+        ),
+    ))
+}
+
+pub enum ArtifactReader<'a> {
+    Memory(Cursor<&'a Vec<u8>>),
+    File(fs::File),
+}
+
+impl Read for ArtifactReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Self::Memory(cursor) => cursor.read(buf),
+            Self::File(file) => file.read(buf),
+        }
+    }
+}
+
+impl Seek for ArtifactReader<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        match self {
+            Self::Memory(cursor) => cursor.seek(pos),
+            Self::File(file) => file.seek(pos),
+        }
+    }
+}
+
+/// 写入时同步计算 SHA256，流式下载无需再次读取文件校验。
+pub struct HashingWriter<W> {
+    inner: W,
+    hasher: Sha256,
+}
+
+impl<W: Write> HashingWriter<W> {
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            hasher: Sha256::new(),
+        }
+    }
+
+    pub fn finish(self) -> (W, String) {
+        let hash = self.hasher.finalize();
+        (self.inner, format!("{hash:x}"))
+    }
+}
+
+impl<W: Write> Write for HashingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.hasher.update(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_payload(bytes: &[u8]) -> ArtifactPayload {
+        let mut file = NamedTempFile::new().expect("create temp file");
+        file.write_all(bytes).expect("write temp file");
+        ArtifactPayload::TempFile {
+            file,
+            len: bytes.len() as u64,
+        }
+    }
+
+    #[test]
+    fn truncated_temp_file_is_rejected_before_parsing() {
+        let payload = temp_payload(b"PK\x03\x04 not really a zip");
+        let ArtifactPayload::TempFile { file, .. } = &payload else {
+            unreachable!()
+        };
+        file.as_file().set_len(4).expect("truncate temp file");
+
+        let err = payload.reader().err().expect("short file must be rejected");
+        assert!(
+            err.to_string().contains("Incomplete write detected")
+                || err.to_string().contains("文件写入不完整"),
+            "unexpected error: {err}"
+        );
+
+        let dest = tempfile::tempdir().expect("temp dir");
+        assert!(payload.write_to(&dest.path().join("skills.zip")).is_err());
+    }
+
+    #[test]
+    fn write_to_accepts_complete_payloads() {
+        let dest = tempfile::tempdir().expect("temp dir");
+        for payload in [
+            ArtifactPayload::Memory(b"in memory".to_vec()),
+            temp_payload(b"spilled to disk"),
+        ] {
+            let path = dest.path().join("artifact.bin");
+            payload.write_to(&path).expect("write payload");
+            assert_eq!(fs::read(&path).unwrap(), payload.read_all().unwrap());
+        }
+    }
+}
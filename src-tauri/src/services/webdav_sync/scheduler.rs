@@ -0,0 +1,84 @@
+//! 自动同步的轮询间隔：远端与本地都没有变化时指数退避，出现变化后回到基础间隔
+
+use std::time::Duration;
+
+/// 默认基础轮询间隔
+pub const DEFAULT_BASE_INTERVAL: Duration = Duration::from_secs(60);
+/// 默认最大轮询间隔
+pub const DEFAULT_MAX_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncScheduler {
+    base: Duration,
+    max: Duration,
+    current: Duration,
+}
+
+impl Default for SyncScheduler {
+    fn default() -> Self {
+        Self::new(DEFAULT_BASE_INTERVAL, DEFAULT_MAX_INTERVAL)
+    }
+}
+
+impl SyncScheduler {
+    /// `max` 小于 `base` 时按 `base` 处理。
+    pub fn new(base: Duration, max: Duration) -> Self {
+        Self {
+            base,
+            max: max.max(base),
+            current: base,
+        }
+    }
+
+    /// 距下一次 `sync()` 应等待的时长
+    pub fn next_delay(&self) -> Duration {
+        self.current
+    }
+
+    /// 记录一次 `sync()` 的结果：无变化时间隔翻倍（不超过上限），有变化时重置为基础间隔。
+    pub fn record_result(&mut self, changed: bool) {
+        self.current = if changed {
+            self.base
+        } else {
+            self.current.saturating_mul(2).min(self.max)
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consecutive_unchanged_results_back_off_up_to_cap() {
+        let mut scheduler = SyncScheduler::new(Duration::from_secs(10), Duration::from_secs(60));
+        assert_eq!(scheduler.next_delay(), Duration::from_secs(10));
+
+        let mut delays = Vec::new();
+        for _ in 0..4 {
+            scheduler.record_result(false);
+            delays.push(scheduler.next_delay().as_secs());
+        }
+        assert_eq!(delays, vec![20, 40, 60, 60]);
+    }
+
+    #[test]
+    fn change_resets_delay_to_base() {
+        let mut scheduler = SyncScheduler::new(Duration::from_secs(10), Duration::from_secs(60));
+        scheduler.record_result(false);
+        scheduler.record_result(false);
+        assert_eq!(scheduler.next_delay(), Duration::from_secs(40));
+
+        scheduler.record_result(true);
+        assert_eq!(scheduler.next_delay(), Duration::from_secs(10));
+        scheduler.record_result(false);
+        assert_eq!(scheduler.next_delay(), Duration::from_secs(20));
+    }
+
+    #[test]
+    fn max_below_base_is_clamped_to_base() {
+        let mut scheduler = SyncScheduler::new(Duration::from_secs(30), Duration::from_secs(5));
+        scheduler.record_result(false);
+        assert_eq!(scheduler.next_delay(), Duration::from_secs(30));
+    }
+}
@@ -0,0 +1,234 @@
+//! 本地 skills.zip 构建缓存：skills 目录内容未变时复用上次打包结果，避免每次上传 / 预演都重新压缩
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+use crate::error::AppError;
+use crate::settings::CompressionAlgo;
+
+use super::archive::{hash_skills_tree, zip_skills_dir, SkillsManifest};
+
+/// 超过该大小的 skills.zip 不写入缓存，避免在配置目录中长期占用大量空间
+const MAX_CACHED_SKILLS_ZIP_BYTES: u64 = 256 * 1024 * 1024; // 256 MB
+
+const CACHE_FILE_PREFIX: &str = "skills-";
+const CACHE_FILE_SUFFIX: &str = ".zip";
+
+/// 一次 skills.zip 构建的结果；`manifest` 为 `None` 表示直接复用了缓存
+#[derive(Debug)]
+pub struct SkillsZipBuild {
+    pub bytes: Vec<u8>,
+    pub manifest: Option<SkillsManifest>,
+}
+
+/// 缓存目录中只保留最近一次构建，文件名为 `skills-{key}-{zip sha256}.zip`，
+/// 读取时按文件名中的 SHA256 校验内容，损坏的缓存直接丢弃。
+#[derive(Debug, Clone)]
+pub struct SkillsZipCache {
+    dir: PathBuf,
+    max_bytes: u64,
+}
+
+impl SkillsZipCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            max_bytes: MAX_CACHED_SKILLS_ZIP_BYTES,
+        }
+    }
+
+    /// 默认位置：`~/.cc-switch/cache/`
+    pub fn in_app_config_dir() -> Self {
+        Self::new(crate::config::get_app_config_dir().join("cache"))
+    }
+
+    /// 打包 `source`；目录内容哈希与缓存一致时直接返回缓存的字节。
+    ///
+    /// 打包时有被跳过的条目（会产生提示）的结果不缓存，保证每次构建都能看到这些提示。
+    /// 缓存读写失败只记录日志，不影响构建本身。
+    pub fn build(
+        &self,
+        source: &Path,
+        tmp: &Path,
+        dedup: bool,
+        compression: CompressionAlgo,
+    ) -> Result<SkillsZipBuild, AppError> {
+        let key = cache_key(&hash_skills_tree(source)?, dedup, compression);
+        if let Some(bytes) = self.get(&key) {
+            return Ok(SkillsZipBuild {
+                bytes,
+                manifest: None,
+            });
+        }
+
+        let zip_path = tmp.join("skills.zip");
+        let manifest = zip_skills_dir(source, &zip_path, dedup, compression)?;
+        let bytes = fs::read(&zip_path).map_err(|e| AppError::io(&zip_path, e))?;
+        if manifest.warnings.is_empty() {
+            if let Err(e) = self.put(&key, &bytes) {
+                log::warn!("[WebDAV] Failed to cache skills.zip: {e}");
+            }
+        }
+        Ok(SkillsZipBuild {
+            bytes,
+            manifest: Some(manifest),
+        })
+    }
+
+    fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let prefix = format!("{CACHE_FILE_PREFIX}{key}-");
+        let (path, expected_sha) = self.entries().into_iter().find_map(|(path, name)| {
+            let sha = name
+                .strip_prefix(&prefix)?
+                .strip_suffix(CACHE_FILE_SUFFIX)?
+                .to_string();
+            Some((path, sha))
+        })?;
+        let bytes = fs::read(&path).ok()?;
+        if sha256_hex(&bytes) != expected_sha {
+            log::warn!(
+                "[WebDAV] Discarding corrupt skills.zip cache {}",
+                path.display()
+            );
+            let _ = fs::remove_file(&path);
+            return None;
+        }
+        Some(bytes)
+    }
+
+    fn put(&self, key: &str, bytes: &[u8]) -> Result<(), AppError> {
+        for (path, _) in self.entries() {
+            fs::remove_file(&path).map_err(|e| AppError::io(&path, e))?;
+        }
+        if bytes.len() as u64 > self.max_bytes {
+            return Ok(());
+        }
+        let name = format!(
+            "{CACHE_FILE_PREFIX}{key}-{}{CACHE_FILE_SUFFIX}",
+            sha256_hex(bytes)
+        );
+        crate::config::atomic_write(&self.dir.join(name), bytes)
+    }
+
+    /// 缓存目录中现有的 skills.zip 缓存文件（路径, 文件名）
+    fn entries(&self) -> Vec<(PathBuf, String)> {
+        let Ok(read_dir) = fs::read_dir(&self.dir) else {
+            return Vec::new();
+        };
+        read_dir
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                (name.starts_with(CACHE_FILE_PREFIX) && name.ends_with(CACHE_FILE_SUFFIX))
+                    .then(|| (entry.path(), name))
+            })
+            .collect()
+    }
+}
+
+/// 打包参数也会改变 ZIP 内容，因此与目录内容哈希一起组成缓存键
+fn cache_key(tree_hash: &str, dedup: bool, compression: CompressionAlgo) -> String {
+    sha256_hex(format!("{tree_hash}:dedup={dedup}:compression={compression:?}").as_bytes())
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn cached_files(cache: &SkillsZipCache) -> Vec<String> {
+        cache.entries().into_iter().map(|(_, name)| name).collect()
+    }
+
+    #[test]
+    fn unchanged_tree_reuses_cached_archive_and_changed_tree_rebuilds() {
+        let tmp = tempdir().expect("create temp dir");
+        let source = tmp.path().join("skills");
+        fs::create_dir_all(source.join("demo")).expect("create skill dir");
+        fs::write(source.join("demo").join("SKILL.md"), b"# demo").expect("write skill");
+        let cache = SkillsZipCache::new(tmp.path().join("cache"));
+
+        let first = cache
+            .build(&source, tmp.path(), false, CompressionAlgo::Deflate)
+            .expect("first build");
+        assert!(first.manifest.is_some(), "first build should pack the tree");
+
+        let second = cache
+            .build(&source, tmp.path(), false, CompressionAlgo::Deflate)
+            .expect("second build");
+        assert!(second.manifest.is_none(), "unchanged tree should hit cache");
+        assert_eq!(second.bytes, first.bytes);
+
+        fs::write(source.join("demo").join("SKILL.md"), b"# changed").expect("edit skill");
+        let third = cache
+            .build(&source, tmp.path(), false, CompressionAlgo::Deflate)
+            .expect("third build");
+        assert!(third.manifest.is_some(), "changed tree should rebuild");
+        assert_ne!(third.bytes, first.bytes);
+        assert_eq!(
+            cached_files(&cache).len(),
+            1,
+            "only the latest build is kept"
+        );
+    }
+
+    #[test]
+    fn compression_setting_is_part_of_cache_key() {
+        let tmp = tempdir().expect("create temp dir");
+        let source = tmp.path().join("skills");
+        fs::create_dir_all(&source).expect("create skills dir");
+        fs::write(source.join("a.txt"), b"aaa").expect("write file");
+        let cache = SkillsZipCache::new(tmp.path().join("cache"));
+
+        cache
+            .build(&source, tmp.path(), false, CompressionAlgo::Deflate)
+            .expect("deflate build");
+        let stored = cache
+            .build(&source, tmp.path(), false, CompressionAlgo::Stored)
+            .expect("stored build");
+        assert!(stored.manifest.is_some(), "new compression should rebuild");
+    }
+
+    #[test]
+    fn corrupt_cache_entry_is_discarded_and_rebuilt() {
+        let tmp = tempdir().expect("create temp dir");
+        let source = tmp.path().join("skills");
+        fs::create_dir_all(&source).expect("create skills dir");
+        fs::write(source.join("a.txt"), b"aaa").expect("write file");
+        let cache = SkillsZipCache::new(tmp.path().join("cache"));
+
+        let first = cache
+            .build(&source, tmp.path(), false, CompressionAlgo::Deflate)
+            .expect("first build");
+        let (path, _) = cache.entries().pop().expect("cache entry");
+        fs::write(&path, b"truncated").expect("corrupt cache");
+
+        let rebuilt = cache
+            .build(&source, tmp.path(), false, CompressionAlgo::Deflate)
+            .expect("rebuild");
+        assert!(rebuilt.manifest.is_some(), "corrupt cache must not be used");
+        assert_eq!(rebuilt.bytes, first.bytes);
+    }
+
+    #[test]
+    fn oversized_archives_are_not_cached() {
+        let tmp = tempdir().expect("create temp dir");
+        let mut cache = SkillsZipCache::new(tmp.path().join("cache"));
+        cache.max_bytes = 4;
+        cache.put("old", b"old").expect("cache small archive");
+
+        cache
+            .put("new", b"oversized")
+            .expect("skip oversized archive");
+        assert!(
+            cached_files(&cache).is_empty(),
+            "oversized build should clear the stale entry without caching itself"
+        );
+    }
+}
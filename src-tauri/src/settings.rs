@@ -2,7 +2,7 @@ use crate::app_config::AppType;
 use crate::config::home_dir;
 use crate::error::AppError;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::PathBuf;
 use std::sync::{OnceLock, RwLock};
@@ -180,8 +180,62 @@ pub struct WebDavSyncStatus {
     pub last_local_manifest_hash: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub last_remote_manifest_hash: Option<String>,
+    /// 上次同步时本地数据的内容指纹（忽略导出时间戳，不打包 skills），
+    /// `sync` 与离线的 `has_local_changes` 都据此判断本地是否有改动
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_local_fingerprint: Option<String>,
+    /// 上次成功上传 / 下载的远端快照 snapshot_id，下载前据此判断远端是否已应用过
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_snapshot_id: Option<String>,
+    /// 上次同步的快照附带的说明（manifest 的 `note`），由 `config webdav show` 展示
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_snapshot_note: Option<String>,
+    /// 上次同步后远端 Tree 模式各 skill 文件的 SHA256（键为 artifact 名称），用于增量上传
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_skills_tree_hashes: Option<BTreeMap<String, String>>,
+}
+
+/// 本地与远端自上次同步后都发生变化时的处理策略
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ConflictPolicy {
+    /// 报错并中止，由用户手动选择上传或下载
+    #[default]
+    Error,
+    /// 以远端为准，覆盖前备份本地数据
+    PreferRemote,
+    /// 以本地为准，覆盖前备份远端数据
+    PreferLocal,
+}
+
+/// Skills 在远端的存放方式
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum SkillsStorage {
+    /// 打包为单个 `skills.zip`
+    #[default]
+    Zip,
+    /// 逐文件存放在 `skills/` 目录下，便于在 WebDAV 网页端浏览
+    Tree,
+}
+
+/// skills.zip 条目的压缩算法
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum CompressionAlgo {
+    /// 兼容性最好
+    #[default]
+    Deflate,
+    /// 以文本为主的大型 skills 目录压缩率更高、速度更快
+    Zstd,
+    /// 不压缩
+    Stored,
 }
 
+/// WebDAV 同步配置。
+///
+/// 旧版配置中的 `deviceId` / `timeoutSecs` 已废弃：设备名改为自动检测，超时改用内置值。
+/// 未知字段在反序列化时直接忽略，因此新旧配置文件都能读取，保存时不再写回这两项。
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct WebDavSyncSettings {
@@ -200,6 +254,47 @@ pub struct WebDavSyncSettings {
     #[serde(default)]
     pub auto_sync: bool,
     #[serde(default)]
+    pub conflict_policy: ConflictPolicy,
+    /// 外部凭据文件（JSON 或 netrc），其中的用户名 / 密码优先于上面两项，且不会写入 settings.json
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials_file: Option<String>,
+    /// 额外信任的 CA 证书文件（PEM），用于内网自建 CA 签发证书的服务器
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_cert_path: Option<String>,
+    /// 是否自动创建缺失的远端目录（MKCOL）；禁止 MKCOL 的受控共享可关闭，改为只检查目录是否存在
+    #[serde(default = "default_webdav_auto_create_dirs")]
+    pub auto_create_dirs: bool,
+    /// 下载应用后重新导出数据库，核对各实体表行数与下载的 SQL 是否一致，不一致时给出警告
+    #[serde(default)]
+    pub verify_after_apply: bool,
+    /// 单个 artifact 的下载上限（字节）；未设置时为 512 MB，最大不超过 4 GB
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_artifact_bytes: Option<u64>,
+    /// 是否同步数据库（`db.sql`）；关闭后只同步 Skills，供应商密钥等仅保留在本机
+    #[serde(default = "default_webdav_sync_database")]
+    pub sync_database: bool,
+    /// 上传时 Skills 的存放方式；下载时以远端 manifest 记录的方式为准
+    #[serde(default)]
+    pub skills_storage: SkillsStorage,
+    /// 打包 skills.zip 时按内容去重，相同文件只存一份；归档不便直接查看，默认关闭
+    #[serde(default)]
+    pub dedup: bool,
+    /// 打包 skills.zip 使用的压缩算法，随 manifest 上传；解压时按条目自身记录的算法处理
+    #[serde(default)]
+    pub compression: CompressionAlgo,
+    /// 上传时写入远端 profile 目录 `README.txt` 的说明，仅供人工查看，不参与同步校验
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile_description: Option<String>,
+    /// 远端 artifact 文件名前缀（如 `ccswitch-` 使 `db.sql` 存为 `ccswitch-db.sql`），
+    /// 用于与其他工具共用目录时避免重名；只作用于 profile 目录下的顶层文件，
+    /// 实际文件名记录在 manifest 中，下载时以 manifest 为准
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub artifact_prefix: Option<String>,
+    /// 上传时写入 manifest 的协议版本，用于与旧版本客户端混用时的兼容测试；
+    /// 未设置时按 manifest 用到的特性自动选择，只能取仍受支持的版本
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol_version_override: Option<u32>,
+    #[serde(default)]
     pub status: WebDavSyncStatus,
 }
 
@@ -211,8 +306,24 @@ fn default_webdav_profile() -> String {
     "default".to_string()
 }
 
+fn default_webdav_auto_create_dirs() -> bool {
+    true
+}
+
+fn default_webdav_sync_database() -> bool {
+    true
+}
+
 const JIANGUOYUN_WEBDAV_BASE_URL: &str = "https://dav.jianguoyun.com/dav";
 
+/// 未配置 `max_artifact_bytes` 时的 artifact 下载上限
+const DEFAULT_WEBDAV_MAX_ARTIFACT_BYTES: u64 = 512 * 1024 * 1024; // 512 MB
+/// `max_artifact_bytes` 允许的最大值，避免误配置导致内存耗尽
+const WEBDAV_MAX_ARTIFACT_BYTES_CEILING: u64 = 4 * 1024 * 1024 * 1024; // 4 GB
+
+/// 未指定名称时（以及旧版单一配置迁移后）使用的 WebDAV 目标名
+pub const DEFAULT_WEBDAV_TARGET: &str = "default";
+
 impl Default for WebDavSyncSettings {
     fn default() -> Self {
         Self {
@@ -223,6 +334,19 @@ impl Default for WebDavSyncSettings {
             username: String::new(),
             password: String::new(),
             auto_sync: false,
+            conflict_policy: ConflictPolicy::default(),
+            credentials_file: None,
+            ca_cert_path: None,
+            auto_create_dirs: default_webdav_auto_create_dirs(),
+            verify_after_apply: false,
+            max_artifact_bytes: None,
+            sync_database: default_webdav_sync_database(),
+            skills_storage: SkillsStorage::default(),
+            dedup: false,
+            compression: CompressionAlgo::default(),
+            profile_description: None,
+            artifact_prefix: None,
+            protocol_version_override: None,
             status: WebDavSyncStatus::default(),
         }
     }
@@ -243,12 +367,53 @@ impl WebDavSyncSettings {
         settings
     }
 
+    /// 是否指向同一远端位置（地址、根目录、profile 与账号一致）。
+    ///
+    /// 只有远端位置变化时旧的同步状态才失效；改密码、描述、压缩等选项不影响已有状态。
+    pub fn same_remote(&self, other: &Self) -> bool {
+        self.base_url == other.base_url
+            && self.remote_root == other.remote_root
+            && self.profile == other.profile
+            && self.username == other.username
+    }
+
+    /// 实际生效的单个 artifact 下载上限（字节）
+    pub fn artifact_size_limit(&self) -> u64 {
+        self.max_artifact_bytes
+            .unwrap_or(DEFAULT_WEBDAV_MAX_ARTIFACT_BYTES)
+            .min(WEBDAV_MAX_ARTIFACT_BYTES_CEILING)
+    }
+
     pub fn normalize(&mut self) {
         self.base_url = self.base_url.trim().trim_end_matches('/').to_string();
         self.remote_root = sanitize_path_segment(&self.remote_root);
         self.profile = sanitize_path_segment(&self.profile);
         self.username = self.username.trim().to_string();
         self.password = self.password.trim().to_string();
+        self.credentials_file = self
+            .credentials_file
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string);
+        self.ca_cert_path = self
+            .ca_cert_path
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string);
+        self.profile_description = self
+            .profile_description
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string);
+        self.artifact_prefix = self
+            .artifact_prefix
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string);
     }
 
     pub fn validate(&self) -> Result<(), AppError> {
@@ -271,10 +436,115 @@ impl WebDavSyncSettings {
                 "WebDAV remote_root/profile 不能包含 '..'".to_string(),
             ));
         }
+        for (field, value) in [
+            ("remote_root", &self.remote_root),
+            ("profile", &self.profile),
+        ] {
+            validate_portable_path(field, value)?;
+        }
+        if let Some(prefix) = &self.artifact_prefix {
+            validate_artifact_prefix(prefix)?;
+        }
+        if let Some(limit) = self.max_artifact_bytes {
+            if limit == 0 || limit > WEBDAV_MAX_ARTIFACT_BYTES_CEILING {
+                return Err(AppError::InvalidInput(format!(
+                    "WebDAV max_artifact_bytes 必须在 1 到 {WEBDAV_MAX_ARTIFACT_BYTES_CEILING} 之间"
+                )));
+            }
+        }
+        if let Some(version) = self.protocol_version_override {
+            let supported = crate::services::webdav_sync::supported_protocol_versions();
+            if !supported.contains(&version) {
+                return Err(AppError::localized(
+                    "webdav.settings.protocol_version_unsupported",
+                    format!(
+                        "WebDAV protocol_version_override 不受支持: v{version}（支持 v{} 到 v{}）",
+                        supported.start(),
+                        supported.end()
+                    ),
+                    format!(
+                        "WebDAV protocol_version_override is not supported: v{version} (supported: v{} to v{})",
+                        supported.start(),
+                        supported.end()
+                    ),
+                ));
+            }
+        }
         Ok(())
     }
 }
 
+/// Windows 保留的设备名，带扩展名（如 `CON.txt`）同样无效
+const WINDOWS_RESERVED_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+fn is_windows_reserved_name(segment: &str) -> bool {
+    let stem = segment.split('.').next().unwrap_or(segment).trim_end();
+    WINDOWS_RESERVED_NAMES
+        .iter()
+        .any(|reserved| stem.eq_ignore_ascii_case(reserved))
+}
+
+/// artifact 前缀直接拼在文件名前，只能是单个路径片段内的普通字符；
+/// 不能以 `.` 开头，部分服务器拒绝上传隐藏文件
+fn validate_artifact_prefix(prefix: &str) -> Result<(), AppError> {
+    if prefix.contains(['/', '\\', '%'])
+        || prefix.starts_with('.')
+        || prefix.chars().any(char::is_control)
+    {
+        return Err(AppError::localized(
+            "webdav.settings.artifact_prefix_invalid",
+            format!(
+                "WebDAV artifact_prefix 只能是简单的文件名前缀（不含 '/'、'\\'、'%' 与控制字符，且不以 '.' 开头）: {prefix:?}"
+            ),
+            format!(
+                "WebDAV artifact_prefix must be a plain file name prefix (no '/', '\\', '%' or control characters, not starting with '.'): {prefix:?}"
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// remote_root/profile 同时用于远端路径和本地临时目录名，需在各平台上都合法。
+fn validate_portable_path(field: &str, value: &str) -> Result<(), AppError> {
+    if value.contains('\\') {
+        return Err(AppError::localized(
+            "webdav.settings.path_backslash",
+            format!("WebDAV {field} 不能包含反斜杠 '\\': {value}"),
+            format!("WebDAV {field} must not contain backslashes '\\': {value}"),
+        ));
+    }
+    // URL 解析会静默丢弃制表符、换行等控制字符，拼出的路径与配置不一致
+    if value.chars().any(char::is_control) {
+        return Err(AppError::localized(
+            "webdav.settings.path_control_char",
+            format!("WebDAV {field} 不能包含控制字符: {value:?}"),
+            format!("WebDAV {field} must not contain control characters: {value:?}"),
+        ));
+    }
+    // 片段在拼 URL 时会被百分号编码；原样的 `%`（含 `%2F` 这类编码后的分隔符）
+    // 在部分服务器上会被二次解码，导致路径错位
+    if value.contains('%') {
+        return Err(AppError::localized(
+            "webdav.settings.path_percent",
+            format!("WebDAV {field} 不能包含 '%'（如 %2F 这类编码字符）: {value}"),
+            format!(
+                "WebDAV {field} must not contain '%' (percent-encoded names like %2F are ambiguous): {value}"
+            ),
+        ));
+    }
+    if let Some(segment) = value.split('/').find(|seg| is_windows_reserved_name(seg)) {
+        return Err(AppError::localized(
+            "webdav.settings.path_reserved_name",
+            format!("WebDAV {field} 不能使用 Windows 保留名称 '{segment}'"),
+            format!("WebDAV {field} must not use the Windows reserved name '{segment}'"),
+        ));
+    }
+    Ok(())
+}
+
 fn sanitize_path_segment(raw: &str) -> String {
     raw.trim()
         .trim_matches('/')
@@ -284,6 +554,45 @@ fn sanitize_path_segment(raw: &str) -> String {
         .join("/")
 }
 
+/// “查看完整内容”时的展示方式
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Viewer {
+    /// 在 TUI 内直接展示
+    #[default]
+    Inline,
+    /// 交给 `$PAGER`（默认 `less`）
+    Pager,
+    /// 以只读临时文件交给 `$VISUAL` / `$EDITOR`
+    Editor,
+}
+
+/// 日志级别，见 [`AppSettings::sync_log_level`]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    /// 额外记录每个 WebDAV 请求的方法、地址（已脱敏）、状态码与耗时
+    Trace,
+}
+
+impl LogLevel {
+    pub fn to_level_filter(self) -> log::LevelFilter {
+        match self {
+            Self::Off => log::LevelFilter::Off,
+            Self::Error => log::LevelFilter::Error,
+            Self::Warn => log::LevelFilter::Warn,
+            Self::Info => log::LevelFilter::Info,
+            Self::Debug => log::LevelFilter::Debug,
+            Self::Trace => log::LevelFilter::Trace,
+        }
+    }
+}
+
 /// 应用设置结构，允许覆盖默认配置目录
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -298,6 +607,9 @@ pub struct AppSettings {
     /// 是否跳过 Claude Code 初次安装确认
     #[serde(default)]
     pub skip_claude_onboarding: bool,
+    /// 切换供应商时是否重置 MCP/提示词 live 文件（移除未托管条目，按当前启用项重写）
+    #[serde(default)]
+    pub switch_resets_live: bool,
     /// 是否已经展示过供应商切换后的通用配置提示
     #[serde(default)]
     pub provider_switch_common_config_tip_shown: bool,
@@ -314,6 +626,28 @@ pub struct AppSettings {
     pub opencode_config_dir: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub openclaw_config_dir: Option<String>,
+    /// Skills SSOT 目录覆盖（默认 `~/.cc-switch/skills`），可用于按 profile 区分技能集
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skills_ssot_override: Option<String>,
+    /// 临时文件目录覆盖（环境变量 `CC_SWITCH_TMPDIR` 优先），系统临时分区较小时可指向大磁盘
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temp_dir: Option<String>,
+    /// 检测本地工具版本时额外尝试的参数（键为可执行文件名，如 `codex`），先于内置参数逐个尝试
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub tool_version_args: HashMap<String, Vec<String>>,
+    /// 本地环境检查中不展示的工具（按可执行文件名）；当前应用对应的工具始终保留
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disabled_env_tools: Vec<String>,
+    /// 提示词、配置等“查看完整内容”时的展示方式；外部程序不可用时回落为内嵌展示
+    #[serde(default)]
+    pub viewer: Viewer,
+    /// WebDAV 同步相关模块的日志级别，运行时修改立即生效，排查问题时无需设置 `RUST_LOG`；
+    /// 未设置时与其他模块一样跟随 `RUST_LOG` / `-v`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sync_log_level: Option<LogLevel>,
+    /// 本机的稳定随机标识，见 [`machine_uuid`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub machine_uuid: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub current_provider_claude: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -334,10 +668,21 @@ pub struct AppSettings {
     /// Skills 同步方式（auto|symlink|copy）
     #[serde(default)]
     pub skill_sync_method: crate::services::skill::SyncMethod,
+    /// WebDAV 下载后是否按新的 SSOT 重新同步各应用的 Skills；自行管理链接时可关闭
+    #[serde(default = "default_resync_skills_after_download")]
+    pub resync_skills_after_download: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub security: Option<SecuritySettings>,
+    /// 旧版单一 WebDAV 配置。加载时迁移为名为 `default` 的目标；保存时写入当前目标的副本，
+    /// 让只认识该字段的旧版本继续同步到同一位置
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub webdav_sync: Option<WebDavSyncSettings>,
+    /// 已保存的 WebDAV 同步目标（名称 -> 配置）
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub webdav_targets: HashMap<String, WebDavSyncSettings>,
+    /// 当前使用的 WebDAV 目标名称，同步服务只操作该目标
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_webdav_target: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub backup_retain_count: Option<u32>,
     /// Claude 自定义端点列表
@@ -356,6 +701,10 @@ fn default_minimize_to_tray_on_close() -> bool {
     true
 }
 
+fn default_resync_skills_after_download() -> bool {
+    true
+}
+
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
@@ -363,6 +712,7 @@ impl Default for AppSettings {
             minimize_to_tray_on_close: true,
             enable_claude_plugin_integration: false,
             skip_claude_onboarding: false,
+            switch_resets_live: false,
             provider_switch_common_config_tip_shown: false,
             provider_switch_common_config_tip_shown_codex: false,
             claude_config_dir: None,
@@ -370,6 +720,13 @@ impl Default for AppSettings {
             gemini_config_dir: None,
             opencode_config_dir: None,
             openclaw_config_dir: None,
+            skills_ssot_override: None,
+            temp_dir: None,
+            tool_version_args: HashMap::new(),
+            disabled_env_tools: Vec::new(),
+            viewer: Viewer::default(),
+            sync_log_level: None,
+            machine_uuid: None,
             current_provider_claude: None,
             current_provider_codex: None,
             current_provider_gemini: None,
@@ -379,8 +736,11 @@ impl Default for AppSettings {
             language: None,
             launch_on_startup: false,
             skill_sync_method: crate::services::skill::SyncMethod::default(),
+            resync_skills_after_download: true,
             security: None,
             webdav_sync: None,
+            webdav_targets: HashMap::new(),
+            active_webdav_target: None,
             backup_retain_count: None,
             custom_endpoints_claude: HashMap::new(),
             custom_endpoints_codex: HashMap::new(),
@@ -388,6 +748,48 @@ impl Default for AppSettings {
     }
 }
 
+/// settings.json 超过该大小时提示清理（正常情况下只有几 KB）
+const SETTINGS_FILE_WARN_BYTES: u64 = 1024 * 1024; // 1 MB
+
+/// 设置中任一集合的条目数超过该值时提示清理
+const SETTINGS_COLLECTION_WARN_ENTRIES: usize = 500;
+
+/// settings.json 的体积检查结果，见 [`settings_health`]；只用于提示，不影响读取与保存
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SettingsHealth {
+    /// 文件大小（字节），文件不存在时为 0
+    pub file_bytes: u64,
+    /// 条目数超过阈值的集合：(settings.json 中的字段路径, 条目数)
+    pub oversized_collections: Vec<(String, usize)>,
+}
+
+impl SettingsHealth {
+    pub fn is_healthy(&self) -> bool {
+        self.file_bytes <= SETTINGS_FILE_WARN_BYTES && self.oversized_collections.is_empty()
+    }
+
+    /// 每个问题一条提示，说明超出的项并引导用户清理
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings: Vec<String> = self
+            .oversized_collections
+            .iter()
+            .map(|(field, count)| {
+                format!(
+                    "settings.json 中的 {field} 有 {count} 项（超过 {SETTINGS_COLLECTION_WARN_ENTRIES}），可能因异常持续增长，请删除不再使用的条目 ({field} in settings.json has {count} entries (over {SETTINGS_COLLECTION_WARN_ENTRIES}) and may be growing because of a bug; prune entries you no longer use)"
+                )
+            })
+            .collect();
+        if self.file_bytes > SETTINGS_FILE_WARN_BYTES {
+            let kb = self.file_bytes / 1024;
+            let limit_kb = SETTINGS_FILE_WARN_BYTES / 1024;
+            warnings.push(format!(
+                "settings.json 大小为 {kb} KB（超过 {limit_kb} KB），会拖慢每次读取设置，请清理不再使用的配置 (settings.json is {kb} KB (over {limit_kb} KB) and slows every settings load; prune entries you no longer use)"
+            ));
+        }
+        warnings
+    }
+}
+
 impl AppSettings {
     fn settings_path() -> PathBuf {
         // settings.json 必须使用固定路径，不能被 app_config_dir 覆盖
@@ -434,6 +836,20 @@ impl AppSettings {
             .filter(|s| !s.is_empty())
             .map(|s| s.to_string());
 
+        self.skills_ssot_override = self
+            .skills_ssot_override
+            .as_ref()
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string());
+
+        self.temp_dir = self
+            .temp_dir
+            .as_ref()
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string());
+
         self.language = self
             .language
             .as_ref()
@@ -441,9 +857,35 @@ impl AppSettings {
             .filter(|s| matches!(*s, "en" | "zh"))
             .map(|s| s.to_string());
 
-        if let Some(webdav) = self.webdav_sync.as_mut() {
+        self.migrate_legacy_webdav_sync();
+        for webdav in self.webdav_targets.values_mut() {
             webdav.normalize();
         }
+        self.active_webdav_target = self
+            .active_webdav_target
+            .take()
+            .filter(|name| self.webdav_targets.contains_key(name));
+    }
+
+    /// 把旧版单一 `webdav_sync` 配置提升为 `default` 目标并设为当前目标。
+    ///
+    /// 已有目标时该字段只是保存时写入的当前目标副本，直接丢弃，避免复制出多余的 `default` 目标。
+    fn migrate_legacy_webdav_sync(&mut self) {
+        let Some(legacy) = self.webdav_sync.take() else {
+            return;
+        };
+        if self.webdav_targets.is_empty() {
+            self.webdav_targets
+                .insert(DEFAULT_WEBDAV_TARGET.to_string(), legacy);
+        }
+        if self.active_webdav_target.is_none() {
+            self.active_webdav_target = Some(DEFAULT_WEBDAV_TARGET.to_string());
+        }
+    }
+
+    fn active_webdav(&self) -> Option<&WebDavSyncSettings> {
+        self.webdav_targets
+            .get(self.active_webdav_target.as_deref()?)
     }
 
     fn normalize_loaded(&mut self) {
@@ -455,26 +897,76 @@ impl AppSettings {
         self.visible_apps.validate()
     }
 
+    /// 可能因异常无限增长的集合及其条目数，字段路径与 settings.json 中一致；
+    /// 每个 WebDAV 目标的同步状态各算一项
+    fn collection_sizes(&self) -> Vec<(String, usize)> {
+        let mut sizes: Vec<(String, usize)> = [
+            ("customEndpointsClaude", self.custom_endpoints_claude.len()),
+            ("customEndpointsCodex", self.custom_endpoints_codex.len()),
+            ("webdavTargets", self.webdav_targets.len()),
+            ("toolVersionArgs", self.tool_version_args.len()),
+            ("disabledEnvTools", self.disabled_env_tools.len()),
+        ]
+        .into_iter()
+        .map(|(field, count)| (field.to_string(), count))
+        .collect();
+
+        let mut targets: Vec<_> = self.webdav_targets.iter().collect();
+        targets.sort_by_key(|(name, _)| name.as_str());
+        for (name, target) in targets {
+            let hashes = target
+                .status
+                .last_skills_tree_hashes
+                .as_ref()
+                .map_or(0, BTreeMap::len);
+            sizes.push((
+                format!("webdavTargets.{name}.status.lastSkillsTreeHashes"),
+                hashes,
+            ));
+        }
+        sizes
+    }
+
+    fn health(&self, file_bytes: u64) -> SettingsHealth {
+        SettingsHealth {
+            file_bytes,
+            oversized_collections: self
+                .collection_sizes()
+                .into_iter()
+                .filter(|(_, count)| *count > SETTINGS_COLLECTION_WARN_ENTRIES)
+                .collect(),
+        }
+    }
+
     pub fn load() -> Self {
-        let path = Self::settings_path();
-        if let Ok(content) = fs::read_to_string(&path) {
-            match serde_json::from_str::<AppSettings>(&content) {
-                Ok(mut settings) => {
-                    settings.normalize_loaded();
-                    settings
-                }
-                Err(err) => {
-                    log::warn!(
-                        "解析设置文件失败，将使用默认设置。路径: {}, 错误: {}",
-                        path.display(),
-                        err
-                    );
-                    Self::default()
-                }
-            }
-        } else {
+        Self::load_strict().unwrap_or_else(|err| {
+            log::warn!("{err}，将使用默认设置");
             Self::default()
+        })
+    }
+
+    /// 严格读取 settings.json：文件不存在时返回默认设置，存在但无法读取或解析时返回错误。
+    ///
+    /// 同步等会把本地配置写到别处的操作应使用此方法，避免在设置文件损坏时以默认配置运行。
+    pub fn load_strict() -> Result<Self, AppError> {
+        let path = Self::settings_path();
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(AppError::io(&path, err)),
+        };
+        let mut settings = serde_json::from_str::<AppSettings>(&content).map_err(|err| {
+            AppError::localized(
+                "settings.parse_failed",
+                format!("解析设置文件失败: {}: {err}", path.display()),
+                format!("Failed to parse settings file {}: {err}", path.display()),
+            )
+        })?;
+        settings.normalize_loaded();
+        for warning in settings.health(content.len() as u64).warnings() {
+            log::warn!("{warning}");
         }
+        Ok(settings)
     }
 
     pub fn save(&self) -> Result<(), AppError> {
@@ -487,6 +979,7 @@ impl AppSettings {
             fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
         }
 
+        normalized.webdav_sync = normalized.active_webdav().cloned();
         let json = serde_json::to_string_pretty(&normalized)
             .map_err(|e| AppError::JsonSerialize { source: e })?;
         fs::write(&path, json).map_err(|e| AppError::io(&path, e))?;
@@ -505,7 +998,7 @@ pub(crate) fn reload_test_settings() {
     *guard = AppSettings::load();
 }
 
-fn resolve_override_path(raw: &str) -> PathBuf {
+pub(crate) fn resolve_override_path(raw: &str) -> PathBuf {
     if raw == "~" {
         if let Some(home) = home_dir() {
             return home;
@@ -527,13 +1020,89 @@ pub fn get_settings() -> AppSettings {
     settings_store().read().expect("读取设置锁失败").clone()
 }
 
+/// 检查当前设置的集合条目数与 settings.json 的大小，过大时在结果中给出清理提示
+pub fn settings_health() -> SettingsHealth {
+    let file_bytes = fs::metadata(AppSettings::settings_path())
+        .map(|meta| meta.len())
+        .unwrap_or(0);
+    settings_store()
+        .read()
+        .expect("读取设置锁失败")
+        .health(file_bytes)
+}
+
+/// 本机的稳定标识：首次调用时随机生成并写入 settings.json，之后保持不变。
+///
+/// 设备名称可能重名，需要区分设备时以此为准；该值只在本地使用，
+/// 不写入远端 manifest，以保持与 GUI 版的 manifest 格式兼容。
+pub fn machine_uuid() -> Result<String, AppError> {
+    let mut guard = settings_store().write().expect("写入设置锁失败");
+    if let Some(id) = guard
+        .machine_uuid
+        .as_deref()
+        .filter(|id| uuid::Uuid::parse_str(id).is_ok())
+    {
+        return Ok(id.to_string());
+    }
+    let id = uuid::Uuid::new_v4().to_string();
+    let mut next = guard.clone();
+    next.machine_uuid = Some(id.clone());
+    next.save()?;
+    *guard = next;
+    Ok(id)
+}
+
+/// 导出当前生效设置的脱敏模板（JSON），便于粘贴到问题反馈中。
+///
+/// WebDAV 用户名 / 密码与 base_url 中的账号信息、自定义端点地址都会被替换或移除，同步状态被清空；
+/// 输出仍是合法的 settings.json，补回敏感项后可作为起始配置导入。
+pub fn export_template() -> String {
+    const PLACEHOLDER: &str = "<redacted>";
+
+    let mut settings = get_settings();
+    settings.webdav_sync = None;
+    settings.machine_uuid = None;
+    for webdav in settings.webdav_targets.values_mut() {
+        for secret in [&mut webdav.username, &mut webdav.password] {
+            if !secret.is_empty() {
+                *secret = PLACEHOLDER.to_string();
+            }
+        }
+        if let Ok(mut url) = url::Url::parse(&webdav.base_url) {
+            if !url.username().is_empty() || url.password().is_some() {
+                let _ = url.set_username("");
+                let _ = url.set_password(None);
+                webdav.base_url = url.to_string();
+            }
+        }
+        webdav.status = WebDavSyncStatus::default();
+    }
+    // 端点以地址为键，脱敏时一并改为按添加顺序编号的键
+    for endpoints in [
+        &mut settings.custom_endpoints_claude,
+        &mut settings.custom_endpoints_codex,
+    ] {
+        let mut entries: Vec<_> = std::mem::take(endpoints).into_values().collect();
+        entries.sort_by_key(|endpoint| endpoint.added_at);
+        for (index, mut endpoint) in entries.into_iter().enumerate() {
+            endpoint.url = PLACEHOLDER.to_string();
+            endpoint.last_used = None;
+            endpoints.insert(format!("endpoint-{}", index + 1), endpoint);
+        }
+    }
+    serde_json::to_string_pretty(&settings).unwrap_or_else(|_| "{}".to_string())
+}
+
 pub fn update_settings(mut new_settings: AppSettings) -> Result<(), AppError> {
     new_settings.normalize_common();
     new_settings.validate()?;
     new_settings.save()?;
 
+    let sync_log_level = new_settings.sync_log_level;
     let mut guard = settings_store().write().expect("写入设置锁失败");
     *guard = new_settings;
+    drop(guard);
+    crate::logging::set_sync_log_level(sync_log_level);
     Ok(())
 }
 
@@ -598,6 +1167,14 @@ pub fn get_openclaw_override_dir() -> Option<PathBuf> {
         .map(|p| resolve_override_path(p))
 }
 
+pub fn get_skills_ssot_override_dir() -> Option<PathBuf> {
+    let settings = settings_store().read().ok()?;
+    settings
+        .skills_ssot_override
+        .as_deref()
+        .map(resolve_override_path)
+}
+
 pub fn get_current_provider(app_type: &AppType) -> Option<String> {
     let settings = settings_store().read().ok()?;
     match app_type {
@@ -666,6 +1243,20 @@ pub fn get_skill_sync_method() -> crate::services::skill::SyncMethod {
         .unwrap_or_default()
 }
 
+pub fn get_resync_skills_after_download() -> bool {
+    settings_store()
+        .read()
+        .map(|s| s.resync_skills_after_download)
+        .unwrap_or(true)
+}
+
+pub fn get_viewer() -> Viewer {
+    settings_store()
+        .read()
+        .map(|s| s.viewer)
+        .unwrap_or_default()
+}
+
 pub fn effective_backup_retain_count() -> usize {
     settings_store()
         .read()
@@ -684,34 +1275,108 @@ pub fn set_skill_sync_method(method: crate::services::skill::SyncMethod) -> Resu
     update_settings(settings)
 }
 
+/// 当前 WebDAV 目标的配置
 pub fn get_webdav_sync_settings() -> Option<WebDavSyncSettings> {
     settings_store()
         .read()
         .ok()
-        .and_then(|s| s.webdav_sync.clone())
+        .and_then(|s| s.active_webdav().cloned())
 }
 
+/// 写入当前 WebDAV 目标（没有当前目标时写入 `default` 并设为当前）；`None` 删除当前目标
 pub fn set_webdav_sync_settings(webdav_sync: Option<WebDavSyncSettings>) -> Result<(), AppError> {
     let mut settings = get_settings();
-    settings.webdav_sync = match webdav_sync {
+    let active = settings
+        .active_webdav_target
+        .clone()
+        .unwrap_or_else(|| DEFAULT_WEBDAV_TARGET.to_string());
+    match webdav_sync {
         Some(mut cfg) => {
             cfg.normalize();
             cfg.validate()?;
-            Some(cfg)
+            settings.webdav_targets.insert(active.clone(), cfg);
+            settings.active_webdav_target = Some(active);
+        }
+        None => {
+            settings.webdav_targets.remove(&active);
+            settings.active_webdav_target = None;
         }
-        None => None,
-    };
+    }
     update_settings(settings)
 }
 
 pub fn update_webdav_sync_status(status: WebDavSyncStatus) -> Result<(), AppError> {
     let mut settings = get_settings();
-    if let Some(ref mut webdav) = settings.webdav_sync {
+    if let Some(webdav) = settings
+        .active_webdav_target
+        .clone()
+        .and_then(|name| settings.webdav_targets.get_mut(&name))
+    {
         webdav.status = status;
     }
     update_settings(settings)
 }
 
+/// 已保存的 WebDAV 目标名称（按名称排序）
+pub fn list_webdav_targets() -> Vec<String> {
+    let mut names: Vec<String> = settings_store()
+        .read()
+        .map(|s| s.webdav_targets.keys().cloned().collect())
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+pub fn get_active_webdav_target() -> Option<String> {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.active_webdav_target.clone())
+}
+
+pub fn get_webdav_target(name: &str) -> Option<WebDavSyncSettings> {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.webdav_targets.get(name.trim()).cloned())
+}
+
+/// 切换当前 WebDAV 目标，目标必须已存在
+pub fn set_active_webdav_target(name: &str) -> Result<(), AppError> {
+    let name = name.trim();
+    let mut settings = get_settings();
+    if !settings.webdav_targets.contains_key(name) {
+        return Err(AppError::localized(
+            "webdav.target.not_found",
+            format!("WebDAV 目标不存在: {name}"),
+            format!("WebDAV target not found: {name}"),
+        ));
+    }
+    settings.active_webdav_target = Some(name.to_string());
+    update_settings(settings)
+}
+
+/// 新增或更新命名 WebDAV 目标；尚无当前目标时将其设为当前目标
+pub fn upsert_webdav_target(name: &str, mut webdav: WebDavSyncSettings) -> Result<(), AppError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::localized(
+            "webdav.target.name_empty",
+            "WebDAV 目标名称不能为空",
+            "WebDAV target name must not be empty",
+        ));
+    }
+    webdav.normalize();
+    webdav.validate()?;
+
+    let mut settings = get_settings();
+    settings.webdav_targets.insert(name.to_string(), webdav);
+    if settings.active_webdav_target.is_none() {
+        settings.active_webdav_target = Some(name.to_string());
+    }
+    update_settings(settings)
+}
+
 pub fn webdav_jianguoyun_preset(username: &str, password: &str) -> WebDavSyncSettings {
     WebDavSyncSettings::jianguoyun_preset(username, password)
 }
@@ -723,6 +1388,13 @@ pub fn get_skip_claude_onboarding() -> bool {
         .unwrap_or(false)
 }
 
+pub fn get_switch_resets_live() -> bool {
+    settings_store()
+        .read()
+        .map(|s| s.switch_resets_live)
+        .unwrap_or(false)
+}
+
 pub fn get_enable_claude_plugin_integration() -> bool {
     settings_store()
         .read()
@@ -762,6 +1434,12 @@ pub fn set_enable_claude_plugin_integration(enabled: bool) -> Result<(), AppErro
     update_settings(settings)
 }
 
+pub fn set_switch_resets_live(enabled: bool) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.switch_resets_live = enabled;
+    update_settings(settings)
+}
+
 pub fn set_skip_claude_onboarding(enabled: bool) -> Result<(), AppError> {
     if enabled {
         crate::claude_mcp::set_has_completed_onboarding()?;
@@ -96,6 +96,11 @@ impl AppState {
                 .map_err(AppError::Config)?;
         }
 
+        // skills 恢复中途崩溃时 SSOT 可能缺失，修复失败不应阻止启动
+        if let Err(e) = crate::services::SkillService::recover_interrupted_restore() {
+            log::warn!("修复中断的 skills 恢复失败: {e}");
+        }
+
         Ok(state)
     }
 
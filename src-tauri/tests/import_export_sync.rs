@@ -3,8 +3,9 @@ use serde_json::json;
 use std::{fs, path::Path};
 
 use cc_switch_lib::{
-    get_claude_settings_path, read_json_file, AppError, AppType, ConfigService, Database,
-    MultiAppConfig, Provider, ProviderMeta, ProviderService,
+    get_claude_settings_path, read_json_file, update_settings, AppError, AppSettings, AppType,
+    ConfigSections, ConfigService, Database, MultiAppConfig, Provider, ProviderMeta,
+    ProviderService,
 };
 
 #[path = "support.rs"]
@@ -67,6 +68,161 @@ fn sync_claude_provider_writes_live_settings() {
     );
 }
 
+fn claude_config_with_current(provider_config: serde_json::Value) -> MultiAppConfig {
+    let mut config = MultiAppConfig::default();
+    let manager = config
+        .get_manager_mut(&AppType::Claude)
+        .expect("claude manager");
+    manager.providers.insert(
+        "prov-1".to_string(),
+        Provider::with_id(
+            "prov-1".to_string(),
+            "Test Claude".to_string(),
+            provider_config,
+            None,
+        ),
+    );
+    manager.current = "prov-1".to_string();
+    config
+}
+
+#[test]
+fn live_provider_fingerprint_matches_freshly_synced_provider() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = claude_config_with_current(json!({
+        "env": {
+            "ANTHROPIC_AUTH_TOKEN": "sk-live-key",
+            "ANTHROPIC_BASE_URL": "https://api.test/"
+        }
+    }));
+    ConfigService::sync_current_providers_to_live(&mut config).expect("sync live settings");
+
+    let provider = &config.get_manager(&AppType::Claude).unwrap().providers["prov-1"];
+    let stored = ConfigService::provider_fingerprint(&AppType::Claude, provider)
+        .expect("provider fingerprint");
+    let live = ConfigService::live_provider_fingerprint(AppType::Claude).expect("live fingerprint");
+    assert_eq!(stored, live);
+    assert_eq!(live.base_url.as_deref(), Some("https://api.test"));
+    let key_hash = live.key_hash.expect("key hash");
+    assert_eq!(key_hash.len(), 12);
+    assert!(!key_hash.contains("sk-live-key"));
+}
+
+#[test]
+fn live_provider_fingerprint_differs_after_hand_edit() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = claude_config_with_current(json!({
+        "env": {
+            "ANTHROPIC_AUTH_TOKEN": "sk-live-key",
+            "ANTHROPIC_BASE_URL": "https://api.test"
+        }
+    }));
+    ConfigService::sync_current_providers_to_live(&mut config).expect("sync live settings");
+
+    let edited = json!({
+        "env": {
+            "ANTHROPIC_AUTH_TOKEN": "sk-other-key",
+            "ANTHROPIC_BASE_URL": "https://api.test"
+        }
+    });
+    fs::write(
+        get_claude_settings_path(),
+        serde_json::to_vec_pretty(&edited).unwrap(),
+    )
+    .expect("hand-edit live settings");
+
+    let provider = &config.get_manager(&AppType::Claude).unwrap().providers["prov-1"];
+    let stored = ConfigService::provider_fingerprint(&AppType::Claude, provider);
+    let live = ConfigService::live_provider_fingerprint(AppType::Claude).expect("live fingerprint");
+    assert_eq!(stored.as_ref().map(|f| &f.base_url), Some(&live.base_url));
+    assert_ne!(stored, Some(live));
+    assert!(ConfigService::live_provider_fingerprint(AppType::OpenCode).is_none());
+}
+
+fn opencode_config_with_current(provider_id: &str) -> MultiAppConfig {
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::OpenCode);
+    let provider = Provider::with_id(
+        provider_id.to_string(),
+        "OpenCode Test".to_string(),
+        json!({
+            "npm": "@ai-sdk/openai-compatible",
+            "name": "OpenCode Test",
+            "options": {
+                "baseURL": "https://opencode.test/v1",
+                "apiKey": "sk-opencode"
+            },
+            "models": {
+                "gpt-4o": { "name": "GPT-4o" }
+            }
+        }),
+        None,
+    );
+    let manager = config
+        .get_manager_mut(&AppType::OpenCode)
+        .expect("opencode manager");
+    manager.providers.insert(provider_id.to_string(), provider);
+    manager.current = provider_id.to_string();
+    config
+}
+
+fn assert_opencode_provider_shape(path: &Path, provider_id: &str) {
+    let live: serde_json::Value = read_json_file(path).expect("read opencode live config");
+    let entry = &live["provider"][provider_id];
+    assert_eq!(entry["npm"], "@ai-sdk/openai-compatible");
+    assert_eq!(entry["options"]["baseURL"], "https://opencode.test/v1");
+    assert_eq!(entry["options"]["apiKey"], "sk-opencode");
+    assert_eq!(entry["models"]["gpt-4o"]["name"], "GPT-4o");
+}
+
+#[test]
+fn sync_opencode_provider_writes_live_config() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let mut config = opencode_config_with_current("oc-1");
+    ConfigService::sync_current_providers_to_live(&mut config).expect("sync opencode live");
+
+    let path = home.join(".config").join("opencode").join("opencode.json");
+    assert_opencode_provider_shape(&path, "oc-1");
+}
+
+#[test]
+fn sync_opencode_provider_respects_config_dir_override() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let custom_dir = home.join("custom-opencode");
+    let settings = AppSettings {
+        opencode_config_dir: Some(custom_dir.display().to_string()),
+        ..AppSettings::default()
+    };
+    update_settings(settings).expect("save opencode override");
+
+    let mut config = opencode_config_with_current("oc-2");
+    let result = ConfigService::sync_current_providers_to_live(&mut config);
+    update_settings(AppSettings::default()).expect("reset settings");
+    result.expect("sync opencode live");
+
+    assert_opencode_provider_shape(&custom_dir.join("opencode.json"), "oc-2");
+    assert!(
+        !home
+            .join(".config")
+            .join("opencode")
+            .join("opencode.json")
+            .exists(),
+        "default location should be untouched when an override is set"
+    );
+}
+
 #[test]
 fn sync_codex_provider_writes_auth_and_config() {
     let _guard = lock_test_mutex();
@@ -853,6 +1009,107 @@ fn create_backup_retains_only_latest_entries() {
     );
 }
 
+#[test]
+fn list_backups_returns_newest_first_with_labels_and_sizes() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    let db_path = home.join(".cc-switch").join("cc-switch.db");
+    state_from_config(MultiAppConfig::default())
+        .save()
+        .expect("persist db");
+
+    let backups_dir = home.join(".cc-switch").join("backups");
+    fs::create_dir_all(&backups_dir).expect("create backups dir");
+    fs::write(
+        backups_dir.join("backup_20200101_000000.sql"),
+        "-- legacy backup\n",
+    )
+    .expect("seed legacy backup");
+
+    let labeled_id =
+        ConfigService::create_backup(&db_path, Some("before-import 2024/11/01".to_string()))
+            .expect("create labeled backup");
+    assert!(
+        labeled_id.starts_with("before-import 2024_11_01_"),
+        "label should be sanitized for the file name, got {labeled_id}"
+    );
+    std::thread::sleep(std::time::Duration::from_millis(1100));
+    let plain_id = ConfigService::create_backup(&db_path, None).expect("create plain backup");
+
+    let backups = ConfigService::list_backups(&db_path).expect("list backups");
+    let ids: Vec<&str> = backups.iter().map(|b| b.id.as_str()).collect();
+    assert_eq!(
+        ids,
+        vec![
+            plain_id.as_str(),
+            labeled_id.as_str(),
+            "backup_20200101_000000"
+        ],
+        "backups should be listed newest first"
+    );
+
+    let labeled = &backups[1];
+    assert_eq!(labeled.label.as_deref(), Some("before-import 2024/11/01"));
+    assert!(labeled
+        .display_name
+        .starts_with("before-import 2024/11/01 ("));
+    assert_eq!(
+        labeled.size,
+        fs::metadata(&labeled.path).expect("stat backup").len()
+    );
+    assert!(labeled.created_at >= backups[2].created_at);
+    assert_eq!(backups[0].label, None);
+    assert_eq!(backups[2].label, None, "legacy backups have no label");
+    assert_eq!(backups[2].created_at, 1_577_836_800);
+}
+
+#[test]
+fn restore_from_backup_id_rejects_tampered_backup() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    let db_path = home.join(".cc-switch").join("cc-switch.db");
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "p1".to_string();
+        manager.providers.insert(
+            "p1".to_string(),
+            Provider::with_id(
+                "p1".to_string(),
+                "Test Claude".to_string(),
+                json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "test-key" } }),
+                None,
+            ),
+        );
+    }
+    let state = state_from_config(config);
+    state.save().expect("persist db");
+
+    let backup_id =
+        ConfigService::create_backup(&db_path, Some("checked".to_string())).expect("create backup");
+    let backup_path = home
+        .join(".cc-switch")
+        .join("backups")
+        .join(format!("{backup_id}.sql"));
+    let original = fs::read_to_string(&backup_path).expect("read backup");
+    fs::write(&backup_path, format!("{original}\n-- tampered\n")).expect("tamper backup");
+
+    let err = ConfigService::restore_from_backup_id(&backup_id, &state)
+        .expect_err("tampered backup must not be restored");
+    assert!(
+        err.to_string().contains("checksum mismatch"),
+        "unexpected error: {err}"
+    );
+
+    fs::write(&backup_path, original).expect("restore original backup bytes");
+    ConfigService::restore_from_backup_id(&backup_id, &state)
+        .expect("intact backup should restore");
+}
+
 #[test]
 fn import_config_from_path_overwrites_state_and_creates_backup() {
     let _guard = lock_test_mutex();
@@ -969,6 +1226,404 @@ fn import_config_from_path_missing_file_produces_io_error() {
     }
 }
 
+fn export_bundle_with_one_provider(path: &Path) {
+    let db = Database::memory().expect("create export db");
+    let provider = Provider::with_id(
+        "p1".to_string(),
+        "Test Claude".to_string(),
+        json!({
+            "env": { "ANTHROPIC_AUTH_TOKEN": "test-key" }
+        }),
+        None,
+    );
+    db.save_provider(AppType::Claude.as_str(), &provider)
+        .expect("seed provider");
+    db.export_sql(path).expect("export sql");
+}
+
+#[test]
+fn inspect_bundle_reports_counts_without_importing() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let bundle_path = home.join("bundle.sql");
+    export_bundle_with_one_provider(&bundle_path);
+
+    let report = ConfigService::inspect_bundle(&bundle_path).expect("inspect should succeed");
+    assert_eq!(report.providers, 1);
+    assert_eq!(report.mcp_servers, 0);
+    assert_eq!(report.prompts, 0);
+    assert!(report.schema_version <= report.supported_schema_version);
+    assert!(
+        report.exported_at.is_some(),
+        "header timestamp should be read"
+    );
+    assert!(
+        report.warnings.is_empty(),
+        "unexpected warnings: {:?}",
+        report.warnings
+    );
+    assert!(
+        !home.join(".cc-switch").join("cc-switch.db").exists(),
+        "inspect must not create or touch the local database"
+    );
+}
+
+#[test]
+fn inspect_bundle_warns_about_newer_schema_version() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let bundle_path = home.join("bundle.sql");
+    export_bundle_with_one_provider(&bundle_path);
+    let sql = fs::read_to_string(&bundle_path).expect("read bundle");
+    let current = ConfigService::inspect_bundle(&bundle_path)
+        .expect("inspect original")
+        .schema_version;
+    let bumped = sql.replace(
+        &format!("PRAGMA user_version={current};"),
+        "PRAGMA user_version=99;",
+    );
+    assert_ne!(bumped, sql, "export should set user_version");
+    fs::write(&bundle_path, bumped).expect("write bumped bundle");
+
+    let report = ConfigService::inspect_bundle(&bundle_path).expect("inspect should succeed");
+    assert_eq!(report.schema_version, 99);
+    assert_eq!(report.providers, 1);
+    assert!(
+        report.warnings.iter().any(|w| w.contains("v99")),
+        "expected newer schema warning, got {:?}",
+        report.warnings
+    );
+}
+
+#[test]
+fn inspect_bundle_reports_line_of_corrupt_statement() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let bundle_path = home.join("corrupt.sql");
+    fs::write(
+        &bundle_path,
+        "-- CC Switch SQLite 导出\n-- user_version: 6\nPRAGMA user_version=6;\nCREATE TABLE providers (id TEXT);\nINSERT INTO providers VALUES ('p1';\n",
+    )
+    .expect("write corrupt bundle");
+
+    let err = ConfigService::inspect_bundle(&bundle_path).expect_err("inspect should fail");
+    match err {
+        AppError::Localized { key, en, .. } => {
+            assert_eq!(key, "backup.sql.parse_failed");
+            assert!(en.contains("line 5"), "expected line number, got {en}");
+        }
+        other => panic!("expected Localized parse error, got {other:?}"),
+    }
+}
+
+/// 在本地端口提供 `/bundle.sql`（SQL 导出）与 `/login`（HTML 登录页），返回基础 URL；
+/// 丢弃返回的 sender 即关闭服务
+fn serve_bundle_and_login_page(bundle: Vec<u8>) -> (String, tokio::sync::oneshot::Sender<()>) {
+    use axum::{http::header::CONTENT_TYPE, routing::get, Router};
+
+    let listener = std::net::TcpListener::bind(("127.0.0.1", 0)).expect("bind test listener");
+    let port = listener.local_addr().expect("listener address").port();
+    listener
+        .set_nonblocking(true)
+        .expect("set listener nonblocking");
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+
+    std::thread::spawn(move || {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("build test runtime");
+        runtime.block_on(async move {
+            let listener =
+                tokio::net::TcpListener::from_std(listener).expect("convert test listener");
+            let app = Router::new()
+                .route(
+                    "/bundle.sql",
+                    get(move || async move { ([(CONTENT_TYPE, "application/sql")], bundle) }),
+                )
+                .route(
+                    "/login",
+                    get(|| async {
+                        (
+                            [(CONTENT_TYPE, "text/html; charset=utf-8")],
+                            "<!DOCTYPE html><html><body>Sign in</body></html>",
+                        )
+                    }),
+                );
+            axum::serve(listener, app)
+                .with_graceful_shutdown(async move {
+                    let _ = shutdown_rx.await;
+                })
+                .await
+                .expect("run test server");
+        });
+    });
+
+    (format!("http://127.0.0.1:{port}"), shutdown_tx)
+}
+
+fn state_with_local_provider() -> cc_switch_lib::AppState {
+    let app_state = state_from_config(MultiAppConfig::default());
+    let provider = Provider::with_id(
+        "p-old".to_string(),
+        "Old Claude".to_string(),
+        json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "old-key" } }),
+        None,
+    );
+    app_state
+        .db
+        .save_provider(AppType::Claude.as_str(), &provider)
+        .expect("seed local provider");
+    app_state
+}
+
+#[test]
+fn import_from_url_imports_bundle_with_backup() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let bundle_path = home.join("bundle.sql");
+    export_bundle_with_one_provider(&bundle_path);
+    let (base_url, _server) =
+        serve_bundle_and_login_page(fs::read(&bundle_path).expect("read bundle"));
+    let app_state = state_with_local_provider();
+    let url = format!("{base_url}/bundle.sql");
+
+    let err = ConfigService::import_from_url(&url, false, &app_state)
+        .expect_err("existing local data should need overwrite");
+    match err {
+        AppError::Localized { key, .. } => assert_eq!(key, "config.import_url.would_overwrite"),
+        other => panic!("expected Localized overwrite error, got {other:?}"),
+    }
+
+    let (backup_id, report) =
+        ConfigService::import_from_url(&url, true, &app_state).expect("import should succeed");
+    assert_eq!(report.providers, 1);
+    assert!(
+        home.join(".cc-switch")
+            .join("backups")
+            .join(format!("{backup_id}.sql"))
+            .exists(),
+        "pre-import backup should be created"
+    );
+    let providers = app_state
+        .db
+        .get_all_providers(AppType::Claude.as_str())
+        .expect("read providers");
+    assert!(
+        providers.contains_key("p1"),
+        "bundle provider should be imported"
+    );
+    assert!(
+        !providers.contains_key("p-old"),
+        "local data should be replaced"
+    );
+}
+
+#[test]
+fn import_from_url_rejects_bundle_that_attaches_other_databases() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let bundle_path = home.join("bundle.sql");
+    export_bundle_with_one_provider(&bundle_path);
+    let attached = home.join("written-by-bundle.db");
+    let hostile = fs::read_to_string(&bundle_path)
+        .expect("read bundle")
+        .replacen(
+            "COMMIT;\n",
+            &format!(
+                "ATTACH DATABASE '{}' AS x;\nCREATE TABLE x.t(a);\nCOMMIT;\n",
+                attached.display()
+            ),
+            1,
+        );
+    let (base_url, _server) = serve_bundle_and_login_page(hostile.into_bytes());
+    let app_state = state_with_local_provider();
+
+    let err = ConfigService::import_from_url(&format!("{base_url}/bundle.sql"), true, &app_state)
+        .expect_err("hostile bundle must be rejected");
+    match err {
+        AppError::Localized { key, .. } => assert_eq!(key, "backup.sql.statement_not_allowed"),
+        other => panic!("expected Localized disallowed statement error, got {other:?}"),
+    }
+    assert!(
+        !attached.exists(),
+        "inspecting a bundle must not write files"
+    );
+    assert!(
+        app_state
+            .db
+            .get_all_providers(AppType::Claude.as_str())
+            .expect("read providers")
+            .contains_key("p-old"),
+        "local data must be untouched"
+    );
+}
+
+#[test]
+fn import_from_url_rejects_html_and_non_http_urls() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let (base_url, _server) = serve_bundle_and_login_page(Vec::new());
+    let app_state = state_with_local_provider();
+
+    let err = ConfigService::import_from_url(&format!("{base_url}/login"), true, &app_state)
+        .expect_err("HTML page should be rejected");
+    match err {
+        AppError::Localized { key, en, .. } => {
+            assert_eq!(key, "config.import_url.not_bundle");
+            assert!(en.contains("text/html"), "unexpected message: {en}");
+        }
+        other => panic!("expected Localized HTML error, got {other:?}"),
+    }
+
+    let err = ConfigService::import_from_url("file:///etc/passwd", true, &app_state)
+        .expect_err("non-http URL should be rejected");
+    match err {
+        AppError::Localized { key, .. } => {
+            assert_eq!(key, "config.import_url.unsupported_scheme")
+        }
+        other => panic!("expected Localized scheme error, got {other:?}"),
+    }
+
+    assert!(
+        app_state
+            .db
+            .get_all_providers(AppType::Claude.as_str())
+            .expect("read providers")
+            .contains_key("p-old"),
+        "rejected imports must leave local data untouched"
+    );
+}
+
+fn save_mcp_server(state: &cc_switch_lib::AppState, id: &str) {
+    state
+        .db
+        .save_mcp_server(&cc_switch_lib::McpServer {
+            id: id.to_string(),
+            name: id.to_string(),
+            server: json!({ "type": "stdio", "command": "echo" }),
+            apps: cc_switch_lib::McpApps::default(),
+            description: None,
+            homepage: None,
+            docs: None,
+            tags: Vec::new(),
+        })
+        .expect("seed mcp server");
+}
+
+#[test]
+fn export_mcp_section_merges_into_config_without_touching_providers() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    // 源配置：一个供应商和一个 MCP 服务器，只导出 MCP 分区
+    let bundle_path = home.join("mcp-only.sql");
+    {
+        let source = state_from_config(MultiAppConfig::default());
+        source
+            .db
+            .save_provider(
+                AppType::Claude.as_str(),
+                &Provider::with_id(
+                    "shared-provider".to_string(),
+                    "Shared Claude".to_string(),
+                    json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "shared-key" } }),
+                    None,
+                ),
+            )
+            .expect("seed source provider");
+        save_mcp_server(&source, "shared-mcp");
+        let sections = ConfigSections {
+            mcp: true,
+            ..ConfigSections::default()
+        };
+        ConfigService::export_sections(sections, &bundle_path).expect("export mcp section");
+    }
+
+    let report = ConfigService::inspect_bundle(&bundle_path).expect("inspect partial bundle");
+    assert_eq!(report.sections.map(|s| s.names()), Some(vec!["mcp"]));
+    assert_eq!(report.mcp_servers, 1);
+    assert_eq!(report.providers, 0, "providers must not be exported");
+    assert!(
+        report.warnings.is_empty(),
+        "unexpected warnings: {:?}",
+        report.warnings
+    );
+
+    // 目标配置：已有自己的供应商（当前）与 MCP 服务器
+    reset_test_fs();
+    let app_state = state_with_local_provider();
+    app_state
+        .db
+        .set_current_provider(AppType::Claude.as_str(), "p-old")
+        .expect("seed current provider");
+    save_mcp_server(&app_state, "local-mcp");
+
+    let backup_id = ConfigService::import_config_from_path(&bundle_path, &app_state)
+        .expect("partial import should succeed");
+    assert!(!backup_id.is_empty(), "pre-import backup should be created");
+
+    let providers = app_state
+        .db
+        .get_all_providers(AppType::Claude.as_str())
+        .expect("read providers");
+    assert_eq!(
+        providers.keys().collect::<Vec<_>>(),
+        vec!["p-old"],
+        "providers must be untouched by an MCP-only import"
+    );
+    assert_eq!(
+        app_state
+            .db
+            .get_current_provider(AppType::Claude.as_str())
+            .expect("read current provider")
+            .as_deref(),
+        Some("p-old")
+    );
+    let servers = app_state
+        .db
+        .get_all_mcp_servers()
+        .expect("read mcp servers");
+    assert!(servers.contains_key("local-mcp"), "local MCP server kept");
+    assert!(
+        servers.contains_key("shared-mcp"),
+        "bundle MCP server merged"
+    );
+}
+
+#[test]
+fn export_sections_rejects_empty_selection_and_unknown_names() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let err = ConfigService::export_sections(ConfigSections::default(), &home.join("empty.sql"))
+        .expect_err("empty selection should fail");
+    assert!(err.to_string().contains("at least one"), "{err}");
+
+    let err = ConfigSections::parse("mcp,skills").expect_err("unknown section");
+    assert!(err.to_string().contains("skills"), "{err}");
+    assert_eq!(
+        ConfigSections::parse(" prompts, mcp ")
+            .expect("parse sections")
+            .names(),
+        vec!["mcp", "prompts"]
+    );
+}
+
 #[test]
 fn sync_gemini_packycode_sets_security_selected_type() {
     let _guard = lock_test_mutex();
@@ -507,3 +507,103 @@ fn upsert_server_disables_app_removes_from_gemini_live() {
         "upsert with Gemini disabled should remove it from ~/.gemini/settings.json, got: {settings_text}"
     );
 }
+
+fn server_with_apps(id: &str, apps: McpApps) -> McpServer {
+    McpServer {
+        id: id.to_string(),
+        name: id.to_string(),
+        server: json!({ "type": "stdio", "command": "echo" }),
+        apps,
+        description: None,
+        homepage: None,
+        docs: None,
+        tags: Vec::new(),
+    }
+}
+
+fn state_with_servers(servers: Vec<McpServer>) -> AppState {
+    let mut config = MultiAppConfig::default();
+    config.mcp.servers = Some(
+        servers
+            .into_iter()
+            .map(|server| (server.id.clone(), server))
+            .collect(),
+    );
+    state_from_config(config)
+}
+
+#[test]
+fn delete_matching_removes_only_servers_matching_the_glob() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let state = state_with_servers(vec![
+        server_with_apps("test-a", McpApps::default()),
+        server_with_apps("test-b", McpApps::default()),
+        server_with_apps("prod-test", McpApps::default()),
+        server_with_apps("test", McpApps::default()),
+    ]);
+
+    let deleted = McpService::delete_matching(&state, "test-*", false).expect("delete matching");
+
+    assert_eq!(deleted, vec!["test-a".to_string(), "test-b".to_string()]);
+    let mut remaining: Vec<_> = McpService::get_all_servers(&state)
+        .expect("servers")
+        .into_keys()
+        .collect();
+    remaining.sort();
+    assert_eq!(remaining, vec!["prod-test".to_string(), "test".to_string()]);
+}
+
+#[test]
+fn delete_matching_refuses_enabled_servers_unless_forced() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let enabled = McpApps {
+        claude: true,
+        ..McpApps::default()
+    };
+    let state = state_with_servers(vec![
+        server_with_apps("test-off", McpApps::default()),
+        server_with_apps("test-on", enabled),
+    ]);
+
+    let err = McpService::delete_matching(&state, "test-?*", false)
+        .expect_err("enabled server should block the deletion");
+    assert!(
+        err.to_string().contains("test-on"),
+        "unexpected error: {err}"
+    );
+    assert_eq!(
+        McpService::get_all_servers(&state).expect("servers").len(),
+        2
+    );
+
+    let deleted = McpService::delete_matching(&state, "test-[!x]*", true).expect("forced delete");
+    assert_eq!(deleted, vec!["test-off".to_string(), "test-on".to_string()]);
+}
+
+#[test]
+fn delete_matching_rejects_invalid_patterns() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let state = state_with_servers(vec![server_with_apps("test-a", McpApps::default())]);
+
+    for pattern in ["test-[ab", "", "[z-a]"] {
+        let err = McpService::delete_matching(&state, pattern, false)
+            .expect_err("invalid pattern should be rejected");
+        assert!(
+            err.to_string().contains("Invalid pattern"),
+            "unexpected error for {pattern:?}: {err}"
+        );
+    }
+    assert_eq!(
+        McpService::get_all_servers(&state).expect("servers").len(),
+        1
+    );
+}
@@ -2,8 +2,9 @@ use serde_json::json;
 use std::collections::HashMap;
 
 use cc_switch_lib::{
-    get_claude_settings_path, read_json_file, write_codex_live_atomic, AppError, AppType, McpApps,
-    McpServer, MultiAppConfig, Provider, ProviderMeta, ProviderService,
+    get_claude_mcp_path, get_claude_settings_path, read_json_file, set_switch_resets_live,
+    write_codex_live_atomic, AppError, AppType, McpApps, McpServer, MultiAppConfig, Provider,
+    ProviderMeta, ProviderService,
 };
 
 #[path = "support.rs"]
@@ -4034,3 +4035,135 @@ fn provider_service_delete_current_provider_returns_error() {
         other => panic!("expected Config error, got {other:?}"),
     }
 }
+
+fn seed_claude_switch_with_mcp_and_prompt() -> cc_switch_lib::AppState {
+    let mcp_path = get_claude_mcp_path();
+    std::fs::write(
+        &mcp_path,
+        serde_json::to_string_pretty(&json!({
+            "mcpServers": {
+                "manual-server": { "type": "stdio", "command": "manual" }
+            }
+        }))
+        .expect("serialize claude mcp"),
+    )
+    .expect("seed claude mcp config");
+
+    let settings_path = get_claude_settings_path();
+    let claude_dir = settings_path.parent().expect("claude dir");
+    std::fs::create_dir_all(claude_dir).expect("create claude dir");
+    std::fs::write(claude_dir.join("CLAUDE.md"), "hand-written notes").expect("seed CLAUDE.md");
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "old-provider".to_string();
+        for (id, key) in [("old-provider", "old-key"), ("new-provider", "new-key")] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(
+                    id.to_string(),
+                    id.to_string(),
+                    json!({ "env": { "ANTHROPIC_API_KEY": key } }),
+                    None,
+                ),
+            );
+        }
+    }
+    config.mcp.servers = Some(HashMap::from([(
+        "managed-server".to_string(),
+        McpServer {
+            id: "managed-server".to_string(),
+            name: "Managed Server".to_string(),
+            server: json!({ "type": "stdio", "command": "managed" }),
+            apps: McpApps {
+                claude: true,
+                codex: false,
+                gemini: false,
+                opencode: false,
+            },
+            description: None,
+            homepage: None,
+            docs: None,
+            tags: Vec::new(),
+        },
+    )]));
+    config.prompts.claude.prompts.insert(
+        "team".to_string(),
+        serde_json::from_value(json!({
+            "id": "team",
+            "name": "Team",
+            "content": "managed prompt",
+            "enabled": true
+        }))
+        .expect("build prompt"),
+    );
+
+    state_from_config(config)
+}
+
+fn claude_live_mcp_ids() -> Vec<String> {
+    let root: serde_json::Value = read_json_file(&get_claude_mcp_path()).expect("read claude mcp");
+    let mut ids: Vec<String> = root
+        .get("mcpServers")
+        .and_then(|v| v.as_object())
+        .map(|servers| servers.keys().cloned().collect())
+        .unwrap_or_default();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn provider_service_switch_keeps_unmanaged_live_entries_by_default() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let state = seed_claude_switch_with_mcp_and_prompt();
+    ProviderService::switch(&state, AppType::Claude, "new-provider").expect("switch provider");
+
+    assert_eq!(
+        claude_live_mcp_ids(),
+        vec!["managed-server".to_string(), "manual-server".to_string()],
+        "additive switch should only upsert managed MCP servers"
+    );
+    let prompt_path = get_claude_settings_path()
+        .parent()
+        .expect("claude dir")
+        .join("CLAUDE.md");
+    assert_eq!(
+        std::fs::read_to_string(prompt_path).expect("read CLAUDE.md"),
+        "hand-written notes",
+        "prompt file should be left untouched"
+    );
+}
+
+#[test]
+fn provider_service_switch_resets_mcp_and_prompt_live_when_enabled() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    set_switch_resets_live(true).expect("enable switch_resets_live");
+    let state = seed_claude_switch_with_mcp_and_prompt();
+    let result = ProviderService::switch(&state, AppType::Claude, "new-provider");
+    set_switch_resets_live(false).expect("restore switch_resets_live");
+    result.expect("switch provider");
+
+    assert_eq!(
+        claude_live_mcp_ids(),
+        vec!["managed-server".to_string()],
+        "unmanaged MCP servers should be cleared from live config"
+    );
+    let prompt_path = get_claude_settings_path()
+        .parent()
+        .expect("claude dir")
+        .join("CLAUDE.md");
+    assert_eq!(
+        std::fs::read_to_string(prompt_path).expect("read CLAUDE.md"),
+        "managed prompt",
+        "prompt file should be rewritten from the enabled prompt"
+    );
+}
@@ -106,6 +106,12 @@ mod error {
         },
         #[error("锁获取失败: {0}")]
         Lock(String),
+        #[error("{zh} ({en})")]
+        Localized {
+            key: &'static str,
+            zh: String,
+            en: String,
+        },
     }
 
     impl AppError {
@@ -115,6 +121,14 @@ mod error {
                 source,
             }
         }
+
+        pub fn localized(key: &'static str, zh: impl Into<String>, en: impl Into<String>) -> Self {
+            Self::Localized {
+                key,
+                zh: zh.into(),
+                en: en.into(),
+            }
+        }
     }
 
     impl<T> From<PoisonError<T>> for AppError {
@@ -124,6 +138,12 @@ mod error {
     }
 }
 
+mod logging {
+    pub fn set_sync_log_level(level: Option<crate::settings_impl::LogLevel>) {
+        let _ = level.map(crate::settings_impl::LogLevel::to_level_filter);
+    }
+}
+
 mod services {
     pub mod skill {
         use serde::{Deserialize, Serialize};
@@ -138,6 +158,13 @@ mod services {
         }
     }
 
+    pub mod webdav_sync {
+        #[allow(dead_code)]
+        pub fn supported_protocol_versions() -> std::ops::RangeInclusive<u32> {
+            2..=2
+        }
+    }
+
     pub mod webdav {
         use crate::error::AppError;
         use url::Url;
@@ -109,6 +109,12 @@ mod error {
         },
         #[error("锁获取失败: {0}")]
         Lock(String),
+        #[error("{zh} ({en})")]
+        Localized {
+            key: &'static str,
+            zh: String,
+            en: String,
+        },
     }
 
     impl AppError {
@@ -118,6 +124,14 @@ mod error {
                 source,
             }
         }
+
+        pub fn localized(key: &'static str, zh: impl Into<String>, en: impl Into<String>) -> Self {
+            Self::Localized {
+                key,
+                zh: zh.into(),
+                en: en.into(),
+            }
+        }
     }
 
     impl<T> From<PoisonError<T>> for AppError {
@@ -127,6 +141,12 @@ mod error {
     }
 }
 
+mod logging {
+    pub fn set_sync_log_level(level: Option<crate::settings_impl::LogLevel>) {
+        let _ = level.map(crate::settings_impl::LogLevel::to_level_filter);
+    }
+}
+
 mod services {
     pub mod skill {
         use serde::{Deserialize, Serialize};
@@ -141,6 +161,13 @@ mod services {
         }
     }
 
+    pub mod webdav_sync {
+        #[allow(dead_code)]
+        pub fn supported_protocol_versions() -> std::ops::RangeInclusive<u32> {
+            2..=2
+        }
+    }
+
     pub mod webdav {
         use crate::error::AppError;
         use url::Url;
@@ -515,6 +542,56 @@ fn malformed_visible_apps_json_falls_back_to_full_defaults() {
     assert_eq!(settings.visible_apps, default_visible_apps());
 }
 
+#[test]
+#[serial]
+fn load_strict_uses_defaults_when_settings_file_is_absent() {
+    let home = HomeGuard::new();
+    assert!(!settings_path(&home).exists());
+
+    let settings = AppSettings::load_strict().expect("absent file should load defaults");
+    assert_eq!(settings.visible_apps, default_visible_apps());
+    assert_eq!(settings.language, AppSettings::default().language);
+}
+
+#[test]
+#[serial]
+fn load_strict_reads_valid_settings_file() {
+    let home = HomeGuard::new();
+    write_settings_json(&home, json!({ "language": "zh", "showInTray": false }));
+
+    let settings = AppSettings::load_strict().expect("valid file should load");
+    assert_eq!(settings.language.as_deref(), Some("zh"));
+    assert!(!settings.show_in_tray);
+}
+
+#[test]
+#[serial]
+fn load_strict_rejects_corrupt_settings_file() {
+    let home = HomeGuard::new();
+    let path = settings_path(&home);
+    fs::create_dir_all(path.parent().expect("settings parent")).expect("create settings dir");
+    fs::write(&path, "{ \"language\": ").expect("write corrupt settings");
+
+    let err = AppSettings::load_strict().expect_err("corrupt file should be an error");
+    assert!(
+        matches!(
+            err,
+            AppError::Localized {
+                key: "settings.parse_failed",
+                ..
+            }
+        ),
+        "unexpected error: {err}"
+    );
+    assert!(err.to_string().contains(&path.display().to_string()));
+
+    // 非严格加载仍回退到默认设置
+    assert_eq!(
+        AppSettings::load().language,
+        AppSettings::default().language
+    );
+}
+
 #[test]
 #[serial]
 fn next_visible_app_wraps_and_skips_hidden_entries() {
@@ -1,4 +1,6 @@
-use cc_switch_lib::{AppType, Database, SkillService};
+use cc_switch_lib::{
+    update_settings, AppSettings, AppType, Database, SkillService, SkillsRestoreRecovery,
+};
 
 #[path = "support.rs"]
 mod support;
@@ -310,3 +312,132 @@ fn pending_migration_with_existing_managed_list_does_not_claim_unmanaged_skills(
         "unmanaged skill should remain unmanaged (not added to db)"
     );
 }
+
+#[test]
+fn ssot_dir_defaults_to_app_config_skills_dir() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let ssot = SkillService::get_ssot_dir().expect("resolve ssot dir");
+    assert_eq!(ssot, home.join(".cc-switch").join("skills"));
+    assert!(ssot.is_dir(), "default SSOT dir should be created");
+}
+
+#[test]
+fn ssot_dir_honors_override_with_tilde_expansion() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    update_settings(AppSettings {
+        skills_ssot_override: Some("~/.config/cc-switch-profiles/work/skills".to_string()),
+        ..AppSettings::default()
+    })
+    .expect("set skills ssot override");
+
+    let ssot = SkillService::get_ssot_dir().expect("resolve ssot dir");
+    let expected = home
+        .join(".config")
+        .join("cc-switch-profiles")
+        .join("work")
+        .join("skills");
+    assert_eq!(ssot, expected);
+    assert!(ssot.is_dir(), "override SSOT dir should be created");
+    assert!(
+        !home.join(".cc-switch").join("skills").exists(),
+        "default SSOT dir should be left untouched"
+    );
+
+    update_settings(AppSettings::default()).expect("reset settings");
+    assert_eq!(
+        SkillService::get_ssot_dir().expect("resolve ssot dir"),
+        home.join(".cc-switch").join("skills")
+    );
+}
+
+#[test]
+fn recover_interrupted_restore_is_a_no_op_without_backup() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let ssot = home.join(".cc-switch").join("skills");
+    write_skill_md(&ssot.join("hello-skill"), "Hello Skill", "A test skill");
+
+    let outcome = SkillService::recover_interrupted_restore().expect("recover");
+    assert_eq!(outcome, SkillsRestoreRecovery::Clean);
+    assert!(ssot.join("hello-skill").join("SKILL.md").is_file());
+}
+
+#[test]
+fn recover_interrupted_restore_restores_backup_when_ssot_is_missing_or_empty() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let ssot = home.join(".cc-switch").join("skills");
+    let bak = home.join(".cc-switch").join("skills.bak");
+
+    // 崩溃发生在 rename 之后、复制之前：SSOT 缺失
+    write_skill_md(&bak.join("hello-skill"), "Hello Skill", "A test skill");
+    let outcome = SkillService::recover_interrupted_restore().expect("recover");
+    assert_eq!(outcome, SkillsRestoreRecovery::RestoredBackup);
+    assert!(ssot.join("hello-skill").join("SKILL.md").is_file());
+    assert!(!bak.exists(), "backup should be moved back into place");
+
+    // 之后又有调用方通过 get_ssot_dir 建出了空目录
+    std::fs::rename(&ssot, &bak).expect("simulate interrupted restore");
+    std::fs::create_dir_all(&ssot).expect("create empty ssot");
+    let outcome = SkillService::recover_interrupted_restore().expect("recover");
+    assert_eq!(outcome, SkillsRestoreRecovery::RestoredBackup);
+    assert!(ssot.join("hello-skill").join("SKILL.md").is_file());
+    assert!(!bak.exists());
+}
+
+#[test]
+fn recover_interrupted_restore_removes_stale_backup_when_restore_completed() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let ssot = home.join(".cc-switch").join("skills");
+    let bak = home.join(".cc-switch").join("skills.bak");
+    let marker = home.join(".cc-switch").join("skills.restore-complete");
+    write_skill_md(&ssot.join("new-skill"), "New Skill", "Restored content");
+    write_skill_md(&bak.join("old-skill"), "Old Skill", "Previous content");
+    std::fs::write(&marker, b"").expect("write completion marker");
+
+    let outcome = SkillService::recover_interrupted_restore().expect("recover");
+    assert_eq!(outcome, SkillsRestoreRecovery::RemovedStaleBackup);
+    assert!(!bak.exists(), "stale backup should be removed");
+    assert!(!marker.exists(), "completion marker should be cleaned up");
+    assert!(ssot.join("new-skill").join("SKILL.md").is_file());
+    assert!(!ssot.join("old-skill").exists());
+}
+
+#[test]
+fn recover_interrupted_restore_rolls_back_partially_copied_ssot() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let ssot = home.join(".cc-switch").join("skills");
+    let bak = home.join(".cc-switch").join("skills.bak");
+    write_skill_md(&ssot.join("alpha"), "Alpha", "Original alpha");
+    write_skill_md(&ssot.join("beta"), "Beta", "Original beta");
+
+    // 模拟恢复在复制途中崩溃：SSOT 已改名为 .bak，新内容只写了一部分，没有完成标记
+    std::fs::rename(&ssot, &bak).expect("simulate rename to backup");
+    write_skill_md(&ssot.join("alpha"), "Alpha", "New alpha");
+
+    let outcome = SkillService::recover_interrupted_restore().expect("recover");
+    assert_eq!(outcome, SkillsRestoreRecovery::RestoredBackup);
+    assert!(!bak.exists(), "backup should be moved back into place");
+    assert!(
+        ssot.join("beta").join("SKILL.md").is_file(),
+        "skills missing from the partial tree must come back from the backup"
+    );
+    let alpha = std::fs::read_to_string(ssot.join("alpha").join("SKILL.md")).expect("read alpha");
+    assert!(alpha.contains("Original alpha"), "{alpha}");
+}
@@ -1,7 +1,7 @@
 use std::{
     collections::{HashMap, HashSet},
     net::TcpListener,
-    sync::{Arc, Mutex},
+    sync::{Arc, Mutex, OnceLock},
     thread,
 };
 
@@ -14,8 +14,13 @@ use axum::{
     Router,
 };
 use cc_switch_lib::{
-    set_webdav_sync_settings, WebDavSyncService, WebDavSyncSettings, WebDavSyncStatus,
+    get_webdav_sync_settings, machine_uuid, set_webdav_sync_settings, update_settings,
+    update_webdav_sync_status, AppSettings, AppType, CompressionAlgo, ConflictPolicy, Database,
+    DiagnosticCheck, DiagnosticReport, DiagnosticStatus, LogLevel, Provider, SkillService,
+    SkillsDiff, SkillsStorage, SyncMethod, WebDavSyncService, WebDavSyncSettings, WebDavSyncStatus,
+    WritableStatus,
 };
+use sha2::{Digest, Sha256};
 use tokio::sync::oneshot;
 
 #[path = "support.rs"]
@@ -38,6 +43,8 @@ enum DeleteBehavior {
     Success,
     NotFound,
     ServerError,
+    /// 207 Multi-Status，逐项列出被删除的成员
+    MultiStatus,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -46,7 +53,11 @@ struct ServerConfig {
     manifest_readback: ProbeReadback,
     manifest_head_behavior: ManifestHeadBehavior,
     reject_dotfile_puts: bool,
+    /// 模拟只读目录：GET/PROPFIND 正常，所有 PUT 返回 403
+    reject_all_puts: bool,
     delete_behavior: DeleteBehavior,
+    /// 设置后只接受用户名 `demo` + 该密码的 Basic 认证，其余请求返回 401
+    accepted_password: Option<&'static str>,
 }
 
 impl ServerConfig {
@@ -56,7 +67,9 @@ impl ServerConfig {
             manifest_readback: ProbeReadback::Stored,
             manifest_head_behavior: ManifestHeadBehavior::Present,
             reject_dotfile_puts: false,
+            reject_all_puts: false,
             delete_behavior: DeleteBehavior::Success,
+            accepted_password: None,
         }
     }
 
@@ -69,7 +82,9 @@ impl ServerConfig {
             manifest_readback,
             manifest_head_behavior,
             reject_dotfile_puts: false,
+            reject_all_puts: false,
             delete_behavior: DeleteBehavior::Success,
+            accepted_password: None,
         }
     }
 }
@@ -90,6 +105,10 @@ struct ServerState {
     head_paths: Vec<String>,
     delete_paths: Vec<String>,
     streamed_chunk_count: usize,
+    authorizations: HashSet<String>,
+    mkcol_paths: Vec<String>,
+    honor_range: bool,
+    range_headers: Vec<(String, String)>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -180,6 +199,59 @@ impl TestWebDavServer {
             streamed_chunk_count: state.streamed_chunk_count,
         }
     }
+
+    fn authorizations(&self) -> HashSet<String> {
+        let state = self.state.lock().expect("lock test WebDAV state");
+        state.authorizations.clone()
+    }
+
+    fn file(&self, path: &str) -> Option<Vec<u8>> {
+        let state = self.state.lock().expect("lock test WebDAV state");
+        state.files.get(path).cloned()
+    }
+
+    fn stored_paths_under(&self, dir: &str) -> Vec<String> {
+        let state = self.state.lock().expect("lock test WebDAV state");
+        let prefix = format!("{dir}/");
+        state
+            .files
+            .keys()
+            .filter(|path| path.starts_with(&prefix))
+            .cloned()
+            .collect()
+    }
+
+    fn put_file(&self, path: &str, bytes: Vec<u8>) {
+        let mut state = self.state.lock().expect("lock test WebDAV state");
+        state.files.insert(path.to_string(), bytes);
+    }
+
+    fn remove_file(&self, path: &str) {
+        let mut state = self.state.lock().expect("lock test WebDAV state");
+        state.files.remove(path);
+    }
+
+    fn create_dir(&self, path: &str) {
+        let mut state = self.state.lock().expect("lock test WebDAV state");
+        state.directories.insert(path.to_string());
+    }
+
+    fn mkcol_paths(&self) -> Vec<String> {
+        let state = self.state.lock().expect("lock test WebDAV state");
+        state.mkcol_paths.clone()
+    }
+
+    /// 让 GET 按 `Range: bytes=0-N` 返回 206 部分内容（默认忽略 Range，返回完整文件）
+    fn honor_range(&self) {
+        let mut state = self.state.lock().expect("lock test WebDAV state");
+        state.honor_range = true;
+    }
+
+    /// 收到的 GET Range 头，按 (path, range) 记录
+    fn range_headers(&self) -> Vec<(String, String)> {
+        let state = self.state.lock().expect("lock test WebDAV state");
+        state.range_headers.clone()
+    }
 }
 
 impl Drop for TestWebDavServer {
@@ -196,6 +268,28 @@ impl Drop for TestWebDavServer {
 async fn handle_webdav_request(State(state): State<AppState>, request: Request<Body>) -> Response {
     let method = request.method().as_str().to_string();
     let path = request.uri().path().to_string();
+    if let Some(authorization) = request
+        .headers()
+        .get("authorization")
+        .and_then(|value| value.to_str().ok())
+    {
+        state
+            .inner
+            .lock()
+            .expect("lock authorization state")
+            .authorizations
+            .insert(authorization.to_string());
+    }
+    if let Some(password) = state.config.accepted_password {
+        let authorized = request
+            .headers()
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            == Some(basic_auth("demo", password).as_str());
+        if !authorized {
+            return StatusCode::UNAUTHORIZED.into_response();
+        }
+    }
 
     match method.as_str() {
         "PROPFIND" => {
@@ -212,15 +306,15 @@ async fn handle_webdav_request(State(state): State<AppState>, request: Request<B
             }
         }
         "MKCOL" => {
-            state
-                .inner
-                .lock()
-                .expect("lock MKCOL state")
-                .directories
-                .insert(path);
+            let mut inner = state.inner.lock().expect("lock MKCOL state");
+            inner.mkcol_paths.push(path.clone());
+            inner.directories.insert(path);
             StatusCode::CREATED.into_response()
         }
         "PUT" => {
+            if state.config.reject_all_puts {
+                return StatusCode::FORBIDDEN.into_response();
+            }
             if state.config.reject_dotfile_puts
                 && path
                     .rsplit('/')
@@ -250,8 +344,16 @@ async fn handle_webdav_request(State(state): State<AppState>, request: Request<B
             StatusCode::CREATED.into_response()
         }
         "GET" => {
+            let range = request
+                .headers()
+                .get("range")
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string);
             let mut inner = state.inner.lock().expect("lock GET state");
             inner.get_paths.push(path.clone());
+            if let Some(range) = &range {
+                inner.range_headers.push((path.clone(), range.clone()));
+            }
             match readback_for_path(&state.config, &path) {
                 ProbeReadback::Missing => StatusCode::NOT_FOUND.into_response(),
                 ProbeReadback::Mismatch => {
@@ -277,7 +379,13 @@ async fn handle_webdav_request(State(state): State<AppState>, request: Request<B
                         .into_response()
                 }
                 ProbeReadback::Stored => match inner.files.get(&path).cloned() {
-                    Some(bytes) => (StatusCode::OK, bytes).into_response(),
+                    Some(bytes) => match range.as_deref().and_then(parse_prefix_range) {
+                        Some(last) if inner.honor_range => {
+                            let end = bytes.len().min(last + 1);
+                            (StatusCode::PARTIAL_CONTENT, bytes[..end].to_vec()).into_response()
+                        }
+                        _ => (StatusCode::OK, bytes).into_response(),
+                    },
                     None => StatusCode::NOT_FOUND.into_response(),
                 },
             }
@@ -302,17 +410,59 @@ async fn handle_webdav_request(State(state): State<AppState>, request: Request<B
         "DELETE" => {
             let mut inner = state.inner.lock().expect("lock DELETE state");
             inner.delete_paths.push(path.clone());
-            inner.files.remove(&path);
+            // 删除集合时连同其下所有成员一起移除
+            let prefix = format!("{path}/");
+            let mut removed: Vec<String> = inner
+                .files
+                .keys()
+                .filter(|file| **file == path || file.starts_with(&prefix))
+                .cloned()
+                .collect();
+            removed.sort();
+            for file in &removed {
+                inner.files.remove(file);
+            }
+            let was_directory = inner.directories.iter().any(|dir| *dir == path);
+            inner
+                .directories
+                .retain(|dir| *dir != path && !dir.starts_with(&prefix));
             match state.config.delete_behavior {
+                DeleteBehavior::Success | DeleteBehavior::MultiStatus
+                    if removed.is_empty() && !was_directory =>
+                {
+                    StatusCode::NOT_FOUND.into_response()
+                }
                 DeleteBehavior::Success => StatusCode::NO_CONTENT.into_response(),
                 DeleteBehavior::NotFound => StatusCode::NOT_FOUND.into_response(),
                 DeleteBehavior::ServerError => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+                DeleteBehavior::MultiStatus => {
+                    let responses: String = removed
+                        .iter()
+                        .map(|file| {
+                            format!(
+                                "<d:response><d:href>{file}</d:href><d:status>HTTP/1.1 200 OK</d:status></d:response>"
+                            )
+                        })
+                        .collect();
+                    (
+                        StatusCode::from_u16(207).expect("build 207 Multi-Status"),
+                        format!(
+                            r#"<?xml version="1.0"?><d:multistatus xmlns:d="DAV:">{responses}</d:multistatus>"#
+                        ),
+                    )
+                        .into_response()
+                }
             }
         }
         _ => StatusCode::METHOD_NOT_ALLOWED.into_response(),
     }
 }
 
+/// 解析 `bytes=0-N`，返回 N；测试服务器只支持从头开始的范围
+fn parse_prefix_range(header: &str) -> Option<usize> {
+    header.strip_prefix("bytes=0-")?.parse().ok()
+}
+
 fn multi_status_response() -> Response {
     StatusCode::from_u16(207)
         .expect("build 207 Multi-Status")
@@ -348,10 +498,38 @@ fn sample_settings(base_url: &str) -> WebDavSyncSettings {
         username: "demo".to_string(),
         password: "secret".to_string(),
         auto_sync: false,
+        conflict_policy: ConflictPolicy::default(),
+        credentials_file: None,
+        ca_cert_path: None,
+        auto_create_dirs: true,
+        verify_after_apply: false,
+        max_artifact_bytes: None,
+        sync_database: true,
+        skills_storage: SkillsStorage::default(),
+        dedup: false,
+        compression: CompressionAlgo::default(),
+        profile_description: None,
+        artifact_prefix: None,
+        protocol_version_override: None,
         status: WebDavSyncStatus::default(),
     }
 }
 
+/// 下载时导入的 SQL 必须包含有效数据，先在本地写入一个供应商。
+fn seed_local_provider() {
+    let provider = Provider::with_id(
+        "p-sync".to_string(),
+        "Sync Claude".to_string(),
+        serde_json::json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "sync-key" } }),
+        None,
+    );
+    let db = Database::init().expect("init test database");
+    db.save_provider(AppType::Claude.as_str(), &provider)
+        .expect("seed provider");
+    db.set_current_provider(AppType::Claude.as_str(), "p-sync")
+        .expect("seed current provider");
+}
+
 fn assert_probe_round_trip(snapshot: &ServerSnapshot) {
     assert_eq!(
         snapshot.put_paths.len(),
@@ -500,7 +678,9 @@ fn check_connection_succeeds_when_server_rejects_hidden_probe_files() {
         manifest_readback: ProbeReadback::Stored,
         manifest_head_behavior: ManifestHeadBehavior::Present,
         reject_dotfile_puts: true,
+        reject_all_puts: false,
         delete_behavior: DeleteBehavior::Success,
+        accepted_password: None,
     });
     set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
         .expect("save test WebDAV settings");
@@ -512,6 +692,62 @@ fn check_connection_succeeds_when_server_rejects_hidden_probe_files() {
     assert_probe_round_trip(&snapshot);
 }
 
+#[test]
+fn test_writable_reports_writable_and_removes_probe_file() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let server =
+        TestWebDavServer::start_with_config(ServerConfig::for_readback(ProbeReadback::Stored));
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+
+    let status = WebDavSyncService::test_writable().expect("writable directory should pass");
+    assert_eq!(status, WritableStatus::Writable);
+
+    let probe = "/dav/sync-root/v2/db-v6/default-profile/cc-switch-write-test.tmp";
+    let snapshot = server.snapshot();
+    assert_eq!(snapshot.put_paths, vec![probe.to_string()]);
+    assert_eq!(snapshot.get_paths, vec![probe.to_string()]);
+    assert_eq!(snapshot.delete_paths, vec![probe.to_string()]);
+    assert!(server.file(probe).is_none(), "probe file should be removed");
+}
+
+#[test]
+fn test_writable_reports_not_writable_when_server_rejects_put() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let server = TestWebDavServer::start_with_config(ServerConfig {
+        reject_all_puts: true,
+        ..ServerConfig::for_readback(ProbeReadback::Stored)
+    });
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+
+    let status = WebDavSyncService::test_writable()
+        .expect("reachable server should yield a status rather than an error");
+    let WritableStatus::NotWritable { reason } = status else {
+        panic!("read-only directory should be reported as not writable");
+    };
+    assert!(
+        reason.contains("403"),
+        "reason should carry the PUT status: {reason}"
+    );
+
+    let probe = "/dav/sync-root/v2/db-v6/default-profile/cc-switch-write-test.tmp";
+    let snapshot = server.snapshot();
+    assert_eq!(snapshot.put_paths, Vec::<String>::new());
+    assert_eq!(
+        snapshot.delete_paths,
+        vec![probe.to_string()],
+        "cleanup DELETE should still be attempted after the failed PUT"
+    );
+    assert!(server.file(probe).is_none());
+}
+
 #[test]
 fn check_connection_succeeds_when_probe_cleanup_delete_fails_after_successful_round_trip() {
     let _guard = lock_test_mutex();
@@ -523,7 +759,9 @@ fn check_connection_succeeds_when_probe_cleanup_delete_fails_after_successful_ro
         manifest_readback: ProbeReadback::Stored,
         manifest_head_behavior: ManifestHeadBehavior::Present,
         reject_dotfile_puts: false,
+        reject_all_puts: false,
         delete_behavior: DeleteBehavior::ServerError,
+        accepted_password: None,
     });
     set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
         .expect("save test WebDAV settings");
@@ -552,7 +790,9 @@ fn check_connection_succeeds_when_probe_cleanup_delete_reports_missing_after_suc
         manifest_readback: ProbeReadback::Stored,
         manifest_head_behavior: ManifestHeadBehavior::Present,
         reject_dotfile_puts: false,
+        reject_all_puts: false,
         delete_behavior: DeleteBehavior::NotFound,
+        accepted_password: None,
     });
     set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
         .expect("save test WebDAV settings");
@@ -580,7 +820,9 @@ fn check_connection_reports_probe_failure_even_when_cleanup_delete_fails() {
         manifest_readback: ProbeReadback::Stored,
         manifest_head_behavior: ManifestHeadBehavior::Present,
         reject_dotfile_puts: false,
+        reject_all_puts: false,
         delete_behavior: DeleteBehavior::ServerError,
+        accepted_password: None,
     });
     set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
         .expect("save test WebDAV settings");
@@ -609,6 +851,7 @@ fn upload_succeeds_when_manifest_readback_matches() {
     let _guard = lock_test_mutex();
     reset_test_fs();
     let _home = ensure_test_home();
+    seed_local_provider();
 
     let server = TestWebDavServer::start_with_config(ServerConfig::for_manifest_readback(
         ProbeReadback::Stored,
@@ -634,11 +877,44 @@ fn upload_succeeds_when_manifest_readback_matches() {
     );
 }
 
+// 共享的测试互斥锁需要覆盖整个异步测试，持锁跨 await 是有意为之
+#[allow(clippy::await_holding_lock)]
+#[tokio::test(flavor = "multi_thread")]
+async fn async_api_runs_inside_an_existing_runtime() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+
+    WebDavSyncService::check_connection_async()
+        .await
+        .expect("async connection check should succeed");
+    let uploaded = WebDavSyncService::upload_async()
+        .await
+        .expect("async upload should succeed");
+    assert_eq!(uploaded.decision, cc_switch_lib::SyncDecision::Upload);
+    let synced = WebDavSyncService::sync_async()
+        .await
+        .expect("async sync should succeed");
+    assert_eq!(synced.decision, cc_switch_lib::SyncDecision::UpToDate);
+    forget_sync_status();
+    let downloaded = WebDavSyncService::download_async()
+        .await
+        .expect("async download should succeed");
+    assert_eq!(downloaded.decision, cc_switch_lib::SyncDecision::Download);
+    assert!(local_provider_exists("p-sync"));
+}
+
 #[test]
 fn upload_succeeds_when_manifest_head_returns_server_error() {
     let _guard = lock_test_mutex();
     reset_test_fs();
     let _home = ensure_test_home();
+    seed_local_provider();
 
     let server = TestWebDavServer::start_with_config(ServerConfig::for_manifest_readback(
         ProbeReadback::Stored,
@@ -670,6 +946,7 @@ fn upload_fails_when_manifest_readback_is_missing() {
     let _guard = lock_test_mutex();
     reset_test_fs();
     let _home = ensure_test_home();
+    seed_local_provider();
 
     let server = TestWebDavServer::start_with_config(ServerConfig::for_manifest_readback(
         ProbeReadback::Missing,
@@ -703,6 +980,7 @@ fn upload_fails_when_manifest_readback_mismatches() {
     let _guard = lock_test_mutex();
     reset_test_fs();
     let _home = ensure_test_home();
+    seed_local_provider();
 
     let server = TestWebDavServer::start_with_config(ServerConfig::for_manifest_readback(
         ProbeReadback::Mismatch,
@@ -736,6 +1014,7 @@ fn upload_fails_when_manifest_readback_exceeds_expected_size() {
     let _guard = lock_test_mutex();
     reset_test_fs();
     let _home = ensure_test_home();
+    seed_local_provider();
 
     let server = TestWebDavServer::start_with_config(ServerConfig::for_manifest_readback(
         ProbeReadback::Oversized,
@@ -758,6 +1037,7 @@ fn upload_fails_when_manifest_stream_readback_exceeds_expected_size() {
     let _guard = lock_test_mutex();
     reset_test_fs();
     let _home = ensure_test_home();
+    seed_local_provider();
 
     let server = TestWebDavServer::start_with_config(ServerConfig::for_manifest_readback(
         ProbeReadback::OversizedStreaming,
@@ -781,25 +1061,2395 @@ fn upload_fails_when_manifest_stream_readback_exceeds_expected_size() {
 }
 
 #[test]
-fn server_rejects_put_when_parent_directory_is_missing() {
+fn upload_and_download_json_return_parseable_summaries() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    seed_local_provider();
+
     let server = TestWebDavServer::start(ProbeReadback::Stored);
-    let runtime = tokio::runtime::Builder::new_current_thread()
-        .enable_all()
-        .build()
-        .expect("build reqwest runtime");
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
 
-    let status = runtime.block_on(async {
-        reqwest::Client::new()
-            .put(format!(
-                "{}/sync-root/v2/db-v6/default-profile/db.sql",
-                server.base_url
-            ))
-            .body("db")
-            .send()
-            .await
-            .expect("PUT missing parent")
-            .status()
+    let uploaded: serde_json::Value = serde_json::from_str(
+        &WebDavSyncService::upload_json().expect("upload_json should succeed"),
+    )
+    .expect("upload_json should return JSON");
+    assert_eq!(uploaded["decision"], "upload");
+    let snapshot_id = uploaded["snapshot_id"]
+        .as_str()
+        .expect("uploaded summary should carry the snapshot id");
+
+    let downloaded: serde_json::Value = serde_json::from_str(
+        &WebDavSyncService::download_json().expect("download_json should succeed"),
+    )
+    .expect("download_json should return JSON");
+    // 刚上传过，远端与本地一致
+    assert_eq!(downloaded["decision"], "upToDate");
+    assert_eq!(downloaded["snapshot_id"], snapshot_id);
+}
+
+#[test]
+fn download_preserves_unknown_artifacts_for_next_upload() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("initial upload should succeed");
+
+    // 模拟更新版本客户端写入了第三个 artifact
+    let profile_dir = "/dav/sync-root/v2/db-v6/default-profile";
+    let manifest_path = format!("{profile_dir}/manifest.json");
+    let notes = br#"{"pinned":["demo"]}"#.to_vec();
+    let mut manifest: serde_json::Value =
+        serde_json::from_slice(&server.file(&manifest_path).expect("manifest uploaded"))
+            .expect("parse uploaded manifest");
+    manifest["artifacts"]["notes.json"] = serde_json::json!({
+        "sha256": format!("{:x}", Sha256::digest(&notes)),
+        "size": notes.len(),
     });
+    assert_eq!(
+        manifest["artifacts"].as_object().map(|a| a.len()),
+        Some(3),
+        "manifest should now list three artifacts"
+    );
+    server.put_file(&format!("{profile_dir}/notes.json"), notes.clone());
+    server.put_file(
+        &manifest_path,
+        serde_json::to_vec_pretty(&manifest).expect("serialize manifest"),
+    );
 
-    assert_eq!(status, StatusCode::CONFLICT);
+    let summary = WebDavSyncService::download().expect("three-artifact download should succeed");
+    assert_eq!(summary.decision, cc_switch_lib::SyncDecision::Download);
+    assert!(
+        server
+            .snapshot()
+            .get_paths
+            .contains(&format!("{profile_dir}/notes.json")),
+        "unknown artifact should be downloaded and verified"
+    );
+
+    let puts_before = server.snapshot().put_paths.len();
+    WebDavSyncService::upload().expect("re-upload should succeed");
+    let snapshot = server.snapshot();
+    assert_eq!(
+        &snapshot.put_paths[puts_before..],
+        &[
+            format!("{profile_dir}/db.sql"),
+            format!("{profile_dir}/skills.zip"),
+            format!("{profile_dir}/notes.json"),
+            manifest_path.clone(),
+        ],
+        "unknown artifact should be re-uploaded before the manifest"
+    );
+    assert_eq!(
+        server.file(&format!("{profile_dir}/notes.json")),
+        Some(notes)
+    );
+    let reuploaded: serde_json::Value =
+        serde_json::from_slice(&server.file(&manifest_path).expect("manifest re-uploaded"))
+            .expect("parse re-uploaded manifest");
+    assert!(
+        reuploaded["artifacts"].get("notes.json").is_some(),
+        "re-uploaded manifest should keep the unknown artifact: {reuploaded}"
+    );
+}
+
+#[test]
+fn download_resyncs_copied_skills_from_the_new_ssot() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    seed_local_provider();
+    let app_skill = home.join(".claude/skills/demo/SKILL.md");
+    std::fs::create_dir_all(app_skill.parent().unwrap()).expect("create app skill dir");
+    std::fs::write(&app_skill, "# demo v1").expect("write app skill");
+    SkillService::import_from_apps(vec!["demo".to_string()]).expect("import skill");
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_copy_sync_with_resync(true, &server);
+    WebDavSyncService::upload().expect("upload v1 skills");
+
+    // 本地改成 v2 并同步到 Claude，下载后应回到远端的 v1
+    std::fs::write(home.join(".cc-switch/skills/demo/SKILL.md"), "# demo v2")
+        .expect("edit SSOT skill");
+    SkillService::sync_all_enabled(Some(&AppType::Claude)).expect("sync v2 to claude");
+    assert_eq!(std::fs::read_to_string(&app_skill).unwrap(), "# demo v2");
+
+    set_copy_sync_with_resync(false, &server);
+    WebDavSyncService::download().expect("download with re-sync disabled");
+    assert_eq!(
+        std::fs::read_to_string(&app_skill).unwrap(),
+        "# demo v2",
+        "opting out should leave the app copy untouched"
+    );
+
+    set_copy_sync_with_resync(true, &server);
+    WebDavSyncService::download().expect("download with re-sync enabled");
+    assert_eq!(
+        std::fs::read_to_string(&app_skill).unwrap(),
+        "# demo v1",
+        "app copy should be re-materialized from the downloaded SSOT"
+    );
+}
+
+fn set_copy_sync_with_resync(enabled: bool, server: &TestWebDavServer) {
+    update_settings(AppSettings {
+        skill_sync_method: SyncMethod::Copy,
+        resync_skills_after_download: enabled,
+        ..AppSettings::default()
+    })
+    .expect("save skill sync settings");
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+}
+
+#[test]
+fn download_rejects_manifest_with_unsafe_artifact_name() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("initial upload should succeed");
+
+    let manifest_path = "/dav/sync-root/v2/db-v6/default-profile/manifest.json";
+    let mut manifest: serde_json::Value =
+        serde_json::from_slice(&server.file(manifest_path).expect("manifest uploaded"))
+            .expect("parse uploaded manifest");
+    manifest["artifacts"]["../escape"] = serde_json::json!({ "sha256": "00", "size": 0 });
+    server.put_file(
+        manifest_path,
+        serde_json::to_vec(&manifest).expect("serialize manifest"),
+    );
+
+    let err = WebDavSyncService::download().expect_err("unsafe artifact name should fail");
+    assert!(
+        err.to_string().contains("../escape"),
+        "unexpected error: {err}"
+    );
+}
+
+const PROFILE_DIR: &str = "/dav/sync-root/v2/db-v6/default-profile";
+
+/// 先完成一次上传，再让远端（另一台设备）和本地各自产生修改。
+fn start_conflicting_sync(policy: ConflictPolicy) -> TestWebDavServer {
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    let mut settings = sample_settings(&server.base_url);
+    settings.conflict_policy = policy;
+    set_webdav_sync_settings(Some(settings)).expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("initial upload should succeed");
+
+    let manifest_path = format!("{PROFILE_DIR}/manifest.json");
+    let mut manifest: serde_json::Value =
+        serde_json::from_slice(&server.file(&manifest_path).expect("manifest uploaded"))
+            .expect("parse uploaded manifest");
+    manifest["deviceName"] = serde_json::json!("Other Device");
+    server.put_file(
+        &manifest_path,
+        serde_json::to_vec_pretty(&manifest).expect("serialize manifest"),
+    );
+
+    let provider = Provider::with_id(
+        "p-local".to_string(),
+        "Local Only".to_string(),
+        serde_json::json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "local-key" } }),
+        None,
+    );
+    Database::init()
+        .expect("init test database")
+        .save_provider(AppType::Claude.as_str(), &provider)
+        .expect("save local-only provider");
+
+    server
+}
+
+fn conflict_backup_dir(suffix: &str) -> std::path::PathBuf {
+    let root = ensure_test_home().join(".cc-switch/webdav-sync/conflict-backups");
+    let mut dirs = std::fs::read_dir(&root)
+        .expect("conflict backup root should exist")
+        .map(|entry| entry.expect("read backup entry").path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.ends_with(suffix))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(dirs.len(), 1, "expected exactly one {suffix} backup");
+    dirs.remove(0)
+}
+
+fn local_provider_exists(id: &str) -> bool {
+    Database::init()
+        .expect("init test database")
+        .get_provider_by_id(id, AppType::Claude.as_str())
+        .expect("query provider")
+        .is_some()
+}
+
+/// 模拟从未同步过的设备，使下载实际应用远端快照
+fn forget_sync_status() {
+    update_webdav_sync_status(WebDavSyncStatus::default()).expect("reset sync status");
+}
+
+#[test]
+fn download_is_a_no_op_when_remote_snapshot_is_already_applied() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    let uploaded = WebDavSyncService::upload().expect("upload should succeed");
+    let artifact_gets = |server: &TestWebDavServer| {
+        server
+            .snapshot()
+            .get_paths
+            .iter()
+            .filter(|path| !path.ends_with("/manifest.json"))
+            .count()
+    };
+    let gets_before = artifact_gets(&server);
+
+    let summary = WebDavSyncService::download().expect("download should succeed");
+    assert_eq!(summary.decision, cc_switch_lib::SyncDecision::UpToDate);
+    assert!(!summary.changed());
+    assert_eq!(summary.snapshot_id, uploaded.snapshot_id);
+    assert!(summary.message.contains("already up to date"));
+    assert_eq!(
+        artifact_gets(&server),
+        gets_before,
+        "no artifact should be fetched for a no-op download"
+    );
+}
+
+#[test]
+fn download_applies_when_remote_snapshot_differs_from_last_sync() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("upload should succeed");
+    // 另一台设备上传了新快照
+    update_webdav_sync_status(WebDavSyncStatus {
+        last_snapshot_id: Some("snapshot-from-before".to_string()),
+        ..get_webdav_sync_settings().expect("settings").status
+    })
+    .expect("store older snapshot id");
+
+    let summary = WebDavSyncService::download().expect("download should succeed");
+    assert_eq!(summary.decision, cc_switch_lib::SyncDecision::Download);
+    assert!(summary.changed());
+    assert_eq!(
+        get_webdav_sync_settings()
+            .expect("settings")
+            .status
+            .last_snapshot_id,
+        summary.snapshot_id,
+        "the applied snapshot becomes the new baseline"
+    );
+}
+
+#[test]
+fn sync_is_up_to_date_right_after_upload() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("initial upload should succeed");
+
+    let puts_before = server.snapshot().put_paths.len();
+    let summary = WebDavSyncService::sync().expect("sync should succeed");
+    assert_eq!(summary.decision, cc_switch_lib::SyncDecision::UpToDate);
+    assert_eq!(server.snapshot().put_paths.len(), puts_before);
+}
+
+#[test]
+fn sync_conflict_with_error_policy_changes_nothing() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let server = start_conflicting_sync(ConflictPolicy::Error);
+    let manifest_path = format!("{PROFILE_DIR}/manifest.json");
+    let remote_manifest = server.file(&manifest_path);
+
+    let err = WebDavSyncService::sync().expect_err("conflict should be reported");
+    assert!(
+        err.to_string().contains("conflict policy"),
+        "unexpected error: {err}"
+    );
+    assert_eq!(server.file(&manifest_path), remote_manifest);
+    assert!(local_provider_exists("p-local"));
+    assert!(!ensure_test_home()
+        .join(".cc-switch/webdav-sync/conflict-backups")
+        .exists());
+}
+
+#[test]
+fn sync_conflict_prefer_remote_backs_up_local_and_downloads() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let _server = start_conflicting_sync(ConflictPolicy::PreferRemote);
+
+    let summary = WebDavSyncService::sync().expect("prefer-remote sync should succeed");
+    assert_eq!(summary.decision, cc_switch_lib::SyncDecision::Download);
+    assert!(
+        !local_provider_exists("p-local"),
+        "remote data should replace local changes"
+    );
+
+    let backup = conflict_backup_dir("-local");
+    let backed_up_sql =
+        std::fs::read_to_string(backup.join("db.sql")).expect("read backed up db.sql");
+    assert!(
+        backed_up_sql.contains("p-local"),
+        "local backup should contain the overwritten provider"
+    );
+    assert!(backup.join("skills.zip").is_file());
+    assert!(backup.join("manifest.json").is_file());
+}
+
+#[test]
+fn sync_conflict_prefer_local_backs_up_remote_and_uploads() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let server = start_conflicting_sync(ConflictPolicy::PreferLocal);
+
+    let summary = WebDavSyncService::sync().expect("prefer-local sync should succeed");
+    assert_eq!(summary.decision, cc_switch_lib::SyncDecision::Upload);
+    assert!(local_provider_exists("p-local"));
+    let remote_sql = String::from_utf8(
+        server
+            .file(&format!("{PROFILE_DIR}/db.sql"))
+            .expect("db.sql uploaded"),
+    )
+    .expect("db.sql is utf-8");
+    assert!(
+        remote_sql.contains("p-local"),
+        "local data should replace the remote snapshot"
+    );
+
+    let backup = conflict_backup_dir("-remote");
+    let backed_up_manifest =
+        std::fs::read_to_string(backup.join("manifest.json")).expect("read backed up manifest");
+    assert!(
+        backed_up_manifest.contains("Other Device"),
+        "remote backup should keep the overwritten manifest"
+    );
+    let backed_up_sql =
+        std::fs::read_to_string(backup.join("db.sql")).expect("read backed up db.sql");
+    assert!(!backed_up_sql.contains("p-local"));
+    assert!(backup.join("skills.zip").is_file());
+}
+
+#[test]
+fn rotate_password_keeps_old_password_when_check_fails() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let server = TestWebDavServer::start_with_config(ServerConfig {
+        accepted_password: Some("secret"),
+        ..ServerConfig::for_readback(ProbeReadback::Stored)
+    });
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+
+    WebDavSyncService::rotate_password("wrong-pass")
+        .expect_err("rejected credentials should fail the rotation");
+
+    let saved = get_webdav_sync_settings().expect("settings present");
+    assert_eq!(saved.password, "secret", "old password must be kept");
+    WebDavSyncService::check_connection().expect("old password should still work");
+}
+
+#[test]
+fn rotate_password_persists_new_password_after_successful_check() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let server = TestWebDavServer::start_with_config(ServerConfig {
+        accepted_password: Some("new-pass"),
+        ..ServerConfig::for_readback(ProbeReadback::Stored)
+    });
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+
+    WebDavSyncService::rotate_password("new-pass").expect("rotation should succeed");
+
+    let saved = get_webdav_sync_settings().expect("settings present");
+    assert_eq!(saved.password, "new-pass");
+    assert_eq!(saved.profile, "default-profile", "other fields unchanged");
+    assert!(server
+        .authorizations()
+        .contains(&basic_auth("demo", "new-pass")));
+}
+
+fn basic_auth(username: &str, password: &str) -> String {
+    use base64::Engine;
+    format!(
+        "Basic {}",
+        base64::engine::general_purpose::STANDARD.encode(format!("{username}:{password}"))
+    )
+}
+
+#[test]
+fn upload_uses_credentials_file_over_inline_settings() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    let credentials_path = home.join(".cc-switch").join("webdav-credentials.json");
+    std::fs::create_dir_all(credentials_path.parent().unwrap()).expect("create config dir");
+    std::fs::write(
+        &credentials_path,
+        r#"{"username": "file-user", "password": "file-pass"}"#,
+    )
+    .expect("write credentials file");
+
+    let mut settings = sample_settings(&server.base_url);
+    settings.credentials_file = Some("~/.cc-switch/webdav-credentials.json".to_string());
+    set_webdav_sync_settings(Some(settings)).expect("save test WebDAV settings");
+
+    WebDavSyncService::upload().expect("upload with file credentials");
+
+    assert_eq!(
+        server.authorizations(),
+        HashSet::from([basic_auth("file-user", "file-pass")]),
+        "every request should authenticate with the file credentials"
+    );
+    let saved = std::fs::read_to_string(home.join(".cc-switch").join("settings.json"))
+        .expect("read settings.json");
+    assert!(
+        !saved.contains("file-pass"),
+        "file secrets must not be persisted"
+    );
+    let saved = get_webdav_sync_settings().expect("settings present");
+    assert_eq!(saved.username, "demo");
+    assert_eq!(saved.password, "secret");
+}
+
+#[test]
+fn check_connection_reads_netrc_credentials_and_falls_back_to_inline_username() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    let dir = tempfile::tempdir().expect("tempdir");
+    let credentials_path = dir.path().join("netrc");
+    std::fs::write(
+        &credentials_path,
+        "machine elsewhere.example.com login nobody password nope\nmachine 127.0.0.1 password app-token\n",
+    )
+    .expect("write netrc");
+
+    let mut settings = sample_settings(&server.base_url);
+    settings.credentials_file = Some(credentials_path.display().to_string());
+    set_webdav_sync_settings(Some(settings)).expect("save test WebDAV settings");
+
+    WebDavSyncService::check_connection().expect("check connection with netrc credentials");
+    assert_eq!(
+        server.authorizations(),
+        HashSet::from([basic_auth("demo", "app-token")])
+    );
+
+    std::fs::write(
+        &credentials_path,
+        "machine elsewhere.example.com login x password y\n",
+    )
+    .expect("rewrite netrc");
+    let err = WebDavSyncService::check_connection()
+        .expect_err("credentials file without a matching entry should fail");
+    assert!(
+        err.to_string().contains("credentials file"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn preflight_reports_upload_sizes_without_network_calls() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    seed_local_provider();
+    let skill_dir = home.join(".cc-switch/skills/demo");
+    std::fs::create_dir_all(&skill_dir).expect("create skill dir");
+    std::fs::write(skill_dir.join("SKILL.md"), "# demo").expect("write skill");
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+
+    let report = WebDavSyncService::preflight().expect("preflight should succeed");
+    let snapshot = server.snapshot();
+    assert!(
+        server.authorizations().is_empty()
+            && snapshot.get_paths.is_empty()
+            && snapshot.head_paths.is_empty()
+            && snapshot.put_paths.is_empty(),
+        "preflight must not contact the server"
+    );
+
+    let profile_dir = "/dav/sync-root/v2/db-v6/default-profile";
+    let origin = server.base_url.trim_end_matches(DAV_ROOT);
+    assert_eq!(report.target_url, format!("{origin}{profile_dir}"));
+    let names: Vec<&str> = report.artifacts.iter().map(|a| a.name.as_str()).collect();
+    assert_eq!(names, ["db.sql", "skills.zip"]);
+    assert_eq!(
+        report.artifacts[1].url,
+        format!("{origin}{profile_dir}/skills.zip")
+    );
+
+    WebDavSyncService::upload().expect("upload should succeed");
+    let manifest: serde_json::Value = serde_json::from_slice(
+        &server
+            .file(&format!("{profile_dir}/manifest.json"))
+            .expect("manifest uploaded"),
+    )
+    .expect("parse manifest");
+    for artifact in &report.artifacts {
+        assert_eq!(
+            manifest["artifacts"][&artifact.name]["size"].as_u64(),
+            Some(artifact.size),
+            "size of {} should match the uploaded snapshot",
+            artifact.name
+        );
+    }
+    assert_eq!(
+        report.total_bytes(),
+        report.artifacts.iter().map(|a| a.size).sum::<u64>() + report.manifest_size
+    );
+}
+
+#[test]
+fn local_snapshot_id_tracks_skill_edits() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    seed_local_provider();
+    let skill_dir = home.join(".cc-switch/skills/demo");
+    std::fs::create_dir_all(&skill_dir).expect("create skill dir");
+    std::fs::write(skill_dir.join("SKILL.md"), "# demo").expect("write skill");
+
+    let first = WebDavSyncService::compute_local_snapshot_id().expect("compute id");
+    assert_eq!(
+        first,
+        WebDavSyncService::compute_local_snapshot_id().expect("recompute id"),
+        "id should be stable while nothing changes"
+    );
+
+    std::fs::write(skill_dir.join("SKILL.md"), "# demo v2").expect("edit skill");
+    let edited = WebDavSyncService::compute_local_snapshot_id().expect("compute edited id");
+    assert_ne!(first, edited, "editing a skill file should change the id");
+}
+
+#[test]
+fn has_local_changes_is_false_right_after_upload_and_true_after_edit() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    seed_local_provider();
+    let skill_dir = home.join(".cc-switch/skills/demo");
+    std::fs::create_dir_all(&skill_dir).expect("create skill dir");
+    std::fs::write(skill_dir.join("SKILL.md"), "# demo").expect("write skill");
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+
+    assert!(
+        WebDavSyncService::has_local_changes().expect("check before first sync"),
+        "never-synced data counts as changed"
+    );
+
+    WebDavSyncService::upload().expect("upload");
+    let requests_after_upload = server.snapshot();
+    assert!(!WebDavSyncService::has_local_changes().expect("check after upload"));
+    assert_eq!(
+        server.snapshot(),
+        requests_after_upload,
+        "has_local_changes must not touch the network"
+    );
+
+    std::fs::write(skill_dir.join("SKILL.md"), "# demo v2").expect("edit skill");
+    assert!(WebDavSyncService::has_local_changes().expect("check after edit"));
+}
+
+#[test]
+fn wipe_remote_refuses_when_confirmation_does_not_match_profile() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    seed_local_provider();
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("seed remote snapshot");
+    let deletes_before = server.snapshot().delete_paths.len();
+
+    let err = WebDavSyncService::wipe_remote("default-Profile")
+        .expect_err("mismatched confirmation should be refused");
+    assert!(
+        err.to_string().contains("does not match"),
+        "unexpected error: {err}"
+    );
+    assert_eq!(server.snapshot().delete_paths.len(), deletes_before);
+    assert!(server
+        .file("/dav/sync-root/v2/db-v6/default-profile/manifest.json")
+        .is_some());
+    let status = get_webdav_sync_settings().expect("settings").status;
+    assert!(status.last_sync_at.is_some(), "sync status should be kept");
+}
+
+#[test]
+fn wipe_remote_deletes_profile_and_clears_sync_status() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    seed_local_provider();
+    let server = TestWebDavServer::start_with_config(ServerConfig {
+        delete_behavior: DeleteBehavior::MultiStatus,
+        ..ServerConfig::for_readback(ProbeReadback::Stored)
+    });
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("seed remote snapshot");
+    let profile_dir = "/dav/sync-root/v2/db-v6/default-profile";
+    let stored = server.stored_paths_under(profile_dir);
+    assert!(stored.len() >= 3, "unexpected remote files: {stored:?}");
+
+    let removed = WebDavSyncService::wipe_remote("default-profile").expect("wipe remote");
+    assert_eq!(removed, stored.len());
+    assert!(
+        !server
+            .snapshot()
+            .delete_paths
+            .contains(&profile_dir.to_string()),
+        "the profile directory must not be deleted recursively"
+    );
+    assert!(server.stored_paths_under(profile_dir).is_empty());
+    assert_eq!(
+        get_webdav_sync_settings().expect("settings").status,
+        WebDavSyncStatus::default(),
+        "local sync status should be cleared"
+    );
+
+    let err = WebDavSyncService::download().expect_err("nothing left to download");
+    assert!(
+        err.to_string().contains("No downloadable sync data"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn wipe_remote_leaves_colliding_and_nested_profiles_alone() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    seed_local_provider();
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    // 旧布局下 profile「db-v6」的目录就是当前布局的 db-v6 根目录
+    let mut settings = sample_settings(&server.base_url);
+    settings.profile = "db-v6".to_string();
+    set_webdav_sync_settings(Some(settings)).expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("seed remote snapshot");
+
+    let other_profile = "/dav/sync-root/v2/db-v6/default-profile/manifest.json";
+    let nested_profile = "/dav/sync-root/v2/db-v6/db-v6/nested/manifest.json";
+    server.put_file(other_profile, b"{}".to_vec());
+    server.put_file(nested_profile, b"{}".to_vec());
+    let deletes_before = server.snapshot().delete_paths.len();
+
+    let removed = WebDavSyncService::wipe_remote("db-v6").expect("wipe remote");
+    assert!(removed >= 3, "unexpected removed count: {removed}");
+    assert!(
+        server.file(other_profile).is_some(),
+        "another profile under the colliding legacy directory must survive"
+    );
+    assert!(
+        server.file(nested_profile).is_some(),
+        "a profile nested below the wiped one must survive"
+    );
+    assert!(server
+        .file("/dav/sync-root/v2/db-v6/db-v6/manifest.json")
+        .is_none());
+    let wipe_deletes = server.snapshot().delete_paths[deletes_before..].to_vec();
+    assert!(
+        wipe_deletes.iter().all(|path| path
+            .rsplit('/')
+            .next()
+            .is_some_and(|name| name.contains('.'))),
+        "only files should be deleted: {wipe_deletes:?}"
+    );
+}
+
+#[test]
+fn invalidate_remote_manifest_deletes_only_the_manifest() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("seed remote snapshot");
+    let deletes_before = server.snapshot().delete_paths.len();
+
+    assert!(WebDavSyncService::invalidate_remote_manifest().expect("invalidate manifest"));
+
+    let manifest_path = "/dav/sync-root/v2/db-v6/default-profile/manifest.json";
+    assert_eq!(
+        server.snapshot().delete_paths[deletes_before..],
+        [manifest_path.to_string()],
+        "only the manifest URL should be deleted"
+    );
+    assert!(server.file(manifest_path).is_none());
+    for artifact in ["db.sql", "skills.zip"] {
+        assert!(
+            server
+                .file(&format!(
+                    "/dav/sync-root/v2/db-v6/default-profile/{artifact}"
+                ))
+                .is_some(),
+            "{artifact} should be left in place"
+        );
+    }
+
+    let err = WebDavSyncService::download().expect_err("download without manifest");
+    assert!(
+        err.to_string().contains("No downloadable sync data"),
+        "unexpected error: {err}"
+    );
+    WebDavSyncService::upload().expect("re-upload after invalidation");
+    assert!(
+        server.file(manifest_path).is_some(),
+        "upload writes a fresh manifest"
+    );
+}
+
+#[test]
+fn download_aborts_before_fetching_when_an_artifact_is_missing() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    seed_local_provider();
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("seed remote snapshot");
+    server.remove_file("/dav/sync-root/v2/db-v6/default-profile/skills.zip");
+
+    let skill_dir = home.join(".cc-switch/skills/local-only");
+    std::fs::create_dir_all(&skill_dir).expect("create local skill");
+    std::fs::write(skill_dir.join("SKILL.md"), "# local").expect("write local skill");
+    let gets_before = server.snapshot().get_paths.len();
+
+    let err = WebDavSyncService::download().expect_err("incomplete remote must abort");
+    assert!(
+        err.to_string().contains("missing artifact skills.zip"),
+        "unexpected error: {err}"
+    );
+
+    let new_gets = server.snapshot().get_paths[gets_before..].to_vec();
+    assert_eq!(
+        new_gets,
+        vec!["/dav/sync-root/v2/db-v6/default-profile/manifest.json".to_string()],
+        "no artifact should be downloaded once the pre-flight check fails"
+    );
+    assert!(
+        skill_dir.join("SKILL.md").exists(),
+        "local skills must not be touched by an aborted download"
+    );
+}
+
+#[test]
+fn download_with_verify_after_apply_reports_no_warning_for_a_clean_import() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    seed_local_provider();
+    set_webdav_sync_settings(Some(WebDavSyncSettings {
+        verify_after_apply: true,
+        ..sample_settings(&server.base_url)
+    }))
+    .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("seed remote snapshot");
+    forget_sync_status();
+
+    let summary = WebDavSyncService::download().expect("download should succeed");
+    assert_eq!(summary.decision, cc_switch_lib::SyncDecision::Download);
+    assert!(
+        summary.warnings.is_empty(),
+        "verification should pass: {:?}",
+        summary.warnings
+    );
+    assert!(local_provider_exists("p-sync"));
+}
+
+#[test]
+fn upload_uses_pre_created_directory_without_mkcol_when_auto_create_disabled() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    server.create_dir("/dav/sync-root/v2/db-v6/default-profile");
+    set_webdav_sync_settings(Some(WebDavSyncSettings {
+        auto_create_dirs: false,
+        ..sample_settings(&server.base_url)
+    }))
+    .expect("save test WebDAV settings");
+
+    WebDavSyncService::upload().expect("pre-created directory should be used as-is");
+
+    assert!(server.mkcol_paths().is_empty(), "MKCOL must not be sent");
+    assert_upload_artifact_puts(&server.snapshot());
+}
+
+#[test]
+fn upload_reports_missing_directory_when_auto_create_disabled() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(WebDavSyncSettings {
+        auto_create_dirs: false,
+        ..sample_settings(&server.base_url)
+    }))
+    .expect("save test WebDAV settings");
+
+    let err = WebDavSyncService::upload().expect_err("missing directory must fail");
+
+    let message = err.to_string();
+    assert!(
+        message
+            .contains("sync-root/v2/db-v6/default-profile is missing and auto-create is disabled"),
+        "unexpected error: {message}"
+    );
+    assert!(server.mkcol_paths().is_empty(), "MKCOL must not be sent");
+    assert!(
+        server.snapshot().put_paths.is_empty(),
+        "nothing should be uploaded"
+    );
+}
+
+#[test]
+fn server_rejects_put_when_parent_directory_is_missing() {
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("build reqwest runtime");
+
+    let status = runtime.block_on(async {
+        reqwest::Client::new()
+            .put(format!(
+                "{}/sync-root/v2/db-v6/default-profile/db.sql",
+                server.base_url
+            ))
+            .body("db")
+            .send()
+            .await
+            .expect("PUT missing parent")
+            .status()
+    });
+
+    assert_eq!(status, StatusCode::CONFLICT);
+}
+
+const MANIFEST_PATH: &str = "/dav/sync-root/v2/db-v6/default-profile/manifest.json";
+
+/// 上传一份快照，再把远端 manifest 改成另一种协议版本，
+/// 并在前面插入 `padding` 字段使 format/version 不在开头 `padding_len` 字节之内
+fn start_with_incompatible_manifest(padding_len: usize) -> TestWebDavServer {
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("initial upload should succeed");
+
+    let mut manifest: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_slice(&server.file(MANIFEST_PATH).expect("manifest uploaded"))
+            .expect("parse uploaded manifest");
+    manifest.insert("version".to_string(), serde_json::json!(99));
+    let mut reordered = serde_json::Map::new();
+    if padding_len > 0 {
+        reordered.insert(
+            "padding".to_string(),
+            serde_json::json!("x".repeat(padding_len)),
+        );
+    }
+    reordered.extend(manifest);
+    server.put_file(
+        MANIFEST_PATH,
+        serde_json::to_vec_pretty(&reordered).expect("serialize manifest"),
+    );
+    server
+}
+
+fn manifest_gets(server: &TestWebDavServer, from: usize) -> usize {
+    server.snapshot().get_paths[from..]
+        .iter()
+        .filter(|path| path.as_str() == MANIFEST_PATH)
+        .count()
+}
+
+#[test]
+fn sync_precheck_reads_only_the_manifest_head_when_range_is_honored() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let server = start_with_incompatible_manifest(0);
+    server.honor_range();
+    let gets_before = server.snapshot().get_paths.len();
+
+    let err = WebDavSyncService::sync().expect_err("incompatible remote must be rejected");
+    assert!(
+        err.to_string()
+            .contains("protocol version is incompatible: v99"),
+        "unexpected error: {err}"
+    );
+    assert_eq!(
+        manifest_gets(&server, gets_before),
+        1,
+        "only the ranged manifest GET should be sent"
+    );
+    assert_eq!(
+        server.range_headers(),
+        vec![(MANIFEST_PATH.to_string(), "bytes=0-4095".to_string())]
+    );
+}
+
+#[test]
+fn sync_precheck_falls_back_to_full_manifest_when_head_is_not_parseable() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let server = start_with_incompatible_manifest(8192);
+    server.honor_range();
+    let gets_before = server.snapshot().get_paths.len();
+
+    let err = WebDavSyncService::sync().expect_err("incompatible remote must be rejected");
+    assert!(
+        err.to_string()
+            .contains("protocol version is incompatible: v99"),
+        "unexpected error: {err}"
+    );
+    assert_eq!(
+        manifest_gets(&server, gets_before),
+        2,
+        "a ranged GET followed by a full GET"
+    );
+}
+
+#[test]
+fn sync_precheck_falls_back_to_full_manifest_when_db_compat_is_past_the_head() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("initial upload should succeed");
+
+    // format/version 在开头，dbCompatVersion 被填充字段挤到 Range 窗口之外
+    let mut manifest: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_slice(&server.file(MANIFEST_PATH).expect("manifest uploaded"))
+            .expect("parse uploaded manifest");
+    let format = manifest.remove("format").expect("format field");
+    let version = manifest.remove("version").expect("version field");
+    manifest.insert("aPadding".to_string(), serde_json::json!("x".repeat(8192)));
+    let rest = serde_json::to_string(&manifest).expect("serialize manifest");
+    let reordered = format!(
+        r#"{{"format":{format},"version":{version},{}"#,
+        rest.strip_prefix('{').expect("object")
+    );
+    server.put_file(MANIFEST_PATH, reordered.into_bytes());
+    server.honor_range();
+    let gets_before = server.snapshot().get_paths.len();
+
+    WebDavSyncService::sync().expect("compatible remote must not be rejected");
+    assert!(
+        manifest_gets(&server, gets_before) >= 2,
+        "a ranged GET followed by a full GET"
+    );
+}
+
+#[test]
+fn sync_precheck_uses_full_body_when_server_ignores_range() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let server = start_with_incompatible_manifest(8192);
+    let gets_before = server.snapshot().get_paths.len();
+
+    let err = WebDavSyncService::sync().expect_err("incompatible remote must be rejected");
+    assert!(
+        err.to_string()
+            .contains("protocol version is incompatible: v99"),
+        "unexpected error: {err}"
+    );
+    assert_eq!(
+        manifest_gets(&server, gets_before),
+        1,
+        "a 200 response to the ranged GET already carries the full manifest"
+    );
+}
+
+#[test]
+fn download_reports_html_at_manifest_path_with_a_targeted_error() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("initial upload should succeed");
+    server.put_file(
+        MANIFEST_PATH,
+        b"<!DOCTYPE html>\n<html><body>Please log in</body></html>".to_vec(),
+    );
+
+    let err = WebDavSyncService::download().expect_err("HTML manifest must be rejected");
+    let message = err.to_string();
+    assert!(
+        message.contains("Remote returned HTML content at the manifest path"),
+        "unexpected error: {message}"
+    );
+    assert!(message.contains("check base_url and credentials"));
+}
+
+#[test]
+fn download_honors_configured_artifact_size_limit() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("initial upload should succeed");
+
+    let mut settings = sample_settings(&server.base_url);
+    settings.max_artifact_bytes = Some(16);
+    set_webdav_sync_settings(Some(settings)).expect("save tiny artifact limit");
+    let err = WebDavSyncService::download().expect_err("artifact above the limit must be rejected");
+    assert!(
+        err.to_string().contains("exceeds download limit (16 B)"),
+        "unexpected error: {err}"
+    );
+
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("restore default limit");
+    WebDavSyncService::download().expect("default limit allows the snapshot");
+}
+
+#[test]
+fn upload_force_overwrites_remote_changed_by_another_device() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("initial upload should succeed");
+
+    // 另一台设备改写了远端 manifest，本机记录的 etag / hash 都已过期
+    let mut manifest: serde_json::Value =
+        serde_json::from_slice(&server.file(MANIFEST_PATH).expect("manifest uploaded"))
+            .expect("parse uploaded manifest");
+    manifest["deviceName"] = serde_json::json!("Other Device");
+    server.put_file(
+        MANIFEST_PATH,
+        serde_json::to_vec_pretty(&manifest).expect("serialize manifest"),
+    );
+    update_webdav_sync_status(WebDavSyncStatus {
+        last_remote_etag: Some("\"stale-etag\"".to_string()),
+        ..get_webdav_sync_settings().expect("settings").status
+    })
+    .expect("store stale etag");
+
+    let err = WebDavSyncService::upload().expect_err("safe upload must not overwrite");
+    assert!(
+        err.to_string().contains("upload --force"),
+        "unexpected error: {err}"
+    );
+
+    let summary = WebDavSyncService::upload_force().expect("force upload should succeed");
+    assert_eq!(summary.message, "WebDAV force upload completed");
+    let remote_manifest = server.file(MANIFEST_PATH).expect("manifest re-uploaded");
+    let status = get_webdav_sync_settings().expect("settings").status;
+    assert_eq!(
+        status.last_remote_manifest_hash.as_deref(),
+        Some(format!("{:x}", Sha256::digest(&remote_manifest)).as_str())
+    );
+    WebDavSyncService::upload().expect("status is current again after the force upload");
+}
+
+#[cfg(unix)]
+#[test]
+fn upload_reports_skipped_skills_symlinks_in_summary() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    seed_local_provider();
+
+    let skills = home.join(".cc-switch").join("skills");
+    let outside = home.join("outside-skills");
+    std::fs::create_dir_all(skills.join("demo")).expect("create skill dir");
+    std::fs::create_dir_all(&outside).expect("create outside dir");
+    std::fs::write(skills.join("demo").join("SKILL.md"), b"# demo").expect("write skill");
+    std::os::unix::fs::symlink(&outside, skills.join("linked")).expect("symlink outside root");
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+
+    let summary = WebDavSyncService::upload().expect("upload should succeed");
+    assert!(
+        summary
+            .warnings
+            .iter()
+            .any(|w| w.contains("skipped 1 symlinks outside skills root")),
+        "warnings: {:?}",
+        summary.warnings
+    );
+}
+
+fn skills_only_settings(base_url: &str) -> WebDavSyncSettings {
+    WebDavSyncSettings {
+        sync_database: false,
+        ..sample_settings(base_url)
+    }
+}
+
+#[test]
+fn skills_only_upload_omits_db_sql_from_manifest() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(skills_only_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("skills-only upload should succeed");
+
+    let manifest: serde_json::Value =
+        serde_json::from_slice(&server.file(MANIFEST_PATH).expect("manifest uploaded"))
+            .expect("parse uploaded manifest");
+    let artifacts = manifest["artifacts"].as_object().expect("artifacts object");
+    assert!(!artifacts.contains_key("db.sql"), "manifest: {manifest}");
+    assert!(artifacts.contains_key("skills.zip"), "manifest: {manifest}");
+    assert!(server
+        .file("/dav/sync-root/v2/db-v6/default-profile/db.sql")
+        .is_none());
+}
+
+#[test]
+fn skills_only_upload_keeps_previous_remote_db_sql() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("full upload");
+    let db_sql_path = "/dav/sync-root/v2/db-v6/default-profile/db.sql";
+    let db_sql = server.file(db_sql_path).expect("db.sql uploaded");
+    let manifest_before: serde_json::Value =
+        serde_json::from_slice(&server.file(MANIFEST_PATH).expect("manifest uploaded"))
+            .expect("parse first manifest");
+
+    set_webdav_sync_settings(Some(skills_only_settings(&server.base_url)))
+        .expect("switch to skills-only");
+    WebDavSyncService::upload().expect("skills-only upload should succeed");
+
+    let manifest: serde_json::Value =
+        serde_json::from_slice(&server.file(MANIFEST_PATH).expect("manifest uploaded"))
+            .expect("parse uploaded manifest");
+    assert_eq!(
+        manifest["artifacts"]["db.sql"], manifest_before["artifacts"]["db.sql"],
+        "the previous database entry must be kept: {manifest}"
+    );
+    assert_eq!(manifest["entityCount"], manifest_before["entityCount"]);
+    assert_eq!(server.file(db_sql_path), Some(db_sql));
+
+    // 保留的条目仍然完整：开启数据库同步的设备可以照常下载
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("enable database sync");
+    WebDavSyncService::download().expect("download keeps working");
+}
+
+#[test]
+fn download_of_db_less_snapshot_leaves_local_database_untouched() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    seed_local_provider();
+    let skill_dir = home.join(".cc-switch/skills/demo");
+    std::fs::create_dir_all(&skill_dir).expect("create skill dir");
+    std::fs::write(skill_dir.join("SKILL.md"), "# demo").expect("write skill");
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(skills_only_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("skills-only upload should succeed");
+
+    // 本机开启数据库同步并改动本地数据；下载不含 db.sql 的快照时只应恢复 Skills
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("enable database sync");
+    let provider = Provider::with_id(
+        "p-local".to_string(),
+        "Local Claude".to_string(),
+        serde_json::json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "local-key" } }),
+        None,
+    );
+    Database::init()
+        .expect("init test database")
+        .save_provider(AppType::Claude.as_str(), &provider)
+        .expect("save local provider");
+    std::fs::remove_dir_all(&skill_dir).expect("remove skill");
+
+    WebDavSyncService::download().expect("DB-less download should succeed");
+
+    assert!(local_provider_exists("p-local"), "local DB must be kept");
+    assert!(local_provider_exists("p-sync"));
+    assert_eq!(
+        std::fs::read_to_string(skill_dir.join("SKILL.md")).expect("skill restored"),
+        "# demo"
+    );
+    assert!(
+        !server
+            .snapshot()
+            .get_paths
+            .iter()
+            .any(|path| path.ends_with("/db.sql")),
+        "db.sql should never be fetched"
+    );
+}
+
+/// Tree 模式上传前就被拒绝：远端不应收到任何 PUT
+fn assert_tree_upload_refused(server: &TestWebDavServer, expected: &str) {
+    set_webdav_sync_settings(Some(WebDavSyncSettings {
+        skills_storage: SkillsStorage::Tree,
+        ..sample_settings(&server.base_url)
+    }))
+    .expect("save test WebDAV settings");
+
+    let err = WebDavSyncService::upload().expect_err("oversized tree upload should fail");
+    let message = err.to_string();
+    assert!(message.contains(expected), "unexpected error: {message}");
+    assert!(
+        message.contains("--skills-storage zip"),
+        "error should point to zip storage: {message}"
+    );
+    assert!(
+        server.snapshot().put_paths.is_empty(),
+        "nothing should be uploaded: {:?}",
+        server.snapshot().put_paths
+    );
+}
+
+#[test]
+fn tree_skills_storage_refuses_too_many_files_before_uploading() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    seed_local_provider();
+    let skill_dir = home.join(".cc-switch/skills/demo");
+    std::fs::create_dir_all(&skill_dir).expect("create skill dir");
+    for i in 0..2_001 {
+        std::fs::write(skill_dir.join(format!("f{i}.md")), "x").expect("write skill file");
+    }
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    assert_tree_upload_refused(&server, "Too many skill files (2001)");
+}
+
+#[test]
+fn tree_skills_storage_refuses_oversized_manifest_before_uploading() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    seed_local_provider();
+    // 文件数在上限内，但每个相对路径接近 1000 字符，manifest 超过 1 MB
+    let mut skill_dir = home.join(".cc-switch/skills/demo");
+    for segment in ['a', 'b', 'c', 'd'] {
+        skill_dir.push(segment.to_string().repeat(200));
+    }
+    std::fs::create_dir_all(&skill_dir).expect("create skill dir");
+    for i in 0..1_000 {
+        let name = format!("{i:04}-{}.md", "e".repeat(150));
+        std::fs::write(skill_dir.join(name), "x").expect("write skill file");
+    }
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    assert_tree_upload_refused(&server, "manifest.json is too large");
+}
+
+#[test]
+fn tree_skills_storage_round_trips_individual_files() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    seed_local_provider();
+    let skills_root = home.join(".cc-switch/skills");
+    std::fs::create_dir_all(skills_root.join("demo/scripts")).expect("create skill dirs");
+    std::fs::write(skills_root.join("demo/SKILL.md"), "# demo").expect("write skill");
+    std::fs::write(skills_root.join("demo/scripts/run.sh"), "echo hi").expect("write script");
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(WebDavSyncSettings {
+        skills_storage: SkillsStorage::Tree,
+        ..sample_settings(&server.base_url)
+    }))
+    .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("tree upload should succeed");
+
+    let profile = "/dav/sync-root/v2/db-v6/default-profile";
+    let manifest: serde_json::Value =
+        serde_json::from_slice(&server.file(MANIFEST_PATH).expect("manifest uploaded"))
+            .expect("parse uploaded manifest");
+    assert_eq!(manifest["skillsStorage"], "tree");
+    let names = manifest["artifacts"]
+        .as_object()
+        .expect("artifacts object")
+        .keys()
+        .cloned()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec![
+            "db.sql",
+            "skills/demo/SKILL.md",
+            "skills/demo/scripts/run.sh"
+        ]
+    );
+    assert_eq!(
+        server.file(&format!("{profile}/skills/demo/scripts/run.sh")),
+        Some(b"echo hi".to_vec())
+    );
+    assert!(server.file(&format!("{profile}/skills.zip")).is_none());
+
+    std::fs::remove_dir_all(skills_root.join("demo")).expect("remove skill");
+    std::fs::create_dir_all(skills_root.join("local-only")).expect("create local skill");
+    std::fs::write(skills_root.join("local-only/SKILL.md"), "# local").expect("write local");
+
+    // 本机切回 zip 模式也能按 manifest 记录的 tree 方式下载
+    set_webdav_sync_settings(Some(WebDavSyncSettings {
+        status: get_webdav_sync_settings().expect("settings").status,
+        ..sample_settings(&server.base_url)
+    }))
+    .expect("switch back to zip storage");
+    WebDavSyncService::download().expect("tree download should succeed");
+
+    assert_eq!(
+        std::fs::read_to_string(skills_root.join("demo/SKILL.md")).expect("skill restored"),
+        "# demo"
+    );
+    assert_eq!(
+        std::fs::read_to_string(skills_root.join("demo/scripts/run.sh")).expect("script restored"),
+        "echo hi"
+    );
+    assert!(
+        !skills_root.join("local-only").exists(),
+        "download replaces the local skills tree"
+    );
+}
+
+#[test]
+fn zstd_skills_zip_is_recorded_in_manifest_and_restores() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    seed_local_provider();
+    let skills_root = home.join(".cc-switch/skills");
+    std::fs::create_dir_all(skills_root.join("demo")).expect("create skill dir");
+    std::fs::write(skills_root.join("demo/SKILL.md"), "# demo\n".repeat(64)).expect("write skill");
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(WebDavSyncSettings {
+        compression: CompressionAlgo::Zstd,
+        ..sample_settings(&server.base_url)
+    }))
+    .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("zstd upload should succeed");
+
+    let manifest: serde_json::Value =
+        serde_json::from_slice(&server.file(MANIFEST_PATH).expect("manifest uploaded"))
+            .expect("parse uploaded manifest");
+    assert_eq!(manifest["skillsCompression"], "zstd");
+
+    std::fs::remove_dir_all(skills_root.join("demo")).expect("remove skill");
+    // 本机改回默认的 deflate 也能还原远端的 zstd 归档
+    set_webdav_sync_settings(Some(WebDavSyncSettings {
+        status: get_webdav_sync_settings().expect("settings").status,
+        ..sample_settings(&server.base_url)
+    }))
+    .expect("switch back to deflate");
+    WebDavSyncService::download().expect("zstd download should succeed");
+
+    assert_eq!(
+        std::fs::read_to_string(skills_root.join("demo/SKILL.md")).expect("skill restored"),
+        "# demo\n".repeat(64)
+    );
+}
+
+#[test]
+fn tree_upload_sends_only_changed_skill_files_and_deletes_removed_ones() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    seed_local_provider();
+    let skills_root = home.join(".cc-switch/skills");
+    std::fs::create_dir_all(skills_root.join("demo/scripts")).expect("create skill dirs");
+    std::fs::write(skills_root.join("demo/SKILL.md"), "# demo").expect("write skill");
+    std::fs::write(skills_root.join("demo/scripts/run.sh"), "echo hi").expect("write script");
+    std::fs::write(skills_root.join("demo/notes.md"), "notes").expect("write notes");
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(WebDavSyncSettings {
+        skills_storage: SkillsStorage::Tree,
+        ..sample_settings(&server.base_url)
+    }))
+    .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("initial tree upload should succeed");
+
+    let profile = "/dav/sync-root/v2/db-v6/default-profile";
+    let skill_puts_since = |from: usize| {
+        server.snapshot().put_paths[from..]
+            .iter()
+            .filter_map(|path| path.strip_prefix(&format!("{profile}/skills/")))
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+
+    // 没有改动时不重复上传任何 skill 文件
+    let puts_before = server.snapshot().put_paths.len();
+    WebDavSyncService::upload().expect("unchanged tree upload should succeed");
+    assert!(skill_puts_since(puts_before).is_empty());
+
+    std::fs::write(skills_root.join("demo/SKILL.md"), "# demo v2").expect("modify skill");
+    std::fs::write(skills_root.join("demo/scripts/new.sh"), "echo new").expect("add script");
+    std::fs::remove_file(skills_root.join("demo/notes.md")).expect("delete notes");
+
+    let puts_before = server.snapshot().put_paths.len();
+    let deletes_before = server.snapshot().delete_paths.len();
+    WebDavSyncService::upload().expect("incremental tree upload should succeed");
+
+    let mut uploaded = skill_puts_since(puts_before);
+    uploaded.sort();
+    assert_eq!(uploaded, vec!["demo/SKILL.md", "demo/scripts/new.sh"]);
+    let skill_deletes = server.snapshot().delete_paths[deletes_before..]
+        .iter()
+        .filter(|path| path.contains("/skills/"))
+        .cloned()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        skill_deletes,
+        vec![format!("{profile}/skills/demo/notes.md")]
+    );
+    assert_eq!(
+        server.file(&format!("{profile}/skills/demo/SKILL.md")),
+        Some(b"# demo v2".to_vec())
+    );
+    assert_eq!(
+        server.file(&format!("{profile}/skills/demo/scripts/run.sh")),
+        Some(b"echo hi".to_vec()),
+        "unchanged files stay on the server"
+    );
+    assert!(server
+        .file(&format!("{profile}/skills/demo/notes.md"))
+        .is_none());
+
+    // 强制上传无法确认远端状态，回退为完整上传
+    let puts_before = server.snapshot().put_paths.len();
+    WebDavSyncService::upload_force().expect("force upload should succeed");
+    let mut uploaded = skill_puts_since(puts_before);
+    uploaded.sort();
+    assert_eq!(
+        uploaded,
+        vec![
+            "demo/SKILL.md",
+            "demo/scripts/new.sh",
+            "demo/scripts/run.sh"
+        ]
+    );
+
+    // 下载后的 tree 记录同样可作为下一次增量上传的基准
+    WebDavSyncService::download().expect("tree download should succeed");
+    std::fs::write(skills_root.join("demo/scripts/run.sh"), "echo bye").expect("modify script");
+    let puts_before = server.snapshot().put_paths.len();
+    WebDavSyncService::sync().expect("sync should upload local changes");
+    assert_eq!(skill_puts_since(puts_before), vec!["demo/scripts/run.sh"]);
+}
+
+#[test]
+fn manifest_keeps_device_name_and_never_exposes_the_machine_uuid() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+    seed_local_provider();
+    let id = machine_uuid().expect("machine uuid");
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("upload should succeed");
+
+    let raw = server.file(MANIFEST_PATH).expect("manifest uploaded");
+    let manifest: serde_json::Value = serde_json::from_slice(&raw).expect("parse manifest");
+    let device_name = manifest["deviceName"].as_str().expect("device name");
+    assert_ne!(device_name, id);
+    assert!(!String::from_utf8_lossy(&raw).contains(&id));
+    assert!(manifest.get("deviceId").is_none());
+}
+
+#[test]
+fn profile_description_round_trips_without_touching_the_manifest() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(WebDavSyncSettings {
+        profile_description: Some("  Team laptops\nshared prompts and skills  ".to_string()),
+        ..sample_settings(&server.base_url)
+    }))
+    .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("upload should succeed");
+
+    let readme_path = "/dav/sync-root/v2/db-v6/default-profile/README.txt";
+    assert_eq!(
+        server.file(readme_path),
+        Some(b"Team laptops\nshared prompts and skills\n".to_vec())
+    );
+    let manifest = server.file(MANIFEST_PATH).expect("manifest uploaded");
+    assert!(!String::from_utf8_lossy(&manifest).contains("README"));
+    assert_eq!(
+        WebDavSyncService::get_remote_profile_description()
+            .expect("read description")
+            .as_deref(),
+        Some("Team laptops\nshared prompts and skills")
+    );
+
+    forget_sync_status();
+    let summary = WebDavSyncService::download().expect("download should succeed");
+    assert_eq!(summary.decision, cc_switch_lib::SyncDecision::Download);
+}
+
+#[test]
+fn missing_profile_description_is_skipped_and_does_not_break_download() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("upload should succeed");
+
+    assert!(!server
+        .snapshot()
+        .put_paths
+        .iter()
+        .any(|path| path.ends_with("/README.txt")));
+    assert_eq!(
+        WebDavSyncService::get_remote_profile_description().expect("read description"),
+        None
+    );
+
+    forget_sync_status();
+    let summary = WebDavSyncService::download().expect("download should succeed");
+    assert_eq!(summary.decision, cc_switch_lib::SyncDecision::Download);
+    assert!(local_provider_exists("p-sync"));
+}
+
+fn remote_entity_count(server: &TestWebDavServer) -> Option<u64> {
+    let raw = server.file(MANIFEST_PATH).expect("manifest uploaded");
+    let manifest: serde_json::Value = serde_json::from_slice(&raw).expect("parse manifest");
+    manifest["entityCount"].as_u64()
+}
+
+#[test]
+fn empty_local_database_does_not_overwrite_non_empty_remote_unless_forced() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("initial upload should succeed");
+    assert_eq!(remote_entity_count(&server), Some(1));
+
+    // 模拟迁移失败后本地数据库被清空
+    Database::init()
+        .expect("open test database")
+        .delete_provider(AppType::Claude.as_str(), "p-sync")
+        .expect("delete seeded provider");
+
+    let puts_before = server.snapshot().put_paths.len();
+    let err = WebDavSyncService::upload().expect_err("empty upload should be refused");
+    assert!(
+        err.to_string().contains("upload --force"),
+        "unexpected error: {err}"
+    );
+    let err = WebDavSyncService::sync().expect_err("sync must not upload an empty database");
+    assert!(
+        err.to_string().contains("upload --force"),
+        "unexpected error: {err}"
+    );
+    assert_eq!(server.snapshot().put_paths.len(), puts_before);
+    assert_eq!(remote_entity_count(&server), Some(1));
+
+    WebDavSyncService::upload_force().expect("force upload should succeed");
+    assert_eq!(remote_entity_count(&server), Some(0));
+
+    // 远端同样为空时不再拦截
+    WebDavSyncService::upload().expect("empty over empty should upload");
+}
+
+fn assert_skills_diff_for(storage: SkillsStorage) {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    seed_local_provider();
+    let skills_root = home.join(".cc-switch/skills");
+    std::fs::create_dir_all(skills_root.join("demo/scripts")).expect("create skill dirs");
+    std::fs::write(skills_root.join("demo/SKILL.md"), "# demo").expect("write skill");
+    std::fs::write(skills_root.join("demo/scripts/run.sh"), "echo hi").expect("write script");
+    std::fs::write(skills_root.join("demo/notes.md"), "notes").expect("write notes");
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(WebDavSyncSettings {
+        skills_storage: storage,
+        ..sample_settings(&server.base_url)
+    }))
+    .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("upload should succeed");
+    assert!(WebDavSyncService::diff_skills()
+        .expect("diff right after upload")
+        .is_empty());
+
+    std::fs::write(skills_root.join("demo/SKILL.md"), "# demo v2").expect("modify skill");
+    std::fs::write(skills_root.join("demo/scripts/new.sh"), "echo new").expect("add script");
+    std::fs::remove_file(skills_root.join("demo/notes.md")).expect("delete notes");
+
+    let before = server.snapshot();
+    let diff = WebDavSyncService::diff_skills().expect("diff should succeed");
+    assert_eq!(
+        diff,
+        SkillsDiff {
+            added: vec!["demo/scripts/new.sh".to_string()],
+            removed: vec!["demo/notes.md".to_string()],
+            changed: vec!["demo/SKILL.md".to_string()],
+        },
+        "storage: {storage:?}"
+    );
+    let after = server.snapshot();
+    assert_eq!(after.put_paths, before.put_paths, "diff must not upload");
+    assert_eq!(
+        after.delete_paths, before.delete_paths,
+        "diff must not delete"
+    );
+}
+
+#[test]
+fn diff_skills_reports_added_removed_and_changed_files_in_tree_mode() {
+    assert_skills_diff_for(SkillsStorage::Tree);
+}
+
+#[test]
+fn diff_skills_reads_the_zip_manifest_in_zip_mode() {
+    assert_skills_diff_for(SkillsStorage::Zip);
+}
+
+#[test]
+fn protocol_version_override_is_written_to_the_manifest_and_downloads() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    let mut settings = sample_settings(&server.base_url);
+    settings.protocol_version_override = Some(2);
+    set_webdav_sync_settings(Some(settings)).expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("upload should succeed");
+
+    let manifest: serde_json::Value =
+        serde_json::from_slice(&server.file(MANIFEST_PATH).expect("manifest uploaded"))
+            .expect("parse manifest");
+    assert_eq!(manifest["version"], 2);
+    WebDavSyncService::download().expect("pinned version should still download");
+}
+
+#[test]
+fn fetch_remote_manifest_raw_returns_pretty_json_without_touching_local_state() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+
+    let err = WebDavSyncService::fetch_remote_manifest_raw()
+        .expect_err("missing manifest should be reported");
+    assert!(
+        err.to_string().contains("No downloadable sync data"),
+        "unexpected error: {err}"
+    );
+
+    let body = r#"{"format":"cc-switch-webdav-sync","version":2,"futureField":{"a":1}}"#;
+    server.put_file(MANIFEST_PATH, body.as_bytes().to_vec());
+    let status_before = get_webdav_sync_settings().expect("settings").status;
+
+    let raw = WebDavSyncService::fetch_remote_manifest_raw().expect("fetch raw manifest");
+
+    let expected: serde_json::Value = serde_json::from_str(body).expect("parse body");
+    assert_eq!(
+        raw,
+        serde_json::to_string_pretty(&expected).expect("pretty body")
+    );
+    assert_eq!(
+        get_webdav_sync_settings().expect("settings").status,
+        status_before
+    );
+    assert!(server.snapshot().put_paths.is_empty());
+}
+
+/// 收集全局日志输出；日志只能安装一次，整个测试进程共用
+#[derive(Clone, Default)]
+struct LogCapture(Arc<Mutex<Vec<u8>>>);
+
+impl std::io::Write for LogCapture {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0
+            .lock()
+            .expect("lock log capture")
+            .extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl LogCapture {
+    fn take(&self) -> String {
+        String::from_utf8_lossy(&std::mem::take(
+            &mut *self.0.lock().expect("lock log capture"),
+        ))
+        .into_owned()
+    }
+}
+
+fn log_capture() -> &'static LogCapture {
+    static CAPTURE: OnceLock<LogCapture> = OnceLock::new();
+    CAPTURE.get_or_init(|| {
+        let capture = LogCapture::default();
+        cc_switch_lib::logging::init_with_target(
+            "error",
+            env_logger::Target::Pipe(Box::new(capture.clone())),
+        )
+        .expect("install test logger");
+        capture
+    })
+}
+
+fn set_sync_log_level(level: Option<LogLevel>, server: &TestWebDavServer) {
+    update_settings(AppSettings {
+        sync_log_level: level,
+        ..AppSettings::default()
+    })
+    .expect("save log level");
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+}
+
+#[test]
+fn trace_sync_log_level_records_each_webdav_request() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+    seed_local_provider();
+    let capture = log_capture();
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+
+    set_sync_log_level(None, &server);
+    capture.take();
+    WebDavSyncService::check_connection().expect("check connection");
+    let unset_logs = capture.take();
+    assert!(
+        !unset_logs.contains("[WebDAV]"),
+        "unset level should follow the global filter: {unset_logs}"
+    );
+
+    set_sync_log_level(Some(LogLevel::Info), &server);
+    WebDavSyncService::check_connection().expect("check connection");
+    let info_logs = capture.take();
+    assert!(
+        !info_logs
+            .lines()
+            .any(|line| line.contains("[WebDAV]") && line.contains(" -> ")),
+        "request traces should be off at info: {info_logs}"
+    );
+
+    set_sync_log_level(Some(LogLevel::Trace), &server);
+    WebDavSyncService::upload().expect("upload should succeed");
+    let trace_logs = capture.take();
+    set_sync_log_level(None, &server);
+
+    let manifest_put = trace_logs
+        .lines()
+        .find(|line| line.contains("[WebDAV] PUT") && line.contains("manifest.json"))
+        .unwrap_or_else(|| panic!("manifest PUT should be traced: {trace_logs}"));
+    assert!(
+        manifest_put.contains(" -> 2"),
+        "status missing: {manifest_put}"
+    );
+    assert!(
+        manifest_put.contains(" ms"),
+        "timing missing: {manifest_put}"
+    );
+    assert!(
+        !trace_logs.contains("secret"),
+        "credentials must not be logged"
+    );
+}
+
+fn remote_artifact_modified_at(server: &TestWebDavServer, artifact: &str) -> String {
+    let manifest: serde_json::Value =
+        serde_json::from_slice(&server.file(MANIFEST_PATH).expect("manifest uploaded"))
+            .expect("parse uploaded manifest");
+    manifest["artifacts"][artifact]["modifiedAt"]
+        .as_str()
+        .unwrap_or_else(|| panic!("{artifact} should record modifiedAt: {manifest}"))
+        .to_string()
+}
+
+#[test]
+fn upload_updates_modified_at_only_for_changed_artifacts() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("initial upload");
+    let initial = remote_artifact_modified_at(&server, "skills.zip");
+
+    let skill_dir = home.join(".cc-switch/skills/demo");
+    std::fs::create_dir_all(&skill_dir).expect("create skill dir");
+    std::fs::write(skill_dir.join("SKILL.md"), "# demo").expect("write skill");
+    WebDavSyncService::upload().expect("upload changed skills");
+    let changed = remote_artifact_modified_at(&server, "skills.zip");
+    assert_ne!(
+        changed, initial,
+        "changed skills.zip should get a new modifiedAt"
+    );
+
+    WebDavSyncService::upload().expect("upload without skill changes");
+    assert_eq!(
+        remote_artifact_modified_at(&server, "skills.zip"),
+        changed,
+        "unchanged skills.zip should keep its previous modifiedAt"
+    );
+}
+
+#[test]
+fn sync_refuses_to_run_when_settings_file_is_corrupt() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    std::fs::write(home.join(".cc-switch").join("settings.json"), "{ not json")
+        .expect("corrupt settings.json");
+
+    let err = WebDavSyncService::upload().expect_err("corrupt settings must block upload");
+    assert!(
+        err.to_string().contains("settings file"),
+        "error should point at the settings file: {err}"
+    );
+    assert!(
+        server.snapshot().put_paths.is_empty(),
+        "nothing should be uploaded"
+    );
+}
+
+#[test]
+fn upload_with_message_records_sanitized_note_in_manifest() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload_with_message(Some("added Gemini\nprovider\u{7}"))
+        .expect("upload with message");
+
+    let manifest: serde_json::Value =
+        serde_json::from_slice(&server.file(MANIFEST_PATH).expect("manifest uploaded"))
+            .expect("parse uploaded manifest");
+    assert_eq!(manifest["note"], "added Gemini provider");
+    let raw = WebDavSyncService::fetch_remote_manifest_raw().expect("fetch raw manifest");
+    assert!(
+        raw.contains("added Gemini provider"),
+        "raw manifest should show the note: {raw}"
+    );
+    let last_note = || {
+        get_webdav_sync_settings()
+            .expect("settings saved")
+            .status
+            .last_snapshot_note
+    };
+    assert_eq!(last_note().as_deref(), Some("added Gemini provider"));
+
+    // 下载时记录远端快照的说明
+    update_webdav_sync_status(WebDavSyncStatus::default()).expect("reset sync status");
+    WebDavSyncService::download().expect("download noted snapshot");
+    assert_eq!(last_note().as_deref(), Some("added Gemini provider"));
+
+    WebDavSyncService::upload().expect("upload without message");
+    assert_eq!(last_note(), None);
+    let manifest: serde_json::Value =
+        serde_json::from_slice(&server.file(MANIFEST_PATH).expect("manifest uploaded"))
+            .expect("parse uploaded manifest");
+    assert!(
+        manifest.get("note").is_none(),
+        "uploads without a message should not carry the previous note: {manifest}"
+    );
+}
+
+#[test]
+fn repair_remote_manifest_fixes_inconsistent_snapshot_id() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("initial upload");
+
+    let mut manifest: serde_json::Value =
+        serde_json::from_slice(&server.file(MANIFEST_PATH).expect("manifest uploaded"))
+            .expect("parse uploaded manifest");
+    let correct_id = manifest["snapshotId"].clone();
+    manifest["snapshotId"] = serde_json::json!("written-by-a-buggy-client");
+    manifest["futureField"] = serde_json::json!({"kept": true});
+    server.put_file(
+        MANIFEST_PATH,
+        serde_json::to_vec_pretty(&manifest).expect("serialize manifest"),
+    );
+
+    assert!(WebDavSyncService::repair_remote_manifest().expect("repair manifest"));
+    let repaired: serde_json::Value =
+        serde_json::from_slice(&server.file(MANIFEST_PATH).expect("manifest present"))
+            .expect("parse repaired manifest");
+    assert_eq!(repaired["snapshotId"], correct_id);
+    assert_eq!(
+        repaired["futureField"], manifest["futureField"],
+        "unknown fields must survive the repair"
+    );
+
+    assert!(
+        !WebDavSyncService::repair_remote_manifest().expect("repair again"),
+        "a repaired manifest should be consistent"
+    );
+}
+
+#[test]
+fn repair_remote_manifest_is_noop_for_consistent_manifest() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("initial upload");
+    let before = server.file(MANIFEST_PATH).expect("manifest uploaded");
+    let puts_before = server.snapshot().put_paths.len();
+
+    assert!(!WebDavSyncService::repair_remote_manifest().expect("check manifest"));
+    assert_eq!(server.file(MANIFEST_PATH), Some(before));
+    assert_eq!(
+        server.snapshot().put_paths.len(),
+        puts_before,
+        "a consistent manifest must not be re-uploaded"
+    );
+}
+
+#[test]
+fn repair_remote_manifest_refuses_when_artifact_hash_mismatches() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("initial upload");
+
+    let mut manifest: serde_json::Value =
+        serde_json::from_slice(&server.file(MANIFEST_PATH).expect("manifest uploaded"))
+            .expect("parse uploaded manifest");
+    manifest["snapshotId"] = serde_json::json!("stale");
+    manifest["artifacts"]["db.sql"]["sha256"] = serde_json::json!("0".repeat(64));
+    let tampered = serde_json::to_vec_pretty(&manifest).expect("serialize manifest");
+    server.put_file(MANIFEST_PATH, tampered.clone());
+
+    WebDavSyncService::repair_remote_manifest()
+        .expect_err("artifacts that fail verification must block the repair");
+    assert_eq!(server.file(MANIFEST_PATH), Some(tampered));
+}
+
+#[test]
+fn upload_uses_configured_artifact_prefix_for_remote_file_names() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(WebDavSyncSettings {
+        artifact_prefix: Some("ccswitch-".to_string()),
+        ..sample_settings(&server.base_url)
+    }))
+    .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("upload with artifact prefix");
+
+    let put_paths = server.snapshot().put_paths;
+    for name in ["db.sql", "skills.zip"] {
+        assert!(
+            put_paths
+                .iter()
+                .any(|path| path.ends_with(&format!("/ccswitch-{name}"))),
+            "{name} should be uploaded with the prefix: {put_paths:?}"
+        );
+        assert!(
+            !put_paths
+                .iter()
+                .any(|path| path.ends_with(&format!("/{name}"))),
+            "{name} should not be uploaded under its default name: {put_paths:?}"
+        );
+    }
+    assert!(
+        put_paths.iter().any(|path| is_manifest_path(path)),
+        "manifest.json keeps its fixed name: {put_paths:?}"
+    );
+
+    let manifest: serde_json::Value =
+        serde_json::from_slice(&server.file(MANIFEST_PATH).expect("manifest uploaded"))
+            .expect("parse uploaded manifest");
+    assert_eq!(
+        manifest["artifacts"]["db.sql"]["remoteName"],
+        "ccswitch-db.sql"
+    );
+    assert_eq!(
+        manifest["version"], 3,
+        "renamed artifacts need a protocol version that v2 clients refuse"
+    );
+}
+
+#[test]
+fn upload_without_renamed_artifacts_stays_on_protocol_v2() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("plain upload");
+
+    let manifest: serde_json::Value =
+        serde_json::from_slice(&server.file(MANIFEST_PATH).expect("manifest uploaded"))
+            .expect("parse uploaded manifest");
+    assert_eq!(manifest["version"], 2);
+}
+
+#[test]
+fn deduplicated_skills_upload_uses_protocol_v3() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(WebDavSyncSettings {
+        dedup: true,
+        ..sample_settings(&server.base_url)
+    }))
+    .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("deduplicated upload");
+
+    let manifest: serde_json::Value =
+        serde_json::from_slice(&server.file(MANIFEST_PATH).expect("manifest uploaded"))
+            .expect("parse uploaded manifest");
+    assert_eq!(
+        manifest["version"], 3,
+        "v2 clients would extract a deduplicated archive incompletely"
+    );
+}
+
+#[test]
+fn upload_refuses_artifact_prefix_when_protocol_v2_is_pinned() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(WebDavSyncSettings {
+        artifact_prefix: Some("ccswitch-".to_string()),
+        protocol_version_override: Some(2),
+        ..sample_settings(&server.base_url)
+    }))
+    .expect("save test WebDAV settings");
+
+    let err = WebDavSyncService::upload().expect_err("v2 cannot describe renamed artifacts");
+    assert!(err.to_string().contains("require protocol v3"), "{err}");
+    assert!(server.file(MANIFEST_PATH).is_none());
+}
+
+#[test]
+fn download_reads_artifact_file_names_from_manifest() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(WebDavSyncSettings {
+        artifact_prefix: Some("ccswitch-".to_string()),
+        ..sample_settings(&server.base_url)
+    }))
+    .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("upload with artifact prefix");
+
+    // 下载端没有配置前缀，仍应按 manifest 记录的文件名读取
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save settings without prefix");
+    forget_sync_status();
+    WebDavSyncService::download().expect("download prefixed artifacts");
+
+    let get_paths = server.snapshot().get_paths;
+    assert!(
+        get_paths
+            .iter()
+            .any(|path| path.ends_with("/ccswitch-db.sql")),
+        "db.sql should be fetched by its recorded name: {get_paths:?}"
+    );
+    assert!(
+        !get_paths.iter().any(|path| path.ends_with("/db.sql")),
+        "the default name must not be requested: {get_paths:?}"
+    );
+}
+
+fn diagnostic_statuses(report: &DiagnosticReport) -> Vec<(DiagnosticCheck, DiagnosticStatus)> {
+    report
+        .steps
+        .iter()
+        .map(|step| (step.check, step.status))
+        .collect()
+}
+
+#[test]
+fn diagnose_passes_every_check_against_healthy_server() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+    seed_local_provider();
+
+    let server = TestWebDavServer::start(ProbeReadback::Stored);
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+    WebDavSyncService::upload().expect("initial upload");
+
+    let report = WebDavSyncService::diagnose(true).expect("run diagnosis");
+    assert!(report.passed(), "unexpected failures: {report:?}");
+    assert_eq!(
+        diagnostic_statuses(&report),
+        vec![
+            (DiagnosticCheck::Settings, DiagnosticStatus::Pass),
+            (DiagnosticCheck::Credentials, DiagnosticStatus::Pass),
+            (DiagnosticCheck::Provider, DiagnosticStatus::Pass),
+            (DiagnosticCheck::RemoteDirectory, DiagnosticStatus::Pass),
+            (DiagnosticCheck::WriteTest, DiagnosticStatus::Pass),
+            (DiagnosticCheck::RemoteManifest, DiagnosticStatus::Pass),
+        ]
+    );
+    let manifest = report.steps.last().expect("manifest step");
+    assert!(
+        manifest
+            .detail
+            .as_deref()
+            .is_some_and(|detail| detail.contains("snapshot")),
+        "manifest step should describe the remote snapshot: {manifest:?}"
+    );
+}
+
+#[test]
+fn diagnose_reports_failing_write_test_and_keeps_checking() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+
+    let server = TestWebDavServer::start_with_config(ServerConfig {
+        reject_all_puts: true,
+        ..ServerConfig::for_readback(ProbeReadback::Stored)
+    });
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+
+    let report = WebDavSyncService::diagnose(true).expect("run diagnosis");
+    assert!(!report.passed());
+    assert_eq!(
+        diagnostic_statuses(&report),
+        vec![
+            (DiagnosticCheck::Settings, DiagnosticStatus::Pass),
+            (DiagnosticCheck::Credentials, DiagnosticStatus::Pass),
+            (DiagnosticCheck::Provider, DiagnosticStatus::Pass),
+            (DiagnosticCheck::RemoteDirectory, DiagnosticStatus::Pass),
+            (DiagnosticCheck::WriteTest, DiagnosticStatus::Fail),
+            (DiagnosticCheck::RemoteManifest, DiagnosticStatus::Pass),
+        ]
+    );
+    let manifest = report.steps.last().expect("manifest step");
+    assert!(
+        manifest
+            .detail
+            .as_deref()
+            .is_some_and(|detail| detail.contains("no manifest")),
+        "empty remote should be reported: {manifest:?}"
+    );
+}
+
+#[test]
+fn diagnose_stops_after_rejected_credentials_unless_asked_to_continue() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+
+    let server = TestWebDavServer::start_with_config(ServerConfig {
+        accepted_password: Some("another-secret"),
+        ..ServerConfig::for_readback(ProbeReadback::Stored)
+    });
+    set_webdav_sync_settings(Some(sample_settings(&server.base_url)))
+        .expect("save test WebDAV settings");
+
+    let report = WebDavSyncService::diagnose(false).expect("run diagnosis");
+    assert_eq!(
+        diagnostic_statuses(&report),
+        vec![
+            (DiagnosticCheck::Settings, DiagnosticStatus::Pass),
+            (DiagnosticCheck::Credentials, DiagnosticStatus::Fail),
+            (DiagnosticCheck::Provider, DiagnosticStatus::Skip),
+            (DiagnosticCheck::RemoteDirectory, DiagnosticStatus::Skip),
+            (DiagnosticCheck::WriteTest, DiagnosticStatus::Skip),
+            (DiagnosticCheck::RemoteManifest, DiagnosticStatus::Skip),
+        ]
+    );
+
+    let report = WebDavSyncService::diagnose(true).expect("run diagnosis");
+    let statuses = diagnostic_statuses(&report);
+    assert_eq!(
+        statuses[2],
+        (DiagnosticCheck::Provider, DiagnosticStatus::Pass),
+        "independent checks still run: {report:?}"
+    );
+    assert_eq!(
+        statuses[4],
+        (DiagnosticCheck::WriteTest, DiagnosticStatus::Skip),
+        "write test needs a usable remote directory: {report:?}"
+    );
+}
+
+#[test]
+fn diagnose_skips_remote_checks_when_not_configured() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+
+    let report = WebDavSyncService::diagnose(true).expect("run diagnosis");
+    assert_eq!(report.steps[0].check, DiagnosticCheck::Settings);
+    assert_eq!(report.steps[0].status, DiagnosticStatus::Fail);
+    assert!(report.steps[1..]
+        .iter()
+        .all(|step| step.status == DiagnosticStatus::Skip));
 }